@@ -0,0 +1,99 @@
+//! Significance levels.
+//!
+//! Every test takes its significance as a plain `f64` in `(0, 1)`; [`Alpha`] holds one that has been
+//! checked, offers the levels of the textbook tables and reads levels written as `0.05` or `5%`.
+
+use core::str::FromStr;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum AlphaError {
+    SignificanceInvalid,
+    Unparsable,
+}
+
+impl core::fmt::Display for AlphaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            AlphaError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            AlphaError::Unparsable => {
+                write!(f, "Significance must be a number such as 0.05 or 5%")
+            }
+        }
+    }
+}
+
+/// Significance level `α`, between 0 and 1 exclusively.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Alpha(f64);
+
+impl Alpha {
+    pub const P01: Alpha = Alpha(0.01);
+    pub const P05: Alpha = Alpha(0.05);
+    pub const P10: Alpha = Alpha(0.1);
+
+    pub fn new(significance: f64) -> Result<Self, AlphaError> {
+        if significance > 0. && significance < 1. {
+            Ok(Alpha(significance))
+        } else {
+            Err(AlphaError::SignificanceInvalid)
+        }
+    }
+
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    /// `1 - α`.
+    pub fn confidence(self) -> f64 {
+        1. - self.0
+    }
+}
+
+/// Checks that the significance lies in `(0, 1)`, reporting the caller's `error` otherwise.
+pub fn validate<E>(significance: f64, error: E) -> Result<(), E> {
+    Alpha::new(significance).map(|_| ()).map_err(|_| error)
+}
+
+/// Reads `0.05`, `5%` or `5 %`.
+impl FromStr for Alpha {
+    type Err = AlphaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let significance = match s.strip_suffix('%') {
+            Some(percents) => {
+                percents
+                    .trim_end()
+                    .parse::<f64>()
+                    .map_err(|_| AlphaError::Unparsable)?
+                    / 100.
+            }
+            None => s.parse::<f64>().map_err(|_| AlphaError::Unparsable)?,
+        };
+
+        Alpha::new(significance)
+    }
+}
+
+impl TryFrom<f64> for Alpha {
+    type Error = AlphaError;
+
+    fn try_from(significance: f64) -> Result<Self, Self::Error> {
+        Alpha::new(significance)
+    }
+}
+
+impl From<Alpha> for f64 {
+    fn from(alpha: Alpha) -> Self {
+        alpha.0
+    }
+}
+
+impl core::fmt::Display for Alpha {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
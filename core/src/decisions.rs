@@ -0,0 +1,276 @@
+//! Statistics of the tests and the decisions on their null hypotheses.
+
+use alloc::vec::Vec;
+
+use crate::alpha;
+use crate::numeric::{
+    compensated_sum, mean, unbiased_variance, weighted_biased_variance, weighted_mean,
+};
+use crate::quantiles::{QuantileProvider, standard_normal_cdf};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum DecisionError {
+    SignificanceInvalid,
+    SampleTooSmall,
+    LengthsMismatch,
+    TableInvalid,
+    QuantileUnavailable,
+}
+
+impl core::fmt::Display for DecisionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            DecisionError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            DecisionError::SampleTooSmall => {
+                write!(f, "Samples are too small for the test")
+            }
+            DecisionError::LengthsMismatch => {
+                write!(f, "Samples must have equal lengths")
+            }
+            DecisionError::TableInvalid => {
+                write!(
+                    f,
+                    "Table must be at least 2×2, rectangular, and have no empty rows or columns"
+                )
+            }
+            DecisionError::QuantileUnavailable => {
+                write!(
+                    f,
+                    "Quantile provider holds no quantile for these parameters"
+                )
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Decision {
+    pub statistic: f64,
+    pub critical_value: f64,
+    /// Whether the null hypothesis is accepted.
+    pub accepted: bool,
+}
+
+fn validate(significance: f64) -> Result<(), DecisionError> {
+    alpha::validate(significance, DecisionError::SignificanceInvalid)
+}
+
+/// Upper-tail decision: `H₀` is accepted while the statistic stays below the `(1 - significance)`-quantile.
+fn upper_tail(statistic: f64, critical_value: Option<f64>) -> Result<Decision, DecisionError> {
+    let critical_value = critical_value.ok_or(DecisionError::QuantileUnavailable)?;
+
+    Ok(Decision {
+        statistic,
+        critical_value,
+        accepted: statistic < critical_value,
+    })
+}
+
+/// `Σ (observed - expected)² / expected` over the classes.
+pub fn chi_squared_statistic(empirical_sample: &[f64], theoretical_sample: &[f64]) -> f64 {
+    compensated_sum(
+        empirical_sample
+            .iter()
+            .zip(theoretical_sample.iter())
+            .map(|(e, t)| (e - t) * (e - t) / t),
+    )
+}
+
+/// Frequencies of the random value ranges expected under the Normal distribution
+/// whose parameters are estimated over the middles of the ranges.
+pub fn normal_expected_frequencies(
+    random_value_ranges: &[(f64, f64)],
+    frequencies: &[f64],
+) -> Vec<f64> {
+    let middles: Vec<_> = random_value_ranges
+        .iter()
+        .map(|(x_1, x_2)| (x_2 + x_1) / 2.)
+        .collect();
+    let mean = weighted_mean(&middles, frequencies);
+    let std_dev = libm::sqrt(weighted_biased_variance(&middles, frequencies));
+    let sample_size = compensated_sum(frequencies.iter().copied());
+
+    random_value_ranges
+        .iter()
+        .map(|(x_1, x_2)| {
+            sample_size
+                * (standard_normal_cdf((x_2 - mean) / std_dev)
+                    - standard_normal_cdf((x_1 - mean) / std_dev))
+        })
+        .collect()
+}
+
+/// The `t` statistic of the difference of the means and its freedom degrees,
+/// pooled if `equal_variances` and by Welch–Satterthwaite otherwise.
+pub fn t_statistic(x_sample: &[f64], y_sample: &[f64], equal_variances: bool) -> (f64, f64) {
+    let (n_x, n_y) = (x_sample.len() as f64, y_sample.len() as f64);
+    let (x_usv, y_usv) = (unbiased_variance(x_sample), unbiased_variance(y_sample));
+    let mean_difference = mean(x_sample) - mean(y_sample);
+
+    if equal_variances {
+        let freedom_degrees = n_x + n_y - 2.;
+        let pooled_variance = ((n_x - 1.) * x_usv + (n_y - 1.) * y_usv) / freedom_degrees;
+        let t = mean_difference / libm::sqrt(pooled_variance * (1. / n_x + 1. / n_y));
+        (t, freedom_degrees)
+    } else {
+        let (x_term, y_term) = (x_usv / n_x, y_usv / n_y);
+        let freedom_degrees = (x_term + y_term) * (x_term + y_term)
+            / (x_term * x_term / (n_x - 1.) + y_term * y_term / (n_y - 1.));
+        let t = mean_difference / libm::sqrt(x_term + y_term);
+        (t, freedom_degrees)
+    }
+}
+
+/// Chi-Squared goodness of fit with `estimated_parameters` parameters of the theoretical distribution
+/// estimated from the sample.
+pub fn goodness_of_fit(
+    empirical_sample: &[f64],
+    theoretical_sample: &[f64],
+    estimated_parameters: usize,
+    significance: f64,
+    quantiles: &impl QuantileProvider,
+) -> Result<Decision, DecisionError> {
+    validate(significance)?;
+    if empirical_sample.len() != theoretical_sample.len() {
+        return Err(DecisionError::LengthsMismatch);
+    }
+    if empirical_sample.len() < estimated_parameters + 2 {
+        return Err(DecisionError::SampleTooSmall);
+    }
+
+    let freedom_degrees = (empirical_sample.len() - estimated_parameters - 1) as f64;
+    upper_tail(
+        chi_squared_statistic(empirical_sample, theoretical_sample),
+        quantiles.chi_squared(freedom_degrees, 1. - significance),
+    )
+}
+
+/// Normal Distribution Hypothesis over a grouped sample, with the mean and the standard deviation estimated.
+pub fn normality(
+    random_value_ranges: &[(f64, f64)],
+    frequencies: &[f64],
+    significance: f64,
+    quantiles: &impl QuantileProvider,
+) -> Result<Decision, DecisionError> {
+    if random_value_ranges.len() != frequencies.len() {
+        return Err(DecisionError::LengthsMismatch);
+    }
+
+    let theoretical_sample = normal_expected_frequencies(random_value_ranges, frequencies);
+    goodness_of_fit(frequencies, &theoretical_sample, 2, significance, quantiles)
+}
+
+/// Fisher–Snedecor test of equal variances with the larger variance in the numerator.
+pub fn same_variance(
+    x_sample: &[f64],
+    y_sample: &[f64],
+    significance: f64,
+    quantiles: &impl QuantileProvider,
+) -> Result<Decision, DecisionError> {
+    variance_ratio(x_sample, y_sample, 1., significance, quantiles)
+}
+
+/// Fisher–Snedecor test of `Var(X) / Var(Y) = ratio`: the variance of **X** is divided by the ratio,
+/// and the larger of the two variances is put into the numerator.
+pub fn variance_ratio(
+    x_sample: &[f64],
+    y_sample: &[f64],
+    ratio: f64,
+    significance: f64,
+    quantiles: &impl QuantileProvider,
+) -> Result<Decision, DecisionError> {
+    validate(significance)?;
+    if x_sample.len() < 2 || y_sample.len() < 2 {
+        return Err(DecisionError::SampleTooSmall);
+    }
+
+    let (x_usv, y_usv) = (
+        unbiased_variance(x_sample) / ratio,
+        unbiased_variance(y_sample),
+    );
+    let (n_x, n_y) = (x_sample.len() as f64, y_sample.len() as f64);
+    let (statistic, freedom_degrees_1, freedom_degrees_2) = if x_usv >= y_usv {
+        (x_usv / y_usv, n_x - 1., n_y - 1.)
+    } else {
+        (y_usv / x_usv, n_y - 1., n_x - 1.)
+    };
+
+    upper_tail(
+        statistic,
+        quantiles.fisher_snedecor(freedom_degrees_1, freedom_degrees_2, 1. - significance),
+    )
+}
+
+/// Two-sided t-test of equal means, Student's if `equal_variances` and Welch's otherwise.
+pub fn same_mean(
+    x_sample: &[f64],
+    y_sample: &[f64],
+    equal_variances: bool,
+    significance: f64,
+    quantiles: &impl QuantileProvider,
+) -> Result<Decision, DecisionError> {
+    validate(significance)?;
+    if x_sample.len() < 2 || y_sample.len() < 2 {
+        return Err(DecisionError::SampleTooSmall);
+    }
+
+    let (t, freedom_degrees) = t_statistic(x_sample, y_sample, equal_variances);
+    let critical_value = quantiles
+        .students_t(freedom_degrees, 1. - significance / 2.)
+        .ok_or(DecisionError::QuantileUnavailable)?;
+
+    Ok(Decision {
+        statistic: t,
+        critical_value,
+        accepted: t.abs() < critical_value,
+    })
+}
+
+/// Chi-Squared test of independence of the row and column classifications of a contingency table.
+pub fn independence(
+    table: &[Vec<f64>],
+    significance: f64,
+    quantiles: &impl QuantileProvider,
+) -> Result<Decision, DecisionError> {
+    validate(significance)?;
+    let columns = table.first().map_or(0, Vec::len);
+    if table.len() < 2 || columns < 2 || table.iter().any(|row| row.len() != columns) {
+        return Err(DecisionError::TableInvalid);
+    }
+
+    let row_totals: Vec<f64> = table
+        .iter()
+        .map(|row| compensated_sum(row.iter().copied()))
+        .collect();
+    let column_totals: Vec<f64> = (0..columns)
+        .map(|j| compensated_sum(table.iter().map(|row| row[j])))
+        .collect();
+    if row_totals
+        .iter()
+        .chain(column_totals.iter())
+        .any(|total| *total <= 0.)
+    {
+        return Err(DecisionError::TableInvalid);
+    }
+    let total = compensated_sum(row_totals.iter().copied());
+
+    let statistic = compensated_sum(table.iter().zip(row_totals.iter()).flat_map(
+        |(row, row_total)| {
+            row.iter()
+                .zip(column_totals.iter())
+                .map(move |(observed, column_total)| {
+                    let expected = row_total * column_total / total;
+                    (observed - expected) * (observed - expected) / expected
+                })
+        },
+    ));
+    let freedom_degrees = ((table.len() - 1) * (columns - 1)) as f64;
+
+    upper_tail(
+        statistic,
+        quantiles.chi_squared(freedom_degrees, 1. - significance),
+    )
+}
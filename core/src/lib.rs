@@ -0,0 +1,14 @@
+//! Core computations of the statistics problems for `no_std + alloc` targets, e.g. embedded data loggers
+//! doing on-device quality checks.
+//!
+//! The statistics and the decisions are computed here; the quantiles of the distributions are supplied by
+//! a [`QuantileProvider`](quantiles::QuantileProvider), since `statrs` needs `std`.
+
+#![no_std]
+
+extern crate alloc;
+
+pub mod alpha;
+pub mod decisions;
+pub mod numeric;
+pub mod quantiles;
@@ -0,0 +1,128 @@
+//! Numerically stable summation and moments.
+//!
+//! Naive accumulation loses precision on long samples and on values sharing a big offset
+//! (e.g. measurements around `1e9` differing in the last digits); these helpers keep the error
+//! independent of the sample length.
+
+fn square(x: f64) -> f64 {
+    x * x
+}
+
+/// Sum with Neumaier's variant of Kahan compensated summation.
+///
+/// An infinite or NaN sum is returned as it is, since its compensation is meaningless.
+pub fn compensated_sum<I: IntoIterator<Item = f64>>(values: I) -> f64 {
+    let (sum, compensation) =
+        values
+            .into_iter()
+            .fold((0., 0.), |(sum, compensation): (f64, f64), value| {
+                let next = sum + value;
+                let lost = if sum.abs() >= value.abs() {
+                    (sum - next) + value
+                } else {
+                    (value - next) + sum
+                };
+                (next, compensation + lost)
+            });
+
+    if sum.is_finite() {
+        sum + compensation
+    } else {
+        sum
+    }
+}
+
+pub fn mean(sample: &[f64]) -> f64 {
+    compensated_sum(sample.iter().copied()) / sample.len() as f64
+}
+
+/// Sum of squared deviations from the mean by the corrected two-pass algorithm.
+fn squared_deviations_sum(sample: &[f64]) -> f64 {
+    let mean = mean(sample);
+    let deviations_sum = compensated_sum(sample.iter().map(|x| x - mean));
+    let squares_sum = compensated_sum(sample.iter().map(|x| square(x - mean)));
+
+    squares_sum - square(deviations_sum) / sample.len() as f64
+}
+
+/// Variance with the `1 / n` normalization.
+pub fn biased_variance(sample: &[f64]) -> f64 {
+    squared_deviations_sum(sample) / sample.len() as f64
+}
+
+/// Variance with the `1 / (n - 1)` normalization.
+pub fn unbiased_variance(sample: &[f64]) -> f64 {
+    squared_deviations_sum(sample) / (sample.len() as f64 - 1.)
+}
+
+/// Mean of `values` weighted by frequencies `weights`.
+pub fn weighted_mean(values: &[f64], weights: &[f64]) -> f64 {
+    compensated_sum(values.iter().zip(weights.iter()).map(|(x, m)| m * x))
+        / compensated_sum(weights.iter().copied())
+}
+
+/// Variance with the `1 / n` normalization of `values` weighted by frequencies `weights`,
+/// by the corrected two-pass algorithm.
+pub fn weighted_biased_variance(values: &[f64], weights: &[f64]) -> f64 {
+    let n = compensated_sum(weights.iter().copied());
+    let mean = weighted_mean(values, weights);
+    let deviations_sum = compensated_sum(
+        values
+            .iter()
+            .zip(weights.iter())
+            .map(|(x, m)| m * (x - mean)),
+    );
+    let squares_sum = compensated_sum(
+        values
+            .iter()
+            .zip(weights.iter())
+            .map(|(x, m)| m * square(x - mean)),
+    );
+
+    (squares_sum - square(deviations_sum) / n) / n
+}
+
+/// Streaming mean and variance by Welford's algorithm, for samples not held in memory.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Welford {
+    count: usize,
+    mean: f64,
+    squared_deviations_sum: f64,
+}
+
+impl Welford {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.squared_deviations_sum += delta * (value - self.mean);
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    pub fn biased_variance(&self) -> f64 {
+        self.squared_deviations_sum / self.count as f64
+    }
+
+    pub fn unbiased_variance(&self) -> f64 {
+        self.squared_deviations_sum / (self.count as f64 - 1.)
+    }
+}
+
+impl Extend<f64> for Welford {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, values: I) {
+        for value in values {
+            self.push(value);
+        }
+    }
+}
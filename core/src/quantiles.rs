@@ -0,0 +1,23 @@
+//! Quantiles of the distributions the decisions compare their statistics with.
+//!
+//! A provider may compute them, as the `statrs`-backed provider of the `std` crate does,
+//! or look them up in tables stored on the device.
+
+/// Each method returns the `p`-quantile, i.e. `x` such that `P(X <= x) = p`,
+/// or `None` if the provider cannot give it for these parameters.
+pub trait QuantileProvider {
+    fn standard_normal(&self, p: f64) -> Option<f64>;
+    fn chi_squared(&self, freedom_degrees: f64, p: f64) -> Option<f64>;
+    fn students_t(&self, freedom_degrees: f64, p: f64) -> Option<f64>;
+    fn fisher_snedecor(
+        &self,
+        numerator_freedom_degrees: f64,
+        denominator_freedom_degrees: f64,
+        p: f64,
+    ) -> Option<f64>;
+}
+
+/// Distribution function of the Standard Normal distribution, which needs no provider.
+pub fn standard_normal_cdf(x: f64) -> f64 {
+    0.5 * libm::erfc(-x / core::f64::consts::SQRT_2)
+}
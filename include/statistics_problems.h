@@ -0,0 +1,57 @@
+/* C interface of the statistics-problems crate, built with the `cdylib` feature. */
+
+#ifndef STATISTICS_PROBLEMS_H
+#define STATISTICS_PROBLEMS_H
+
+#include <stdbool.h>
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define SP_OK 0
+#define SP_ERROR_NULL_POINTER 1
+#define SP_ERROR_LENGTH_MISMATCH 2
+/* The solver rejected the problem, e.g. for an invalid significance or a too small sample. */
+#define SP_ERROR_INVALID_PROBLEM 3
+
+typedef struct SpOutcome {
+    double statistic;
+    double critical_value;
+    /* Whether the null hypothesis is accepted. */
+    bool accepted;
+} SpOutcome;
+
+/* Copies the NUL-terminated message of the last error of the calling thread, truncated to
+ * `capacity - 1` bytes, and returns the length of the whole message. */
+size_t sp_last_error_message(char *buffer, size_t capacity);
+
+int sp_ndh_complete(const double *empirical_sample, const double *theoretical_sample,
+                    size_t classes, double significance, SpOutcome *out);
+
+/* `edges` holds the `classes + 1` boundaries of the random value ranges. */
+int sp_ndh_grouped(const double *edges, const double *frequencies, size_t classes,
+                   double significance, SpOutcome *out);
+
+/* The raw sample is grouped by Sturges' rule. */
+int sp_ndh_raw(const double *sample, size_t len, double significance, SpOutcome *out);
+
+int sp_same_variance(const double *x_sample, size_t x_len, const double *y_sample, size_t y_len,
+                     double significance, SpOutcome *out);
+
+int sp_same_mean(const double *x_sample, size_t x_len, const double *y_sample, size_t y_len,
+                 double significance, bool equal_variances, SpOutcome *out);
+
+int sp_mann_whitney(const double *x_sample, size_t x_len, const double *y_sample, size_t y_len,
+                    double significance, SpOutcome *out);
+
+/* `table` holds the `rows * columns` frequencies row by row. */
+int sp_independence(const double *table, size_t rows, size_t columns, double significance,
+                    SpOutcome *out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* STATISTICS_PROBLEMS_H */
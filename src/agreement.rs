@@ -0,0 +1,352 @@
+//! Algorithms for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *categorical ratings* of the same subjects by several raters.
+//! **To figure out**: Is it appropriate to **assume** that the raters agree no better than by chance?
+//!
+//! Cohen's `κ` compares two raters through their agreement table, weighted `κ` gives partial credit
+//! to near misses of ordered categories, and Fleiss' `κ` extends the comparison to many raters.
+
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::alpha;
+use crate::hypotheses::{Alternative, Hypotheses};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum AgreementError {
+    SignificanceInvalid,
+    TableInvalid,
+    RatingsInvalid,
+    AgreementDegenerate,
+}
+
+impl std::fmt::Display for AgreementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AgreementError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            AgreementError::TableInvalid => {
+                write!(
+                    f,
+                    "Table must be square with at least 2 categories and a positive total"
+                )
+            }
+            AgreementError::RatingsInvalid => {
+                write!(
+                    f,
+                    "Every subject must be rated by the same number of raters, at least 2, into known categories"
+                )
+            }
+            AgreementError::AgreementDegenerate => {
+                write!(
+                    f,
+                    "Chance agreement is complete, so agreement beyond chance is undefined"
+                )
+            }
+        }
+    }
+}
+
+/// Agreement weights `wᵢⱼ` of the categories `i` and `j` out of `c` ordered categories.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum KappaWeights {
+    /// `wᵢⱼ = 1` if `i = j` and `0` otherwise, which is Cohen's `κ`.
+    Unweighted,
+    /// `wᵢⱼ = 1 - |i - j| / (c - 1)`.
+    Linear,
+    /// `wᵢⱼ = 1 - (i - j)² / (c - 1)²`.
+    Quadratic,
+}
+
+impl KappaWeights {
+    fn weight(&self, i: usize, j: usize, categories: usize) -> f64 {
+        let distance = i.abs_diff(j) as f64 / (categories - 1) as f64;
+        match self {
+            KappaWeights::Unweighted => f64::from(i == j),
+            KappaWeights::Linear => 1. - distance,
+            KappaWeights::Quadratic => 1. - distance.powi(2),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct KappaOutcome {
+    /// `Pₒ`, the observed proportion of agreement.
+    pub observed_agreement: f64,
+    /// `Pₑ`, the proportion of agreement expected by chance.
+    pub expected_agreement: f64,
+    /// `κ = (Pₒ - Pₑ) / (1 - Pₑ)`.
+    pub kappa: f64,
+    /// Large-sample standard error of `κ`, if known for the statistic.
+    pub standard_error: Option<f64>,
+    /// Confidence interval for `κ` at the `1 - significance` level, if the standard error is known.
+    pub confidence_interval: Option<(f64, f64)>,
+    /// `z = κ / SE₀` with the standard error `SE₀` under `κ = 0`.
+    pub z_observed: f64,
+    pub z_critical_value: f64,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    /// Whether the hypothesis of chance agreement is accepted.
+    pub accepted: bool,
+}
+
+/// Cohen's `κ` over the agreement table of two raters, weighted for ordered categories.
+pub struct CohenKappa {
+    table: Vec<Vec<f64>>,
+    weights: KappaWeights,
+    significance: f64,
+}
+
+impl CohenKappa {
+    /// `table[i][j]` counts the subjects put into category `i` by the first rater and into `j` by the second.
+    pub fn new(table: &[Vec<f64>], significance: f64) -> Result<Self, AgreementError> {
+        let categories = table.len();
+        if categories < 2
+            || table.iter().any(|row| row.len() != categories)
+            || table.iter().flatten().any(|count| *count < 0.)
+            || table.iter().flatten().sum::<f64>() <= 0.
+        {
+            return Err(AgreementError::TableInvalid);
+        }
+
+        validate(significance)?;
+
+        Ok(Self {
+            table: table.to_owned(),
+            weights: KappaWeights::Unweighted,
+            significance,
+        })
+    }
+
+    /// Builds the agreement table from the categories `0..categories` assigned by each rater to each subject.
+    pub fn from_ratings(
+        first: &[usize],
+        second: &[usize],
+        categories: usize,
+        significance: f64,
+    ) -> Result<Self, AgreementError> {
+        if first.len() != second.len()
+            || first
+                .iter()
+                .chain(second.iter())
+                .any(|category| *category >= categories)
+        {
+            return Err(AgreementError::RatingsInvalid);
+        }
+
+        let mut table = vec![vec![0.; categories]; categories];
+        for (i, j) in first.iter().zip(second) {
+            table[*i][*j] += 1.;
+        }
+
+        Self::new(&table, significance)
+    }
+
+    /// Weights of the disagreements, unweighted by default.
+    pub fn with_weights(mut self, weights: KappaWeights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            "κ = 0, the raters agree by chance",
+            "κ > 0, the raters agree beyond chance",
+            Alternative::Greater,
+        )
+    }
+
+    pub fn solve(&self) -> Result<bool, AgreementError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    /// The standard errors are those of Fleiss, Cohen and Everitt for the general and the null case.
+    pub fn outcome(&self) -> Result<KappaOutcome, AgreementError> {
+        let categories = self.table.len();
+        let total = self.table.iter().flatten().sum::<f64>();
+        let p = |i: usize, j: usize| self.table[i][j] / total;
+        let w = |i: usize, j: usize| self.weights.weight(i, j, categories);
+
+        let row_proportions: Vec<f64> = (0..categories)
+            .map(|i| (0..categories).map(|j| p(i, j)).sum())
+            .collect();
+        let column_proportions: Vec<f64> = (0..categories)
+            .map(|j| (0..categories).map(|i| p(i, j)).sum())
+            .collect();
+        let cells = || (0..categories).flat_map(|i| (0..categories).map(move |j| (i, j)));
+
+        let observed_agreement = cells().map(|(i, j)| w(i, j) * p(i, j)).sum::<f64>();
+        let expected_agreement = cells()
+            .map(|(i, j)| w(i, j) * row_proportions[i] * column_proportions[j])
+            .sum::<f64>();
+        if expected_agreement >= 1. {
+            return Err(AgreementError::AgreementDegenerate);
+        }
+        let kappa = (observed_agreement - expected_agreement) / (1. - expected_agreement);
+
+        // `w̄ᵢ.` and `w̄.ⱼ`, the weights averaged over the other rater's categories.
+        let row_weights: Vec<f64> = (0..categories)
+            .map(|i| {
+                (0..categories)
+                    .map(|j| column_proportions[j] * w(i, j))
+                    .sum()
+            })
+            .collect();
+        let column_weights: Vec<f64> = (0..categories)
+            .map(|j| (0..categories).map(|i| row_proportions[i] * w(i, j)).sum())
+            .collect();
+        let scale = total * (1. - expected_agreement).powi(2);
+
+        let variance = (cells()
+            .map(|(i, j)| {
+                p(i, j) * (w(i, j) - (row_weights[i] + column_weights[j]) * (1. - kappa)).powi(2)
+            })
+            .sum::<f64>()
+            - (kappa - expected_agreement * (1. - kappa)).powi(2))
+            / scale;
+        let null_variance = (cells()
+            .map(|(i, j)| {
+                row_proportions[i]
+                    * column_proportions[j]
+                    * (w(i, j) - (row_weights[i] + column_weights[j])).powi(2)
+            })
+            .sum::<f64>()
+            - expected_agreement.powi(2))
+            / scale;
+
+        Ok(decide(
+            observed_agreement,
+            expected_agreement,
+            Some(variance.max(0.).sqrt()),
+            null_variance.sqrt(),
+            self.hypotheses(),
+            self.significance,
+        ))
+    }
+}
+
+/// Fleiss' `κ` for many raters, who need not be the same for every subject.
+pub struct FleissKappa {
+    counts: Vec<Vec<f64>>,
+    significance: f64,
+}
+
+impl FleissKappa {
+    /// `counts[i][j]` is the number of raters who put subject `i` into category `j`;
+    /// every subject must be rated by the same number of raters.
+    pub fn new(counts: &[Vec<f64>], significance: f64) -> Result<Self, AgreementError> {
+        let categories = counts.first().map_or(0, Vec::len);
+        if counts.is_empty()
+            || categories < 2
+            || counts.iter().any(|row| row.len() != categories)
+            || counts.iter().flatten().any(|count| *count < 0.)
+        {
+            return Err(AgreementError::TableInvalid);
+        }
+
+        let raters = counts[0].iter().sum::<f64>();
+        if raters < 2. || counts.iter().any(|row| row.iter().sum::<f64>() != raters) {
+            return Err(AgreementError::RatingsInvalid);
+        }
+
+        validate(significance)?;
+
+        Ok(Self {
+            counts: counts.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            "κ = 0, the raters agree by chance",
+            "κ > 0, the raters agree beyond chance",
+            Alternative::Greater,
+        )
+    }
+
+    pub fn solve(&self) -> Result<bool, AgreementError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    /// Only the null standard error of Fleiss, Nee and Landis is known in closed form,
+    /// so the outcome holds no confidence interval.
+    pub fn outcome(&self) -> Result<KappaOutcome, AgreementError> {
+        let subjects = self.counts.len() as f64;
+        let raters = self.counts[0].iter().sum::<f64>();
+        let categories = self.counts[0].len();
+
+        let proportions: Vec<f64> = (0..categories)
+            .map(|j| self.counts.iter().map(|row| row[j]).sum::<f64>() / (subjects * raters))
+            .collect();
+        let observed_agreement = self
+            .counts
+            .iter()
+            .map(|row| {
+                (row.iter().map(|count| count.powi(2)).sum::<f64>() - raters)
+                    / (raters * (raters - 1.))
+            })
+            .sum::<f64>()
+            / subjects;
+        let expected_agreement = proportions.iter().map(|p| p.powi(2)).sum::<f64>();
+        if expected_agreement >= 1. {
+            return Err(AgreementError::AgreementDegenerate);
+        }
+
+        let spread = proportions.iter().map(|p| p * (1. - p)).sum::<f64>();
+        let null_variance = 2. / (subjects * raters * (raters - 1.))
+            * (spread.powi(2)
+                - proportions
+                    .iter()
+                    .map(|p| p * (1. - p) * (1. - 2. * p))
+                    .sum::<f64>())
+            / spread.powi(2);
+
+        Ok(decide(
+            observed_agreement,
+            expected_agreement,
+            None,
+            null_variance.sqrt(),
+            self.hypotheses(),
+            self.significance,
+        ))
+    }
+}
+
+fn validate(significance: f64) -> Result<(), AgreementError> {
+    alpha::validate(significance, AgreementError::SignificanceInvalid)?;
+
+    Ok(())
+}
+
+/// The test is one-sided, since only agreement beyond chance is of interest.
+fn decide(
+    observed_agreement: f64,
+    expected_agreement: f64,
+    standard_error: Option<f64>,
+    null_standard_error: f64,
+    hypotheses: Hypotheses,
+    significance: f64,
+) -> KappaOutcome {
+    let standard_normal = Normal::standard();
+    let kappa = (observed_agreement - expected_agreement) / (1. - expected_agreement);
+    let z_observed = kappa / null_standard_error;
+    let z_critical_value = standard_normal.inverse_cdf(1. - significance);
+
+    let margin = standard_normal.inverse_cdf(1. - significance / 2.);
+    KappaOutcome {
+        observed_agreement,
+        expected_agreement,
+        kappa,
+        standard_error,
+        confidence_interval: standard_error.map(|se| (kappa - margin * se, kappa + margin * se)),
+        z_observed,
+        z_critical_value,
+        p_value: standard_normal.sf(z_observed),
+        hypotheses,
+        accepted: z_observed < z_critical_value,
+    }
+}
@@ -0,0 +1,8 @@
+//! Significance levels.
+//!
+//! [`Alpha`] holds a checked significance, offers the levels of the textbook tables and reads levels
+//! written as `0.05` or `5%`; every constructor of the crate checks its significance through [`validate`].
+//!
+//! The type lives in the `no_std` core crate and is re-exported here.
+
+pub use statistics_problems_core::alpha::*;
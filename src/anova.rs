@@ -0,0 +1,1046 @@
+//! Algorithms for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *samples of `k` random variables **X₁**, …, **Xₖ***.
+//! **To figure out**: Is it appropriate to **assume** `E(X₁) = … = E(Xₖ)`?
+//!
+//! The classic one-way ANOVA needs equal variances, which Levene's test checks.
+//! When Levene's test rejects them, Welch's ANOVA compares the means without that assumption,
+//! and the Games–Howell procedure finds the pairs of groups whose means differ.
+//! ANCOVA compares the means after adjusting them for a continuous covariate.
+//! Under the random-effects model the groups are sampled, and the variance between them is estimated instead.
+//! The repeated-measures ANOVA compares conditions measured on the same subjects
+//! and underlies the intraclass correlations of ratings.
+
+use statistics_problems_core::quantiles::standard_normal_cdf;
+use statrs::distribution::{ContinuousCDF, FisherSnedecor};
+use statrs::function::gamma::ln_gamma;
+
+use crate::alpha;
+use crate::bayes::simpson;
+use crate::descriptive::median;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+use crate::numeric::{mean, unbiased_variance};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum AnovaError {
+    SignificanceInvalid,
+    GroupsCountInvalid,
+    GroupTooSmall,
+    GroupDegenerate,
+    FreedomDegreesInvalid,
+    LengthsMismatch,
+    CovariateDegenerate,
+    ConditionsCountMismatch,
+    ValueMissing,
+}
+
+impl std::fmt::Display for AnovaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AnovaError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            AnovaError::GroupsCountInvalid => {
+                write!(f, "At least two groups are required")
+            }
+            AnovaError::GroupTooSmall => {
+                write!(f, "Each group must contain at least two values")
+            }
+            AnovaError::GroupDegenerate => {
+                write!(f, "Each group must contain at least two distinct values")
+            }
+            AnovaError::FreedomDegreesInvalid => {
+                write!(
+                    f,
+                    "Freedom Degrees led to fail in initialization of Fisher-Snedecor"
+                )
+            }
+            AnovaError::LengthsMismatch => {
+                write!(
+                    f,
+                    "Each group must have as many covariate values as responses"
+                )
+            }
+            AnovaError::CovariateDegenerate => {
+                write!(
+                    f,
+                    "Covariate must take at least two distinct values in each group"
+                )
+            }
+            AnovaError::ConditionsCountMismatch => {
+                write!(f, "Each subject must be measured under every condition")
+            }
+            AnovaError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AnovaOutcome {
+    /// Means of the groups, or of the absolute deviations from the group medians in Levene's test,
+    /// the adjusted means in ANCOVA, the slopes of the groups in the homogeneity-of-slopes check,
+    /// or the means of the conditions, or of the subjects in the tests of the intraclass correlations.
+    pub means: Vec<f64>,
+    /// Freedom degrees of the numerator and of the denominator.
+    pub freedom_degrees: (f64, f64),
+    pub fisher_snedecor_observed: f64,
+    pub fisher_snedecor_critical_value: f64,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+/// The classic one-way ANOVA, which assumes equal variances of the groups.
+pub struct OneWayAnova {
+    groups: Vec<Vec<f64>>,
+    significance: f64,
+}
+
+impl OneWayAnova {
+    pub fn new(groups: &[Vec<f64>], significance: f64) -> Result<Self, AnovaError> {
+        validate(groups, significance)?;
+
+        Ok(Self {
+            groups: groups.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        mean_hypotheses()
+    }
+
+    /// Returns the `F` statistic and its freedom degrees.
+    pub fn statistic(&self) -> Result<(f64, (f64, f64)), AnovaError> {
+        classic_f(&self.groups)
+    }
+
+    pub fn solve(&self) -> Result<bool, AnovaError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<AnovaOutcome, AnovaError> {
+        let (f_observed, freedom_degrees) = self.statistic()?;
+        let means = self.groups.iter().map(|group| mean(group)).collect();
+
+        decide(
+            means,
+            f_observed,
+            freedom_degrees,
+            self.hypotheses(),
+            self.significance,
+        )
+    }
+}
+
+/// Levene's test in the Brown–Forsythe form, which measures deviations from the group medians.
+pub struct LeveneHypothesis {
+    deviations: Vec<Vec<f64>>,
+    significance: f64,
+}
+
+impl LeveneHypothesis {
+    pub fn new(groups: &[Vec<f64>], significance: f64) -> Result<Self, AnovaError> {
+        validate(groups, significance)?;
+
+        let deviations = groups
+            .iter()
+            .map(|group| {
+                let median = median(group);
+                group.iter().map(|x| (x - median).abs()).collect()
+            })
+            .collect();
+
+        Ok(Self {
+            deviations,
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            "σ²(X₁) = … = σ²(Xₖ)",
+            "σ²(Xᵢ) ≠ σ²(Xⱼ) for some i, j",
+            Alternative::Greater,
+        )
+    }
+
+    /// The classic one-way `F` statistic over the absolute deviations `|xᵢⱼ - medᵢ|`.
+    pub fn statistic(&self) -> Result<(f64, (f64, f64)), AnovaError> {
+        classic_f(&self.deviations)
+    }
+
+    /// Whether the hypothesis of equal variances is accepted.
+    pub fn solve(&self) -> Result<bool, AnovaError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<AnovaOutcome, AnovaError> {
+        let (f_observed, freedom_degrees) = self.statistic()?;
+        let means = self.deviations.iter().map(|group| mean(group)).collect();
+
+        decide(
+            means,
+            f_observed,
+            freedom_degrees,
+            self.hypotheses(),
+            self.significance,
+        )
+    }
+}
+
+/// Welch's ANOVA weighs each group by `wᵢ = nᵢ / sᵢ²`, so the variances may differ.
+pub struct WelchAnova {
+    groups: Vec<Vec<f64>>,
+    significance: f64,
+}
+
+impl WelchAnova {
+    pub fn new(groups: &[Vec<f64>], significance: f64) -> Result<Self, AnovaError> {
+        validate(groups, significance)?;
+
+        if groups.iter().any(|group| unbiased_variance(group) == 0.) {
+            return Err(AnovaError::GroupDegenerate);
+        }
+
+        Ok(Self {
+            groups: groups.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        mean_hypotheses()
+    }
+
+    /// Returns the `F` statistic and its freedom degrees.
+    ///
+    /// `F = Σ wᵢ (x̄ᵢ - x̄_w)² / (k - 1) / (1 + 2 (k - 2) / (k² - 1) · Λ)`
+    /// with `Λ = Σ (1 - wᵢ / W)² / (nᵢ - 1)`, and the denominator freedom degrees are `(k² - 1) / (3 Λ)`.
+    pub fn statistic(&self) -> (f64, (f64, f64)) {
+        let k = self.groups.len() as f64;
+        let sizes: Vec<_> = self.groups.iter().map(|group| group.len() as f64).collect();
+        let means: Vec<_> = self.groups.iter().map(|group| mean(group)).collect();
+        let weights: Vec<_> = self
+            .groups
+            .iter()
+            .zip(&sizes)
+            .map(|(group, n)| n / unbiased_variance(group))
+            .collect();
+
+        let weights_sum = weights.iter().sum::<f64>();
+        let weighted_mean = weights
+            .iter()
+            .zip(&means)
+            .map(|(w, x_mean)| w * x_mean)
+            .sum::<f64>()
+            / weights_sum;
+
+        let between = weights
+            .iter()
+            .zip(&means)
+            .map(|(w, x_mean)| w * (x_mean - weighted_mean).powi(2))
+            .sum::<f64>()
+            / (k - 1.);
+        let lambda = weights
+            .iter()
+            .zip(&sizes)
+            .map(|(w, n)| (1. - w / weights_sum).powi(2) / (n - 1.))
+            .sum::<f64>();
+
+        let f_observed = between / (1. + 2. * (k - 2.) / (k * k - 1.) * lambda);
+        (f_observed, (k - 1., (k * k - 1.) / (3. * lambda)))
+    }
+
+    pub fn solve(&self) -> Result<bool, AnovaError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    /// The post-hoc comparisons to run when the means are not all equal.
+    pub fn games_howell(&self) -> GamesHowell {
+        GamesHowell {
+            groups: self.groups.clone(),
+            significance: self.significance,
+        }
+    }
+
+    pub fn outcome(&self) -> Result<AnovaOutcome, AnovaError> {
+        let (f_observed, freedom_degrees) = self.statistic();
+        let means = self.groups.iter().map(|group| mean(group)).collect();
+
+        decide(
+            means,
+            f_observed,
+            freedom_degrees,
+            self.hypotheses(),
+            self.significance,
+        )
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VarianceComponentsOutcome {
+    /// `σ²_between = (MS_between - MS_within) / n₀`, truncated at zero.
+    pub between_variance: f64,
+    /// `σ²_within = MS_within`.
+    pub within_variance: f64,
+    /// Effective group size `n₀ = (N - Σ nᵢ² / N) / (k - 1)`, the common size of balanced groups.
+    pub effective_group_size: f64,
+    /// `ρ = σ²_between / (σ²_between + σ²_within)`, the share of the variance that lies between the groups.
+    pub intraclass_correlation: f64,
+    /// Confidence interval for `ρ` at the `1 - significance` level, exact for balanced groups.
+    pub intraclass_correlation_interval: (f64, f64),
+    /// The `F`-test of `σ²_between = 0`.
+    pub test: AnovaOutcome,
+}
+
+/// One-way ANOVA under the random-effects model `xᵢⱼ = μ + aᵢ + εᵢⱼ` with `aᵢ ~ N(0, σ²_between)`
+/// and `εᵢⱼ ~ N(0, σ²_within)`, where the groups are a sample of a larger population of groups.
+pub struct RandomEffectsAnova {
+    groups: Vec<Vec<f64>>,
+    significance: f64,
+}
+
+impl RandomEffectsAnova {
+    pub fn new(groups: &[Vec<f64>], significance: f64) -> Result<Self, AnovaError> {
+        validate(groups, significance)?;
+
+        Ok(Self {
+            groups: groups.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new("σ²_between = 0", "σ²_between > 0", Alternative::Greater)
+    }
+
+    /// Whether the hypothesis of no variance between the groups is accepted.
+    pub fn solve(&self) -> Result<bool, AnovaError> {
+        Ok(self.outcome()?.test.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<VarianceComponentsOutcome, AnovaError> {
+        let MeanSquares {
+            between: ms_between,
+            within: ms_within,
+            freedom_degrees,
+        } = mean_squares(&self.groups)?;
+        let k = self.groups.len() as f64;
+        let total_size = self.groups.iter().map(Vec::len).sum::<usize>() as f64;
+        let effective_group_size = (total_size
+            - self
+                .groups
+                .iter()
+                .map(|group| (group.len() as f64).powi(2))
+                .sum::<f64>()
+                / total_size)
+            / (k - 1.);
+
+        let between_variance = ((ms_between - ms_within) / effective_group_size).max(0.);
+        let f_observed = ms_between / ms_within;
+
+        // `F / (σ²_within + n₀ σ²_between) · σ²_within ~ F(k - 1, N - k)` bounds `ρ`.
+        let fisher_snedecor_dist = FisherSnedecor::new(freedom_degrees.0, freedom_degrees.1)
+            .map_err(|_| AnovaError::FreedomDegreesInvalid)?;
+        let correlation = |f: f64| ((f - 1.) / (f + effective_group_size - 1.)).max(0.);
+        let intraclass_correlation_interval = (
+            correlation(f_observed / fisher_snedecor_dist.inverse_cdf(1. - self.significance / 2.)),
+            correlation(f_observed / fisher_snedecor_dist.inverse_cdf(self.significance / 2.)),
+        );
+
+        let means = self.groups.iter().map(|group| mean(group)).collect();
+        Ok(VarianceComponentsOutcome {
+            between_variance,
+            within_variance: ms_within,
+            effective_group_size,
+            intraclass_correlation: between_variance / (between_variance + ms_within),
+            intraclass_correlation_interval,
+            test: decide(
+                means,
+                f_observed,
+                freedom_degrees,
+                self.hypotheses(),
+                self.significance,
+            )?,
+        })
+    }
+}
+
+/// One-way repeated-measures ANOVA over a subjects × conditions table, which removes the variance between subjects
+/// from the error of the comparison.
+pub struct RepeatedMeasuresAnova {
+    subjects: Vec<Vec<f64>>,
+    significance: f64,
+}
+
+impl RepeatedMeasuresAnova {
+    /// `subjects[i][j]` is the measurement of subject `i` under condition `j`.
+    pub fn new(subjects: &[Vec<f64>], significance: f64) -> Result<Self, AnovaError> {
+        validate_table(subjects, significance)?;
+
+        Ok(Self {
+            subjects: subjects.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            "E(X₁) = … = E(Xₖ) under all conditions",
+            "E(Xᵢ) ≠ E(Xⱼ) for some conditions i, j",
+            Alternative::Greater,
+        )
+    }
+
+    /// Returns `F = MS_conditions / MS_residual` and its freedom degrees `k - 1` and `(n - 1)(k - 1)`.
+    pub fn statistic(&self) -> Result<(f64, (f64, f64)), AnovaError> {
+        let mean_squares = two_way_mean_squares(&self.subjects)?;
+        let (n, k) = mean_squares.size;
+
+        Ok((
+            mean_squares.columns / mean_squares.residual,
+            (k - 1., (n - 1.) * (k - 1.)),
+        ))
+    }
+
+    pub fn solve(&self) -> Result<bool, AnovaError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<AnovaOutcome, AnovaError> {
+        let (f_observed, freedom_degrees) = self.statistic()?;
+        let conditions = self.subjects[0].len();
+        let means = (0..conditions)
+            .map(|j| {
+                self.subjects.iter().map(|subject| subject[j]).sum::<f64>()
+                    / self.subjects.len() as f64
+            })
+            .collect();
+
+        decide(
+            means,
+            f_observed,
+            freedom_degrees,
+            self.hypotheses(),
+            self.significance,
+        )
+    }
+}
+
+/// Intraclass correlation models in the notation of Shrout and Fleiss, each for a single rating.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum IccModel {
+    /// `ICC(1,1)`: every subject is rated by different raters, sampled at random.
+    OneWayRandom,
+    /// `ICC(2,1)`: all subjects are rated by the same raters, sampled at random; absolute agreement.
+    TwoWayRandom,
+    /// `ICC(3,1)`: all subjects are rated by the same fixed raters; consistency.
+    TwoWayMixed,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct IccOutcome {
+    pub model: IccModel,
+    pub coefficient: f64,
+    /// Confidence interval for the coefficient at the `1 - significance` level.
+    pub confidence_interval: (f64, f64),
+    /// The `F`-test of a zero intraclass correlation.
+    pub test: AnovaOutcome,
+}
+
+/// Reliability of ratings over a subjects × raters table.
+pub struct IntraclassCorrelation {
+    subjects: Vec<Vec<f64>>,
+    significance: f64,
+}
+
+impl IntraclassCorrelation {
+    /// `subjects[i][j]` is the rating of subject `i` by rater `j`.
+    pub fn new(subjects: &[Vec<f64>], significance: f64) -> Result<Self, AnovaError> {
+        validate_table(subjects, significance)?;
+
+        Ok(Self {
+            subjects: subjects.to_owned(),
+            significance,
+        })
+    }
+
+    /// `MS_rows`, `MS_columns`, `MS_residual` and `MS_within` below are the mean squares of the subjects,
+    /// of the raters, of the two-way residual and within the subjects.
+    pub fn outcome(&self, model: IccModel) -> Result<IccOutcome, AnovaError> {
+        let TwoWayMeanSquares {
+            rows,
+            columns,
+            residual,
+            within_rows,
+            size: (n, k),
+        } = two_way_mean_squares(&self.subjects)?;
+        let upper_quantile = |freedom_degrees_1: f64, freedom_degrees_2: f64| {
+            FisherSnedecor::new(freedom_degrees_1, freedom_degrees_2)
+                .map(|dist| dist.inverse_cdf(1. - self.significance / 2.))
+                .map_err(|_| AnovaError::FreedomDegreesInvalid)
+        };
+
+        let (coefficient, confidence_interval, f_observed, freedom_degrees) = match model {
+            IccModel::OneWayRandom => {
+                let freedom_degrees = (n - 1., n * (k - 1.));
+                let f_observed = rows / within_rows;
+                let f_lower = f_observed / upper_quantile(freedom_degrees.0, freedom_degrees.1)?;
+                let f_upper = f_observed * upper_quantile(freedom_degrees.1, freedom_degrees.0)?;
+                (
+                    (rows - within_rows) / (rows + (k - 1.) * within_rows),
+                    (
+                        (f_lower - 1.) / (f_lower + k - 1.),
+                        (f_upper - 1.) / (f_upper + k - 1.),
+                    ),
+                    f_observed,
+                    freedom_degrees,
+                )
+            }
+            IccModel::TwoWayRandom => {
+                let freedom_degrees = (n - 1., (n - 1.) * (k - 1.));
+                let coefficient =
+                    (rows - residual) / (rows + (k - 1.) * residual + k * (columns - residual) / n);
+
+                // Satterthwaite's freedom degrees for the combination of `MS_columns` and `MS_residual`.
+                let a = k * coefficient / (n * (1. - coefficient));
+                let b = 1. + k * coefficient * (n - 1.) / (n * (1. - coefficient));
+                let v = (a * columns + b * residual).powi(2)
+                    / ((a * columns).powi(2) / (k - 1.)
+                        + (b * residual).powi(2) / freedom_degrees.1);
+                let f_lower = upper_quantile(n - 1., v)?;
+                let f_upper = upper_quantile(v, n - 1.)?;
+                let denominator = k * columns + (k * n - k - n) * residual;
+                (
+                    coefficient,
+                    (
+                        n * (rows - f_lower * residual) / (f_lower * denominator + n * rows),
+                        n * (f_upper * rows - residual) / (denominator + n * f_upper * rows),
+                    ),
+                    rows / residual,
+                    freedom_degrees,
+                )
+            }
+            IccModel::TwoWayMixed => {
+                let freedom_degrees = (n - 1., (n - 1.) * (k - 1.));
+                let f_observed = rows / residual;
+                let f_lower = f_observed / upper_quantile(freedom_degrees.0, freedom_degrees.1)?;
+                let f_upper = f_observed * upper_quantile(freedom_degrees.1, freedom_degrees.0)?;
+                (
+                    (rows - residual) / (rows + (k - 1.) * residual),
+                    (
+                        (f_lower - 1.) / (f_lower + k - 1.),
+                        (f_upper - 1.) / (f_upper + k - 1.),
+                    ),
+                    f_observed,
+                    freedom_degrees,
+                )
+            }
+        };
+
+        let means = self.subjects.iter().map(|subject| mean(subject)).collect();
+        Ok(IccOutcome {
+            model,
+            coefficient,
+            confidence_interval,
+            test: decide(
+                means,
+                f_observed,
+                freedom_degrees,
+                Hypotheses::new("ρ = 0", "ρ > 0", Alternative::Greater),
+                self.significance,
+            )?,
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AncovaOutcome {
+    /// Pooled within-group slope `b_w = S_xy / S_xx` of the response on the covariate.
+    pub common_slope: f64,
+    /// `ȳᵢ - b_w (x̄ᵢ - x̄)`, the group means at the grand mean of the covariate.
+    pub adjusted_means: Vec<f64>,
+    /// The `F`-test of the factor after the covariate adjustment.
+    pub factor: AnovaOutcome,
+    /// The `F`-test of equal slopes in all the groups, which ANCOVA assumes.
+    pub slopes_homogeneity: AnovaOutcome,
+}
+
+/// One-way analysis of covariance with a single covariate.
+pub struct Ancova {
+    responses: Vec<Vec<f64>>,
+    covariates: Vec<Vec<f64>>,
+    significance: f64,
+}
+
+impl Ancova {
+    /// `covariates[i]` holds the covariate values paired with the responses `responses[i]` of group `i`.
+    pub fn new(
+        responses: &[Vec<f64>],
+        covariates: &[Vec<f64>],
+        significance: f64,
+    ) -> Result<Self, AnovaError> {
+        validate(responses, significance)?;
+        reject_missing(covariates)?;
+
+        if covariates.len() != responses.len()
+            || responses
+                .iter()
+                .zip(covariates)
+                .any(|(y, x)| y.len() != x.len())
+        {
+            return Err(AnovaError::LengthsMismatch);
+        }
+
+        // The separate slopes of the homogeneity check leave `N - 2k` freedom degrees.
+        let total_size = responses.iter().map(Vec::len).sum::<usize>();
+        if total_size <= 2 * responses.len() {
+            return Err(AnovaError::GroupTooSmall);
+        }
+
+        if covariates.iter().any(|x| unbiased_variance(x) == 0.) {
+            return Err(AnovaError::CovariateDegenerate);
+        }
+
+        Ok(Self {
+            responses: responses.to_owned(),
+            covariates: covariates.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            "adjusted E(Y₁) = … = E(Yₖ)",
+            "adjusted E(Yᵢ) ≠ E(Yⱼ) for some i, j",
+            Alternative::Greater,
+        )
+    }
+
+    /// Whether the hypothesis of equal adjusted means is accepted.
+    pub fn solve(&self) -> Result<bool, AnovaError> {
+        Ok(self.outcome()?.factor.accepted)
+    }
+
+    /// The factor is tested by the drop of the residual sum of squares from the model with the covariate only
+    /// to the model with the group intercepts and the common slope;
+    /// the slopes are tested by the drop from the latter to the model with a slope per group.
+    pub fn outcome(&self) -> Result<AncovaOutcome, AnovaError> {
+        let k = self.responses.len() as f64;
+        let total_size = self.responses.iter().map(Vec::len).sum::<usize>() as f64;
+
+        let groups: Vec<_> = self
+            .responses
+            .iter()
+            .zip(&self.covariates)
+            .map(|(y, x)| cross_products(x, y))
+            .collect();
+        let total = cross_products(&self.covariates.concat(), &self.responses.concat());
+        let (s_xx, s_xy, s_yy) = groups.iter().fold((0., 0., 0.), |acc, group| {
+            (acc.0 + group.s_xx, acc.1 + group.s_xy, acc.2 + group.s_yy)
+        });
+
+        let common_slope = s_xy / s_xx;
+        let residual_covariate_only = total.s_yy - total.s_xy.powi(2) / total.s_xx;
+        let residual_common_slope = s_yy - s_xy.powi(2) / s_xx;
+        let residual_separate_slopes = groups
+            .iter()
+            .map(|group| group.s_yy - group.s_xy.powi(2) / group.s_xx)
+            .sum::<f64>();
+
+        let adjusted_means: Vec<_> = groups
+            .iter()
+            .map(|group| group.y_mean - common_slope * (group.x_mean - total.x_mean))
+            .collect();
+        let slopes = groups.iter().map(|group| group.s_xy / group.s_xx).collect();
+
+        let factor_freedom_degrees = (k - 1., total_size - k - 1.);
+        let factor = decide(
+            adjusted_means.clone(),
+            (residual_covariate_only - residual_common_slope)
+                / factor_freedom_degrees.0
+                / (residual_common_slope / factor_freedom_degrees.1),
+            factor_freedom_degrees,
+            self.hypotheses(),
+            self.significance,
+        )?;
+
+        let slopes_freedom_degrees = (k - 1., total_size - 2. * k);
+        let slopes_homogeneity = decide(
+            slopes,
+            (residual_common_slope - residual_separate_slopes)
+                / slopes_freedom_degrees.0
+                / (residual_separate_slopes / slopes_freedom_degrees.1),
+            slopes_freedom_degrees,
+            Hypotheses::new("β₁ = … = βₖ", "βᵢ ≠ βⱼ for some i, j", Alternative::Greater),
+            self.significance,
+        )?;
+
+        Ok(AncovaOutcome {
+            common_slope,
+            adjusted_means,
+            factor,
+            slopes_homogeneity,
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PairwiseComparison {
+    /// Indices `(i, j)` of the compared groups, `i < j`.
+    pub groups: (usize, usize),
+    /// `x̄ᵢ - x̄ⱼ`.
+    pub difference: f64,
+    /// `√(sᵢ² / nᵢ + sⱼ² / nⱼ)`.
+    pub standard_error: f64,
+    /// Welch–Satterthwaite freedom degrees of the pair.
+    pub freedom_degrees: f64,
+    /// `q = √2 · |x̄ᵢ - x̄ⱼ| / SE`.
+    pub studentized_range_observed: f64,
+    pub studentized_range_critical_value: f64,
+    pub p_value: f64,
+    /// Simultaneous confidence interval for `E(Xᵢ) - E(Xⱼ)`.
+    pub confidence_interval: (f64, f64),
+    pub hypotheses: Hypotheses,
+    /// Whether the hypothesis of equal means of the pair is accepted.
+    pub accepted: bool,
+}
+
+/// Games–Howell pairwise comparisons, which keep the family-wise significance
+/// without assuming equal variances or equal group sizes.
+pub struct GamesHowell {
+    groups: Vec<Vec<f64>>,
+    significance: f64,
+}
+
+impl GamesHowell {
+    pub fn new(groups: &[Vec<f64>], significance: f64) -> Result<Self, AnovaError> {
+        Ok(WelchAnova::new(groups, significance)?.games_howell())
+    }
+
+    /// Compares every pair of groups against the studentized range distribution with `k` groups.
+    pub fn comparisons(&self) -> Result<Vec<PairwiseComparison>, AnovaError> {
+        let k = self.groups.len();
+        let summaries: Vec<_> = self
+            .groups
+            .iter()
+            .map(|group| {
+                (
+                    mean(group),
+                    unbiased_variance(group) / group.len() as f64,
+                    group.len() as f64,
+                )
+            })
+            .collect();
+
+        let mut comparisons = Vec::with_capacity(k * (k - 1) / 2);
+        for i in 0..k {
+            for j in i + 1..k {
+                let ((mean_i, v_i, n_i), (mean_j, v_j, n_j)) = (summaries[i], summaries[j]);
+                let difference = mean_i - mean_j;
+                let standard_error = (v_i + v_j).sqrt();
+                let freedom_degrees =
+                    (v_i + v_j).powi(2) / (v_i.powi(2) / (n_i - 1.) + v_j.powi(2) / (n_j - 1.));
+
+                let studentized_range_observed =
+                    std::f64::consts::SQRT_2 * difference.abs() / standard_error;
+                let studentized_range_critical_value =
+                    studentized_range_quantile(1. - self.significance, k, freedom_degrees)?;
+                let margin =
+                    studentized_range_critical_value / std::f64::consts::SQRT_2 * standard_error;
+
+                comparisons.push(PairwiseComparison {
+                    groups: (i, j),
+                    difference,
+                    standard_error,
+                    freedom_degrees,
+                    studentized_range_observed,
+                    studentized_range_critical_value,
+                    p_value: 1.
+                        - studentized_range_cdf(studentized_range_observed, k, freedom_degrees)?,
+                    confidence_interval: (difference - margin, difference + margin),
+                    hypotheses: Hypotheses::new(
+                        format!("E(X{}) = E(X{})", i + 1, j + 1),
+                        format!("E(X{}) ≠ E(X{})", i + 1, j + 1),
+                        Alternative::Greater,
+                    ),
+                    accepted: studentized_range_observed < studentized_range_critical_value,
+                });
+            }
+        }
+
+        Ok(comparisons)
+    }
+}
+
+/// Distribution function of the studentized range `Q` of `k` Standard Normal values
+/// divided by an independent `√(χ²(ν) / ν)`.
+///
+/// `P(Q <= q) = ∫ f(s) · P(R <= q s) ds`, where `R` is the range without studentizing
+/// and `f` is the density of `√(χ²(ν) / ν)`; both integrals are evaluated by Simpson's rule.
+pub fn studentized_range_cdf(q: f64, k: usize, freedom_degrees: f64) -> Result<f64, AnovaError> {
+    if k < 2 {
+        return Err(AnovaError::GroupsCountInvalid);
+    }
+
+    if freedom_degrees.is_nan() || freedom_degrees <= 0. {
+        return Err(AnovaError::FreedomDegreesInvalid);
+    }
+
+    if q <= 0. {
+        return Ok(0.);
+    }
+
+    // For large freedom degrees `√(χ²(ν) / ν)` concentrates at `1`.
+    if freedom_degrees > 25_000. {
+        return Ok(range_cdf(q, k));
+    }
+
+    // `√(χ²(ν) / ν)` is close to `N(1, 1 / (2ν))` and its density falls as `sᵛ` towards zero,
+    // which bounds the integral; it runs over `u = ln(s)` to resolve small `s` for small freedom degrees.
+    let spread = 8. / (2. * freedom_degrees).sqrt();
+    let u_lower = (1. - spread).max((-40. / freedom_degrees).exp()).ln();
+    let u_upper = (1. + spread).ln();
+    let half_freedom_degrees = freedom_degrees / 2.;
+    let ln_normalization = half_freedom_degrees * freedom_degrees.ln()
+        - ln_gamma(half_freedom_degrees)
+        - (half_freedom_degrees - 1.) * std::f64::consts::LN_2;
+    let integrand = |u: f64| {
+        let s = u.exp();
+        let s_density =
+            (ln_normalization + freedom_degrees * u - freedom_degrees * s * s / 2.).exp();
+        s_density * range_cdf(q * s, k)
+    };
+
+    let cdf = simpson(integrand, u_lower, u_upper, 200);
+    Ok(cdf.clamp(0., 1.))
+}
+
+/// `q` such that `P(Q <= q) = p` for the studentized range `Q`, found by bisection.
+pub fn studentized_range_quantile(
+    p: f64,
+    k: usize,
+    freedom_degrees: f64,
+) -> Result<f64, AnovaError> {
+    if !(p > 0. && p < 1.) {
+        return Err(AnovaError::SignificanceInvalid);
+    }
+
+    let (mut lower, mut upper) = (0., 1.);
+    while studentized_range_cdf(upper, k, freedom_degrees)? < p {
+        lower = upper;
+        upper *= 2.;
+    }
+    while upper - lower > 1e-7 * upper {
+        let middle = (lower + upper) / 2.;
+        if studentized_range_cdf(middle, k, freedom_degrees)? < p {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+
+    Ok((lower + upper) / 2.)
+}
+
+/// `P(R <= w) = k ∫ φ(z) (Φ(z) - Φ(z - w))^(k - 1) dz` for the range `R` of `k` Standard Normal values.
+fn range_cdf(w: f64, k: usize) -> f64 {
+    let density = |z: f64| (-z * z / 2.).exp() / (2. * std::f64::consts::PI).sqrt();
+    let cdf = k as f64
+        * simpson(
+            |z| {
+                density(z)
+                    * (standard_normal_cdf(z) - standard_normal_cdf(z - w)).powi(k as i32 - 1)
+            },
+            -8.,
+            8.,
+            200,
+        );
+    cdf.clamp(0., 1.)
+}
+
+/// Means and centered sums of squares and cross products of paired samples.
+struct CrossProducts {
+    x_mean: f64,
+    y_mean: f64,
+    s_xx: f64,
+    s_xy: f64,
+    s_yy: f64,
+}
+
+fn cross_products(x: &[f64], y: &[f64]) -> CrossProducts {
+    let (x_mean, y_mean) = (mean(x), mean(y));
+    let (mut s_xx, mut s_xy, mut s_yy) = (0., 0., 0.);
+    for (x_i, y_i) in x.iter().zip(y) {
+        s_xx += (x_i - x_mean).powi(2);
+        s_xy += (x_i - x_mean) * (y_i - y_mean);
+        s_yy += (y_i - y_mean).powi(2);
+    }
+
+    CrossProducts {
+        x_mean,
+        y_mean,
+        s_xx,
+        s_xy,
+        s_yy,
+    }
+}
+
+fn mean_hypotheses() -> Hypotheses {
+    Hypotheses::new(
+        "E(X₁) = … = E(Xₖ)",
+        "E(Xᵢ) ≠ E(Xⱼ) for some i, j",
+        Alternative::Greater,
+    )
+}
+
+fn validate(groups: &[Vec<f64>], significance: f64) -> Result<(), AnovaError> {
+    reject_missing(groups)?;
+
+    if groups.len() < 2 {
+        return Err(AnovaError::GroupsCountInvalid);
+    }
+
+    if groups.iter().any(|group| group.len() < 2) {
+        return Err(AnovaError::GroupTooSmall);
+    }
+
+    alpha::validate(significance, AnovaError::SignificanceInvalid)?;
+
+    Ok(())
+}
+
+fn reject_missing(groups: &[Vec<f64>]) -> Result<(), AnovaError> {
+    for group in groups {
+        MissingPolicy::Error
+            .apply(group)
+            .map_err(|_| AnovaError::ValueMissing)?;
+    }
+
+    Ok(())
+}
+
+/// `F = MS_between / MS_within` with `k - 1` and `N - k` freedom degrees.
+fn classic_f(groups: &[Vec<f64>]) -> Result<(f64, (f64, f64)), AnovaError> {
+    let mean_squares = mean_squares(groups)?;
+    Ok((
+        mean_squares.between / mean_squares.within,
+        mean_squares.freedom_degrees,
+    ))
+}
+
+/// `MS_between` and `MS_within` with their freedom degrees `k - 1` and `N - k`.
+struct MeanSquares {
+    between: f64,
+    within: f64,
+    freedom_degrees: (f64, f64),
+}
+
+fn mean_squares(groups: &[Vec<f64>]) -> Result<MeanSquares, AnovaError> {
+    let k = groups.len() as f64;
+    let total_size = groups.iter().map(Vec::len).sum::<usize>() as f64;
+    let grand_mean = groups.iter().flatten().sum::<f64>() / total_size;
+
+    let between = groups
+        .iter()
+        .map(|group| group.len() as f64 * (mean(group) - grand_mean).powi(2))
+        .sum::<f64>();
+    let within = groups
+        .iter()
+        .map(|group| unbiased_variance(group) * (group.len() as f64 - 1.))
+        .sum::<f64>();
+    if within == 0. {
+        return Err(AnovaError::GroupDegenerate);
+    }
+
+    let freedom_degrees = (k - 1., total_size - k);
+    Ok(MeanSquares {
+        between: between / freedom_degrees.0,
+        within: within / freedom_degrees.1,
+        freedom_degrees,
+    })
+}
+
+/// Mean squares of a two-way table without replication, with its size `(n, k)`.
+struct TwoWayMeanSquares {
+    rows: f64,
+    columns: f64,
+    residual: f64,
+    /// Columns and residual pooled, as in the one-way ANOVA over the rows.
+    within_rows: f64,
+    size: (f64, f64),
+}
+
+fn two_way_mean_squares(table: &[Vec<f64>]) -> Result<TwoWayMeanSquares, AnovaError> {
+    let (n, k) = (table.len() as f64, table[0].len() as f64);
+    let grand_mean = table.iter().flatten().sum::<f64>() / (n * k);
+
+    let rows = k * table
+        .iter()
+        .map(|row| (mean(row) - grand_mean).powi(2))
+        .sum::<f64>();
+    let columns = n
+        * (0..table[0].len())
+            .map(|j| (table.iter().map(|row| row[j]).sum::<f64>() / n - grand_mean).powi(2))
+            .sum::<f64>();
+    let total = table
+        .iter()
+        .flatten()
+        .map(|x| (x - grand_mean).powi(2))
+        .sum::<f64>();
+    let residual = total - rows - columns;
+    if residual <= 0. {
+        return Err(AnovaError::GroupDegenerate);
+    }
+
+    Ok(TwoWayMeanSquares {
+        rows: rows / (n - 1.),
+        columns: columns / (k - 1.),
+        residual: residual / ((n - 1.) * (k - 1.)),
+        within_rows: (columns + residual) / (n * (k - 1.)),
+        size: (n, k),
+    })
+}
+
+fn validate_table(table: &[Vec<f64>], significance: f64) -> Result<(), AnovaError> {
+    validate(table, significance)?;
+
+    if table.iter().any(|row| row.len() != table[0].len()) {
+        return Err(AnovaError::ConditionsCountMismatch);
+    }
+
+    Ok(())
+}
+
+fn decide(
+    means: Vec<f64>,
+    fisher_snedecor_observed: f64,
+    freedom_degrees: (f64, f64),
+    hypotheses: Hypotheses,
+    significance: f64,
+) -> Result<AnovaOutcome, AnovaError> {
+    let fisher_snedecor_dist = FisherSnedecor::new(freedom_degrees.0, freedom_degrees.1)
+        .map_err(|_| AnovaError::FreedomDegreesInvalid)?;
+    let fisher_snedecor_critical_value = fisher_snedecor_dist.inverse_cdf(1. - significance);
+
+    Ok(AnovaOutcome {
+        means,
+        freedom_degrees,
+        fisher_snedecor_observed,
+        fisher_snedecor_critical_value,
+        p_value: fisher_snedecor_dist.sf(fisher_snedecor_observed),
+        hypotheses,
+        accepted: fisher_snedecor_observed < fisher_snedecor_critical_value,
+    })
+}
@@ -0,0 +1,154 @@
+//! Checking of answers computed by hand.
+//!
+//! **Given**: a hypothesis problem and its *answer* computed by hand: the statistic, the critical value,
+//! the decision and, optionally, intermediate quantities such as the expected frequencies.
+//! **To figure out**: Is the answer right, and if not, at which step of the solution does it go astray?
+//!
+//! A number matches if it differs from the computed `x` by at most `tolerance · max(1, |x|)`, which allows
+//! for the rounding of the tables and of the intermediate results. The steps are checked in the order of
+//! the solution; an error made early is carried into the later steps, so the first divergence is the one
+//! to look at. When all the numbers match, the decision can still diverge on its own.
+
+/// Relative tolerance of the answers, loose enough for the rounding of textbook tables.
+pub const DEFAULT_TOLERANCE: f64 = 0.01;
+
+/// Answer computed by hand.
+#[derive(Clone, PartialEq, Debug)]
+pub struct UserAnswer {
+    pub expected_frequencies: Option<Vec<f64>>,
+    pub statistic: f64,
+    pub freedom_degrees: Option<f64>,
+    pub critical_value: f64,
+    /// Whether `H₀` is rejected.
+    pub rejected: bool,
+    pub tolerance: f64,
+}
+
+impl UserAnswer {
+    pub fn new(statistic: f64, critical_value: f64, rejected: bool) -> Self {
+        Self {
+            expected_frequencies: None,
+            statistic,
+            freedom_degrees: None,
+            critical_value,
+            rejected,
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    pub fn with_expected_frequencies(mut self, expected_frequencies: &[f64]) -> Self {
+        self.expected_frequencies = Some(expected_frequencies.to_owned());
+        self
+    }
+
+    pub fn with_freedom_degrees(mut self, freedom_degrees: f64) -> Self {
+        self.freedom_degrees = Some(freedom_degrees);
+        self
+    }
+
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+}
+
+/// Step of the solution of a hypothesis problem, in their order.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Step {
+    /// The expected frequency of the class, counted from 0.
+    ExpectedFrequency(usize),
+    Statistic,
+    FreedomDegrees,
+    CriticalValue,
+    Decision,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StepCheck {
+    pub step: Step,
+    pub computed: f64,
+    /// `NaN` if the answer lacks the value, e.g. of a class.
+    pub answered: f64,
+    pub matches: bool,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Verification {
+    /// The numbers the answer gives, in the order of the solution.
+    pub steps: Vec<StepCheck>,
+    /// Whether the solution rejects `H₀`.
+    pub rejected: bool,
+    pub decision_matches: bool,
+    /// The first step at which the answer departs from the solution.
+    pub first_divergence: Option<Step>,
+    /// Whether the whole answer matches.
+    pub correct: bool,
+}
+
+/// Quantities of a solved problem to compare an answer with.
+pub(crate) struct Solution {
+    pub(crate) expected_frequencies: Vec<f64>,
+    pub(crate) statistic: f64,
+    pub(crate) freedom_degrees: f64,
+    pub(crate) critical_value: f64,
+    pub(crate) rejected: bool,
+}
+
+pub(crate) fn verify(solution: &Solution, answer: &UserAnswer) -> Verification {
+    let check = |step: Step, computed: f64, answered: f64| StepCheck {
+        step,
+        computed,
+        answered,
+        matches: (computed - answered).abs() <= answer.tolerance * computed.abs().max(1.),
+    };
+
+    let mut steps = vec![];
+    if let Some(expected_frequencies) = &answer.expected_frequencies {
+        let classes = solution
+            .expected_frequencies
+            .len()
+            .max(expected_frequencies.len());
+        steps.extend((0..classes).map(|i| {
+            check(
+                Step::ExpectedFrequency(i),
+                solution
+                    .expected_frequencies
+                    .get(i)
+                    .copied()
+                    .unwrap_or(f64::NAN),
+                expected_frequencies.get(i).copied().unwrap_or(f64::NAN),
+            )
+        }));
+    }
+    steps.push(check(Step::Statistic, solution.statistic, answer.statistic));
+    if let Some(freedom_degrees) = answer.freedom_degrees {
+        steps.push(check(
+            Step::FreedomDegrees,
+            solution.freedom_degrees,
+            freedom_degrees,
+        ));
+    }
+    steps.push(check(
+        Step::CriticalValue,
+        solution.critical_value,
+        answer.critical_value,
+    ));
+
+    let decision_matches = solution.rejected == answer.rejected;
+    let first_divergence = steps
+        .iter()
+        .find(|check| !check.matches)
+        .map(|check| check.step)
+        .or((!decision_matches).then_some(Step::Decision));
+
+    Verification {
+        steps,
+        rejected: solution.rejected,
+        decision_matches,
+        correct: first_divergence.is_none(),
+        first_divergence,
+    }
+}
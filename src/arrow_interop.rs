@@ -0,0 +1,187 @@
+//! Exchange of samples and result tables with Python and R through Arrow IPC files and Parquet files.
+//!
+//! - Every numeric column of a file is read as a named sample; nulls pad the shorter columns and are dropped.
+//! - Samples of different sizes are written as nullable `Float64` columns padded with nulls.
+//! - A [`Table`] is written with its row labels in the first, string column and its title in the schema metadata.
+
+use std::collections::HashMap;
+use std::io::{Read, Seek, Write};
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, Float64Array, StringArray};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::ipc::reader::FileReader;
+use arrow::ipc::writer::FileWriter;
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::file::reader::ChunkReader;
+
+use crate::tables::Table;
+
+/// Key of the schema metadata that holds the title of a [`Table`].
+pub const TITLE_METADATA_KEY: &str = "title";
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum ArrowInteropError {
+    ReadFailed,
+    WriteFailed,
+    ColumnTypeInvalid,
+}
+
+impl std::fmt::Display for ArrowInteropError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ArrowInteropError::ReadFailed => {
+                write!(f, "File is not a readable Arrow IPC or Parquet file")
+            }
+            ArrowInteropError::WriteFailed => {
+                write!(f, "Record batch could not be written")
+            }
+            ArrowInteropError::ColumnTypeInvalid => {
+                write!(f, "Column cannot be converted to Float64")
+            }
+        }
+    }
+}
+
+/// Samples named after the numeric columns of the batches, in the order of the schema.
+///
+/// Non-numeric columns are skipped.
+pub fn samples_from_batches(
+    batches: &[RecordBatch],
+) -> Result<Vec<(String, Vec<f64>)>, ArrowInteropError> {
+    let Some(first) = batches.first() else {
+        return Ok(Vec::new());
+    };
+
+    let mut samples = Vec::new();
+    for (i, field) in first.schema().fields().iter().enumerate() {
+        if !field.data_type().is_numeric() {
+            continue;
+        }
+
+        let mut sample = Vec::new();
+        for batch in batches {
+            let column = cast(batch.column(i), &DataType::Float64)
+                .map_err(|_| ArrowInteropError::ColumnTypeInvalid)?;
+            let column = column
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .ok_or(ArrowInteropError::ColumnTypeInvalid)?;
+            sample.extend(column.iter().flatten());
+        }
+        samples.push((field.name().clone(), sample));
+    }
+
+    Ok(samples)
+}
+
+/// One nullable `Float64` column per sample, padded with nulls to the size of the largest sample.
+pub fn samples_to_batch(samples: &[(&str, &[f64])]) -> Result<RecordBatch, ArrowInteropError> {
+    let rows = samples
+        .iter()
+        .map(|(_, sample)| sample.len())
+        .max()
+        .unwrap_or(0);
+
+    let fields: Vec<_> = samples
+        .iter()
+        .map(|(name, _)| Field::new(*name, DataType::Float64, true))
+        .collect();
+    let columns: Vec<ArrayRef> = samples
+        .iter()
+        .map(|(_, sample)| {
+            let values = (0..rows).map(|i| sample.get(i).copied());
+            Arc::new(values.collect::<Float64Array>()) as ArrayRef
+        })
+        .collect();
+
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+        .map_err(|_| ArrowInteropError::WriteFailed)
+}
+
+/// The row labels under the corner label, followed by one `Float64` column per column label.
+pub fn table_to_batch(table: &Table) -> Result<RecordBatch, ArrowInteropError> {
+    let mut fields = vec![Field::new(&table.corner, DataType::Utf8, false)];
+    let mut columns: Vec<ArrayRef> = vec![Arc::new(StringArray::from(table.row_labels.clone()))];
+
+    for (j, label) in table.column_labels.iter().enumerate() {
+        fields.push(Field::new(label, DataType::Float64, false));
+        let values: Vec<_> = table.values.iter().map(|row| row[j]).collect();
+        columns.push(Arc::new(Float64Array::from(values)));
+    }
+
+    let metadata = HashMap::from([(TITLE_METADATA_KEY.to_string(), table.title.clone())]);
+    let schema = Schema::new(fields).with_metadata(metadata);
+
+    RecordBatch::try_new(Arc::new(schema), columns).map_err(|_| ArrowInteropError::WriteFailed)
+}
+
+pub fn read_samples_ipc<R: Read + Seek>(
+    reader: R,
+) -> Result<Vec<(String, Vec<f64>)>, ArrowInteropError> {
+    let batches = FileReader::try_new(reader, None)
+        .map_err(|_| ArrowInteropError::ReadFailed)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| ArrowInteropError::ReadFailed)?;
+
+    samples_from_batches(&batches)
+}
+
+pub fn read_samples_parquet<R: ChunkReader + 'static>(
+    reader: R,
+) -> Result<Vec<(String, Vec<f64>)>, ArrowInteropError> {
+    let batches = ParquetRecordBatchReaderBuilder::try_new(reader)
+        .and_then(|builder| builder.build())
+        .map_err(|_| ArrowInteropError::ReadFailed)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| ArrowInteropError::ReadFailed)?;
+
+    samples_from_batches(&batches)
+}
+
+pub fn write_samples_ipc<W: Write>(
+    writer: W,
+    samples: &[(&str, &[f64])],
+) -> Result<(), ArrowInteropError> {
+    write_ipc(writer, &samples_to_batch(samples)?)
+}
+
+pub fn write_samples_parquet<W: Write + Send>(
+    writer: W,
+    samples: &[(&str, &[f64])],
+) -> Result<(), ArrowInteropError> {
+    write_parquet(writer, &samples_to_batch(samples)?)
+}
+
+pub fn write_table_ipc<W: Write>(writer: W, table: &Table) -> Result<(), ArrowInteropError> {
+    write_ipc(writer, &table_to_batch(table)?)
+}
+
+pub fn write_table_parquet<W: Write + Send>(
+    writer: W,
+    table: &Table,
+) -> Result<(), ArrowInteropError> {
+    write_parquet(writer, &table_to_batch(table)?)
+}
+
+fn write_ipc<W: Write>(writer: W, batch: &RecordBatch) -> Result<(), ArrowInteropError> {
+    let mut writer =
+        FileWriter::try_new(writer, &batch.schema()).map_err(|_| ArrowInteropError::WriteFailed)?;
+    writer
+        .write(batch)
+        .and_then(|_| writer.finish())
+        .map_err(|_| ArrowInteropError::WriteFailed)
+}
+
+fn write_parquet<W: Write + Send>(writer: W, batch: &RecordBatch) -> Result<(), ArrowInteropError> {
+    let mut writer = ArrowWriter::try_new(writer, batch.schema(), None)
+        .map_err(|_| ArrowInteropError::WriteFailed)?;
+    writer
+        .write(batch)
+        .and_then(|_| writer.close().map(|_| ()))
+        .map_err(|_| ArrowInteropError::WriteFailed)
+}
@@ -0,0 +1,384 @@
+//! Bayesian counterparts of the mean and variance comparisons.
+//!
+//! Bayes factors `BF₁₀ = P(data | H₁) / P(data | H₀)` quantify the evidence in the data,
+//! in contrast to the accept/reject decision of a significance test.
+//!
+//! - Mean comparison: the default JZS Bayes factor of Rouder et al. (2009), with a Cauchy prior
+//!   on the standardized effect size `δ` under `H₁`.
+//! - Variance comparison: a Cauchy prior on `ln(D(X) / D(Y))` under `H₁` and the Jeffreys prior
+//!   on the common scale under both hypotheses.
+//!
+//! Posterior summaries of `E(X)` and `D(X)` of a normal sample come from the conjugate
+//! Normal-Inverse-Gamma prior.
+
+use statrs::distribution::{ContinuousCDF, Gamma, StudentsT};
+
+use crate::numeric::{mean, unbiased_variance};
+
+/// Scale `√2 / 2` of the Cauchy prior on the effect size, the "medium" default of Rouder et al.
+pub const DEFAULT_EFFECT_PRIOR_SCALE: f64 = std::f64::consts::FRAC_1_SQRT_2;
+
+/// Scale of the Cauchy prior on the log variance ratio.
+pub const DEFAULT_LOG_RATIO_PRIOR_SCALE: f64 = 1.;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum BayesError {
+    SampleTooSmall,
+    SampleDegenerate,
+    PriorScaleInvalid,
+    PriorInvalid,
+    LevelInvalid,
+}
+
+impl std::fmt::Display for BayesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BayesError::SampleTooSmall => {
+                write!(f, "Each sample must contain at least two values")
+            }
+            BayesError::SampleDegenerate => {
+                write!(f, "Sample must contain at least two distinct values")
+            }
+            BayesError::PriorScaleInvalid => {
+                write!(f, "Prior scale must be positive")
+            }
+            BayesError::PriorInvalid => {
+                write!(
+                    f,
+                    "Prior together with the sample must give positive posterior parameters"
+                )
+            }
+            BayesError::LevelInvalid => {
+                write!(f, "Credibility level must be between 0.0 and 1.0")
+            }
+        }
+    }
+}
+
+/// Strength of evidence by the classification of Lee and Wagenmakers (2013).
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, PartialOrd, Ord)]
+pub enum Evidence {
+    /// `1 <= BF < 3`.
+    Anecdotal,
+    /// `3 <= BF < 10`.
+    Moderate,
+    /// `10 <= BF < 30`.
+    Strong,
+    /// `30 <= BF < 100`.
+    VeryStrong,
+    /// `BF >= 100`.
+    Extreme,
+}
+
+impl std::fmt::Display for Evidence {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Evidence::Anecdotal => write!(f, "anecdotal"),
+            Evidence::Moderate => write!(f, "moderate"),
+            Evidence::Strong => write!(f, "strong"),
+            Evidence::VeryStrong => write!(f, "very strong"),
+            Evidence::Extreme => write!(f, "extreme"),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BayesFactor {
+    /// `P(data | H₁) / P(data | H₀)`.
+    pub bf10: f64,
+}
+
+impl BayesFactor {
+    /// `P(data | H₀) / P(data | H₁)`.
+    pub fn bf01(&self) -> f64 {
+        1. / self.bf10
+    }
+
+    pub fn favours_alternative(&self) -> bool {
+        self.bf10 > 1.
+    }
+
+    /// Strength of evidence for the favoured hypothesis.
+    pub fn evidence(&self) -> Evidence {
+        match self.bf10.max(self.bf01()) {
+            bf if bf < 3. => Evidence::Anecdotal,
+            bf if bf < 10. => Evidence::Moderate,
+            bf if bf < 30. => Evidence::Strong,
+            bf if bf < 100. => Evidence::VeryStrong,
+            _ => Evidence::Extreme,
+        }
+    }
+}
+
+impl std::fmt::Display for BayesFactor {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "BF₁₀ = {:.4}: {} evidence for {}",
+            self.bf10,
+            self.evidence(),
+            if self.favours_alternative() {
+                "H₁"
+            } else {
+                "H₀"
+            }
+        )
+    }
+}
+
+/// JZS Bayes factor for `H₀: E(X) = mu` against `H₁: E(X) ≠ mu`.
+pub fn jzs_one_sample(
+    sample: &[f64],
+    mu: f64,
+    prior_scale: f64,
+) -> Result<BayesFactor, BayesError> {
+    if sample.len() < 2 {
+        return Err(BayesError::SampleTooSmall);
+    }
+
+    validate_prior_scale(prior_scale)?;
+
+    let n = sample.len() as f64;
+    let standard_error = (unbiased_variance(sample) / n).sqrt();
+    if standard_error == 0. {
+        return Err(BayesError::SampleDegenerate);
+    }
+    let t = (mean(sample) - mu) / standard_error;
+
+    Ok(BayesFactor {
+        bf10: jzs_bayes_factor(t, n, n - 1., prior_scale),
+    })
+}
+
+/// JZS Bayes factor for `H₀: E(X) = E(Y)` against `H₁: E(X) ≠ E(Y)`, with equal variances.
+pub fn jzs_two_sample(
+    x_sample: &[f64],
+    y_sample: &[f64],
+    prior_scale: f64,
+) -> Result<BayesFactor, BayesError> {
+    if x_sample.len() < 2 || y_sample.len() < 2 {
+        return Err(BayesError::SampleTooSmall);
+    }
+
+    validate_prior_scale(prior_scale)?;
+
+    let (n_x, n_y) = (x_sample.len() as f64, y_sample.len() as f64);
+    let freedom_degrees = n_x + n_y - 2.;
+    let pooled_variance = ((n_x - 1.) * unbiased_variance(x_sample)
+        + (n_y - 1.) * unbiased_variance(y_sample))
+        / freedom_degrees;
+    let standard_error = (pooled_variance * (1. / n_x + 1. / n_y)).sqrt();
+    if standard_error == 0. {
+        return Err(BayesError::SampleDegenerate);
+    }
+    let t = (mean(x_sample) - mean(y_sample)) / standard_error;
+
+    Ok(BayesFactor {
+        bf10: jzs_bayes_factor(t, n_x * n_y / (n_x + n_y), freedom_degrees, prior_scale),
+    })
+}
+
+/// Bayes factor for `H₀: D(X) = D(Y)` against `H₁: D(X) ≠ D(Y)`.
+pub fn variance_ratio(
+    x_sample: &[f64],
+    y_sample: &[f64],
+    prior_scale: f64,
+) -> Result<BayesFactor, BayesError> {
+    if x_sample.len() < 2 || y_sample.len() < 2 {
+        return Err(BayesError::SampleTooSmall);
+    }
+
+    validate_prior_scale(prior_scale)?;
+
+    let (freedom_degrees_x, freedom_degrees_y) =
+        (x_sample.len() as f64 - 1., y_sample.len() as f64 - 1.);
+    let (squares_sum_x, squares_sum_y) = (
+        unbiased_variance(x_sample) * freedom_degrees_x,
+        unbiased_variance(y_sample) * freedom_degrees_y,
+    );
+    if squares_sum_x == 0. || squares_sum_y == 0. {
+        return Err(BayesError::SampleDegenerate);
+    }
+
+    // With `D(X) = σ² · e^(ρ/2)`, `D(Y) = σ² · e^(-ρ/2)` and `σ²` integrated out,
+    // the marginal likelihood of `ρ` is proportional to
+    // `e^(-ρ (k_x - k_y) / 4) · (SS_x · e^(-ρ/2) + SS_y · e^(ρ/2))^(-(k_x + k_y) / 2)`.
+    let log_likelihood = |rho: f64| {
+        -rho * (freedom_degrees_x - freedom_degrees_y) / 4.
+            - (freedom_degrees_x + freedom_degrees_y) / 2.
+                * (squares_sum_x * (-rho / 2.).exp() + squares_sum_y * (rho / 2.).exp()).ln()
+    };
+    let log_likelihood_null = log_likelihood(0.);
+
+    // `ρ = r · tan(θ)` turns the Cauchy prior into the uniform density `1 / π` on `(-π/2; π/2)`.
+    let half_pi = std::f64::consts::FRAC_PI_2;
+    let bf10 = simpson(
+        |theta| (log_likelihood(prior_scale * theta.tan()) - log_likelihood_null).exp(),
+        -half_pi,
+        half_pi,
+        INTEGRATION_INTERVALS,
+    ) / std::f64::consts::PI;
+
+    Ok(BayesFactor { bf10 })
+}
+
+/// Parameters of `σ² ~ InvGamma(α, β)`, `μ | σ² ~ N(μ₀, σ² / κ)`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct NormalInverseGamma {
+    pub mu: f64,
+    pub kappa: f64,
+    pub alpha: f64,
+    pub beta: f64,
+}
+
+impl NormalInverseGamma {
+    /// Limit `p(μ, σ²) ∝ 1 / σ²`, whose credible intervals coincide with the classical confidence
+    /// intervals for a sample of at least two distinct values.
+    pub const REFERENCE: Self = Self {
+        mu: 0.,
+        kappa: 0.,
+        alpha: -0.5,
+        beta: 0.,
+    };
+
+    pub fn new(mu: f64, kappa: f64, alpha: f64, beta: f64) -> Self {
+        Self {
+            mu,
+            kappa,
+            alpha,
+            beta,
+        }
+    }
+
+    /// Conjugate update of the prior by a raw sample.
+    pub fn posterior(&self, sample: &[f64]) -> Result<Self, BayesError> {
+        if sample.is_empty() {
+            return Err(BayesError::SampleTooSmall);
+        }
+
+        let n = sample.len() as f64;
+        let sample_mean = mean(sample);
+        let squares_sum = if sample.len() > 1 {
+            unbiased_variance(sample) * (n - 1.)
+        } else {
+            0.
+        };
+
+        let kappa = self.kappa + n;
+        let posterior = Self {
+            mu: (self.kappa * self.mu + n * sample_mean) / kappa,
+            kappa,
+            alpha: self.alpha + n / 2.,
+            beta: self.beta
+                + squares_sum / 2.
+                + self.kappa * n * (sample_mean - self.mu).powi(2) / (2. * kappa),
+        };
+
+        if !(posterior.kappa > 0. && posterior.alpha > 0. && posterior.beta > 0.) {
+            return Err(BayesError::PriorInvalid);
+        }
+
+        Ok(posterior)
+    }
+
+    /// Mean of the marginal distribution of `μ`.
+    pub fn mean_estimate(&self) -> f64 {
+        self.mu
+    }
+
+    /// Mean of the marginal distribution of `σ²`, infinite for `α <= 1`.
+    pub fn variance_estimate(&self) -> f64 {
+        if self.alpha > 1. {
+            self.beta / (self.alpha - 1.)
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    /// Equal-tailed credible interval for `μ` from its marginal Student's t distribution.
+    pub fn mean_credible_interval(&self, level: f64) -> Result<(f64, f64), BayesError> {
+        validate_level(level)?;
+
+        let students_t_dist = StudentsT::new(
+            self.mu,
+            (self.beta / (self.alpha * self.kappa)).sqrt(),
+            2. * self.alpha,
+        )
+        .map_err(|_| BayesError::PriorInvalid)?;
+        let tail = (1. - level) / 2.;
+
+        Ok((
+            students_t_dist.inverse_cdf(tail),
+            students_t_dist.inverse_cdf(1. - tail),
+        ))
+    }
+
+    /// Equal-tailed credible interval for `σ²` from its marginal Inverse-Gamma distribution.
+    pub fn variance_credible_interval(&self, level: f64) -> Result<(f64, f64), BayesError> {
+        validate_level(level)?;
+
+        // `1 / σ² ~ Gamma(α, β)` with `β` as the rate, so the quantiles swap under inversion.
+        let precision_dist =
+            Gamma::new(self.alpha, self.beta).map_err(|_| BayesError::PriorInvalid)?;
+        let tail = (1. - level) / 2.;
+
+        Ok((
+            1. / precision_dist.inverse_cdf(1. - tail),
+            1. / precision_dist.inverse_cdf(tail),
+        ))
+    }
+}
+
+const INTEGRATION_INTERVALS: usize = 4000;
+
+fn validate_level(level: f64) -> Result<(), BayesError> {
+    if !(level > 0. && level < 1.) {
+        return Err(BayesError::LevelInvalid);
+    }
+
+    Ok(())
+}
+
+fn validate_prior_scale(prior_scale: f64) -> Result<(), BayesError> {
+    if prior_scale <= 0. || !prior_scale.is_finite() {
+        return Err(BayesError::PriorScaleInvalid);
+    }
+
+    Ok(())
+}
+
+/// Equation (1) of Rouder et al. (2009) with the prior scale `r`:
+/// `t` statistic, effective sample size `N` and freedom degrees `ν`.
+fn jzs_bayes_factor(t: f64, effective_size: f64, freedom_degrees: f64, prior_scale: f64) -> f64 {
+    let exponent = -(freedom_degrees + 1.) / 2.;
+    let log_likelihood_null = exponent * (1. + t.powi(2) / freedom_degrees).ln();
+
+    // `g` has the Inverse-Gamma(1/2, 1/2) prior; the integral runs over `u = ln(g)`.
+    let integrand = |u: f64| {
+        let g = u.exp();
+        let scaled = 1. + effective_size * prior_scale.powi(2) * g;
+        let log_value = -0.5 * scaled.ln()
+            + exponent * (1. + t.powi(2) / (scaled * freedom_degrees)).ln()
+            - 0.5 * (2. * std::f64::consts::PI).ln()
+            - 1.5 * u
+            - 1. / (2. * g)
+            + u;
+        (log_value - log_likelihood_null).exp()
+    };
+
+    simpson(integrand, -15., 50., INTEGRATION_INTERVALS)
+}
+
+/// Composite Simpson's rule with an even number of `intervals`; the end points are not evaluated.
+pub(crate) fn simpson(f: impl Fn(f64) -> f64, a: f64, b: f64, intervals: usize) -> f64 {
+    let h = (b - a) / intervals as f64;
+    let interior = (1..intervals)
+        .map(|i| {
+            let weight = if i.is_multiple_of(2) { 2. } else { 4. };
+            weight * f(a + i as f64 * h)
+        })
+        .sum::<f64>();
+
+    interior * h / 3.
+}
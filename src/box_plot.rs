@@ -0,0 +1,135 @@
+//! Box plot statistics: the five-number summary, whisker ends and outliers by the `1.5·IQR` rule,
+//! or by fences a few robust standard deviations away from the median, or standard deviations away from the mean.
+
+use crate::descriptive::{RobustScale, quantile};
+use crate::numeric::{mean, unbiased_variance};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum BoxPlotError {
+    SampleEmpty,
+}
+
+impl std::fmt::Display for BoxPlotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BoxPlotError::SampleEmpty => {
+                write!(f, "Sample must not be empty")
+            }
+        }
+    }
+}
+
+/// Fences lie this many interquartile ranges beyond the quartiles.
+const FENCE_IQR_RATIO: f64 = 1.5;
+
+/// Robust fences lie this many robust standard deviations beyond the median.
+const FENCE_ROBUST_SCALE_RATIO: f64 = 3.;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct BoxPlot {
+    pub min: f64,
+    pub first_quartile: f64,
+    pub median: f64,
+    pub third_quartile: f64,
+    pub max: f64,
+    /// `Q1 - 1.5·IQR`, or `median - 3σ̂` with robust fences.
+    pub lower_fence: f64,
+    /// `Q3 + 1.5·IQR`, or `median + 3σ̂` with robust fences.
+    pub upper_fence: f64,
+    /// The smallest value within the fences.
+    pub lower_whisker: f64,
+    /// The largest value within the fences.
+    pub upper_whisker: f64,
+    /// Values beyond the fences, ascending.
+    pub outliers: Vec<f64>,
+}
+
+impl BoxPlot {
+    pub fn new(sample: &[f64]) -> Result<Self, BoxPlotError> {
+        Self::with_fences(sample, |box_plot| {
+            let interquartile_range = box_plot.interquartile_range();
+            (
+                box_plot.first_quartile - FENCE_IQR_RATIO * interquartile_range,
+                box_plot.third_quartile + FENCE_IQR_RATIO * interquartile_range,
+            )
+        })
+    }
+
+    /// Box plot whose fences lie `3σ̂` beyond the median, with `σ̂` estimated robustly,
+    /// which suits heavy-tailed samples better than the quartiles.
+    pub fn with_robust_fences(sample: &[f64], scale: RobustScale) -> Result<Self, BoxPlotError> {
+        let scale = scale.estimate(sample);
+        Self::with_fences(sample, |box_plot| {
+            (
+                box_plot.median - FENCE_ROBUST_SCALE_RATIO * scale,
+                box_plot.median + FENCE_ROBUST_SCALE_RATIO * scale,
+            )
+        })
+    }
+
+    /// Box plot whose fences lie `kσ` beyond the mean, so that the outliers are the observations
+    /// [`beyond_sigmas`](crate::descriptive::beyond_sigmas) reports.
+    pub fn with_sigma_fences(sample: &[f64], sigmas: f64) -> Result<Self, BoxPlotError> {
+        let (mean, std_dev) = (mean(sample), unbiased_variance(sample).sqrt());
+        Self::with_fences(sample, |_| {
+            (mean - sigmas * std_dev, mean + sigmas * std_dev)
+        })
+    }
+
+    /// Computes the five-number summary, then the fences from it, then the whiskers and outliers.
+    fn with_fences(
+        sample: &[f64],
+        fences: impl Fn(&Self) -> (f64, f64),
+    ) -> Result<Self, BoxPlotError> {
+        if sample.is_empty() {
+            return Err(BoxPlotError::SampleEmpty);
+        }
+
+        let mut sorted = sample.to_owned();
+        sorted.sort_by(f64::total_cmp);
+
+        let mut box_plot = Self {
+            min: sorted[0],
+            first_quartile: quantile(&sorted, 0.25),
+            median: quantile(&sorted, 0.5),
+            third_quartile: quantile(&sorted, 0.75),
+            max: sorted[sorted.len() - 1],
+            lower_fence: f64::NEG_INFINITY,
+            upper_fence: f64::INFINITY,
+            lower_whisker: sorted[0],
+            upper_whisker: sorted[sorted.len() - 1],
+            outliers: Vec::new(),
+        };
+        let (lower_fence, upper_fence) = fences(&box_plot);
+
+        let within_fences = |value: &&f64| (lower_fence..=upper_fence).contains(*value);
+        box_plot.lower_whisker = *sorted
+            .iter()
+            .find(within_fences)
+            .unwrap_or(&box_plot.first_quartile);
+        box_plot.upper_whisker = *sorted
+            .iter()
+            .rev()
+            .find(within_fences)
+            .unwrap_or(&box_plot.third_quartile);
+        box_plot.outliers = sorted
+            .iter()
+            .filter(|value| !within_fences(value))
+            .copied()
+            .collect();
+        box_plot.lower_fence = lower_fence;
+        box_plot.upper_fence = upper_fence;
+
+        Ok(box_plot)
+    }
+
+    pub fn interquartile_range(&self) -> f64 {
+        self.third_quartile - self.first_quartile
+    }
+}
+
+/// Box plot statistics for each of many samples.
+pub fn box_plots(samples: &[&[f64]]) -> Result<Vec<BoxPlot>, BoxPlotError> {
+    samples.iter().map(|sample| BoxPlot::new(sample)).collect()
+}
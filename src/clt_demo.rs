@@ -0,0 +1,209 @@
+//! Demonstration of the central limit theorem.
+//!
+//! **Given**: a *population distribution* with a finite variance `σ²`, a *sample size* `n`, a number of
+//! *replications* and a *significance ratio*.
+//! **To figure out**: Does the mean of `n` values drawn from the population follow `N(μ, σ / √n)`?
+//!
+//! The means of the replicated samples form the simulated sampling distribution. They are grouped by
+//! Sturges' rule and passed to the Normal Distribution Hypothesis, so the demonstration runs the crate
+//! end to end. For skewed or discrete populations and small `n` the normality is rejected, and it is
+//! accepted more often as `n` grows; with many replications the test detects even small departures.
+//!
+//! ```ignore
+//! let demo = clt_demo::simulate(Population::Exponential { rate: 1. }, 30, 2_000, 0.05, RngSource::Seed(1))?;
+//! println!("{:.3} vs {:.3}", demo.means_std_dev, demo.expected_standard_error);
+//! ```
+
+use rand::{Rng, RngCore};
+use statrs::distribution::{Exp, Normal, Poisson, Uniform};
+
+use crate::alpha;
+use crate::normal_distribution_hypothesis::{
+    GroupedSample, IncompleteNDHProblemSituation, NDHError, NDHOutcome,
+    NormalDistributionHypothesis,
+};
+use crate::numeric::{mean, unbiased_variance};
+use crate::rng::RngSource;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum CltError {
+    SignificanceInvalid,
+    SampleSizeInvalid,
+    ReplicationsTooFew,
+    ParametersInvalid,
+    NormalDistribution(NDHError),
+}
+
+impl std::fmt::Display for CltError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CltError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            CltError::SampleSizeInvalid => {
+                write!(f, "Every sample must contain at least one value")
+            }
+            CltError::ReplicationsTooFew => {
+                write!(f, "At least two replications are required")
+            }
+            CltError::ParametersInvalid => {
+                write!(
+                    f,
+                    "Parameters of the population distribution are out of its domain"
+                )
+            }
+            CltError::NormalDistribution(e) => {
+                write!(f, "Normal Distribution Hypothesis failed: {e}")
+            }
+        }
+    }
+}
+
+/// Distribution the samples are drawn from.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Population {
+    /// `N(μ, σ)`.
+    Normal { mean: f64, std_dev: f64 },
+    /// `U(a, b)`.
+    Uniform { lower: f64, upper: f64 },
+    /// `Exp(λ)`, skewed to the right.
+    Exponential { rate: f64 },
+    /// `Poisson(λ)`.
+    Poisson { rate: f64 },
+    /// `1` with the probability `p` and `0` otherwise.
+    Bernoulli { p: f64 },
+}
+
+impl Population {
+    /// `μ`.
+    pub fn mean(&self) -> f64 {
+        match *self {
+            Population::Normal { mean, .. } => mean,
+            Population::Uniform { lower, upper } => (lower + upper) / 2.,
+            Population::Exponential { rate } => 1. / rate,
+            Population::Poisson { rate } => rate,
+            Population::Bernoulli { p } => p,
+        }
+    }
+
+    /// `σ²`.
+    pub fn variance(&self) -> f64 {
+        match *self {
+            Population::Normal { std_dev, .. } => std_dev * std_dev,
+            Population::Uniform { lower, upper } => (upper - lower).powi(2) / 12.,
+            Population::Exponential { rate } => 1. / (rate * rate),
+            Population::Poisson { rate } => rate,
+            Population::Bernoulli { p } => p * (1. - p),
+        }
+    }
+
+    fn validate(&self) -> Result<(), CltError> {
+        let valid = match *self {
+            Population::Normal { mean, std_dev } => {
+                mean.is_finite() && std_dev > 0. && std_dev.is_finite()
+            }
+            Population::Uniform { lower, upper } => {
+                lower.is_finite() && upper.is_finite() && lower < upper
+            }
+            Population::Exponential { rate } | Population::Poisson { rate } => {
+                rate > 0. && rate.is_finite()
+            }
+            Population::Bernoulli { p } => p > 0. && p < 1.,
+        };
+
+        if !valid {
+            return Err(CltError::ParametersInvalid);
+        }
+
+        Ok(())
+    }
+
+    /// Draws `size` values; the parameters are validated.
+    fn sample(&self, rng: &mut dyn RngCore, size: usize) -> Vec<f64> {
+        match *self {
+            Population::Normal { mean, std_dev } => {
+                let normal = Normal::new(mean, std_dev).unwrap();
+                (0..size).map(|_| rng.sample(normal)).collect()
+            }
+            Population::Uniform { lower, upper } => {
+                let uniform = Uniform::new(lower, upper).unwrap();
+                (0..size).map(|_| rng.sample(uniform)).collect()
+            }
+            Population::Exponential { rate } => {
+                let exponential = Exp::new(rate).unwrap();
+                (0..size).map(|_| rng.sample(exponential)).collect()
+            }
+            Population::Poisson { rate } => {
+                let poisson = Poisson::new(rate).unwrap();
+                (0..size).map(|_| rng.sample(poisson)).collect()
+            }
+            Population::Bernoulli { p } => (0..size).map(|_| f64::from(rng.gen_bool(p))).collect(),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CltOutcome {
+    /// Means of the replicated samples.
+    pub means: Vec<f64>,
+    /// Mean of the means, close to `μ`.
+    pub means_mean: f64,
+    /// Standard deviation of the means, close to `σ / √n`.
+    pub means_std_dev: f64,
+    /// `μ`.
+    pub expected_mean: f64,
+    /// `σ / √n`.
+    pub expected_standard_error: f64,
+    /// Normal Distribution Hypothesis over the means grouped by Sturges' rule.
+    pub test: NDHOutcome,
+}
+
+/// Simulates the sampling distribution of the mean of `sample_size` values from the population
+/// and tests its normality.
+pub fn simulate(
+    population: Population,
+    sample_size: usize,
+    replications: usize,
+    significance: f64,
+    rng: RngSource,
+) -> Result<CltOutcome, CltError> {
+    population.validate()?;
+
+    if sample_size == 0 {
+        return Err(CltError::SampleSizeInvalid);
+    }
+
+    if replications < 2 {
+        return Err(CltError::ReplicationsTooFew);
+    }
+
+    alpha::validate(significance, CltError::SignificanceInvalid)?;
+
+    let means: Vec<_> = rng.with_rng(|rng| {
+        (0..replications)
+            .map(|_| mean(&population.sample(rng, sample_size)))
+            .collect()
+    });
+
+    let grouped_sample = GroupedSample::sturges(&means).map_err(CltError::NormalDistribution)?;
+    let test = IncompleteNDHProblemSituation::new(
+        &grouped_sample.random_value_ranges,
+        &grouped_sample.frequencies,
+        significance,
+    )
+    .and_then(|situation| NormalDistributionHypothesis::new(Box::new(situation)))
+    .and_then(|ndh| ndh.outcome())
+    .map_err(CltError::NormalDistribution)?;
+
+    Ok(CltOutcome {
+        means_mean: mean(&means),
+        means_std_dev: unbiased_variance(&means).sqrt(),
+        expected_mean: population.mean(),
+        expected_standard_error: (population.variance() / sample_size as f64).sqrt(),
+        means,
+        test,
+    })
+}
@@ -0,0 +1,76 @@
+//! Exact counts of classical probability problems.
+//!
+//! **Given**: numbers of objects and of the objects chosen or arranged.
+//! **To figure out**: In how many ways can they be chosen or arranged, and what is the probability
+//! of an event as the ratio of the favourable outcomes to all of them?
+//!
+//! The counts are exact big integers: `C(n, k) = n! / (k! (n - k)!)`, `A(n, k) = n! / (n - k)!` and
+//! the multinomial coefficient `(k₁ + … + kₘ)! / (k₁! … kₘ!)`. The `f64` wrappers are infinite beyond
+//! the range of `f64`; a ratio of two counts is accurate even where both are.
+
+use num_bigint::BigUint;
+use num_traits::{One, ToPrimitive, Zero};
+
+/// `n!`.
+pub fn factorial(n: u64) -> BigUint {
+    (2..=n).fold(BigUint::one(), |product, i| product * i)
+}
+
+/// `C(n, k)`, the number of the ways to choose `k` of `n` objects, zero if `k > n`.
+pub fn n_choose_k(n: u64, k: u64) -> BigUint {
+    if k > n {
+        return BigUint::zero();
+    }
+
+    // Every partial product `C(n - k + i, i)` is an integer, so the divisions are exact.
+    let k = k.min(n - k);
+    (1..=k).fold(BigUint::one(), |product, i| product * (n - k + i) / i)
+}
+
+/// `A(n, k)`, the number of the ordered arrangements of `k` of `n` objects, zero if `k > n`.
+pub fn permutations(n: u64, k: u64) -> BigUint {
+    if k > n {
+        return BigUint::zero();
+    }
+
+    (n - k + 1..=n).fold(BigUint::one(), |product, i| product * i)
+}
+
+/// `(k₁ + … + kₘ)! / (k₁! … kₘ!)`, the number of the ways to split the objects into groups of the given sizes.
+pub fn multinomial(counts: &[u64]) -> BigUint {
+    let mut total = 0;
+    counts.iter().fold(BigUint::one(), |product, count| {
+        total += count;
+        product * n_choose_k(total, *count)
+    })
+}
+
+pub fn n_choose_k_f64(n: u64, k: u64) -> f64 {
+    to_f64(&n_choose_k(n, k))
+}
+
+pub fn permutations_f64(n: u64, k: u64) -> f64 {
+    to_f64(&permutations(n, k))
+}
+
+pub fn multinomial_f64(counts: &[u64]) -> f64 {
+    to_f64(&multinomial(counts))
+}
+
+/// `favourable / total`, e.g. the classical probability of an event; `NaN` if `total` is zero.
+pub fn ratio(favourable: &BigUint, total: &BigUint) -> f64 {
+    if total.is_zero() {
+        return f64::NAN;
+    }
+
+    // Dropping the same low bits of both keeps them within the range of `f64`.
+    let shift = favourable
+        .bits()
+        .max(total.bits())
+        .saturating_sub(f64::MAX_EXP as u64 - 2);
+    to_f64(&(favourable >> shift)) / to_f64(&(total >> shift))
+}
+
+fn to_f64(count: &BigUint) -> f64 {
+    count.to_f64().unwrap_or(f64::INFINITY)
+}
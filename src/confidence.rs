@@ -0,0 +1,230 @@
+//! Confidence intervals for the difference and the ratio of two means.
+//!
+//! **Given**: *significance ratio*, *samples of random variables **X** and **Y***, or estimates `a` and `b`
+//! with their variances, covariance and freedom degrees.
+//! **To figure out**: Where do `E(X) - E(Y)` and `E(X) / E(Y)` lie with probability `1 - significance`?
+//!
+//! The difference uses Welch's standard error `√(s²_X / n + s²_Y / m)` and the Welch–Satterthwaite
+//! freedom degrees. The ratio `ρ = a / b` uses Fieller's theorem: the region of `ρ` for which
+//! `(a - ρb)² ≤ t² (v_a - 2ρ c + ρ² v_b)`, the roots of a quadratic. With `g = t² v_b / b² < 1` the region
+//! is an interval, not symmetric about `a / b`; with `g ≥ 1` the denominator is not significantly different
+//! from zero and the region is unbounded, as in bioassay with a weak slope.
+//! For samples drawn without replacement from finite populations of sizes `N` and `M`, the variances
+//! of the means are multiplied by the finite population corrections `1 - n / N` and `1 - m / M`.
+
+use statrs::distribution::{ContinuousCDF, StudentsT};
+
+use crate::alpha;
+use crate::numeric::{mean, unbiased_variance};
+use crate::sampling::finite_population_correction;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum ConfidenceError {
+    SignificanceInvalid,
+    SampleTooSmall,
+    VarianceInvalid,
+    FreedomDegreesInvalid,
+    PopulationSizeInvalid,
+}
+
+impl std::fmt::Display for ConfidenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ConfidenceError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            ConfidenceError::SampleTooSmall => {
+                write!(f, "Each sample must contain at least two values")
+            }
+            ConfidenceError::VarianceInvalid => {
+                write!(
+                    f,
+                    "Variances must be non-negative, not both zero, and the covariance within their bounds"
+                )
+            }
+            ConfidenceError::FreedomDegreesInvalid => {
+                write!(f, "Freedom degrees must be positive")
+            }
+            ConfidenceError::PopulationSizeInvalid => {
+                write!(f, "Each population must hold at least its sample")
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DifferenceInterval {
+    /// `x̄ - ȳ`.
+    pub estimate: f64,
+    pub standard_error: f64,
+    pub freedom_degrees: f64,
+    pub confidence_interval: (f64, f64),
+}
+
+/// Region of the ratio given by Fieller's theorem.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum FiellerRegion {
+    /// `[lower, upper]`.
+    Bounded(f64, f64),
+    /// `(-∞, lower] ∪ [upper, ∞)`.
+    Exclusive(f64, f64),
+    /// The whole line.
+    Unbounded,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FiellerInterval {
+    /// `a / b`.
+    pub estimate: f64,
+    /// `g = t² v_b / b²`, below 1 for a bounded region; values above 0.05 make the delta method unreliable.
+    pub g: f64,
+    pub region: FiellerRegion,
+}
+
+/// Welch's confidence interval for `E(X) - E(Y)`, with the sizes `(N, M)` of finite populations if any.
+pub fn difference_of_means(
+    x_sample: &[f64],
+    y_sample: &[f64],
+    population_sizes: Option<(f64, f64)>,
+    significance: f64,
+) -> Result<DifferenceInterval, ConfidenceError> {
+    let (x_mean, x_variance, y_mean, y_variance) =
+        means(x_sample, y_sample, population_sizes, significance)?;
+
+    let standard_error = (x_variance + y_variance).sqrt();
+    let freedom_degrees =
+        welch_freedom_degrees((x_variance, x_sample.len()), (y_variance, y_sample.len()));
+    let t = t_critical_value(freedom_degrees, significance)?;
+    let estimate = x_mean - y_mean;
+
+    Ok(DifferenceInterval {
+        estimate,
+        standard_error,
+        freedom_degrees,
+        confidence_interval: (estimate - t * standard_error, estimate + t * standard_error),
+    })
+}
+
+/// Fieller's confidence region for `E(X) / E(Y)` of independent samples, with the sizes `(N, M)`
+/// of finite populations if any.
+///
+/// The freedom degrees are those of Welch–Satterthwaite for `X - ρY` at the estimate `ρ = x̄ / ȳ`.
+pub fn ratio_of_means(
+    x_sample: &[f64],
+    y_sample: &[f64],
+    population_sizes: Option<(f64, f64)>,
+    significance: f64,
+) -> Result<FiellerInterval, ConfidenceError> {
+    let (x_mean, x_variance, y_mean, y_variance) =
+        means(x_sample, y_sample, population_sizes, significance)?;
+
+    let ratio = x_mean / y_mean;
+    let freedom_degrees = welch_freedom_degrees(
+        (x_variance, x_sample.len()),
+        (ratio * ratio * y_variance, y_sample.len()),
+    );
+
+    fieller(
+        (x_mean, y_mean),
+        (x_variance, y_variance),
+        0.,
+        freedom_degrees,
+        significance,
+    )
+}
+
+/// Fieller's confidence region for `a / b` from the estimates `(a, b)`, their variances `(v_a, v_b)`,
+/// their covariance `c`, e.g. of two regression coefficients, and the freedom degrees of the variances.
+pub fn fieller(
+    (a, b): (f64, f64),
+    (a_variance, b_variance): (f64, f64),
+    covariance: f64,
+    freedom_degrees: f64,
+    significance: f64,
+) -> Result<FiellerInterval, ConfidenceError> {
+    alpha::validate(significance, ConfidenceError::SignificanceInvalid)?;
+
+    if a_variance.is_nan()
+        || a_variance < 0.
+        || b_variance.is_nan()
+        || b_variance < 0.
+        || a_variance + b_variance == 0.
+        || covariance * covariance > a_variance * b_variance
+    {
+        return Err(ConfidenceError::VarianceInvalid);
+    }
+
+    let t_squared = t_critical_value(freedom_degrees, significance)?.powi(2);
+
+    // `A ρ² - 2 B ρ + C ≤ 0`.
+    let quadratic = b * b - t_squared * b_variance;
+    let linear = a * b - t_squared * covariance;
+    let constant = a * a - t_squared * a_variance;
+    let discriminant = linear * linear - quadratic * constant;
+
+    let region = if quadratic > 0. {
+        // `A > 0` makes the discriminant non-negative up to round-off.
+        let root = discriminant.max(0.).sqrt();
+        FiellerRegion::Bounded((linear - root) / quadratic, (linear + root) / quadratic)
+    } else if discriminant > 0. && quadratic < 0. {
+        let root = discriminant.sqrt();
+        FiellerRegion::Exclusive((linear + root) / quadratic, (linear - root) / quadratic)
+    } else {
+        FiellerRegion::Unbounded
+    };
+
+    Ok(FiellerInterval {
+        estimate: a / b,
+        g: t_squared * b_variance / (b * b),
+        region,
+    })
+}
+
+/// Means and the variances of the means of both samples.
+fn means(
+    x_sample: &[f64],
+    y_sample: &[f64],
+    population_sizes: Option<(f64, f64)>,
+    significance: f64,
+) -> Result<(f64, f64, f64, f64), ConfidenceError> {
+    if x_sample.len() < 2 || y_sample.len() < 2 {
+        return Err(ConfidenceError::SampleTooSmall);
+    }
+
+    alpha::validate(significance, ConfidenceError::SignificanceInvalid)?;
+
+    let (n, m) = (x_sample.len() as f64, y_sample.len() as f64);
+    let (x_fpc, y_fpc) = match population_sizes {
+        Some((x_size, y_size)) if x_size >= n && y_size >= m => (
+            finite_population_correction(n, x_size),
+            finite_population_correction(m, y_size),
+        ),
+        Some(_) => return Err(ConfidenceError::PopulationSizeInvalid),
+        None => (1., 1.),
+    };
+    let (x_variance, y_variance) = (
+        x_fpc * unbiased_variance(x_sample) / n,
+        y_fpc * unbiased_variance(y_sample) / m,
+    );
+    if x_variance + y_variance == 0. {
+        return Err(ConfidenceError::VarianceInvalid);
+    }
+
+    Ok((mean(x_sample), x_variance, mean(y_sample), y_variance))
+}
+
+/// Welch–Satterthwaite freedom degrees of a sum of the variances of means `vᵢ` of samples of sizes `nᵢ`.
+fn welch_freedom_degrees((x_variance, n): (f64, usize), (y_variance, m): (f64, usize)) -> f64 {
+    (x_variance + y_variance).powi(2)
+        / (x_variance.powi(2) / (n as f64 - 1.) + y_variance.powi(2) / (m as f64 - 1.))
+}
+
+fn t_critical_value(freedom_degrees: f64, significance: f64) -> Result<f64, ConfidenceError> {
+    Ok(StudentsT::new(0., 1., freedom_degrees)
+        .map_err(|_| ConfidenceError::FreedomDegreesInvalid)?
+        .inverse_cdf(1. - significance / 2.))
+}
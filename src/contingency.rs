@@ -0,0 +1,155 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *contingency table* of two categorical variables **X** and **Y**.
+//! **To figure out**: Is it appropriate to **assume** that **X** and **Y** are independent?
+//!
+//! Along with the decision, the residuals of every cell tell where the dependence comes from.
+
+use statistics_problems_core::decisions::{self, DecisionError};
+
+use crate::alpha;
+use crate::critical_region::CriticalRegion;
+use crate::critical_values::StatrsQuantiles;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::residuals::Residuals;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum ContingencyError {
+    TableInvalid,
+    SignificanceInvalid,
+    FreedomDegreesInvalid,
+}
+
+impl std::fmt::Display for ContingencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ContingencyError::TableInvalid => {
+                write!(
+                    f,
+                    "Table must be rectangular with at least 2 rows and 2 columns and positive totals"
+                )
+            }
+            ContingencyError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            ContingencyError::FreedomDegreesInvalid => {
+                write!(
+                    f,
+                    "Freedom Degrees led to fail in initialization of Gamma underlying Chi"
+                )
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Cell {
+    pub observed: f64,
+    pub expected: f64,
+    pub residuals: Residuals,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct IndependenceOutcome {
+    pub freedom_degrees: f64,
+    pub chi_squared_observed: f64,
+    pub chi_squared_critical_value: f64,
+    /// Values of the statistic for which the hypothesis of independence is rejected, the upper tail.
+    pub critical_region: CriticalRegion,
+    /// Cells in the layout of the table.
+    pub cells: Vec<Vec<Cell>>,
+    pub hypotheses: Hypotheses,
+    /// Whether the hypothesis of independence is accepted.
+    pub accepted: bool,
+}
+
+pub struct IndependenceHypothesis {
+    table: Vec<Vec<f64>>,
+    significance: f64,
+}
+
+impl IndependenceHypothesis {
+    pub fn new(table: &[Vec<f64>], significance: f64) -> Result<Self, ContingencyError> {
+        let columns = table.first().map_or(0, Vec::len);
+        if table.len() < 2 || columns < 2 || table.iter().any(|row| row.len() != columns) {
+            return Err(ContingencyError::TableInvalid);
+        }
+
+        let has_empty_margin = table.iter().any(|row| row.iter().sum::<f64>() <= 0.)
+            || (0..columns).any(|j| table.iter().map(|row| row[j]).sum::<f64>() <= 0.);
+        if has_empty_margin {
+            return Err(ContingencyError::TableInvalid);
+        }
+
+        alpha::validate(significance, ContingencyError::SignificanceInvalid)?;
+
+        Ok(Self {
+            table: table.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn solve(&self) -> Result<bool, ContingencyError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    /// The statistic and the decision are the ones of the core; the cells break the statistic down.
+    pub fn outcome(&self) -> Result<IndependenceOutcome, ContingencyError> {
+        let decision = decisions::independence(&self.table, self.significance, &StatrsQuantiles)
+            .map_err(|e| match e {
+                DecisionError::TableInvalid => ContingencyError::TableInvalid,
+                DecisionError::SignificanceInvalid => ContingencyError::SignificanceInvalid,
+                _ => ContingencyError::FreedomDegreesInvalid,
+            })?;
+
+        let row_totals: Vec<f64> = self.table.iter().map(|row| row.iter().sum()).collect();
+        let column_totals: Vec<f64> = (0..self.table[0].len())
+            .map(|j| self.table.iter().map(|row| row[j]).sum())
+            .collect();
+        let total = row_totals.iter().sum::<f64>();
+
+        let cells: Vec<Vec<Cell>> = self
+            .table
+            .iter()
+            .zip(row_totals.iter())
+            .map(|(row, row_total)| {
+                row.iter()
+                    .zip(column_totals.iter())
+                    .map(|(observed, column_total)| {
+                        let expected = row_total * column_total / total;
+                        Cell {
+                            observed: *observed,
+                            expected,
+                            residuals: Residuals::contingency(
+                                *observed,
+                                expected,
+                                *row_total,
+                                *column_total,
+                                total,
+                            ),
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let freedom_degrees = ((row_totals.len() - 1) * (column_totals.len() - 1)) as f64;
+
+        Ok(IndependenceOutcome {
+            freedom_degrees,
+            chi_squared_observed: decision.statistic,
+            chi_squared_critical_value: decision.critical_value,
+            critical_region: CriticalRegion::Upper(decision.critical_value),
+            cells,
+            hypotheses: Hypotheses::new(
+                "X and Y are independent",
+                "X and Y are dependent",
+                Alternative::Greater,
+            ),
+            accepted: decision.accepted,
+        })
+    }
+}
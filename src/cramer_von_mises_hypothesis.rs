@@ -0,0 +1,153 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *sample of a random variable **X***, *sample of a random variable **Y***.
+//! **To figure out**: Is it appropriate to **assume** that **X** and **Y** have the same distribution?
+//!
+//! The two-sample Cramér–von Mises statistic of Anderson (1962) integrates the squared difference
+//! of the empirical distribution functions over the pooled sample; its p-value is taken from
+//! the limiting distribution.
+
+use statrs::function::gamma::ln_gamma;
+
+use crate::alpha;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+use crate::order_statistics::average_ranks;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum CvMError {
+    SignificanceInvalid,
+    SampleTooSmall,
+    ValueMissing,
+}
+
+impl std::fmt::Display for CvMError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CvMError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            CvMError::SampleTooSmall => {
+                write!(f, "Each sample must contain at least two values")
+            }
+            CvMError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+pub struct CramerVonMisesHypothesis {
+    x_sample: Vec<f64>,
+    y_sample: Vec<f64>,
+    significance: f64,
+}
+
+impl CramerVonMisesHypothesis {
+    pub fn new(x_sample: &[f64], y_sample: &[f64], significance: f64) -> Result<Self, CvMError> {
+        MissingPolicy::Error
+            .apply(x_sample)
+            .and(MissingPolicy::Error.apply(y_sample))
+            .map_err(|_| CvMError::ValueMissing)?;
+
+        if x_sample.len() < 2 || y_sample.len() < 2 {
+            return Err(CvMError::SampleTooSmall);
+        }
+
+        alpha::validate(significance, CvMError::SignificanceInvalid)?;
+
+        Ok(Self {
+            x_sample: x_sample.to_owned(),
+            y_sample: y_sample.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            "X and Y have the same distribution",
+            "X and Y have different distributions",
+            Alternative::Greater,
+        )
+    }
+
+    /// Returns the `T` statistic and its asymptotic p-value.
+    pub fn statistic(&self) -> (f64, f64) {
+        let (n, m) = (self.x_sample.len(), self.y_sample.len());
+
+        let pooled: Vec<_> = self
+            .x_sample
+            .iter()
+            .chain(self.y_sample.iter())
+            .copied()
+            .collect();
+        let (ranks, _) = average_ranks(&pooled);
+
+        let squared_shifts = |ranks: &[f64]| {
+            let mut ranks = ranks.to_owned();
+            ranks.sort_by(f64::total_cmp);
+            ranks
+                .iter()
+                .enumerate()
+                .map(|(i, r)| (r - (i + 1) as f64).powi(2))
+                .sum::<f64>()
+        };
+
+        let (n, m) = (n as f64, m as f64);
+        let u = n * squared_shifts(&ranks[..self.x_sample.len()])
+            + m * squared_shifts(&ranks[self.x_sample.len()..]);
+        let t = u / (n * m * (n + m)) - (4. * m * n - 1.) / (6. * (m + n));
+
+        (t, 1. - limiting_cdf(t))
+    }
+
+    pub fn solve(&self) -> Result<bool, CvMError> {
+        let (_, p_value) = self.statistic();
+
+        Ok(p_value > self.significance)
+    }
+}
+
+/// CDF of the limiting Cramér–von Mises distribution by the series of Anderson and Darling (1952):
+/// `1 / (π√x) · Σₖ Γ(k + ½) / (Γ(½) k!) · √(4k + 1) · e^(-uₖ) · K¼(uₖ)`, `uₖ = (4k + 1)² / (16x)`.
+fn limiting_cdf(x: f64) -> f64 {
+    if x <= 0. {
+        return 0.;
+    }
+
+    let mut total = 0.;
+    for k in 0..100 {
+        let k = k as f64;
+        let y = 4. * k + 1.;
+        let u = y.powi(2) / (16. * x);
+        let term = (ln_gamma(k + 0.5) - ln_gamma(k + 1.) - ln_gamma(0.5)).exp()
+            * y.sqrt()
+            * scaled_bessel_k_quarter(u);
+        total += term;
+        if term < 1e-12 * total {
+            break;
+        }
+    }
+
+    (total / (std::f64::consts::PI * x.sqrt())).min(1.)
+}
+
+/// `e^(-u) · K¼(u)` from `K¼(u) = ∫₀^∞ e^(-u·cosh t) cosh(t / 4) dt`, with the factor `e^(-u)`
+/// taken under the integral to avoid underflow.
+fn scaled_bessel_k_quarter(u: f64) -> f64 {
+    // The integrand `e^(-u (1 + cosh t))` is negligible once `u (cosh t - 1)` exceeds 50.
+    let upper = (1. + 50. / u).acosh();
+    let intervals: usize = 2000;
+    let h = upper / intervals as f64;
+    let integrand = |t: f64| (-u * (1. + t.cosh())).exp() * (t / 4.).cosh();
+
+    let interior = (1..intervals)
+        .map(|i| {
+            let weight = if i.is_multiple_of(2) { 2. } else { 4. };
+            weight * integrand(i as f64 * h)
+        })
+        .sum::<f64>();
+
+    (integrand(0.) + interior + integrand(upper)) * h / 3.
+}
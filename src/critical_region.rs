@@ -0,0 +1,149 @@
+//! Critical regions: the values of a statistic for which `H₀` is rejected.
+//!
+//! Most tests of the crate reject in the upper tail only. A test of the variance against `σ² ≠ σ₀²`
+//! rejects in both tails of **Chi-Squared**, and some textbooks test the goodness of fit in both tails too,
+//! a statistic that is too small pointing to a fit too good to be true. The t-test of equal means rejects
+//! in both tails of **Student's T** unless the alternative is one-sided. The significance is split equally
+//! between the two tails.
+
+use crate::critical_values::{self, CriticalValueError, CriticalValueSource};
+use crate::hypotheses::Alternative;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum CriticalRegion {
+    /// `statistic ≥ upper`.
+    Upper(f64),
+    /// `statistic ≤ lower`.
+    Lower(f64),
+    /// `statistic ≤ lower` or `statistic ≥ upper`.
+    TwoTailed(f64, f64),
+}
+
+impl CriticalRegion {
+    /// Region of the **Chi-Squared** statistic with `freedom_degrees` for the alternative.
+    ///
+    /// The lower critical values are looked up in the table by the significances `1 - α` or `1 - α/2`.
+    pub fn chi_squared(
+        freedom_degrees: f64,
+        significance: f64,
+        alternative: Alternative,
+        source: CriticalValueSource,
+    ) -> Result<Self, CriticalValueError> {
+        // `P(X > x) = 1 - p` for the lower `p`-quantile `x`.
+        let upper = |p| critical_values::chi_squared(freedom_degrees, p, source);
+        let lower = |p| critical_values::chi_squared(freedom_degrees, 1. - p, source);
+
+        match alternative {
+            Alternative::Greater => Ok(CriticalRegion::Upper(upper(significance)?)),
+            Alternative::Less => Ok(CriticalRegion::Lower(lower(significance)?)),
+            Alternative::TwoSided => Ok(CriticalRegion::TwoTailed(
+                lower(significance / 2.)?,
+                upper(significance / 2.)?,
+            )),
+        }
+    }
+
+    /// Region of the **Student's T** statistic with `freedom_degrees` for the alternative,
+    /// symmetric around zero if two-tailed.
+    pub fn students_t(
+        freedom_degrees: f64,
+        significance: f64,
+        alternative: Alternative,
+        source: CriticalValueSource,
+    ) -> Result<Self, CriticalValueError> {
+        match alternative {
+            Alternative::Greater => Ok(CriticalRegion::Upper(critical_values::students_t(
+                freedom_degrees,
+                significance,
+                source,
+            )?)),
+            Alternative::Less => Ok(CriticalRegion::Lower(-critical_values::students_t(
+                freedom_degrees,
+                significance,
+                source,
+            )?)),
+            Alternative::TwoSided => {
+                let upper =
+                    critical_values::students_t_two_sided(freedom_degrees, significance, source)?;
+                Ok(CriticalRegion::TwoTailed(-upper, upper))
+            }
+        }
+    }
+
+    /// Region of the **Fisher–Snedecor** statistic with the freedom degrees of the numerator
+    /// and of the denominator for the alternative.
+    ///
+    /// The lower critical values are the reciprocals of the upper ones with the freedom degrees swapped,
+    /// `F(1 - α; k₁, k₂) = 1 / F(α; k₂, k₁)`.
+    pub fn fisher_snedecor(
+        numerator_freedom_degrees: f64,
+        denominator_freedom_degrees: f64,
+        significance: f64,
+        alternative: Alternative,
+        source: CriticalValueSource,
+    ) -> Result<Self, CriticalValueError> {
+        let upper = |p| {
+            critical_values::fisher_snedecor(
+                numerator_freedom_degrees,
+                denominator_freedom_degrees,
+                p,
+                source,
+            )
+        };
+        let lower = |p| {
+            critical_values::fisher_snedecor(
+                denominator_freedom_degrees,
+                numerator_freedom_degrees,
+                p,
+                source,
+            )
+            .map(|f| 1. / f)
+        };
+
+        match alternative {
+            Alternative::Greater => Ok(CriticalRegion::Upper(upper(significance)?)),
+            Alternative::Less => Ok(CriticalRegion::Lower(lower(significance)?)),
+            Alternative::TwoSided => Ok(CriticalRegion::TwoTailed(
+                lower(significance / 2.)?,
+                upper(significance / 2.)?,
+            )),
+        }
+    }
+
+    /// Whether `H₀` is rejected for the statistic.
+    pub fn contains(&self, statistic: f64) -> bool {
+        match *self {
+            CriticalRegion::Upper(upper) => statistic >= upper,
+            CriticalRegion::Lower(lower) => statistic <= lower,
+            CriticalRegion::TwoTailed(lower, upper) => statistic <= lower || statistic >= upper,
+        }
+    }
+
+    /// The lower critical value, if the region has a lower tail.
+    pub fn lower(&self) -> Option<f64> {
+        match *self {
+            CriticalRegion::Lower(lower) | CriticalRegion::TwoTailed(lower, _) => Some(lower),
+            CriticalRegion::Upper(_) => None,
+        }
+    }
+
+    /// The upper critical value, if the region has an upper tail.
+    pub fn upper(&self) -> Option<f64> {
+        match *self {
+            CriticalRegion::Upper(upper) | CriticalRegion::TwoTailed(_, upper) => Some(upper),
+            CriticalRegion::Lower(_) => None,
+        }
+    }
+
+    /// Applies `f` to the critical values, e.g. to round them.
+    pub fn map(self, f: impl Fn(f64) -> f64) -> Self {
+        match self {
+            CriticalRegion::Upper(upper) => CriticalRegion::Upper(f(upper)),
+            CriticalRegion::Lower(lower) => CriticalRegion::Lower(f(lower)),
+            CriticalRegion::TwoTailed(lower, upper) => {
+                CriticalRegion::TwoTailed(f(lower), f(upper))
+            }
+        }
+    }
+}
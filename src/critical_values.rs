@@ -0,0 +1,651 @@
+//! Critical values of the **Chi-Squared**, **Student's t** and **Fisher–Snedecor** distributions,
+//! either computed by `inverse_cdf` or looked up in the standard tables printed in textbooks,
+//! so that comparisons can match the printed tables exactly.
+//!
+//! Tabulated:
+//! - Chi-Squared upper critical values with 3 decimals for `k = 1..=30, 40, 50, …, 100`,
+//!   `α ∈ {0.99, 0.975, 0.95, 0.9, 0.1, 0.05, 0.025, 0.01, 0.005}`;
+//! - Student's t two-sided critical values with 3 decimals for `k = 1..=30, 40, 60, 120, ∞`,
+//!   `α ∈ {0.2, 0.1, 0.05, 0.02, 0.01, 0.002, 0.001}`;
+//! - Fisher–Snedecor upper critical values with 2 decimals for `k₁ = 1..=10, 12, 15, 20, 24, 30, 40, 60, 120`,
+//!   `k₂ = 1..=30, 40, 60, 120`, `α ∈ {0.05, 0.01}`.
+
+use statistics_problems_core::quantiles::QuantileProvider;
+use statrs::distribution::{ChiSquared, ContinuousCDF, FisherSnedecor, Normal, StudentsT};
+
+use crate::alpha;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum CriticalValueError {
+    SignificanceInvalid,
+    FreedomDegreesInvalid,
+    NotTabulated,
+}
+
+impl std::fmt::Display for CriticalValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CriticalValueError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            CriticalValueError::FreedomDegreesInvalid => {
+                write!(
+                    f,
+                    "Freedom Degrees led to fail in initialization of the distribution"
+                )
+            }
+            CriticalValueError::NotTabulated => {
+                write!(
+                    f,
+                    "Standard tables hold no critical value for these freedom degrees and significance"
+                )
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub enum CriticalValueSource {
+    /// Computed by `inverse_cdf`.
+    #[default]
+    Quantile,
+    /// Looked up in the embedded standard tables; missing entries are errors, not interpolated.
+    Table,
+}
+
+/// `x` such that `P(X > x) = significance` for the Chi-Squared `X` with `freedom_degrees`.
+pub fn chi_squared(
+    freedom_degrees: f64,
+    significance: f64,
+    source: CriticalValueSource,
+) -> Result<f64, CriticalValueError> {
+    validate(significance)?;
+
+    match source {
+        CriticalValueSource::Quantile => {
+            let chi_squared_dist = ChiSquared::new(freedom_degrees)
+                .map_err(|_| CriticalValueError::FreedomDegreesInvalid)?;
+            Ok(chi_squared_dist.inverse_cdf(1. - significance))
+        }
+        CriticalValueSource::Table => {
+            let i = tabulated_index(&CHI_SQUARED_FREEDOM_DEGREES, freedom_degrees)?;
+            let j = tabulated_significance(&CHI_SQUARED_SIGNIFICANCES, significance)?;
+            Ok(CHI_SQUARED[i][j])
+        }
+    }
+}
+
+/// `t` such that `P(|T| > t) = significance` for the Student's `T` with `freedom_degrees`.
+///
+/// In the table, freedom degrees beyond 120 are looked up in the `∞` row.
+pub fn students_t_two_sided(
+    freedom_degrees: f64,
+    significance: f64,
+    source: CriticalValueSource,
+) -> Result<f64, CriticalValueError> {
+    validate(significance)?;
+
+    match source {
+        CriticalValueSource::Quantile => {
+            let students_t_dist = StudentsT::new(0., 1., freedom_degrees)
+                .map_err(|_| CriticalValueError::FreedomDegreesInvalid)?;
+            Ok(students_t_dist.inverse_cdf(1. - significance / 2.))
+        }
+        CriticalValueSource::Table => {
+            let i = if freedom_degrees > 120. && freedom_degrees.fract() == 0. {
+                STUDENTS_T_FREEDOM_DEGREES.len()
+            } else {
+                tabulated_index(&STUDENTS_T_FREEDOM_DEGREES, freedom_degrees)?
+            };
+            let j = tabulated_significance(&STUDENTS_T_SIGNIFICANCES, significance)?;
+            Ok(STUDENTS_T[i][j])
+        }
+    }
+}
+
+/// `t` such that `P(T > t) = significance` for the Student's `T` with `freedom_degrees`.
+///
+/// In the table, it is the two-sided critical value of the doubled significance.
+pub fn students_t(
+    freedom_degrees: f64,
+    significance: f64,
+    source: CriticalValueSource,
+) -> Result<f64, CriticalValueError> {
+    validate(significance)?;
+
+    students_t_two_sided(freedom_degrees, 2. * significance, source)
+}
+
+/// `f` such that `P(F > f) = significance` for the Fisher–Snedecor `F` with the freedom degrees
+/// of the numerator and of the denominator.
+pub fn fisher_snedecor(
+    numerator_freedom_degrees: f64,
+    denominator_freedom_degrees: f64,
+    significance: f64,
+    source: CriticalValueSource,
+) -> Result<f64, CriticalValueError> {
+    validate(significance)?;
+
+    match source {
+        CriticalValueSource::Quantile => {
+            let fisher_snedecor_dist =
+                FisherSnedecor::new(numerator_freedom_degrees, denominator_freedom_degrees)
+                    .map_err(|_| CriticalValueError::FreedomDegreesInvalid)?;
+            Ok(fisher_snedecor_dist.inverse_cdf(1. - significance))
+        }
+        CriticalValueSource::Table => {
+            let i = tabulated_index(
+                &FISHER_SNEDECOR_DENOMINATOR_FREEDOM_DEGREES,
+                denominator_freedom_degrees,
+            )?;
+            let j = tabulated_index(
+                &FISHER_SNEDECOR_NUMERATOR_FREEDOM_DEGREES,
+                numerator_freedom_degrees,
+            )?;
+            match tabulated_significance(&[0.05, 0.01], significance)? {
+                0 => Ok(FISHER_SNEDECOR_05[i][j]),
+                _ => Ok(FISHER_SNEDECOR_01[i][j]),
+            }
+        }
+    }
+}
+
+/// Quantiles computed by `statrs`, for the decisions of the `no_std` core crate.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct StatrsQuantiles;
+
+impl QuantileProvider for StatrsQuantiles {
+    fn standard_normal(&self, p: f64) -> Option<f64> {
+        (p > 0. && p < 1.).then(|| Normal::standard().inverse_cdf(p))
+    }
+
+    fn chi_squared(&self, freedom_degrees: f64, p: f64) -> Option<f64> {
+        let chi_squared_dist = ChiSquared::new(freedom_degrees).ok()?;
+        (p > 0. && p < 1.).then(|| chi_squared_dist.inverse_cdf(p))
+    }
+
+    fn students_t(&self, freedom_degrees: f64, p: f64) -> Option<f64> {
+        let students_t_dist = StudentsT::new(0., 1., freedom_degrees).ok()?;
+        (p > 0. && p < 1.).then(|| students_t_dist.inverse_cdf(p))
+    }
+
+    fn fisher_snedecor(
+        &self,
+        numerator_freedom_degrees: f64,
+        denominator_freedom_degrees: f64,
+        p: f64,
+    ) -> Option<f64> {
+        let fisher_snedecor_dist =
+            FisherSnedecor::new(numerator_freedom_degrees, denominator_freedom_degrees).ok()?;
+        (p > 0. && p < 1.).then(|| fisher_snedecor_dist.inverse_cdf(p))
+    }
+}
+
+fn validate(significance: f64) -> Result<(), CriticalValueError> {
+    alpha::validate(significance, CriticalValueError::SignificanceInvalid)
+}
+
+fn tabulated_index(
+    freedom_degrees_column: &[usize],
+    freedom_degrees: f64,
+) -> Result<usize, CriticalValueError> {
+    if freedom_degrees <= 0. || freedom_degrees.is_nan() {
+        return Err(CriticalValueError::FreedomDegreesInvalid);
+    }
+
+    freedom_degrees_column
+        .iter()
+        .position(|k| *k as f64 == freedom_degrees)
+        .ok_or(CriticalValueError::NotTabulated)
+}
+
+fn tabulated_significance(
+    significances_row: &[f64],
+    significance: f64,
+) -> Result<usize, CriticalValueError> {
+    significances_row
+        .iter()
+        .position(|alpha| (alpha - significance).abs() < 1e-12)
+        .ok_or(CriticalValueError::NotTabulated)
+}
+
+const CHI_SQUARED_FREEDOM_DEGREES: [usize; 37] = [
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+    27, 28, 29, 30, 40, 50, 60, 70, 80, 90, 100,
+];
+const CHI_SQUARED_SIGNIFICANCES: [f64; 9] = [0.99, 0.975, 0.95, 0.9, 0.1, 0.05, 0.025, 0.01, 0.005];
+// Printed table values, not approximations of mathematical constants.
+#[allow(clippy::approx_constant)]
+const CHI_SQUARED: [[f64; 9]; 37] = [
+    [
+        0.000157, 0.000982, 0.004, 0.016, 2.706, 3.841, 5.024, 6.635, 7.879,
+    ],
+    [
+        0.020, 0.051, 0.103, 0.211, 4.605, 5.991, 7.378, 9.210, 10.597,
+    ],
+    [
+        0.115, 0.216, 0.352, 0.584, 6.251, 7.815, 9.348, 11.345, 12.838,
+    ],
+    [
+        0.297, 0.484, 0.711, 1.064, 7.779, 9.488, 11.143, 13.277, 14.860,
+    ],
+    [
+        0.554, 0.831, 1.145, 1.610, 9.236, 11.070, 12.833, 15.086, 16.750,
+    ],
+    [
+        0.872, 1.237, 1.635, 2.204, 10.645, 12.592, 14.449, 16.812, 18.548,
+    ],
+    [
+        1.239, 1.690, 2.167, 2.833, 12.017, 14.067, 16.013, 18.475, 20.278,
+    ],
+    [
+        1.646, 2.180, 2.733, 3.490, 13.362, 15.507, 17.535, 20.090, 21.955,
+    ],
+    [
+        2.088, 2.700, 3.325, 4.168, 14.684, 16.919, 19.023, 21.666, 23.589,
+    ],
+    [
+        2.558, 3.247, 3.940, 4.865, 15.987, 18.307, 20.483, 23.209, 25.188,
+    ],
+    [
+        3.053, 3.816, 4.575, 5.578, 17.275, 19.675, 21.920, 24.725, 26.757,
+    ],
+    [
+        3.571, 4.404, 5.226, 6.304, 18.549, 21.026, 23.337, 26.217, 28.300,
+    ],
+    [
+        4.107, 5.009, 5.892, 7.042, 19.812, 22.362, 24.736, 27.688, 29.819,
+    ],
+    [
+        4.660, 5.629, 6.571, 7.790, 21.064, 23.685, 26.119, 29.141, 31.319,
+    ],
+    [
+        5.229, 6.262, 7.261, 8.547, 22.307, 24.996, 27.488, 30.578, 32.801,
+    ],
+    [
+        5.812, 6.908, 7.962, 9.312, 23.542, 26.296, 28.845, 32.000, 34.267,
+    ],
+    [
+        6.408, 7.564, 8.672, 10.085, 24.769, 27.587, 30.191, 33.409, 35.718,
+    ],
+    [
+        7.015, 8.231, 9.390, 10.865, 25.989, 28.869, 31.526, 34.805, 37.156,
+    ],
+    [
+        7.633, 8.907, 10.117, 11.651, 27.204, 30.144, 32.852, 36.191, 38.582,
+    ],
+    [
+        8.260, 9.591, 10.851, 12.443, 28.412, 31.410, 34.170, 37.566, 39.997,
+    ],
+    [
+        8.897, 10.283, 11.591, 13.240, 29.615, 32.671, 35.479, 38.932, 41.401,
+    ],
+    [
+        9.542, 10.982, 12.338, 14.041, 30.813, 33.924, 36.781, 40.289, 42.796,
+    ],
+    [
+        10.196, 11.689, 13.091, 14.848, 32.007, 35.172, 38.076, 41.638, 44.181,
+    ],
+    [
+        10.856, 12.401, 13.848, 15.659, 33.196, 36.415, 39.364, 42.980, 45.559,
+    ],
+    [
+        11.524, 13.120, 14.611, 16.473, 34.382, 37.652, 40.646, 44.314, 46.928,
+    ],
+    [
+        12.198, 13.844, 15.379, 17.292, 35.563, 38.885, 41.923, 45.642, 48.290,
+    ],
+    [
+        12.879, 14.573, 16.151, 18.114, 36.741, 40.113, 43.195, 46.963, 49.645,
+    ],
+    [
+        13.565, 15.308, 16.928, 18.939, 37.916, 41.337, 44.461, 48.278, 50.993,
+    ],
+    [
+        14.256, 16.047, 17.708, 19.768, 39.087, 42.557, 45.722, 49.588, 52.336,
+    ],
+    [
+        14.953, 16.791, 18.493, 20.599, 40.256, 43.773, 46.979, 50.892, 53.672,
+    ],
+    [
+        22.164, 24.433, 26.509, 29.051, 51.805, 55.758, 59.342, 63.691, 66.766,
+    ],
+    [
+        29.707, 32.357, 34.764, 37.689, 63.167, 67.505, 71.420, 76.154, 79.490,
+    ],
+    [
+        37.485, 40.482, 43.188, 46.459, 74.397, 79.082, 83.298, 88.379, 91.952,
+    ],
+    [
+        45.442, 48.758, 51.739, 55.329, 85.527, 90.531, 95.023, 100.425, 104.215,
+    ],
+    [
+        53.540, 57.153, 60.391, 64.278, 96.578, 101.879, 106.629, 112.329, 116.321,
+    ],
+    [
+        61.754, 65.647, 69.126, 73.291, 107.565, 113.145, 118.136, 124.116, 128.299,
+    ],
+    [
+        70.065, 74.222, 77.929, 82.358, 118.498, 124.342, 129.561, 135.807, 140.169,
+    ],
+];
+const STUDENTS_T_FREEDOM_DEGREES: [usize; 33] = [
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+    27, 28, 29, 30, 40, 60, 120,
+];
+const STUDENTS_T_SIGNIFICANCES: [f64; 7] = [0.2, 0.1, 0.05, 0.02, 0.01, 0.002, 0.001];
+#[allow(clippy::approx_constant)]
+const STUDENTS_T: [[f64; 7]; 34] = [
+    [3.078, 6.314, 12.706, 31.821, 63.657, 318.309, 636.619],
+    [1.886, 2.920, 4.303, 6.965, 9.925, 22.327, 31.599],
+    [1.638, 2.353, 3.182, 4.541, 5.841, 10.215, 12.924],
+    [1.533, 2.132, 2.776, 3.747, 4.604, 7.173, 8.610],
+    [1.476, 2.015, 2.571, 3.365, 4.032, 5.893, 6.869],
+    [1.440, 1.943, 2.447, 3.143, 3.707, 5.208, 5.959],
+    [1.415, 1.895, 2.365, 2.998, 3.499, 4.785, 5.408],
+    [1.397, 1.860, 2.306, 2.896, 3.355, 4.501, 5.041],
+    [1.383, 1.833, 2.262, 2.821, 3.250, 4.297, 4.781],
+    [1.372, 1.812, 2.228, 2.764, 3.169, 4.144, 4.587],
+    [1.363, 1.796, 2.201, 2.718, 3.106, 4.025, 4.437],
+    [1.356, 1.782, 2.179, 2.681, 3.055, 3.930, 4.318],
+    [1.350, 1.771, 2.160, 2.650, 3.012, 3.852, 4.221],
+    [1.345, 1.761, 2.145, 2.624, 2.977, 3.787, 4.140],
+    [1.341, 1.753, 2.131, 2.602, 2.947, 3.733, 4.073],
+    [1.337, 1.746, 2.120, 2.583, 2.921, 3.686, 4.015],
+    [1.333, 1.740, 2.110, 2.567, 2.898, 3.646, 3.965],
+    [1.330, 1.734, 2.101, 2.552, 2.878, 3.610, 3.922],
+    [1.328, 1.729, 2.093, 2.539, 2.861, 3.579, 3.883],
+    [1.325, 1.725, 2.086, 2.528, 2.845, 3.552, 3.850],
+    [1.323, 1.721, 2.080, 2.518, 2.831, 3.527, 3.819],
+    [1.321, 1.717, 2.074, 2.508, 2.819, 3.505, 3.792],
+    [1.319, 1.714, 2.069, 2.500, 2.807, 3.485, 3.768],
+    [1.318, 1.711, 2.064, 2.492, 2.797, 3.467, 3.745],
+    [1.316, 1.708, 2.060, 2.485, 2.787, 3.450, 3.725],
+    [1.315, 1.706, 2.056, 2.479, 2.779, 3.435, 3.707],
+    [1.314, 1.703, 2.052, 2.473, 2.771, 3.421, 3.690],
+    [1.313, 1.701, 2.048, 2.467, 2.763, 3.408, 3.674],
+    [1.311, 1.699, 2.045, 2.462, 2.756, 3.396, 3.659],
+    [1.310, 1.697, 2.042, 2.457, 2.750, 3.385, 3.646],
+    [1.303, 1.684, 2.021, 2.423, 2.704, 3.307, 3.551],
+    [1.296, 1.671, 2.000, 2.390, 2.660, 3.232, 3.460],
+    [1.289, 1.658, 1.980, 2.358, 2.617, 3.160, 3.373],
+    [1.282, 1.645, 1.960, 2.326, 2.576, 3.090, 3.291],
+];
+const FISHER_SNEDECOR_NUMERATOR_FREEDOM_DEGREES: [usize; 18] = [
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 12, 15, 20, 24, 30, 40, 60, 120,
+];
+const FISHER_SNEDECOR_DENOMINATOR_FREEDOM_DEGREES: [usize; 33] = [
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+    27, 28, 29, 30, 40, 60, 120,
+];
+#[allow(clippy::approx_constant)]
+const FISHER_SNEDECOR_05: [[f64; 18]; 33] = [
+    [
+        161.45, 199.50, 215.71, 224.58, 230.16, 233.99, 236.77, 238.88, 240.54, 241.88, 243.91,
+        245.95, 248.01, 249.05, 250.10, 251.14, 252.20, 253.25,
+    ],
+    [
+        18.51, 19.00, 19.16, 19.25, 19.30, 19.33, 19.35, 19.37, 19.38, 19.40, 19.41, 19.43, 19.45,
+        19.45, 19.46, 19.47, 19.48, 19.49,
+    ],
+    [
+        10.13, 9.55, 9.28, 9.12, 9.01, 8.94, 8.89, 8.85, 8.81, 8.79, 8.74, 8.70, 8.66, 8.64, 8.62,
+        8.59, 8.57, 8.55,
+    ],
+    [
+        7.71, 6.94, 6.59, 6.39, 6.26, 6.16, 6.09, 6.04, 6.00, 5.96, 5.91, 5.86, 5.80, 5.77, 5.75,
+        5.72, 5.69, 5.66,
+    ],
+    [
+        6.61, 5.79, 5.41, 5.19, 5.05, 4.95, 4.88, 4.82, 4.77, 4.74, 4.68, 4.62, 4.56, 4.53, 4.50,
+        4.46, 4.43, 4.40,
+    ],
+    [
+        5.99, 5.14, 4.76, 4.53, 4.39, 4.28, 4.21, 4.15, 4.10, 4.06, 4.00, 3.94, 3.87, 3.84, 3.81,
+        3.77, 3.74, 3.70,
+    ],
+    [
+        5.59, 4.74, 4.35, 4.12, 3.97, 3.87, 3.79, 3.73, 3.68, 3.64, 3.57, 3.51, 3.44, 3.41, 3.38,
+        3.34, 3.30, 3.27,
+    ],
+    [
+        5.32, 4.46, 4.07, 3.84, 3.69, 3.58, 3.50, 3.44, 3.39, 3.35, 3.28, 3.22, 3.15, 3.12, 3.08,
+        3.04, 3.01, 2.97,
+    ],
+    [
+        5.12, 4.26, 3.86, 3.63, 3.48, 3.37, 3.29, 3.23, 3.18, 3.14, 3.07, 3.01, 2.94, 2.90, 2.86,
+        2.83, 2.79, 2.75,
+    ],
+    [
+        4.96, 4.10, 3.71, 3.48, 3.33, 3.22, 3.14, 3.07, 3.02, 2.98, 2.91, 2.85, 2.77, 2.74, 2.70,
+        2.66, 2.62, 2.58,
+    ],
+    [
+        4.84, 3.98, 3.59, 3.36, 3.20, 3.09, 3.01, 2.95, 2.90, 2.85, 2.79, 2.72, 2.65, 2.61, 2.57,
+        2.53, 2.49, 2.45,
+    ],
+    [
+        4.75, 3.89, 3.49, 3.26, 3.11, 3.00, 2.91, 2.85, 2.80, 2.75, 2.69, 2.62, 2.54, 2.51, 2.47,
+        2.43, 2.38, 2.34,
+    ],
+    [
+        4.67, 3.81, 3.41, 3.18, 3.03, 2.92, 2.83, 2.77, 2.71, 2.67, 2.60, 2.53, 2.46, 2.42, 2.38,
+        2.34, 2.30, 2.25,
+    ],
+    [
+        4.60, 3.74, 3.34, 3.11, 2.96, 2.85, 2.76, 2.70, 2.65, 2.60, 2.53, 2.46, 2.39, 2.35, 2.31,
+        2.27, 2.22, 2.18,
+    ],
+    [
+        4.54, 3.68, 3.29, 3.06, 2.90, 2.79, 2.71, 2.64, 2.59, 2.54, 2.48, 2.40, 2.33, 2.29, 2.25,
+        2.20, 2.16, 2.11,
+    ],
+    [
+        4.49, 3.63, 3.24, 3.01, 2.85, 2.74, 2.66, 2.59, 2.54, 2.49, 2.42, 2.35, 2.28, 2.24, 2.19,
+        2.15, 2.11, 2.06,
+    ],
+    [
+        4.45, 3.59, 3.20, 2.96, 2.81, 2.70, 2.61, 2.55, 2.49, 2.45, 2.38, 2.31, 2.23, 2.19, 2.15,
+        2.10, 2.06, 2.01,
+    ],
+    [
+        4.41, 3.55, 3.16, 2.93, 2.77, 2.66, 2.58, 2.51, 2.46, 2.41, 2.34, 2.27, 2.19, 2.15, 2.11,
+        2.06, 2.02, 1.97,
+    ],
+    [
+        4.38, 3.52, 3.13, 2.90, 2.74, 2.63, 2.54, 2.48, 2.42, 2.38, 2.31, 2.23, 2.16, 2.11, 2.07,
+        2.03, 1.98, 1.93,
+    ],
+    [
+        4.35, 3.49, 3.10, 2.87, 2.71, 2.60, 2.51, 2.45, 2.39, 2.35, 2.28, 2.20, 2.12, 2.08, 2.04,
+        1.99, 1.95, 1.90,
+    ],
+    [
+        4.32, 3.47, 3.07, 2.84, 2.68, 2.57, 2.49, 2.42, 2.37, 2.32, 2.25, 2.18, 2.10, 2.05, 2.01,
+        1.96, 1.92, 1.87,
+    ],
+    [
+        4.30, 3.44, 3.05, 2.82, 2.66, 2.55, 2.46, 2.40, 2.34, 2.30, 2.23, 2.15, 2.07, 2.03, 1.98,
+        1.94, 1.89, 1.84,
+    ],
+    [
+        4.28, 3.42, 3.03, 2.80, 2.64, 2.53, 2.44, 2.37, 2.32, 2.27, 2.20, 2.13, 2.05, 2.01, 1.96,
+        1.91, 1.86, 1.81,
+    ],
+    [
+        4.26, 3.40, 3.01, 2.78, 2.62, 2.51, 2.42, 2.36, 2.30, 2.25, 2.18, 2.11, 2.03, 1.98, 1.94,
+        1.89, 1.84, 1.79,
+    ],
+    [
+        4.24, 3.39, 2.99, 2.76, 2.60, 2.49, 2.40, 2.34, 2.28, 2.24, 2.16, 2.09, 2.01, 1.96, 1.92,
+        1.87, 1.82, 1.77,
+    ],
+    [
+        4.23, 3.37, 2.98, 2.74, 2.59, 2.47, 2.39, 2.32, 2.27, 2.22, 2.15, 2.07, 1.99, 1.95, 1.90,
+        1.85, 1.80, 1.75,
+    ],
+    [
+        4.21, 3.35, 2.96, 2.73, 2.57, 2.46, 2.37, 2.31, 2.25, 2.20, 2.13, 2.06, 1.97, 1.93, 1.88,
+        1.84, 1.79, 1.73,
+    ],
+    [
+        4.20, 3.34, 2.95, 2.71, 2.56, 2.45, 2.36, 2.29, 2.24, 2.19, 2.12, 2.04, 1.96, 1.91, 1.87,
+        1.82, 1.77, 1.71,
+    ],
+    [
+        4.18, 3.33, 2.93, 2.70, 2.55, 2.43, 2.35, 2.28, 2.22, 2.18, 2.10, 2.03, 1.94, 1.90, 1.85,
+        1.81, 1.75, 1.70,
+    ],
+    [
+        4.17, 3.32, 2.92, 2.69, 2.53, 2.42, 2.33, 2.27, 2.21, 2.16, 2.09, 2.01, 1.93, 1.89, 1.84,
+        1.79, 1.74, 1.68,
+    ],
+    [
+        4.08, 3.23, 2.84, 2.61, 2.45, 2.34, 2.25, 2.18, 2.12, 2.08, 2.00, 1.92, 1.84, 1.79, 1.74,
+        1.69, 1.64, 1.58,
+    ],
+    [
+        4.00, 3.15, 2.76, 2.53, 2.37, 2.25, 2.17, 2.10, 2.04, 1.99, 1.92, 1.84, 1.75, 1.70, 1.65,
+        1.59, 1.53, 1.47,
+    ],
+    [
+        3.92, 3.07, 2.68, 2.45, 2.29, 2.18, 2.09, 2.02, 1.96, 1.91, 1.83, 1.75, 1.66, 1.61, 1.55,
+        1.50, 1.43, 1.35,
+    ],
+];
+#[allow(clippy::approx_constant)]
+const FISHER_SNEDECOR_01: [[f64; 18]; 33] = [
+    [
+        4052.18, 4999.50, 5403.35, 5624.58, 5763.65, 5858.99, 5928.36, 5981.07, 6022.47, 6055.85,
+        6106.32, 6157.28, 6208.73, 6234.63, 6260.65, 6286.78, 6313.03, 6339.39,
+    ],
+    [
+        98.50, 99.00, 99.17, 99.25, 99.30, 99.33, 99.36, 99.37, 99.39, 99.40, 99.42, 99.43, 99.45,
+        99.46, 99.47, 99.47, 99.48, 99.49,
+    ],
+    [
+        34.12, 30.82, 29.46, 28.71, 28.24, 27.91, 27.67, 27.49, 27.35, 27.23, 27.05, 26.87, 26.69,
+        26.60, 26.50, 26.41, 26.32, 26.22,
+    ],
+    [
+        21.20, 18.00, 16.69, 15.98, 15.52, 15.21, 14.98, 14.80, 14.66, 14.55, 14.37, 14.20, 14.02,
+        13.93, 13.84, 13.75, 13.65, 13.56,
+    ],
+    [
+        16.26, 13.27, 12.06, 11.39, 10.97, 10.67, 10.46, 10.29, 10.16, 10.05, 9.89, 9.72, 9.55,
+        9.47, 9.38, 9.29, 9.20, 9.11,
+    ],
+    [
+        13.75, 10.92, 9.78, 9.15, 8.75, 8.47, 8.26, 8.10, 7.98, 7.87, 7.72, 7.56, 7.40, 7.31, 7.23,
+        7.14, 7.06, 6.97,
+    ],
+    [
+        12.25, 9.55, 8.45, 7.85, 7.46, 7.19, 6.99, 6.84, 6.72, 6.62, 6.47, 6.31, 6.16, 6.07, 5.99,
+        5.91, 5.82, 5.74,
+    ],
+    [
+        11.26, 8.65, 7.59, 7.01, 6.63, 6.37, 6.18, 6.03, 5.91, 5.81, 5.67, 5.52, 5.36, 5.28, 5.20,
+        5.12, 5.03, 4.95,
+    ],
+    [
+        10.56, 8.02, 6.99, 6.42, 6.06, 5.80, 5.61, 5.47, 5.35, 5.26, 5.11, 4.96, 4.81, 4.73, 4.65,
+        4.57, 4.48, 4.40,
+    ],
+    [
+        10.04, 7.56, 6.55, 5.99, 5.64, 5.39, 5.20, 5.06, 4.94, 4.85, 4.71, 4.56, 4.41, 4.33, 4.25,
+        4.17, 4.08, 4.00,
+    ],
+    [
+        9.65, 7.21, 6.22, 5.67, 5.32, 5.07, 4.89, 4.74, 4.63, 4.54, 4.40, 4.25, 4.10, 4.02, 3.94,
+        3.86, 3.78, 3.69,
+    ],
+    [
+        9.33, 6.93, 5.95, 5.41, 5.06, 4.82, 4.64, 4.50, 4.39, 4.30, 4.16, 4.01, 3.86, 3.78, 3.70,
+        3.62, 3.54, 3.45,
+    ],
+    [
+        9.07, 6.70, 5.74, 5.21, 4.86, 4.62, 4.44, 4.30, 4.19, 4.10, 3.96, 3.82, 3.66, 3.59, 3.51,
+        3.43, 3.34, 3.25,
+    ],
+    [
+        8.86, 6.51, 5.56, 5.04, 4.69, 4.46, 4.28, 4.14, 4.03, 3.94, 3.80, 3.66, 3.51, 3.43, 3.35,
+        3.27, 3.18, 3.09,
+    ],
+    [
+        8.68, 6.36, 5.42, 4.89, 4.56, 4.32, 4.14, 4.00, 3.89, 3.80, 3.67, 3.52, 3.37, 3.29, 3.21,
+        3.13, 3.05, 2.96,
+    ],
+    [
+        8.53, 6.23, 5.29, 4.77, 4.44, 4.20, 4.03, 3.89, 3.78, 3.69, 3.55, 3.41, 3.26, 3.18, 3.10,
+        3.02, 2.93, 2.84,
+    ],
+    [
+        8.40, 6.11, 5.18, 4.67, 4.34, 4.10, 3.93, 3.79, 3.68, 3.59, 3.46, 3.31, 3.16, 3.08, 3.00,
+        2.92, 2.83, 2.75,
+    ],
+    [
+        8.29, 6.01, 5.09, 4.58, 4.25, 4.01, 3.84, 3.71, 3.60, 3.51, 3.37, 3.23, 3.08, 3.00, 2.92,
+        2.84, 2.75, 2.66,
+    ],
+    [
+        8.18, 5.93, 5.01, 4.50, 4.17, 3.94, 3.77, 3.63, 3.52, 3.43, 3.30, 3.15, 3.00, 2.92, 2.84,
+        2.76, 2.67, 2.58,
+    ],
+    [
+        8.10, 5.85, 4.94, 4.43, 4.10, 3.87, 3.70, 3.56, 3.46, 3.37, 3.23, 3.09, 2.94, 2.86, 2.78,
+        2.69, 2.61, 2.52,
+    ],
+    [
+        8.02, 5.78, 4.87, 4.37, 4.04, 3.81, 3.64, 3.51, 3.40, 3.31, 3.17, 3.03, 2.88, 2.80, 2.72,
+        2.64, 2.55, 2.46,
+    ],
+    [
+        7.95, 5.72, 4.82, 4.31, 3.99, 3.76, 3.59, 3.45, 3.35, 3.26, 3.12, 2.98, 2.83, 2.75, 2.67,
+        2.58, 2.50, 2.40,
+    ],
+    [
+        7.88, 5.66, 4.76, 4.26, 3.94, 3.71, 3.54, 3.41, 3.30, 3.21, 3.07, 2.93, 2.78, 2.70, 2.62,
+        2.54, 2.45, 2.35,
+    ],
+    [
+        7.82, 5.61, 4.72, 4.22, 3.90, 3.67, 3.50, 3.36, 3.26, 3.17, 3.03, 2.89, 2.74, 2.66, 2.58,
+        2.49, 2.40, 2.31,
+    ],
+    [
+        7.77, 5.57, 4.68, 4.18, 3.85, 3.63, 3.46, 3.32, 3.22, 3.13, 2.99, 2.85, 2.70, 2.62, 2.54,
+        2.45, 2.36, 2.27,
+    ],
+    [
+        7.72, 5.53, 4.64, 4.14, 3.82, 3.59, 3.42, 3.29, 3.18, 3.09, 2.96, 2.81, 2.66, 2.58, 2.50,
+        2.42, 2.33, 2.23,
+    ],
+    [
+        7.68, 5.49, 4.60, 4.11, 3.78, 3.56, 3.39, 3.26, 3.15, 3.06, 2.93, 2.78, 2.63, 2.55, 2.47,
+        2.38, 2.29, 2.20,
+    ],
+    [
+        7.64, 5.45, 4.57, 4.07, 3.75, 3.53, 3.36, 3.23, 3.12, 3.03, 2.90, 2.75, 2.60, 2.52, 2.44,
+        2.35, 2.26, 2.17,
+    ],
+    [
+        7.60, 5.42, 4.54, 4.04, 3.73, 3.50, 3.33, 3.20, 3.09, 3.00, 2.87, 2.73, 2.57, 2.49, 2.41,
+        2.33, 2.23, 2.14,
+    ],
+    [
+        7.56, 5.39, 4.51, 4.02, 3.70, 3.47, 3.30, 3.17, 3.07, 2.98, 2.84, 2.70, 2.55, 2.47, 2.39,
+        2.30, 2.21, 2.11,
+    ],
+    [
+        7.31, 5.18, 4.31, 3.83, 3.51, 3.29, 3.12, 2.99, 2.89, 2.80, 2.66, 2.52, 2.37, 2.29, 2.20,
+        2.11, 2.02, 1.92,
+    ],
+    [
+        7.08, 4.98, 4.13, 3.65, 3.34, 3.12, 2.95, 2.82, 2.72, 2.63, 2.50, 2.35, 2.20, 2.12, 2.03,
+        1.94, 1.84, 1.73,
+    ],
+    [
+        6.85, 4.79, 3.95, 3.48, 3.17, 2.96, 2.79, 2.66, 2.56, 2.47, 2.34, 2.19, 2.03, 1.95, 1.86,
+        1.76, 1.66, 1.53,
+    ],
+];
@@ -0,0 +1,184 @@
+//! `k`-fold cross-validation of prediction models.
+//!
+//! The model is given as a closure that fits on the training part of a [`Split`] and predicts the responses
+//! of its holdout part, so any model can be evaluated, however it is fitted. The out-of-sample errors are
+//! summarized by `RMSE = √(1/n Σ (yᵢ - ŷᵢ)²)`, `MAE = 1/n Σ |yᵢ - ŷᵢ|` and `R² = 1 - Σ (yᵢ - ŷᵢ)² / Σ (yᵢ - ȳ)²`.
+//!
+//! [`cross_validate_linear`] does the fitting for the least squares [`LinearRegression`]; a polynomial model
+//! is the linear one over the powers of its predictor given by
+//! [`polynomial_predictors`](crate::regression::polynomial_predictors).
+//! The errors measure numeric responses, so classifiers such as logistic models are out of scope.
+
+use crate::numeric::mean;
+use crate::regression::{LinearRegression, RegressionError};
+use crate::rng::RngSource;
+use crate::split::{Split, SplitError, k_folds};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum CrossValidationError {
+    Split(SplitError),
+    PredictionsCountMismatch,
+    Regression(RegressionError),
+}
+
+impl std::fmt::Display for CrossValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CrossValidationError::Split(e) => {
+                write!(f, "{e}")
+            }
+            CrossValidationError::PredictionsCountMismatch => {
+                write!(
+                    f,
+                    "Model must predict exactly one response per holdout observation"
+                )
+            }
+            CrossValidationError::Regression(e) => {
+                write!(f, "{e}")
+            }
+        }
+    }
+}
+
+/// Errors of predictions of the observed responses.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PredictionErrors {
+    pub rmse: f64,
+    pub mae: f64,
+    /// `R²` against the mean of the responses predicted, negative if the model is worse than that mean.
+    pub r_squared: f64,
+}
+
+impl PredictionErrors {
+    /// Returns `NaN`s for lengths mismatch or no observations, and a `NaN` `R²` for constant responses.
+    pub fn new(observed: &[f64], predicted: &[f64]) -> Self {
+        if observed.len() != predicted.len() || observed.is_empty() {
+            return Self {
+                rmse: f64::NAN,
+                mae: f64::NAN,
+                r_squared: f64::NAN,
+            };
+        }
+
+        let n = observed.len() as f64;
+        let residuals: Vec<_> = observed
+            .iter()
+            .zip(predicted)
+            .map(|(y, prediction)| y - prediction)
+            .collect();
+        let squared_errors_sum = residuals.iter().map(|e| e * e).sum::<f64>();
+        let observed_mean = mean(observed);
+        let total_sum = observed
+            .iter()
+            .map(|y| (y - observed_mean).powi(2))
+            .sum::<f64>();
+
+        Self {
+            rmse: (squared_errors_sum / n).sqrt(),
+            mae: residuals.iter().map(|e| e.abs()).sum::<f64>() / n,
+            r_squared: if total_sum > 0. {
+                1. - squared_errors_sum / total_sum
+            } else {
+                f64::NAN
+            },
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CrossValidationOutcome {
+    pub folds: Vec<PredictionErrors>,
+    /// Averages of the errors of the folds.
+    pub mean: PredictionErrors,
+    /// Errors of all the out-of-fold predictions taken together.
+    pub pooled: PredictionErrors,
+    /// Out-of-fold prediction of every observation.
+    pub predictions: Vec<f64>,
+}
+
+/// Cross-validates the model over `k` random folds of the responses.
+///
+/// `fit_predict` gets every split in turn and returns the predictions for its `holdout` indices, in their order.
+pub fn cross_validate(
+    responses: &[f64],
+    folds: usize,
+    rng: RngSource,
+    mut fit_predict: impl FnMut(&Split) -> Vec<f64>,
+) -> Result<CrossValidationOutcome, CrossValidationError> {
+    let splits = k_folds(responses.len(), folds, rng).map_err(CrossValidationError::Split)?;
+
+    let mut predictions = vec![f64::NAN; responses.len()];
+    let mut fold_errors = Vec::with_capacity(splits.len());
+    for split in &splits {
+        let predicted = fit_predict(split);
+        if predicted.len() != split.holdout.len() {
+            return Err(CrossValidationError::PredictionsCountMismatch);
+        }
+
+        let (_, observed) = split.select(responses);
+        fold_errors.push(PredictionErrors::new(&observed, &predicted));
+        for (i, prediction) in split.holdout.iter().zip(predicted) {
+            predictions[*i] = prediction;
+        }
+    }
+
+    let average = |error: fn(&PredictionErrors) -> f64| {
+        mean(&fold_errors.iter().map(error).collect::<Vec<_>>())
+    };
+
+    Ok(CrossValidationOutcome {
+        mean: PredictionErrors {
+            rmse: average(|errors| errors.rmse),
+            mae: average(|errors| errors.mae),
+            r_squared: average(|errors| errors.r_squared),
+        },
+        pooled: PredictionErrors::new(responses, &predictions),
+        folds: fold_errors,
+        predictions,
+    })
+}
+
+/// Cross-validates the least squares regression of the responses on the predictors, `predictors[i]`
+/// holding the predictors of the `i`-th observation as in [`LinearRegression::new`].
+///
+/// The first fold whose training part cannot be fitted fails the whole cross-validation.
+pub fn cross_validate_linear(
+    predictors: &[Vec<f64>],
+    responses: &[f64],
+    folds: usize,
+    rng: RngSource,
+) -> Result<CrossValidationOutcome, CrossValidationError> {
+    if predictors.len() != responses.len() {
+        return Err(CrossValidationError::Regression(
+            RegressionError::DimensionsMismatch,
+        ));
+    }
+
+    let mut failure = None;
+    let outcome = cross_validate(responses, folds, rng, |split| {
+        let (train_predictors, holdout_predictors) = split.select(predictors);
+        let (train_responses, _) = split.select(responses);
+        // The significance only affects the tests of the coefficients, which are not used.
+        let fitted = LinearRegression::new(&train_predictors, &train_responses, 0.05)
+            .and_then(|regression| regression.outcome());
+
+        match fitted {
+            Ok(fitted) => holdout_predictors
+                .iter()
+                .map(|predictors| fitted.predict(predictors))
+                .collect(),
+            Err(e) => {
+                failure.get_or_insert(e);
+                vec![f64::NAN; split.holdout.len()]
+            }
+        }
+    })?;
+
+    match failure {
+        Some(e) => Err(CrossValidationError::Regression(e)),
+        None => Ok(outcome),
+    }
+}
@@ -0,0 +1,255 @@
+//! Descriptive statistics of raw samples.
+
+use crate::numeric::{mean, unbiased_variance};
+
+/// `p`-quantile of a sample, linearly interpolated between the order statistics
+/// (the definition used by most software, `R`'s type 7).
+///
+/// Returns `NaN` for an empty sample or `p` outside `[0, 1]`.
+pub fn quantile(sample: &[f64], p: f64) -> f64 {
+    if sample.is_empty() || !(0. ..=1.).contains(&p) {
+        return f64::NAN;
+    }
+
+    let mut sorted = sample.to_owned();
+    sorted.sort_by(f64::total_cmp);
+
+    let h = (sorted.len() - 1) as f64 * p;
+    let (lower, upper) = (h.floor() as usize, h.ceil() as usize);
+    sorted[lower] + (h - lower as f64) * (sorted[upper] - sorted[lower])
+}
+
+pub fn median(sample: &[f64]) -> f64 {
+    quantile(sample, 0.5)
+}
+
+/// Raw moment `1/n Σ xᵢᵏ`.
+///
+/// Returns `NaN` for an empty sample.
+pub fn moment(sample: &[f64], order: u32) -> f64 {
+    grouped_moment(sample, &vec![1.; sample.len()], order)
+}
+
+/// Central moment `1/n Σ (xᵢ - x̄)ᵏ`.
+///
+/// Returns `NaN` for an empty sample.
+pub fn central_moment(sample: &[f64], order: u32) -> f64 {
+    grouped_central_moment(sample, &vec![1.; sample.len()], order)
+}
+
+/// Standardized moment `mₖ / m₂^(k/2)` of the central moments `m`: the skewness for `k = 3`
+/// and the kurtosis for `k = 4`, both in their biased, moment forms.
+///
+/// Returns `NaN` for an empty or constant sample.
+pub fn standardized_moment(sample: &[f64], order: u32) -> f64 {
+    grouped_standardized_moment(sample, &vec![1.; sample.len()], order)
+}
+
+/// Raw moment `1/n Σ nᵢ xᵢᵏ` of a grouped sample, where `xᵢ` are the values, usually the interval midpoints,
+/// and `nᵢ` their frequencies.
+///
+/// Returns `NaN` for lengths mismatch or a zero total frequency.
+pub fn grouped_moment(values: &[f64], frequencies: &[f64], order: u32) -> f64 {
+    let total = frequencies.iter().sum::<f64>();
+    if values.len() != frequencies.len() || total == 0. {
+        return f64::NAN;
+    }
+
+    values
+        .iter()
+        .zip(frequencies)
+        .map(|(x, n)| n * x.powi(order as i32))
+        .sum::<f64>()
+        / total
+}
+
+/// Central moment `1/n Σ nᵢ (xᵢ - x̄)ᵏ` of a grouped sample.
+pub fn grouped_central_moment(values: &[f64], frequencies: &[f64], order: u32) -> f64 {
+    let mean = grouped_moment(values, frequencies, 1);
+    let deviations: Vec<_> = values.iter().map(|x| x - mean).collect();
+    grouped_moment(&deviations, frequencies, order)
+}
+
+/// Standardized moment `mₖ / m₂^(k/2)` of a grouped sample.
+pub fn grouped_standardized_moment(values: &[f64], frequencies: &[f64], order: u32) -> f64 {
+    let variance = grouped_central_moment(values, frequencies, 2);
+    if variance == 0. {
+        return f64::NAN;
+    }
+
+    grouped_central_moment(values, frequencies, order) / variance.powf(order as f64 / 2.)
+}
+
+/// Standard scores `(xᵢ - x̄) / s` with the unbiased standard deviation `s`.
+///
+/// Returns `NaN`s for fewer than two observations or a constant sample.
+pub fn z_scores(sample: &[f64]) -> Vec<f64> {
+    standardize(sample, mean(sample), unbiased_variance(sample).sqrt())
+}
+
+/// Standard scores `(xᵢ - μ) / σ` against a reference mean and standard deviation,
+/// such as the ones of a population or of a past sample.
+pub fn standardize(sample: &[f64], mean: f64, std_dev: f64) -> Vec<f64> {
+    sample.iter().map(|x| (x - mean) / std_dev).collect()
+}
+
+/// Indices of the observations more than `k` standard deviations away from the mean, `|zᵢ| > k`.
+pub fn beyond_sigmas(sample: &[f64], sigmas: f64) -> Vec<usize> {
+    z_scores(sample)
+        .iter()
+        .enumerate()
+        .filter(|(_, z)| z.abs() > sigmas)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Mean of the sample without its `⌊γn⌋` smallest and `⌊γn⌋` largest values.
+///
+/// Returns `NaN` for an empty sample or `γ` outside `[0, 0.5)`.
+pub fn trimmed_mean(sample: &[f64], proportion: f64) -> f64 {
+    let Some((sorted, trimmed)) = trim(sample, proportion) else {
+        return f64::NAN;
+    };
+
+    let kept = &sorted[trimmed..sorted.len() - trimmed];
+    kept.iter().sum::<f64>() / kept.len() as f64
+}
+
+/// Sample with its `⌊γn⌋` smallest values replaced by the next smallest one and its `⌊γn⌋` largest values
+/// replaced by the next largest one, in the original order.
+///
+/// Returns an empty sample for `γ` outside `[0, 0.5)`.
+pub fn winsorize(sample: &[f64], proportion: f64) -> Vec<f64> {
+    let Some((sorted, trimmed)) = trim(sample, proportion) else {
+        return Vec::new();
+    };
+
+    let (lower, upper) = (sorted[trimmed], sorted[sorted.len() - 1 - trimmed]);
+    sample.iter().map(|x| x.clamp(lower, upper)).collect()
+}
+
+/// Unbiased variance of the winsorized sample.
+///
+/// Returns `NaN` for a sample of less than two values or `γ` outside `[0, 0.5)`.
+pub fn winsorized_variance(sample: &[f64], proportion: f64) -> f64 {
+    let winsorized = winsorize(sample, proportion);
+    if winsorized.len() < 2 {
+        return f64::NAN;
+    }
+
+    unbiased_variance(&winsorized)
+}
+
+/// The sorted sample and the number `⌊γn⌋` of values trimmed from each end.
+fn trim(sample: &[f64], proportion: f64) -> Option<(Vec<f64>, usize)> {
+    if sample.is_empty() || !(0. ..0.5).contains(&proportion) {
+        return None;
+    }
+
+    let mut sorted = sample.to_owned();
+    sorted.sort_by(f64::total_cmp);
+    let trimmed = (proportion * sample.len() as f64).floor() as usize;
+
+    Some((sorted, trimmed))
+}
+
+/// `MAD = med |xᵢ - med x|`, unscaled.
+///
+/// Returns `NaN` for an empty sample.
+pub fn median_absolute_deviation(sample: &[f64]) -> f64 {
+    let center = median(sample);
+    let deviations: Vec<_> = sample.iter().map(|x| (x - center).abs()).collect();
+    median(&deviations)
+}
+
+/// `1.4826 · MAD`, which estimates `σ` consistently for normal samples.
+pub fn mad_scale(sample: &[f64]) -> f64 {
+    1.4826 * median_absolute_deviation(sample)
+}
+
+/// Rousseeuw and Croux's `Qn = dₙ · 2.2219 · {|xᵢ - xⱼ|; i < j}₍ₖ₎` with `k = C(h, 2)` and `h = ⌊n/2⌋ + 1`,
+/// the first quartile of the pairwise distances; it estimates `σ` consistently for normal samples
+/// and tolerates almost half of the values being outliers.
+///
+/// Returns `NaN` for a sample of less than two values.
+pub fn qn_scale(sample: &[f64]) -> f64 {
+    let n = sample.len();
+    if n < 2 {
+        return f64::NAN;
+    }
+
+    let mut distances: Vec<_> = (0..n)
+        .flat_map(|i| (i + 1..n).map(move |j| (sample[i] - sample[j]).abs()))
+        .collect();
+    let h = n / 2 + 1;
+    let k = h * (h - 1) / 2;
+    let (_, distance, _) = distances.select_nth_unstable_by(k - 1, f64::total_cmp);
+
+    let correction = match n {
+        2 => 0.399,
+        3 => 0.994,
+        4 => 0.512,
+        5 => 0.844,
+        6 => 0.611,
+        7 => 0.857,
+        8 => 0.669,
+        9 => 0.872,
+        _ if n % 2 == 1 => n as f64 / (n as f64 + 1.4),
+        _ => n as f64 / (n as f64 + 3.8),
+    };
+    correction * 2.2219 * *distance
+}
+
+/// Rousseeuw and Croux's `Sn = cₙ · 1.1926 · lomedᵢ himedⱼ |xᵢ - xⱼ|`, a median of the medians of the distances,
+/// which estimates `σ` consistently for normal samples and, unlike `MAD`, does not assume symmetry.
+///
+/// Returns `NaN` for a sample of less than two values.
+pub fn sn_scale(sample: &[f64]) -> f64 {
+    let n = sample.len();
+    if n < 2 {
+        return f64::NAN;
+    }
+
+    // The high median is the `(⌊n/2⌋ + 1)`-th order statistic, the low median is the `⌈n/2⌉`-th.
+    let mut inner_medians: Vec<_> = sample
+        .iter()
+        .map(|x_i| {
+            let mut distances: Vec<_> = sample.iter().map(|x_j| (x_i - x_j).abs()).collect();
+            *distances.select_nth_unstable_by(n / 2, f64::total_cmp).1
+        })
+        .collect();
+    let (_, outer_median, _) =
+        inner_medians.select_nth_unstable_by(n.div_ceil(2) - 1, f64::total_cmp);
+
+    let correction = match n {
+        2 => 0.743,
+        3 => 1.851,
+        4 => 0.954,
+        5 => 1.351,
+        6 => 0.993,
+        7 => 1.198,
+        8 => 1.005,
+        9 => 1.131,
+        _ if n % 2 == 1 => n as f64 / (n as f64 - 0.9),
+        _ => 1.,
+    };
+    correction * 1.1926 * *outer_median
+}
+
+/// Robust estimators of the scale `σ`, for heavy-tailed samples where the standard deviation misleads.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum RobustScale {
+    Mad,
+    Qn,
+    Sn,
+}
+
+impl RobustScale {
+    pub fn estimate(&self, sample: &[f64]) -> f64 {
+        match self {
+            RobustScale::Mad => mad_scale(sample),
+            RobustScale::Qn => qn_scale(sample),
+            RobustScale::Sn => sn_scale(sample),
+        }
+    }
+}
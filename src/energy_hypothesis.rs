@@ -0,0 +1,188 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *sample of a random vector **X***, *sample of a random vector **Y***
+//! of the same dimension.
+//! **To figure out**: Is it appropriate to **assume** that **X** and **Y** have the same distribution?
+//!
+//! The energy statistic of Székely and Rizzo
+//! `E = nm / (n + m) · (2 · mean|X - Y| - mean|X - X'| - mean|Y - Y'|)`
+//! with Euclidean distances is compared against its permutation distribution.
+
+use rand::seq::SliceRandom;
+
+use crate::alpha;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+use crate::rng::RngSource;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum EnergyError {
+    SignificanceInvalid,
+    SampleEmpty,
+    DimensionsMismatch,
+    PermutationsInvalid,
+    ValueMissing,
+}
+
+impl std::fmt::Display for EnergyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            EnergyError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            EnergyError::SampleEmpty => {
+                write!(f, "Samples must not be empty")
+            }
+            EnergyError::DimensionsMismatch => {
+                write!(f, "All observations must have the same positive dimension")
+            }
+            EnergyError::PermutationsInvalid => {
+                write!(f, "Number of permutations must be positive")
+            }
+            EnergyError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct EnergyOutcome {
+    pub statistic: f64,
+    /// `(1 + #{E* >= E}) / (1 + R)` over `R` permutations.
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    /// Whether the hypothesis of equal distributions is accepted.
+    pub accepted: bool,
+}
+
+pub struct EnergyHypothesis {
+    /// Pooled observations, **X** first.
+    pooled: Vec<Vec<f64>>,
+    x_size: usize,
+    significance: f64,
+    permutations: usize,
+    rng: RngSource,
+}
+
+impl EnergyHypothesis {
+    /// Starts with 999 permutations and the seed `0`; univariate samples are passed as one-element observations.
+    pub fn new(
+        x_sample: &[Vec<f64>],
+        y_sample: &[Vec<f64>],
+        significance: f64,
+    ) -> Result<Self, EnergyError> {
+        if x_sample.is_empty() || y_sample.is_empty() {
+            return Err(EnergyError::SampleEmpty);
+        }
+
+        let dimension = x_sample[0].len();
+        if dimension == 0
+            || x_sample
+                .iter()
+                .chain(y_sample.iter())
+                .any(|observation| observation.len() != dimension)
+        {
+            return Err(EnergyError::DimensionsMismatch);
+        }
+
+        for observation in x_sample.iter().chain(y_sample.iter()) {
+            MissingPolicy::Error
+                .apply(observation)
+                .map_err(|_| EnergyError::ValueMissing)?;
+        }
+
+        alpha::validate(significance, EnergyError::SignificanceInvalid)?;
+
+        Ok(Self {
+            pooled: x_sample.iter().chain(y_sample.iter()).cloned().collect(),
+            x_size: x_sample.len(),
+            significance,
+            permutations: 999,
+            rng: RngSource::default(),
+        })
+    }
+
+    pub fn permutations(mut self, permutations: usize) -> Result<Self, EnergyError> {
+        if permutations == 0 {
+            return Err(EnergyError::PermutationsInvalid);
+        }
+
+        self.permutations = permutations;
+        Ok(self)
+    }
+
+    /// Seed of the permutations, which makes the p-value reproducible.
+    pub fn seed(self, seed: u64) -> Self {
+        self.rng(RngSource::Seed(seed))
+    }
+
+    /// Source of the permutations.
+    pub fn rng(mut self, rng: RngSource) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    pub fn solve(&self) -> Result<bool, EnergyError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<EnergyOutcome, EnergyError> {
+        let size = self.pooled.len();
+        let distances: Vec<Vec<f64>> = self
+            .pooled
+            .iter()
+            .map(|a| {
+                self.pooled
+                    .iter()
+                    .map(|b| {
+                        a.iter()
+                            .zip(b.iter())
+                            .map(|(a_k, b_k)| (a_k - b_k).powi(2))
+                            .sum::<f64>()
+                            .sqrt()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut labels: Vec<usize> = (0..size).collect();
+        let statistic = self.energy(&distances, &labels);
+
+        let exceeding = self.rng.with_rng(|rng| {
+            (0..self.permutations)
+                .filter(|_| {
+                    labels.shuffle(rng);
+                    self.energy(&distances, &labels) >= statistic
+                })
+                .count()
+        });
+        let p_value = (1 + exceeding) as f64 / (1 + self.permutations) as f64;
+
+        Ok(EnergyOutcome {
+            statistic,
+            p_value,
+            hypotheses: Hypotheses::new(
+                "X and Y have the same distribution",
+                "X and Y have different distributions",
+                Alternative::Greater,
+            ),
+            accepted: p_value > self.significance,
+        })
+    }
+
+    /// Statistic for the first `x_size` of `order` taken as **X** and the rest as **Y**.
+    fn energy(&self, distances: &[Vec<f64>], order: &[usize]) -> f64 {
+        let (x, y) = order.split_at(self.x_size);
+        let mean_distance = |a: &[usize], b: &[usize]| {
+            a.iter()
+                .flat_map(|i| b.iter().map(move |j| distances[*i][*j]))
+                .sum::<f64>()
+                / (a.len() * b.len()) as f64
+        };
+        let (n, m) = (x.len() as f64, y.len() as f64);
+
+        n * m / (n + m) * (2. * mean_distance(x, y) - mean_distance(x, x) - mean_distance(y, y))
+    }
+}
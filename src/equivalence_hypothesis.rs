@@ -0,0 +1,251 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *equivalence margin*, *sample of a random variable **X***,
+//! *sample of a random variable **Y***, both variables are normally distributed.
+//! **To figure out**: Is it appropriate to **claim** that `E(X)` and `E(Y)`, or `D(X)` and `D(Y)`,
+//! differ by no more than the margin?
+//!
+//! Two one-sided tests (TOST) are used: equivalence is claimed only if both of them reject
+//! the hypothesis that the difference lies beyond the corresponding bound of the margin.
+
+use statrs::distribution::{ContinuousCDF, FisherSnedecor, StudentsT};
+
+use crate::alpha;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+use crate::numeric::{mean, unbiased_variance};
+use crate::same_mean_hypothesis::Variances;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum TostError {
+    SignificanceInvalid,
+    SampleTooSmall,
+    MarginInvalid,
+    FreedomDegreesInvalid,
+    ValueMissing,
+}
+
+impl std::fmt::Display for TostError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TostError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            TostError::SampleTooSmall => {
+                write!(f, "Each sample must contain at least two values")
+            }
+            TostError::MarginInvalid => {
+                write!(
+                    f,
+                    "Margin must be positive for means and greater than 1.0 for variances"
+                )
+            }
+            TostError::FreedomDegreesInvalid => {
+                write!(
+                    f,
+                    "Freedom Degrees led to fail in initialization of the distribution"
+                )
+            }
+            TostError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct TostMeansOutcome {
+    /// `E(X) - E(Y)` estimated by the difference of the sample means.
+    pub mean_difference: f64,
+    /// `t` statistics against the lower and the upper bound of the margin.
+    pub t_observed: (f64, f64),
+    pub freedom_degrees: f64,
+    /// One-sided critical value `t(1 - α)`.
+    pub t_critical_value: f64,
+    pub hypotheses: Hypotheses,
+    /// Whether equivalence is claimed.
+    pub equivalent: bool,
+}
+
+pub struct TostMeans {
+    x_sample: Vec<f64>,
+    y_sample: Vec<f64>,
+    variances: Variances,
+    margin: f64,
+    significance: f64,
+}
+
+impl TostMeans {
+    /// Equivalence means `|E(X) - E(Y)| < margin`.
+    pub fn new(
+        x_sample: &[f64],
+        y_sample: &[f64],
+        variances: Variances,
+        margin: f64,
+        significance: f64,
+    ) -> Result<Self, TostError> {
+        MissingPolicy::Error
+            .apply(x_sample)
+            .and(MissingPolicy::Error.apply(y_sample))
+            .map_err(|_| TostError::ValueMissing)?;
+
+        if x_sample.len() < 2 || y_sample.len() < 2 {
+            return Err(TostError::SampleTooSmall);
+        }
+
+        if margin <= 0. || margin.is_nan() {
+            return Err(TostError::MarginInvalid);
+        }
+
+        alpha::validate(significance, TostError::SignificanceInvalid)?;
+
+        Ok(Self {
+            x_sample: x_sample.to_owned(),
+            y_sample: y_sample.to_owned(),
+            variances,
+            margin,
+            significance,
+        })
+    }
+
+    pub fn solve(&self) -> Result<bool, TostError> {
+        Ok(self.outcome()?.equivalent)
+    }
+
+    pub fn outcome(&self) -> Result<TostMeansOutcome, TostError> {
+        let (n_x, n_y) = (self.x_sample.len() as f64, self.y_sample.len() as f64);
+        let (x_usv, y_usv) = (
+            unbiased_variance(&self.x_sample),
+            unbiased_variance(&self.y_sample),
+        );
+        let mean_difference = mean(&self.x_sample) - mean(&self.y_sample);
+
+        let (standard_error, freedom_degrees) = match self.variances {
+            Variances::Equal => {
+                let freedom_degrees = n_x + n_y - 2.;
+                let pooled_variance = ((n_x - 1.) * x_usv + (n_y - 1.) * y_usv) / freedom_degrees;
+                (
+                    (pooled_variance * (1. / n_x + 1. / n_y)).sqrt(),
+                    freedom_degrees,
+                )
+            }
+            Variances::Unequal => {
+                let (x_term, y_term) = (x_usv / n_x, y_usv / n_y);
+                let freedom_degrees = (x_term + y_term).powi(2)
+                    / (x_term.powi(2) / (n_x - 1.) + y_term.powi(2) / (n_y - 1.));
+                ((x_term + y_term).sqrt(), freedom_degrees)
+            }
+        };
+
+        let students_t_dist = StudentsT::new(0., 1., freedom_degrees)
+            .map_err(|_| TostError::FreedomDegreesInvalid)?;
+        let t_critical_value = students_t_dist.inverse_cdf(1. - self.significance);
+
+        let t_lower = (mean_difference + self.margin) / standard_error;
+        let t_upper = (mean_difference - self.margin) / standard_error;
+
+        Ok(TostMeansOutcome {
+            mean_difference,
+            t_observed: (t_lower, t_upper),
+            freedom_degrees,
+            t_critical_value,
+            hypotheses: Hypotheses::new(
+                format!("|E(X) - E(Y)| >= {}", self.margin),
+                format!("|E(X) - E(Y)| < {}", self.margin),
+                Alternative::TwoSided,
+            ),
+            equivalent: t_lower > t_critical_value && t_upper < -t_critical_value,
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct TostVariancesOutcome {
+    /// `D(X) / D(Y)` estimated by the ratio of the unbiased sample variances.
+    pub variance_ratio: f64,
+    /// Freedom degrees of the numerator and of the denominator.
+    pub freedom_degrees: (f64, f64),
+    /// Bounds `F(1 - α) / margin` and `F(α) · margin` the ratio must lie strictly between.
+    pub critical_values: (f64, f64),
+    pub hypotheses: Hypotheses,
+    /// Whether equivalence is claimed.
+    pub equivalent: bool,
+}
+
+pub struct TostVariances {
+    x_sample: Vec<f64>,
+    y_sample: Vec<f64>,
+    margin: f64,
+    significance: f64,
+}
+
+impl TostVariances {
+    /// Equivalence means `1 / margin < D(X) / D(Y) < margin`.
+    pub fn new(
+        x_sample: &[f64],
+        y_sample: &[f64],
+        margin: f64,
+        significance: f64,
+    ) -> Result<Self, TostError> {
+        MissingPolicy::Error
+            .apply(x_sample)
+            .and(MissingPolicy::Error.apply(y_sample))
+            .map_err(|_| TostError::ValueMissing)?;
+
+        if x_sample.len() < 2 || y_sample.len() < 2 {
+            return Err(TostError::SampleTooSmall);
+        }
+
+        if margin <= 1. || margin.is_nan() {
+            return Err(TostError::MarginInvalid);
+        }
+
+        alpha::validate(significance, TostError::SignificanceInvalid)?;
+
+        Ok(Self {
+            x_sample: x_sample.to_owned(),
+            y_sample: y_sample.to_owned(),
+            margin,
+            significance,
+        })
+    }
+
+    pub fn solve(&self) -> Result<bool, TostError> {
+        Ok(self.outcome()?.equivalent)
+    }
+
+    pub fn outcome(&self) -> Result<TostVariancesOutcome, TostError> {
+        let freedom_degrees = (
+            self.x_sample.len() as f64 - 1.,
+            self.y_sample.len() as f64 - 1.,
+        );
+        let variance_ratio = unbiased_variance(&self.x_sample) / unbiased_variance(&self.y_sample);
+
+        let fisher_snedecor_dist = FisherSnedecor::new(freedom_degrees.0, freedom_degrees.1)
+            .map_err(|_| TostError::FreedomDegreesInvalid)?;
+
+        // The ratio is compared against each bound separately:
+        // `ratio · margin > F(1 - α)` rejects `D(X) / D(Y) <= 1 / margin`,
+        // `ratio / margin < F(α)` rejects `D(X) / D(Y) >= margin`.
+        let critical_values = (
+            fisher_snedecor_dist.inverse_cdf(1. - self.significance) / self.margin,
+            fisher_snedecor_dist.inverse_cdf(self.significance) * self.margin,
+        );
+
+        Ok(TostVariancesOutcome {
+            variance_ratio,
+            freedom_degrees,
+            critical_values,
+            hypotheses: Hypotheses::new(
+                format!(
+                    "D(X) / D(Y) <= 1 / {} or D(X) / D(Y) >= {}",
+                    self.margin, self.margin
+                ),
+                format!("1 / {} < D(X) / D(Y) < {}", self.margin, self.margin),
+                Alternative::TwoSided,
+            ),
+            equivalent: variance_ratio > critical_values.0 && variance_ratio < critical_values.1,
+        })
+    }
+}
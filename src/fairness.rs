@@ -0,0 +1,176 @@
+//! Algorithms for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *counts of the faces* of a die thrown, or of heads and tails of a coin tossed.
+//! **To figure out**: Is it appropriate to **assume** that the die or the coin is fair?
+//!
+//! The die is checked by the chi-squared goodness of fit against equal expected counts,
+//! the coin by the exact binomial test of `p = 1/2`, which needs no approximation for few tosses.
+
+use statistics_problems_core::decisions::chi_squared_statistic;
+use statrs::distribution::{ChiSquared, ContinuousCDF};
+
+use crate::alpha;
+use crate::critical_values::{self, CriticalValueSource};
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::poisson_rate_hypothesis::binomial_two_sided_p_value;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum FairnessError {
+    SignificanceInvalid,
+    FacesCountInvalid,
+    CountsDegenerate,
+}
+
+impl std::fmt::Display for FairnessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            FairnessError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            FairnessError::FacesCountInvalid => {
+                write!(f, "At least two faces are required")
+            }
+            FairnessError::CountsDegenerate => {
+                write!(f, "At least one throw must be counted")
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FairDieOutcome {
+    /// Count of every face expected from a fair die.
+    pub expected: f64,
+    pub freedom_degrees: f64,
+    pub chi_squared_observed: f64,
+    pub chi_squared_critical_value: f64,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FairCoinOutcome {
+    /// Proportion of heads.
+    pub proportion: f64,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+pub struct FairDieTest {
+    face_counts: Vec<u64>,
+    significance: f64,
+}
+
+impl FairDieTest {
+    /// `face_counts[i]` is the number of throws that showed the face `i + 1`.
+    pub fn new(face_counts: &[u64], significance: f64) -> Result<Self, FairnessError> {
+        if face_counts.len() < 2 {
+            return Err(FairnessError::FacesCountInvalid);
+        }
+
+        if face_counts.iter().sum::<u64>() == 0 {
+            return Err(FairnessError::CountsDegenerate);
+        }
+
+        validate(significance)?;
+
+        Ok(Self {
+            face_counts: face_counts.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            "every face is equally likely",
+            "some faces are more likely than others",
+            Alternative::Greater,
+        )
+    }
+
+    pub fn solve(&self) -> Result<bool, FairnessError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<FairDieOutcome, FairnessError> {
+        let observed: Vec<_> = self.face_counts.iter().map(|count| *count as f64).collect();
+        let faces = observed.len() as f64;
+        let expected = observed.iter().sum::<f64>() / faces;
+
+        let freedom_degrees = faces - 1.;
+        let chi_squared_observed =
+            chi_squared_statistic(&observed, &vec![expected; observed.len()]);
+        let chi_squared_critical_value = critical_values::chi_squared(
+            freedom_degrees,
+            self.significance,
+            CriticalValueSource::Quantile,
+        )
+        .map_err(|_| FairnessError::FacesCountInvalid)?;
+        let p_value = ChiSquared::new(freedom_degrees)
+            .map_err(|_| FairnessError::FacesCountInvalid)?
+            .sf(chi_squared_observed);
+
+        Ok(FairDieOutcome {
+            expected,
+            freedom_degrees,
+            chi_squared_observed,
+            chi_squared_critical_value,
+            p_value,
+            hypotheses: self.hypotheses(),
+            accepted: chi_squared_observed < chi_squared_critical_value,
+        })
+    }
+}
+
+pub struct FairCoinTest {
+    heads: u64,
+    tails: u64,
+    significance: f64,
+}
+
+impl FairCoinTest {
+    pub fn new(heads: u64, tails: u64, significance: f64) -> Result<Self, FairnessError> {
+        if heads + tails == 0 {
+            return Err(FairnessError::CountsDegenerate);
+        }
+
+        validate(significance)?;
+
+        Ok(Self {
+            heads,
+            tails,
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new("p = 1/2", "p ≠ 1/2", Alternative::TwoSided)
+    }
+
+    pub fn solve(&self) -> Result<bool, FairnessError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<FairCoinOutcome, FairnessError> {
+        let tosses = self.heads + self.tails;
+        let p_value = binomial_two_sided_p_value(self.heads, tosses, 0.5);
+
+        Ok(FairCoinOutcome {
+            proportion: self.heads as f64 / tosses as f64,
+            p_value,
+            hypotheses: self.hypotheses(),
+            accepted: p_value > self.significance,
+        })
+    }
+}
+
+fn validate(significance: f64) -> Result<(), FairnessError> {
+    alpha::validate(significance, FairnessError::SignificanceInvalid)?;
+
+    Ok(())
+}
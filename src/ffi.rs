@@ -0,0 +1,374 @@
+//! C interface to the main tests, so the solvers can be embedded in C and C++ tools.
+//!
+//! Samples are passed as pointers to `double` with their lengths, and the outcome is written into
+//! a caller-owned [`SpOutcome`]. Every function returns one of the `SP_*` codes; on failure the message
+//! of the error is kept per thread and is read with [`sp_last_error_message`].
+//! The declarations are in `include/statistics_problems.h`.
+
+use std::cell::RefCell;
+use std::ffi::c_char;
+
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::contingency::IndependenceHypothesis;
+use crate::critical_values::{self, CriticalValueSource};
+use crate::mann_whitney_hypothesis::MannWhitneyHypothesis;
+use crate::normal_distribution_hypothesis::{
+    CompleteNDHProblemSituation, IncompleteNDHProblemSituation, NDHProblemSituation,
+    NormalDistributionHypothesis,
+};
+use crate::same_mean_hypothesis::{SameMeanHypothesis, Variances};
+use crate::same_variance_hypothesis::SameVarianceHypothesis;
+
+pub const SP_OK: i32 = 0;
+pub const SP_ERROR_NULL_POINTER: i32 = 1;
+pub const SP_ERROR_LENGTH_MISMATCH: i32 = 2;
+/// The solver rejected the problem, e.g. for an invalid significance or a too small sample.
+pub const SP_ERROR_INVALID_PROBLEM: i32 = 3;
+
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SpOutcome {
+    pub statistic: f64,
+    pub critical_value: f64,
+    /// Whether the null hypothesis is accepted.
+    pub accepted: bool,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+fn fail(code: i32, message: impl std::fmt::Display) -> i32 {
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = message.to_string());
+    code
+}
+
+/// # Safety
+///
+/// `values` must be null or point to `len` initialized `double`s.
+unsafe fn slice<'a>(values: *const f64, len: usize) -> Option<&'a [f64]> {
+    match (values.is_null(), len) {
+        (_, 0) => Some(&[]),
+        (true, _) => None,
+        // SAFETY: the caller guarantees `len` initialized values behind a non-null pointer.
+        (false, _) => Some(unsafe { std::slice::from_raw_parts(values, len) }),
+    }
+}
+
+/// # Safety
+///
+/// `out` must be null or point to a writable `SpOutcome`.
+unsafe fn write(out: *mut SpOutcome, outcome: SpOutcome) -> i32 {
+    if out.is_null() {
+        return fail(SP_ERROR_NULL_POINTER, "Outcome pointer is null");
+    }
+
+    // SAFETY: the caller guarantees that a non-null `out` is writable.
+    unsafe { out.write(outcome) };
+    SP_OK
+}
+
+/// # Safety
+///
+/// `out` must be null or point to a writable `SpOutcome`.
+unsafe fn solve_ndh(situation: Box<dyn NDHProblemSituation>, out: *mut SpOutcome) -> i32 {
+    match NormalDistributionHypothesis::new(situation).and_then(|ndh| ndh.outcome()) {
+        // SAFETY: forwarded from the caller.
+        Ok(outcome) => unsafe {
+            write(
+                out,
+                SpOutcome {
+                    statistic: outcome.chi_squared_observed,
+                    critical_value: outcome.chi_squared_critical_value,
+                    accepted: outcome.accepted,
+                },
+            )
+        },
+        Err(e) => fail(SP_ERROR_INVALID_PROBLEM, e),
+    }
+}
+
+/// Copies the message of the last error of the calling thread into `buffer` as a NUL-terminated string,
+/// truncated to `capacity - 1` bytes, and returns the length of the whole message.
+///
+/// # Safety
+///
+/// `buffer` must be null or point to `capacity` writable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sp_last_error_message(buffer: *mut c_char, capacity: usize) -> usize {
+    LAST_ERROR.with(|last_error| {
+        let message = last_error.borrow();
+        if !buffer.is_null() && capacity > 0 {
+            let copied = message.len().min(capacity - 1);
+            // SAFETY: the caller guarantees `capacity` writable bytes, and `copied + 1 <= capacity`.
+            unsafe {
+                std::ptr::copy_nonoverlapping(message.as_ptr().cast(), buffer, copied);
+                buffer.add(copied).write(0);
+            }
+        }
+        message.len()
+    })
+}
+
+/// Normal Distribution Hypothesis with the empirical and theoretical frequencies of `classes` classes.
+///
+/// # Safety
+///
+/// Both samples must point to `classes` `double`s and `out` to a writable `SpOutcome`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sp_ndh_complete(
+    empirical_sample: *const f64,
+    theoretical_sample: *const f64,
+    classes: usize,
+    significance: f64,
+    out: *mut SpOutcome,
+) -> i32 {
+    // SAFETY: forwarded from the caller.
+    let (Some(empirical_sample), Some(theoretical_sample)) = (unsafe {
+        (
+            slice(empirical_sample, classes),
+            slice(theoretical_sample, classes),
+        )
+    }) else {
+        return fail(SP_ERROR_NULL_POINTER, "Sample pointer is null");
+    };
+
+    match CompleteNDHProblemSituation::new(empirical_sample, theoretical_sample, significance) {
+        // SAFETY: forwarded from the caller.
+        Ok(situation) => unsafe { solve_ndh(Box::new(situation), out) },
+        Err(e) => fail(SP_ERROR_INVALID_PROBLEM, e),
+    }
+}
+
+/// Normal Distribution Hypothesis with the `classes + 1` edges of the random value ranges and their frequencies.
+///
+/// # Safety
+///
+/// `edges` must point to `classes + 1` `double`s, `frequencies` to `classes` `double`s
+/// and `out` to a writable `SpOutcome`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sp_ndh_grouped(
+    edges: *const f64,
+    frequencies: *const f64,
+    classes: usize,
+    significance: f64,
+    out: *mut SpOutcome,
+) -> i32 {
+    let Some(edges_len) = classes.checked_add(1) else {
+        return fail(SP_ERROR_LENGTH_MISMATCH, "Number of classes overflows");
+    };
+    // SAFETY: forwarded from the caller.
+    let (Some(edges), Some(frequencies)) =
+        (unsafe { (slice(edges, edges_len), slice(frequencies, classes)) })
+    else {
+        return fail(SP_ERROR_NULL_POINTER, "Sample pointer is null");
+    };
+
+    let random_value_ranges: Vec<_> = edges.windows(2).map(|edge| (edge[0], edge[1])).collect();
+    match IncompleteNDHProblemSituation::new(&random_value_ranges, frequencies, significance) {
+        // SAFETY: forwarded from the caller.
+        Ok(situation) => unsafe { solve_ndh(Box::new(situation), out) },
+        Err(e) => fail(SP_ERROR_INVALID_PROBLEM, e),
+    }
+}
+
+/// Normal Distribution Hypothesis with the raw sample grouped by Sturges' rule.
+///
+/// # Safety
+///
+/// `sample` must point to `len` `double`s and `out` to a writable `SpOutcome`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sp_ndh_raw(
+    sample: *const f64,
+    len: usize,
+    significance: f64,
+    out: *mut SpOutcome,
+) -> i32 {
+    // SAFETY: forwarded from the caller.
+    let Some(sample) = (unsafe { slice(sample, len) }) else {
+        return fail(SP_ERROR_NULL_POINTER, "Sample pointer is null");
+    };
+
+    match IncompleteNDHProblemSituation::from_raw_sample(sample, significance) {
+        // SAFETY: forwarded from the caller.
+        Ok(situation) => unsafe { solve_ndh(Box::new(situation), out) },
+        Err(e) => fail(SP_ERROR_INVALID_PROBLEM, e),
+    }
+}
+
+/// Same Variance Hypothesis; the statistic is Fisher–Snedecor's `F`.
+///
+/// # Safety
+///
+/// The samples must point to `x_len` and `y_len` `double`s and `out` to a writable `SpOutcome`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sp_same_variance(
+    x_sample: *const f64,
+    x_len: usize,
+    y_sample: *const f64,
+    y_len: usize,
+    significance: f64,
+    out: *mut SpOutcome,
+) -> i32 {
+    // SAFETY: forwarded from the caller.
+    let (Some(x_sample), Some(y_sample)) =
+        (unsafe { (slice(x_sample, x_len), slice(y_sample, y_len)) })
+    else {
+        return fail(SP_ERROR_NULL_POINTER, "Sample pointer is null");
+    };
+
+    match SameVarianceHypothesis::new(x_sample, y_sample, significance).outcome() {
+        // SAFETY: forwarded from the caller.
+        Ok(outcome) => unsafe {
+            write(
+                out,
+                SpOutcome {
+                    statistic: outcome.fisher_snedecor_observed,
+                    critical_value: outcome.fisher_snedecor_critical_value,
+                    accepted: outcome.accepted,
+                },
+            )
+        },
+        Err(e) => fail(SP_ERROR_INVALID_PROBLEM, e),
+    }
+}
+
+/// Same Mean Hypothesis by Student's t-test if `equal_variances`, by Welch's t-test otherwise;
+/// the statistic is `t` and the critical value is two-sided.
+///
+/// # Safety
+///
+/// The samples must point to `x_len` and `y_len` `double`s and `out` to a writable `SpOutcome`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sp_same_mean(
+    x_sample: *const f64,
+    x_len: usize,
+    y_sample: *const f64,
+    y_len: usize,
+    significance: f64,
+    equal_variances: bool,
+    out: *mut SpOutcome,
+) -> i32 {
+    // SAFETY: forwarded from the caller.
+    let (Some(x_sample), Some(y_sample)) =
+        (unsafe { (slice(x_sample, x_len), slice(y_sample, y_len)) })
+    else {
+        return fail(SP_ERROR_NULL_POINTER, "Sample pointer is null");
+    };
+
+    let variances = if equal_variances {
+        Variances::Equal
+    } else {
+        Variances::Unequal
+    };
+    let smh = match SameMeanHypothesis::new(x_sample, y_sample, variances, significance) {
+        Ok(smh) => smh,
+        Err(e) => return fail(SP_ERROR_INVALID_PROBLEM, e),
+    };
+
+    let (t, freedom_degrees) = smh.statistic();
+    let critical_value = match critical_values::students_t_two_sided(
+        freedom_degrees,
+        significance,
+        CriticalValueSource::Quantile,
+    ) {
+        Ok(critical_value) => critical_value,
+        Err(e) => return fail(SP_ERROR_INVALID_PROBLEM, e),
+    };
+
+    // SAFETY: forwarded from the caller.
+    unsafe {
+        write(
+            out,
+            SpOutcome {
+                statistic: t,
+                critical_value,
+                accepted: t.abs() < critical_value,
+            },
+        )
+    }
+}
+
+/// Mann–Whitney U test; the statistic is the standardized `U` and the critical value is two-sided.
+///
+/// # Safety
+///
+/// The samples must point to `x_len` and `y_len` `double`s and `out` to a writable `SpOutcome`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sp_mann_whitney(
+    x_sample: *const f64,
+    x_len: usize,
+    y_sample: *const f64,
+    y_len: usize,
+    significance: f64,
+    out: *mut SpOutcome,
+) -> i32 {
+    // SAFETY: forwarded from the caller.
+    let (Some(x_sample), Some(y_sample)) =
+        (unsafe { (slice(x_sample, x_len), slice(y_sample, y_len)) })
+    else {
+        return fail(SP_ERROR_NULL_POINTER, "Sample pointer is null");
+    };
+
+    let z = match MannWhitneyHypothesis::new(x_sample, y_sample, significance)
+        .and_then(|mwh| mwh.statistic())
+    {
+        Ok((_, z)) => z,
+        Err(e) => return fail(SP_ERROR_INVALID_PROBLEM, e),
+    };
+    let critical_value = Normal::standard().inverse_cdf(1. - significance / 2.);
+
+    // SAFETY: forwarded from the caller.
+    unsafe {
+        write(
+            out,
+            SpOutcome {
+                statistic: z,
+                critical_value,
+                accepted: z.abs() < critical_value,
+            },
+        )
+    }
+}
+
+/// Chi-Squared test of independence for a `rows × columns` contingency table stored row by row.
+///
+/// # Safety
+///
+/// `table` must point to `rows * columns` `double`s and `out` to a writable `SpOutcome`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sp_independence(
+    table: *const f64,
+    rows: usize,
+    columns: usize,
+    significance: f64,
+    out: *mut SpOutcome,
+) -> i32 {
+    let Some(len) = rows.checked_mul(columns) else {
+        return fail(SP_ERROR_LENGTH_MISMATCH, "Table size overflows");
+    };
+    // SAFETY: forwarded from the caller.
+    let Some(table) = (unsafe { slice(table, len) }) else {
+        return fail(SP_ERROR_NULL_POINTER, "Table pointer is null");
+    };
+    if len == 0 {
+        return fail(SP_ERROR_LENGTH_MISMATCH, "Table must not be empty");
+    }
+
+    let table: Vec<_> = table.chunks(columns).map(<[f64]>::to_vec).collect();
+    match IndependenceHypothesis::new(&table, significance).and_then(|ih| ih.outcome()) {
+        // SAFETY: forwarded from the caller.
+        Ok(outcome) => unsafe {
+            write(
+                out,
+                SpOutcome {
+                    statistic: outcome.chi_squared_observed,
+                    critical_value: outcome.chi_squared_critical_value,
+                    accepted: outcome.accepted,
+                },
+            )
+        },
+        Err(e) => fail(SP_ERROR_INVALID_PROBLEM, e),
+    }
+}
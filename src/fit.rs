@@ -0,0 +1,685 @@
+//! Estimation of the parameters of common distributions from raw or grouped samples.
+//!
+//! **Given**: *sample of a random variable **X***, raw or as values with frequencies,
+//! and the *family* of the distribution of **X**.
+//! **To figure out**: What are the parameters of the distribution, and how precise are they?
+//!
+//! The method of moments equates the mean and the variance of the sample with those of the distribution;
+//! maximum likelihood maximizes the probability of the sample. Standard errors are asymptotic:
+//! the inverse Fisher information for the likelihood and the delta method for the moments.
+//! For the normal, exponential, Poisson and binomial families both methods give the same estimates.
+
+use statistics_problems_core::quantiles::standard_normal_cdf;
+use statrs::distribution::{Binomial, DiscreteCDF, Poisson};
+use statrs::function::gamma::{digamma, gamma_lr, ln_gamma};
+
+use crate::numeric::{compensated_sum, weighted_biased_variance, weighted_mean};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum FitError {
+    SampleTooSmall,
+    LengthsMismatch,
+    FrequenciesInvalid,
+    SupportInvalid,
+    SampleDegenerate,
+    TrialsInvalid,
+}
+
+impl std::fmt::Display for FitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            FitError::SampleTooSmall => {
+                write!(f, "Sample must contain at least two values")
+            }
+            FitError::LengthsMismatch => {
+                write!(f, "Every value must have its frequency")
+            }
+            FitError::FrequenciesInvalid => {
+                write!(f, "Frequencies must be non-negative")
+            }
+            FitError::SupportInvalid => {
+                write!(f, "Sample has values outside the support of the family")
+            }
+            FitError::SampleDegenerate => {
+                write!(
+                    f,
+                    "Sample does not determine the parameters, e.g. all its values are equal"
+                )
+            }
+            FitError::TrialsInvalid => {
+                write!(f, "Number of trials must be positive")
+            }
+        }
+    }
+}
+
+/// Families of distributions, with their parameters in the order of [`Fit::parameters`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Family {
+    /// `N(μ, σ)`.
+    Normal,
+    /// `Exp(λ)` with the density `λ e^(-λx)`.
+    Exponential,
+    /// `Poisson(λ)`.
+    Poisson,
+    /// `B(n, p)` with the known number of trials `n`.
+    Binomial { trials: u64 },
+    /// `U(a, b)`.
+    Uniform,
+    /// `Γ(k, θ)` with the shape `k` and the scale `θ`.
+    Gamma,
+    /// `Weibull(k, λ)` with the shape `k` and the scale `λ`.
+    Weibull,
+    /// `LogN(μ, σ)`, so that `ln X ~ N(μ, σ)`.
+    LogNormal,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Method {
+    Moments,
+    MaximumLikelihood,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Estimate {
+    pub name: &'static str,
+    pub value: f64,
+    pub standard_error: f64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Fit {
+    pub family: Family,
+    pub method: Method,
+    /// Total of the frequencies.
+    pub sample_size: f64,
+    pub parameters: Vec<Estimate>,
+}
+
+impl Fit {
+    /// `F(x) = P(X ≤ x)` of the fitted distribution.
+    pub fn cdf(&self, x: f64) -> f64 {
+        let p = |i: usize| self.parameters[i].value;
+        match self.family {
+            Family::Normal => standard_normal_cdf((x - p(0)) / p(1)),
+            Family::Exponential => {
+                if x <= 0. {
+                    0.
+                } else {
+                    1. - (-p(0) * x).exp()
+                }
+            }
+            Family::Poisson => match Poisson::new(p(0)) {
+                Ok(poisson) if x >= 0. => poisson.cdf(x.floor() as u64),
+                Ok(_) => 0.,
+                Err(_) => f64::NAN,
+            },
+            Family::Binomial { trials } => match Binomial::new(p(0), trials) {
+                Ok(binomial) if x >= 0. => binomial.cdf(x.floor() as u64),
+                Ok(_) => 0.,
+                Err(_) => f64::NAN,
+            },
+            Family::Uniform => ((x - p(0)) / (p(1) - p(0))).clamp(0., 1.),
+            Family::Gamma => {
+                if x <= 0. {
+                    0.
+                } else {
+                    gamma_lr(p(0), x / p(1))
+                }
+            }
+            Family::Weibull => {
+                if x <= 0. {
+                    0.
+                } else {
+                    1. - (-(x / p(1)).powf(p(0))).exp()
+                }
+            }
+            Family::LogNormal => {
+                if x <= 0. {
+                    0.
+                } else {
+                    standard_normal_cdf((x.ln() - p(0)) / p(1))
+                }
+            }
+        }
+    }
+
+    /// Frequencies of the ranges `(x₁, x₂]` expected out of `sample_size` values.
+    pub fn expected_frequencies(&self, random_value_ranges: &[(f64, f64)]) -> Vec<f64> {
+        frequency_table(self, random_value_ranges)
+            .iter()
+            .map(|row| row.expected_frequency)
+            .collect()
+    }
+
+    /// `E(X)` of the fitted distribution.
+    pub fn mean(&self) -> f64 {
+        let p = |i: usize| self.parameters[i].value;
+        match self.family {
+            Family::Normal | Family::Poisson => p(0),
+            Family::Exponential => 1. / p(0),
+            Family::Binomial { trials } => trials as f64 * p(0),
+            Family::Uniform => (p(0) + p(1)) / 2.,
+            Family::Gamma => p(0) * p(1),
+            Family::Weibull => p(1) * ln_gamma(1. + 1. / p(0)).exp(),
+            Family::LogNormal => (p(0) + p(1).powi(2) / 2.).exp(),
+        }
+    }
+
+    /// `Var(X)` of the fitted distribution.
+    pub fn variance(&self) -> f64 {
+        let p = |i: usize| self.parameters[i].value;
+        match self.family {
+            Family::Normal => p(1).powi(2),
+            Family::Exponential => p(0).powi(-2),
+            Family::Poisson => p(0),
+            Family::Binomial { trials } => trials as f64 * p(0) * (1. - p(0)),
+            Family::Uniform => (p(1) - p(0)).powi(2) / 12.,
+            Family::Gamma => p(0) * p(1).powi(2),
+            Family::Weibull => {
+                central_moments(|r| p(1).powi(r) * ln_gamma(1. + r as f64 / p(0)).exp()).0
+            }
+            Family::LogNormal => (p(1).powi(2).exp() - 1.) * (2. * p(0) + p(1).powi(2)).exp(),
+        }
+    }
+
+    /// Logarithm of the density, or of the probability for the discrete families, at `x`;
+    /// `-∞` outside the support.
+    pub fn ln_density(&self, x: f64) -> f64 {
+        let p = |i: usize| self.parameters[i].value;
+        let ln_normal =
+            |z: f64, sigma: f64| -sigma.ln() - 0.5 * (2. * std::f64::consts::PI).ln() - z * z / 2.;
+        let inside =
+            |condition: bool, value: f64| if condition { value } else { f64::NEG_INFINITY };
+        // `x ln p` with `0 ln 0 = 0`.
+        let x_ln = |x: f64, p: f64| if x == 0. { 0. } else { x * p.ln() };
+
+        match self.family {
+            Family::Normal => ln_normal((x - p(0)) / p(1), p(1)),
+            Family::Exponential => inside(x >= 0., p(0).ln() - p(0) * x),
+            Family::Poisson => inside(
+                x >= 0. && x.fract() == 0.,
+                x_ln(x, p(0)) - p(0) - ln_gamma(x + 1.),
+            ),
+            Family::Binomial { trials } => {
+                let n = trials as f64;
+                inside(
+                    (0. ..=n).contains(&x) && x.fract() == 0.,
+                    ln_gamma(n + 1.) - ln_gamma(x + 1.) - ln_gamma(n - x + 1.)
+                        + x_ln(x, p(0))
+                        + x_ln(n - x, 1. - p(0)),
+                )
+            }
+            Family::Uniform => inside((p(0)..=p(1)).contains(&x), -(p(1) - p(0)).ln()),
+            Family::Gamma => inside(
+                x > 0.,
+                (p(0) - 1.) * x.ln() - x / p(1) - ln_gamma(p(0)) - p(0) * p(1).ln(),
+            ),
+            Family::Weibull => inside(
+                x > 0.,
+                p(0).ln() - p(1).ln() + (p(0) - 1.) * (x / p(1)).ln() - (x / p(1)).powf(p(0)),
+            ),
+            Family::LogNormal => inside(x > 0., ln_normal((x.ln() - p(0)) / p(1), p(1)) - x.ln()),
+        }
+    }
+}
+
+/// A candidate distribution ranked by [`best_fit`].
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Ranking {
+    pub fit: Fit,
+    /// `ln L`, the log-likelihood of the sample.
+    pub log_likelihood: f64,
+    /// `AIC = 2k - 2 ln L` for `k` estimated parameters.
+    pub aic: f64,
+    /// `BIC = k ln n - 2 ln L`.
+    pub bic: f64,
+}
+
+/// Row of the theoretical frequency table of a fitted distribution.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TheoreticalFrequency {
+    /// `(x₁, x₂]`.
+    pub range: (f64, f64),
+    /// `P(x₁ < X ≤ x₂)`.
+    pub probability: f64,
+    /// `F(x₂) = P(X ≤ x₂)`.
+    pub cumulative_probability: f64,
+    /// `n P(x₁ < X ≤ x₂)` out of [`Fit::sample_size`] values.
+    pub expected_frequency: f64,
+    /// Total of the expected frequencies of the ranges up to this one.
+    pub cumulative_expected_frequency: f64,
+}
+
+/// Fits each candidate family by maximum likelihood and ranks them from the best, by the smallest `AIC`,
+/// the ties broken by `BIC`. Families that cannot be fitted, e.g. to values outside their support, are left out.
+///
+/// The likelihoods of discrete and continuous families are not comparable, so the candidates should be
+/// of one kind.
+pub fn best_fit(sample: &[f64], candidates: &[Family]) -> Vec<Ranking> {
+    let mut rankings: Vec<_> = candidates
+        .iter()
+        .filter_map(|family| fit(sample, *family, Method::MaximumLikelihood).ok())
+        .map(|fit| {
+            let log_likelihood = compensated_sum(sample.iter().map(|x| fit.ln_density(*x)));
+            let k = fit.parameters.len() as f64;
+            Ranking {
+                aic: 2. * k - 2. * log_likelihood,
+                bic: k * fit.sample_size.ln() - 2. * log_likelihood,
+                log_likelihood,
+                fit,
+            }
+        })
+        .collect();
+
+    rankings.sort_by(|a, b| a.aic.total_cmp(&b.aic).then(a.bic.total_cmp(&b.bic)));
+    rankings
+}
+
+/// Theoretical frequency table of the fitted distribution over the ranges `(x₁, x₂]`, in their order;
+/// the expected frequencies are those the Normal Distribution Hypothesis compares with the empirical ones.
+pub fn frequency_table(fit: &Fit, random_value_ranges: &[(f64, f64)]) -> Vec<TheoreticalFrequency> {
+    let mut cumulative_expected_frequency = 0.;
+    random_value_ranges
+        .iter()
+        .map(|&(x_1, x_2)| {
+            let cumulative_probability = fit.cdf(x_2);
+            let probability = cumulative_probability - fit.cdf(x_1);
+            let expected_frequency = fit.sample_size * probability;
+            cumulative_expected_frequency += expected_frequency;
+            TheoreticalFrequency {
+                range: (x_1, x_2),
+                probability,
+                cumulative_probability,
+                expected_frequency,
+                cumulative_expected_frequency,
+            }
+        })
+        .collect()
+}
+
+/// Fits the family to a raw sample.
+pub fn fit(sample: &[f64], family: Family, method: Method) -> Result<Fit, FitError> {
+    fit_grouped(sample, &vec![1.; sample.len()], family, method)
+}
+
+/// Fits the family to the values with their frequencies, e.g. the midpoints of grouped intervals.
+pub fn fit_grouped(
+    values: &[f64],
+    frequencies: &[f64],
+    family: Family,
+    method: Method,
+) -> Result<Fit, FitError> {
+    if values.len() != frequencies.len() {
+        return Err(FitError::LengthsMismatch);
+    }
+
+    if frequencies.iter().any(|f| f.is_nan() || *f < 0.) {
+        return Err(FitError::FrequenciesInvalid);
+    }
+
+    let n = compensated_sum(frequencies.iter().copied());
+    if n < 2. {
+        return Err(FitError::SampleTooSmall);
+    }
+
+    // Values of zero frequency take no part, even outside the support.
+    let (values, frequencies): (Vec<f64>, Vec<f64>) = values
+        .iter()
+        .zip(frequencies)
+        .filter(|(_, f)| **f > 0.)
+        .unzip();
+    let (values, frequencies) = (&values[..], &frequencies[..]);
+    let within = |support: &dyn Fn(f64) -> bool| values.iter().all(|x| support(*x));
+    let average = |g: &dyn Fn(f64) -> f64| {
+        let transformed: Vec<_> = values.iter().map(|x| g(*x)).collect();
+        weighted_mean(&transformed, frequencies)
+    };
+
+    let mean = weighted_mean(values, frequencies);
+    let variance = weighted_biased_variance(values, frequencies);
+
+    let parameters = match family {
+        Family::Normal => {
+            if variance == 0. {
+                return Err(FitError::SampleDegenerate);
+            }
+
+            let sigma = variance.sqrt();
+            vec![
+                estimate("μ", mean, sigma / n.sqrt()),
+                estimate("σ", sigma, sigma / (2. * n).sqrt()),
+            ]
+        }
+        Family::Exponential => {
+            if !within(&|x| x >= 0.) {
+                return Err(FitError::SupportInvalid);
+            }
+
+            if mean == 0. {
+                return Err(FitError::SampleDegenerate);
+            }
+
+            vec![estimate("λ", 1. / mean, 1. / (mean * n.sqrt()))]
+        }
+        Family::Poisson => {
+            if !within(&|x| x >= 0.) {
+                return Err(FitError::SupportInvalid);
+            }
+
+            if mean == 0. {
+                return Err(FitError::SampleDegenerate);
+            }
+
+            vec![estimate("λ", mean, (mean / n).sqrt())]
+        }
+        Family::Binomial { trials } => {
+            if trials == 0 {
+                return Err(FitError::TrialsInvalid);
+            }
+
+            let trials = trials as f64;
+            if !within(&|x| (0. ..=trials).contains(&x)) {
+                return Err(FitError::SupportInvalid);
+            }
+
+            let p = mean / trials;
+            vec![estimate("p", p, (p * (1. - p) / (n * trials)).sqrt())]
+        }
+        Family::Uniform => {
+            if variance == 0. {
+                return Err(FitError::SampleDegenerate);
+            }
+
+            match method {
+                Method::Moments => moments_fit(
+                    ["a", "b"],
+                    mean,
+                    variance,
+                    n,
+                    |m, v| (m - (3. * v).sqrt(), m + (3. * v).sqrt()),
+                    |a, b| {
+                        let width: f64 = b - a;
+                        (width.powi(2) / 12., 0., width.powi(4) / 80.)
+                    },
+                ),
+                // The likelihood is not regular, so the errors are those of the extreme order statistics.
+                Method::MaximumLikelihood => {
+                    let a = values.iter().copied().fold(f64::INFINITY, f64::min);
+                    let b = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                    let error = (b - a) / (n + 1.) * (n / (n + 2.)).sqrt();
+                    vec![estimate("a", a, error), estimate("b", b, error)]
+                }
+            }
+        }
+        Family::Gamma => {
+            if !within(&|x| x > 0.) {
+                return Err(FitError::SupportInvalid);
+            }
+
+            if variance == 0. {
+                return Err(FitError::SampleDegenerate);
+            }
+
+            match method {
+                Method::Moments => moments_fit(
+                    ["k", "θ"],
+                    mean,
+                    variance,
+                    n,
+                    |m, v| (m * m / v, v / m),
+                    |k, theta| {
+                        (
+                            k * theta.powi(2),
+                            2. * k * theta.powi(3),
+                            3. * k * (k + 2.) * theta.powi(4),
+                        )
+                    },
+                ),
+                Method::MaximumLikelihood => {
+                    let k = gamma_shape(mean.ln() - average(&f64::ln));
+                    let theta = mean / k;
+                    let information = k * trigamma(k) - 1.;
+                    vec![
+                        estimate("k", k, (k / (information * n)).sqrt()),
+                        estimate("θ", theta, theta * (trigamma(k) / (information * n)).sqrt()),
+                    ]
+                }
+            }
+        }
+        Family::Weibull => {
+            if !within(&|x| x > 0.) {
+                return Err(FitError::SupportInvalid);
+            }
+
+            if variance == 0. {
+                return Err(FitError::SampleDegenerate);
+            }
+
+            match method {
+                Method::Moments => moments_fit(
+                    ["k", "λ"],
+                    mean,
+                    variance,
+                    n,
+                    |m, v| {
+                        let k = weibull_moments_shape(v / (m * m));
+                        (k, m / (ln_gamma(1. + 1. / k)).exp())
+                    },
+                    |k, lambda| {
+                        central_moments(|r| lambda.powi(r) * ln_gamma(1. + r as f64 / k).exp())
+                    },
+                ),
+                Method::MaximumLikelihood => {
+                    let largest = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                    let scaled: Vec<_> = values.iter().map(|x| x / largest).collect();
+                    let k = weibull_likelihood_shape(&scaled, frequencies);
+                    let lambda = largest
+                        * weighted_mean(
+                            &scaled.iter().map(|x| x.powf(k)).collect::<Vec<_>>(),
+                            frequencies,
+                        )
+                        .powf(1. / k);
+
+                    // With `γ` the Euler–Mascheroni constant, `Var(k) = 6k² / (π² n)`
+                    // and `Var(λ) = λ² (1 + 6 (1 - γ)² / π²) / (k² n)`.
+                    let pi_squared = std::f64::consts::PI.powi(2);
+                    let shift: f64 = 1. - 0.577_215_664_901_532_9;
+                    vec![
+                        estimate("k", k, k * (6. / (pi_squared * n)).sqrt()),
+                        estimate(
+                            "λ",
+                            lambda,
+                            lambda / k * ((1. + 6. * shift.powi(2) / pi_squared) / n).sqrt(),
+                        ),
+                    ]
+                }
+            }
+        }
+        Family::LogNormal => {
+            if !within(&|x| x > 0.) {
+                return Err(FitError::SupportInvalid);
+            }
+
+            if variance == 0. {
+                return Err(FitError::SampleDegenerate);
+            }
+
+            match method {
+                Method::Moments => moments_fit(
+                    ["μ", "σ"],
+                    mean,
+                    variance,
+                    n,
+                    |m, v| {
+                        let sigma_squared = (1. + v / (m * m)).ln();
+                        (m.ln() - sigma_squared / 2., sigma_squared.sqrt())
+                    },
+                    |mu, sigma| {
+                        central_moments(|r| {
+                            (r as f64 * mu + (r * r) as f64 * sigma * sigma / 2.).exp()
+                        })
+                    },
+                ),
+                Method::MaximumLikelihood => {
+                    let logarithms: Vec<_> = values.iter().map(|x| x.ln()).collect();
+                    let sigma = weighted_biased_variance(&logarithms, frequencies).sqrt();
+                    vec![
+                        estimate(
+                            "μ",
+                            weighted_mean(&logarithms, frequencies),
+                            sigma / n.sqrt(),
+                        ),
+                        estimate("σ", sigma, sigma / (2. * n).sqrt()),
+                    ]
+                }
+            }
+        }
+    };
+
+    Ok(Fit {
+        family,
+        method,
+        sample_size: n,
+        parameters,
+    })
+}
+
+fn estimate(name: &'static str, value: f64, standard_error: f64) -> Estimate {
+    Estimate {
+        name,
+        value,
+        standard_error,
+    }
+}
+
+/// Two parameters from the mean `m` and the biased variance `v`, with the delta method errors
+/// from `Var(m) = μ₂ / n`, `Cov(m, v) = μ₃ / n` and `Var(v) = (μ₄ - μ₂²) / n`,
+/// the central moments `μ` being those of the fitted distribution.
+fn moments_fit(
+    names: [&'static str; 2],
+    mean: f64,
+    variance: f64,
+    n: f64,
+    parameters: impl Fn(f64, f64) -> (f64, f64),
+    moments: impl Fn(f64, f64) -> (f64, f64, f64),
+) -> Vec<Estimate> {
+    let (first, second) = parameters(mean, variance);
+    let (mu_2, mu_3, mu_4) = moments(first, second);
+
+    // Central differences approximate the gradient of the parameters over `(m, v)`.
+    let (h_m, h_v) = (1e-6 * mean.abs().max(variance.sqrt()), 1e-6 * variance);
+    let (m_plus, m_minus) = (
+        parameters(mean + h_m, variance),
+        parameters(mean - h_m, variance),
+    );
+    let (v_plus, v_minus) = (
+        parameters(mean, variance + h_v),
+        parameters(mean, variance - h_v),
+    );
+    let error = |d_m: f64, d_v: f64| {
+        ((d_m * d_m * mu_2 + 2. * d_m * d_v * mu_3 + d_v * d_v * (mu_4 - mu_2 * mu_2)) / n).sqrt()
+    };
+
+    vec![
+        estimate(
+            names[0],
+            first,
+            error(
+                (m_plus.0 - m_minus.0) / (2. * h_m),
+                (v_plus.0 - v_minus.0) / (2. * h_v),
+            ),
+        ),
+        estimate(
+            names[1],
+            second,
+            error(
+                (m_plus.1 - m_minus.1) / (2. * h_m),
+                (v_plus.1 - v_minus.1) / (2. * h_v),
+            ),
+        ),
+    ]
+}
+
+/// `μ₂`, `μ₃` and `μ₄` from the raw moments `E(Xʳ)`.
+fn central_moments(raw: impl Fn(i32) -> f64) -> (f64, f64, f64) {
+    let (r_1, r_2, r_3, r_4) = (raw(1), raw(2), raw(3), raw(4));
+    (
+        r_2 - r_1.powi(2),
+        r_3 - 3. * r_1 * r_2 + 2. * r_1.powi(3),
+        r_4 - 4. * r_1 * r_3 + 6. * r_1.powi(2) * r_2 - 3. * r_1.powi(4),
+    )
+}
+
+/// Solves `ln k - ψ(k) = s` by Newton's method from the approximation of Minka (2002).
+fn gamma_shape(s: f64) -> f64 {
+    let mut k = (3. - s + ((s - 3.).powi(2) + 24. * s).sqrt()) / (12. * s);
+    for _ in 0..100 {
+        let step = (k.ln() - digamma(k) - s) / (1. / k - trigamma(k));
+        k = (k - step).max(k / 2.);
+        if step.abs() < 1e-12 * k {
+            break;
+        }
+    }
+
+    k
+}
+
+/// `ψ'(x)` by the recurrence `ψ'(x) = ψ'(x + 1) + 1 / x²` and the asymptotic series.
+fn trigamma(mut x: f64) -> f64 {
+    let mut total = 0.;
+    while x < 6. {
+        total += 1. / (x * x);
+        x += 1.;
+    }
+
+    let x_2 = 1. / (x * x);
+    total
+        + 1. / x
+        + x_2 / 2.
+        + x_2 / x * (1. / 6. - x_2 * (1. / 30. - x_2 * (1. / 42. - x_2 / 30.)))
+}
+
+/// Solves `Γ(1 + 2/k) / Γ²(1 + 1/k) - 1 = v / m²` by bisection, the left side decreasing in `k`.
+fn weibull_moments_shape(ratio: f64) -> f64 {
+    let squared_variation =
+        |k: f64| (ln_gamma(1. + 2. / k) - 2. * ln_gamma(1. + 1. / k)).exp() - 1.;
+    bisect_log(|k| squared_variation(k) - ratio, -1)
+}
+
+/// Solves `Σ xᵏ ln x / Σ xᵏ - 1/k - mean(ln x) = 0`, the left side increasing in `k`.
+fn weibull_likelihood_shape(values: &[f64], frequencies: &[f64]) -> f64 {
+    let logarithms: Vec<_> = values.iter().map(|x| x.ln()).collect();
+    let mean_logarithm = weighted_mean(&logarithms, frequencies);
+    let score = |k: f64| {
+        let powers: Vec<_> = values.iter().map(|x| x.powf(k)).collect();
+        let weighted: Vec<_> = powers.iter().zip(&logarithms).map(|(p, l)| p * l).collect();
+        weighted_mean(&weighted, frequencies) / weighted_mean(&powers, frequencies)
+            - 1. / k
+            - mean_logarithm
+    };
+    bisect_log(score, 1)
+}
+
+/// Root of a monotone function over `k` in `[0.02, 1000]`, bisected on the logarithmic scale;
+/// `direction` is the sign of the slope.
+pub(crate) fn bisect_log(f: impl Fn(f64) -> f64, direction: i32) -> f64 {
+    let (mut lower, mut upper) = (0.02f64.ln(), 1000f64.ln());
+    for _ in 0..200 {
+        let middle = (lower + upper) / 2.;
+        if f(middle.exp()) * direction as f64 > 0. {
+            upper = middle;
+        } else {
+            lower = middle;
+        }
+    }
+
+    ((lower + upper) / 2.).exp()
+}
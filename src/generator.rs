@@ -0,0 +1,643 @@
+//! Randomized textbook problems with their solutions, for problem sets.
+//!
+//! **Given**: a *difficulty* and a source of randomness.
+//! **To figure out**: A Normal Distribution Hypothesis, Same Variance Hypothesis or t-test problem with
+//! "nice" numbers and its full solution.
+//!
+//! Grouped samples have integer frequencies over intervals of round width starting at a round value,
+//! the classes expected fewer than five times being merged with their neighbours;
+//! the samples of the two-sample problems are rounded to integers or to one decimal; the significance is
+//! one of [`SIGNIFICANCES`]. About half of the problems are drawn under `H₀` and the rest away from it,
+//! so both decisions occur. The problems are [`GoldenProblem`]s, which are written in the format of the
+//! golden collection by their `Display` and checked later with [`golden::run`](crate::golden::run).
+//!
+//! [`export`] turns generated problems, or any golden problems solved by [`GeneratedProblem::from_problem`],
+//! into question and answer [`Card`]s for Anki or a printed quiz: the question states the data and the
+//! significance, the answer holds the solving steps and the decision.
+//!
+//! ```ignore
+//! let rng = RngSource::from_rng(StdRng::seed_from_u64(7));
+//! for problem in generator::problem_set(Difficulty::Medium, 6, rng) {
+//!     println!("{}", problem.problem);
+//! }
+//! ```
+
+use std::fmt::Write;
+
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+use statistics_problems_core::decisions::Decision;
+use statrs::distribution::{Exp, Normal};
+
+use crate::contingency::{IndependenceHypothesis, IndependenceOutcome};
+use crate::golden::{DEFAULT_TOLERANCE, GoldenInput, GoldenProblem};
+use crate::hypotheses::Hypotheses;
+use crate::normal_distribution_hypothesis::{
+    CompleteNDHProblemSituation, IncompleteNDHProblemSituation, NDHOutcome, NDHProblemSituation,
+    NormalDistributionHypothesis,
+};
+use crate::numeric::{mean, unbiased_variance};
+use crate::report::{self, Locale, NotationStyle};
+use crate::rng::RngSource;
+use crate::rounding::round;
+use crate::same_mean_hypothesis::{SameMeanHypothesis, Variances};
+use crate::same_variance_hypothesis::{SVHOutcome, SameVarianceHypothesis};
+
+/// Significance ratios the problems are given with.
+pub const SIGNIFICANCES: [f64; 3] = [0.01, 0.05, 0.1];
+
+/// Smallest expected frequency of a class for the Chi-Squared approximation; sparser classes are merged.
+const MIN_EXPECTED_FREQUENCY: f64 = 5.;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Difficulty {
+    /// Small samples of integers, five classes, mostly four after the sparse ones are merged.
+    Easy,
+    /// Samples with one decimal, six or seven classes.
+    Medium,
+    /// Larger samples with one decimal, up to ten classes, and Welch's t-test.
+    Hard,
+}
+
+impl Difficulty {
+    /// Sizes of the samples of the two-sample problems.
+    fn sample_sizes(self) -> std::ops::RangeInclusive<usize> {
+        match self {
+            Difficulty::Easy => 5..=8,
+            Difficulty::Medium => 8..=12,
+            Difficulty::Hard => 12..=20,
+        }
+    }
+
+    fn decimals(self) -> i32 {
+        match self {
+            Difficulty::Easy => 0,
+            Difficulty::Medium | Difficulty::Hard => 1,
+        }
+    }
+}
+
+/// Intermediate quantities of the solution.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Solution {
+    NormalDistribution(NDHOutcome),
+    SameVariance(SVHOutcome),
+    SameMean {
+        /// `x̄` and `ȳ`.
+        means: (f64, f64),
+        /// Unbiased sample variances of **X** and **Y**.
+        variances: (f64, f64),
+        t_observed: f64,
+        freedom_degrees: f64,
+        t_critical_value: f64,
+        hypotheses: Hypotheses,
+        accepted: bool,
+    },
+    Independence(IndependenceOutcome),
+}
+
+impl Solution {
+    /// The statistic, the critical value and the decision.
+    pub fn decision(&self) -> Decision {
+        let (statistic, critical_value, accepted) = match self {
+            Solution::NormalDistribution(outcome) => (
+                outcome.chi_squared_observed,
+                outcome.chi_squared_critical_value,
+                outcome.accepted,
+            ),
+            Solution::SameVariance(outcome) => (
+                outcome.fisher_snedecor_observed,
+                outcome.fisher_snedecor_critical_value,
+                outcome.accepted,
+            ),
+            Solution::SameMean {
+                t_observed,
+                t_critical_value,
+                accepted,
+                ..
+            } => (*t_observed, *t_critical_value, *accepted),
+            Solution::Independence(outcome) => (
+                outcome.chi_squared_observed,
+                outcome.chi_squared_critical_value,
+                outcome.accepted,
+            ),
+        };
+
+        Decision {
+            statistic,
+            critical_value,
+            accepted,
+        }
+    }
+
+    /// The solving steps in the locale and the notation, one per line, ending with the decision.
+    pub fn steps(&self, locale: Locale, notation: NotationStyle) -> String {
+        match self {
+            Solution::NormalDistribution(outcome) => {
+                report::normal_distribution_hypothesis(outcome, locale, notation)
+            }
+            Solution::SameVariance(outcome) => {
+                report::same_variance_hypothesis(outcome, locale, notation)
+            }
+            Solution::SameMean {
+                means,
+                variances,
+                t_observed,
+                freedom_degrees,
+                t_critical_value,
+                hypotheses,
+                accepted,
+            } => {
+                let (observed, critical) = (notation.observed("t"), notation.critical("t"));
+                let mut steps = String::new();
+                writeln!(steps, "{}", report::hypotheses(hypotheses, locale)).unwrap();
+                writeln!(steps, "x̄ = {:.4}, ȳ = {:.4}", means.0, means.1).unwrap();
+                writeln!(
+                    steps,
+                    "{} = {:.4}, {} = {:.4}",
+                    notation.variance("x"),
+                    variances.0,
+                    notation.variance("y"),
+                    variances.1
+                )
+                .unwrap();
+                writeln!(
+                    steps,
+                    "{observed} = {t_observed:.4}, {critical}({freedom_degrees:.2}) = {t_critical_value:.4}"
+                )
+                .unwrap();
+                writeln!(
+                    steps,
+                    "{}",
+                    report::conclusion(
+                        &report::comparison(&format!("|{observed}|"), &critical, *accepted),
+                        hypotheses,
+                        *accepted,
+                        locale
+                    )
+                )
+                .unwrap();
+                steps
+            }
+            Solution::Independence(outcome) => {
+                report::independence_hypothesis(outcome, locale, notation)
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct GeneratedProblem {
+    /// The statement, the input and the decision.
+    pub problem: GoldenProblem,
+    pub solution: Solution,
+}
+
+impl GeneratedProblem {
+    /// Solves a problem supplied by the user; its expected decision is replaced by the solved one.
+    pub fn from_problem(mut problem: GoldenProblem) -> Result<Self, String> {
+        let solution = solve(&problem.input, problem.significance)?;
+        problem.expected = solution.decision();
+
+        Ok(Self { problem, solution })
+    }
+
+    /// The question with the data and the significance, and the answer with the solving steps
+    /// in the locale and the notation.
+    pub fn card(&self, locale: Locale, notation: NotationStyle) -> Card {
+        Card {
+            question: question(&self.problem),
+            answer: self.solution.steps(locale, notation).trim_end().to_string(),
+        }
+    }
+}
+
+/// Question and answer pair of a quiz.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Card {
+    pub question: String,
+    pub answer: String,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum CardFormat {
+    /// `Q:` and `A:` blocks separated by blank lines, for printing.
+    Text,
+    /// Two quoted fields per card, the line breaks kept, for Anki's CSV import.
+    Csv,
+    /// An array of `{"question": .., "answer": ..}` objects.
+    Json,
+}
+
+/// Cards of the problems in the format, the answers in the locale and the notation.
+pub fn export(
+    problems: &[GeneratedProblem],
+    format: CardFormat,
+    locale: Locale,
+    notation: NotationStyle,
+) -> String {
+    let cards: Vec<_> = problems
+        .iter()
+        .map(|problem| problem.card(locale, notation))
+        .collect();
+
+    match format {
+        CardFormat::Text => cards
+            .iter()
+            .map(|card| format!("Q: {}\nA: {}\n", card.question, card.answer))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        CardFormat::Csv => cards
+            .iter()
+            .map(|card| {
+                let quote = |field: &str| format!("\"{}\"", field.replace('"', "\"\""));
+                format!("{},{}\n", quote(&card.question), quote(&card.answer))
+            })
+            .collect(),
+        CardFormat::Json => {
+            let objects: Vec<_> = cards
+                .iter()
+                .map(|card| {
+                    format!(
+                        "  {{\"question\": {}, \"answer\": {}}}",
+                        json_string(&card.question),
+                        json_string(&card.answer)
+                    )
+                })
+                .collect();
+            format!("[\n{}\n]\n", objects.join(",\n"))
+        }
+    }
+}
+
+/// Normal Distribution Hypothesis over a grouped sample.
+pub fn normal_distribution(difficulty: Difficulty, rng: RngSource) -> GeneratedProblem {
+    rng.with_rng(|rng| generate_normal_distribution(difficulty, rng))
+}
+
+/// Same Variance Hypothesis over two samples.
+pub fn same_variance(difficulty: Difficulty, rng: RngSource) -> GeneratedProblem {
+    rng.with_rng(|rng| generate_same_variance(difficulty, rng))
+}
+
+/// Student's t-test, or Welch's for [`Difficulty::Hard`], over two samples.
+pub fn same_mean(difficulty: Difficulty, rng: RngSource) -> GeneratedProblem {
+    rng.with_rng(|rng| generate_same_mean(difficulty, rng))
+}
+
+/// `count` problems of the three kinds in turn, named by their kind and number.
+pub fn problem_set(difficulty: Difficulty, count: usize, rng: RngSource) -> Vec<GeneratedProblem> {
+    rng.with_rng(|rng| {
+        (0..count)
+            .map(|i| {
+                let mut generated = match i % 3 {
+                    0 => generate_normal_distribution(difficulty, rng),
+                    1 => generate_same_variance(difficulty, rng),
+                    _ => generate_same_mean(difficulty, rng),
+                };
+                generated.problem.name = format!("{}-{}", generated.problem.name, i + 1);
+                generated
+            })
+            .collect()
+    })
+}
+
+fn generate_normal_distribution(difficulty: Difficulty, rng: &mut dyn RngCore) -> GeneratedProblem {
+    let (sample_size, classes) = match difficulty {
+        Difficulty::Easy => (50, 5),
+        Difficulty::Medium => (100, rng.gen_range(6..=7)),
+        Difficulty::Hard => (200, rng.gen_range(8..=10)),
+    };
+
+    // Samples with an empty class, or with too few classes left after the merging, are drawn again.
+    'draw: loop {
+        let width = *[1., 2., 5., 10.].choose(rng).unwrap();
+        let start = width * rng.gen_range(2..=10) as f64;
+        let span = width * classes as f64;
+        let mut random_value_ranges: Vec<_> = (0..classes)
+            .map(|i| (start + i as f64 * width, start + (i + 1) as f64 * width))
+            .collect();
+
+        let normal = rng.gen_bool(0.5);
+        let normal_distribution = Normal::new(start + span / 2., span / 4.).unwrap();
+        let exponential_distribution = Exp::new(4. / span).unwrap();
+        // Values beyond the intervals are drawn again, so every value belongs to a class.
+        let mut frequencies = vec![0.; classes];
+        for _ in 0..sample_size {
+            let value = loop {
+                let value = if normal {
+                    rng.sample(normal_distribution)
+                } else {
+                    start + rng.sample(exponential_distribution)
+                };
+                if (start..start + span).contains(&value) {
+                    break value;
+                }
+            };
+            frequencies[((value - start) / width) as usize] += 1.;
+        }
+        if frequencies.contains(&0.) {
+            continue;
+        }
+
+        let significance = *SIGNIFICANCES.choose(rng).unwrap();
+        loop {
+            let Some(generated) = generated(
+                "generated-ndh",
+                format!(
+                    "Is a grouped sample of {sample_size} values in {} classes normal?",
+                    random_value_ranges.len()
+                ),
+                GoldenInput::NDHGrouped {
+                    random_value_ranges: random_value_ranges.clone(),
+                    frequencies: frequencies.clone(),
+                },
+                significance,
+            ) else {
+                continue 'draw;
+            };
+            let Solution::NormalDistribution(outcome) = &generated.solution else {
+                unreachable!("a grouped sample is solved by the Normal Distribution Hypothesis");
+            };
+
+            let expected: Vec<_> = outcome.bins.iter().map(|bin| bin.expected).collect();
+            if expected.iter().all(|e| *e >= MIN_EXPECTED_FREQUENCY) {
+                return generated;
+            }
+
+            (random_value_ranges, frequencies) =
+                merge_sparse_classes(&random_value_ranges, &frequencies, &expected);
+            // The mean and the standard deviation take two of the `k - 1` freedom degrees.
+            if random_value_ranges.len() < 4 {
+                continue 'draw;
+            }
+        }
+    }
+}
+
+/// Merges each class expected less than [`MIN_EXPECTED_FREQUENCY`] times with the following ones,
+/// the last such run with the preceding class.
+fn merge_sparse_classes(
+    random_value_ranges: &[(f64, f64)],
+    frequencies: &[f64],
+    expected: &[f64],
+) -> (Vec<(f64, f64)>, Vec<f64>) {
+    let mut merged: Vec<((f64, f64), f64, f64)> = Vec::new();
+    let mut pending: Option<((f64, f64), f64, f64)> = None;
+    for ((&(x_1, x_2), &frequency), &expected) in
+        random_value_ranges.iter().zip(frequencies).zip(expected)
+    {
+        let class = match pending.take() {
+            Some(((start, _), f, e)) => ((start, x_2), f + frequency, e + expected),
+            None => ((x_1, x_2), frequency, expected),
+        };
+        if class.2 < MIN_EXPECTED_FREQUENCY {
+            pending = Some(class);
+        } else {
+            merged.push(class);
+        }
+    }
+    if let Some(((_, end), f, e)) = pending {
+        match merged.last_mut() {
+            Some(last) => *last = ((last.0.0, end), last.1 + f, last.2 + e),
+            None => merged.push(((random_value_ranges[0].0, end), f, e)),
+        }
+    }
+
+    merged.into_iter().map(|(range, f, _)| (range, f)).unzip()
+}
+
+fn generate_same_variance(difficulty: Difficulty, rng: &mut dyn RngCore) -> GeneratedProblem {
+    loop {
+        let (x_sample, y_sample) = two_samples(difficulty, rng, 0., &[1., 1.5, 2., 3.]);
+        let generated = generated(
+            "generated-same-variance",
+            format!(
+                "Do samples of {} and {} values have equal variances?",
+                x_sample.len(),
+                y_sample.len()
+            ),
+            GoldenInput::SameVariance { x_sample, y_sample },
+            *SIGNIFICANCES.choose(rng).unwrap(),
+        );
+        if let Some(generated) = generated {
+            return generated;
+        }
+    }
+}
+
+fn generate_same_mean(difficulty: Difficulty, rng: &mut dyn RngCore) -> GeneratedProblem {
+    let (variances, ratios, kind): (_, &[f64], _) = match difficulty {
+        Difficulty::Hard => (Variances::Unequal, &[1., 2., 3.], "Welch's"),
+        Difficulty::Easy | Difficulty::Medium => (Variances::Equal, &[1.], "Student's"),
+    };
+
+    loop {
+        let shift = *[0., 0., 1., 1.5].choose(rng).unwrap();
+        let (x_sample, y_sample) = two_samples(difficulty, rng, shift, ratios);
+        let generated = generated(
+            "generated-same-mean",
+            format!(
+                "Do samples of {} and {} values have equal means by {kind} t-test?",
+                x_sample.len(),
+                y_sample.len()
+            ),
+            GoldenInput::SameMean {
+                x_sample,
+                y_sample,
+                variances,
+            },
+            *SIGNIFICANCES.choose(rng).unwrap(),
+        );
+        if let Some(generated) = generated {
+            return generated;
+        }
+    }
+}
+
+/// The problem with its solution, if the solver accepts the drawn input and the statistic is finite.
+fn generated(
+    name: &str,
+    description: String,
+    input: GoldenInput,
+    significance: f64,
+) -> Option<GeneratedProblem> {
+    let solution = solve(&input, significance).ok()?;
+    let expected = solution.decision();
+    if !expected.statistic.is_finite() {
+        return None;
+    }
+
+    Some(GeneratedProblem {
+        problem: GoldenProblem {
+            name: name.to_string(),
+            description,
+            input,
+            significance,
+            expected,
+            tolerance: DEFAULT_TOLERANCE,
+        },
+        solution,
+    })
+}
+
+fn solve(input: &GoldenInput, significance: f64) -> Result<Solution, String> {
+    let normal_distribution = |situation: Box<dyn NDHProblemSituation>| {
+        NormalDistributionHypothesis::new(situation)
+            .and_then(|ndh| ndh.outcome())
+            .map(Solution::NormalDistribution)
+            .map_err(|e| e.to_string())
+    };
+
+    match input {
+        GoldenInput::NDHComplete {
+            empirical_sample,
+            theoretical_sample,
+        } => CompleteNDHProblemSituation::new(empirical_sample, theoretical_sample, significance)
+            .map_err(|e| e.to_string())
+            .and_then(|situation| normal_distribution(Box::new(situation))),
+        GoldenInput::NDHGrouped {
+            random_value_ranges,
+            frequencies,
+        } => IncompleteNDHProblemSituation::new(random_value_ranges, frequencies, significance)
+            .map_err(|e| e.to_string())
+            .and_then(|situation| normal_distribution(Box::new(situation))),
+        GoldenInput::SameVariance { x_sample, y_sample } => {
+            SameVarianceHypothesis::new(x_sample, y_sample, significance)
+                .outcome()
+                .map(Solution::SameVariance)
+                .map_err(|e| e.to_string())
+        }
+        GoldenInput::SameMean {
+            x_sample,
+            y_sample,
+            variances,
+        } => {
+            let outcome = SameMeanHypothesis::new(x_sample, y_sample, *variances, significance)
+                .and_then(|smh| smh.outcome())
+                .map_err(|e| e.to_string())?;
+
+            Ok(Solution::SameMean {
+                means: (mean(x_sample), mean(y_sample)),
+                variances: (unbiased_variance(x_sample), unbiased_variance(y_sample)),
+                t_observed: outcome.t_observed,
+                freedom_degrees: outcome.freedom_degrees,
+                t_critical_value: outcome.t_critical_value,
+                hypotheses: outcome.hypotheses,
+                accepted: outcome.accepted,
+            })
+        }
+        GoldenInput::Independence { table } => IndependenceHypothesis::new(table, significance)
+            .and_then(|independence| independence.outcome())
+            .map(Solution::Independence)
+            .map_err(|e| e.to_string()),
+    }
+}
+
+/// The description, the data and the significance of the problem.
+fn question(problem: &GoldenProblem) -> String {
+    let list = |values: &[f64]| {
+        values
+            .iter()
+            .map(f64::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let mut question = String::new();
+    if !problem.description.is_empty() {
+        writeln!(question, "{}", problem.description).unwrap();
+    }
+    match &problem.input {
+        GoldenInput::NDHComplete {
+            empirical_sample,
+            theoretical_sample,
+        } => {
+            writeln!(
+                question,
+                "Empirical frequencies: {}",
+                list(empirical_sample)
+            )
+            .unwrap();
+            writeln!(
+                question,
+                "Theoretical frequencies: {}",
+                list(theoretical_sample)
+            )
+            .unwrap();
+        }
+        GoldenInput::NDHGrouped {
+            random_value_ranges,
+            frequencies,
+        } => {
+            let ranges: Vec<_> = random_value_ranges
+                .iter()
+                .map(|(x_1, x_2)| format!("[{x_1}; {x_2})"))
+                .collect();
+            writeln!(question, "Intervals: {}", ranges.join(", ")).unwrap();
+            writeln!(question, "Frequencies: {}", list(frequencies)).unwrap();
+        }
+        GoldenInput::SameVariance { x_sample, y_sample }
+        | GoldenInput::SameMean {
+            x_sample, y_sample, ..
+        } => {
+            writeln!(question, "x: {}", list(x_sample)).unwrap();
+            writeln!(question, "y: {}", list(y_sample)).unwrap();
+        }
+        GoldenInput::Independence { table } => {
+            for row in table {
+                writeln!(question, "{}", list(row)).unwrap();
+            }
+        }
+    }
+    write!(question, "α = {}", problem.significance).unwrap();
+
+    question
+}
+
+/// `value` as a JSON string literal.
+fn json_string(value: &str) -> String {
+    let mut literal = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_control() => write!(literal, "\\u{:04x}", c as u32).unwrap(),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+
+    literal
+}
+
+/// Two normal samples around a round mean with a round standard deviation, the mean of **Y** shifted
+/// by `shift` standard deviations and its standard deviation multiplied by one of the `ratios`.
+fn two_samples(
+    difficulty: Difficulty,
+    rng: &mut dyn RngCore,
+    shift: f64,
+    ratios: &[f64],
+) -> (Vec<f64>, Vec<f64>) {
+    let std_dev = *[2., 5., 10.].choose(rng).unwrap();
+    let center = 10. * std_dev * rng.gen_range(2..=10) as f64;
+    let ratio = *ratios.choose(rng).unwrap();
+    let decimals = difficulty.decimals();
+
+    let mut draw = |mean: f64, std_dev: f64| {
+        let size = rng.gen_range(difficulty.sample_sizes());
+        let normal = Normal::new(mean, std_dev).unwrap();
+        (0..size)
+            .map(|_| round(rng.sample(normal), decimals))
+            .collect::<Vec<_>>()
+    };
+    let x_sample = draw(center, std_dev);
+    let y_sample = draw(center + shift * std_dev, std_dev * ratio);
+
+    (x_sample, y_sample)
+}
@@ -0,0 +1,431 @@
+//! Golden collection of classic textbook problems with their expected statistics, critical values and decisions,
+//! so that numerical regressions in the solvers are caught.
+//!
+//! The collection shipped with the crate is `fixtures/textbook.txt`; collections in the same format
+//! are read with [`parse`] and checked with [`run`], and a problem is written in the format by its `Display`.
+
+use std::collections::HashMap;
+
+use statistics_problems_core::decisions::Decision;
+
+use crate::contingency::IndependenceHypothesis;
+use crate::critical_values::{self, CriticalValueSource};
+use crate::normal_distribution_hypothesis::{
+    CompleteNDHProblemSituation, IncompleteNDHProblemSituation, NDHProblemSituation,
+    NormalDistributionHypothesis,
+};
+use crate::same_mean_hypothesis::{SameMeanHypothesis, Variances};
+use crate::same_variance_hypothesis::SameVarianceHypothesis;
+
+/// The collection shipped with the crate.
+pub const TEXTBOOK_PROBLEMS: &str = include_str!("../fixtures/textbook.txt");
+
+pub(crate) const DEFAULT_TOLERANCE: f64 = 0.001;
+
+/// Errors carry the number of the offending line, counted from 1.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum GoldenError {
+    LineInvalid(usize),
+    ValueInvalid(usize),
+    KindUnknown(usize),
+    /// The line is the header of the problem missing the key.
+    KeyMissing(usize),
+}
+
+impl std::fmt::Display for GoldenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            GoldenError::LineInvalid(line) => {
+                write!(
+                    f,
+                    "Line {line} is neither `[name]`, `key = value` nor a comment"
+                )
+            }
+            GoldenError::ValueInvalid(line) => {
+                write!(f, "Line {line} holds a value that cannot be parsed")
+            }
+            GoldenError::KindUnknown(line) => {
+                write!(f, "Line {line} names an unknown kind of problem")
+            }
+            GoldenError::KeyMissing(line) => {
+                write!(f, "Problem starting at line {line} misses a required key")
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum GoldenInput {
+    NDHComplete {
+        empirical_sample: Vec<f64>,
+        theoretical_sample: Vec<f64>,
+    },
+    NDHGrouped {
+        random_value_ranges: Vec<(f64, f64)>,
+        frequencies: Vec<f64>,
+    },
+    SameVariance {
+        x_sample: Vec<f64>,
+        y_sample: Vec<f64>,
+    },
+    SameMean {
+        x_sample: Vec<f64>,
+        y_sample: Vec<f64>,
+        variances: Variances,
+    },
+    Independence {
+        table: Vec<Vec<f64>>,
+    },
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct GoldenProblem {
+    pub name: String,
+    pub description: String,
+    pub input: GoldenInput,
+    pub significance: f64,
+    pub expected: Decision,
+    /// Largest accepted absolute difference of the statistic and of the critical value.
+    pub tolerance: f64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct GoldenOutcome {
+    pub name: String,
+    pub expected: Decision,
+    /// The solved problem, or the message of the solver's error.
+    pub actual: Result<Decision, String>,
+    pub passed: bool,
+}
+
+impl GoldenProblem {
+    pub fn solve(&self) -> Result<Decision, String> {
+        match &self.input {
+            GoldenInput::NDHComplete {
+                empirical_sample,
+                theoretical_sample,
+            } => CompleteNDHProblemSituation::new(
+                empirical_sample,
+                theoretical_sample,
+                self.significance,
+            )
+            .map_err(|e| e.to_string())
+            .and_then(|situation| solve_ndh(Box::new(situation))),
+            GoldenInput::NDHGrouped {
+                random_value_ranges,
+                frequencies,
+            } => IncompleteNDHProblemSituation::new(
+                random_value_ranges,
+                frequencies,
+                self.significance,
+            )
+            .map_err(|e| e.to_string())
+            .and_then(|situation| solve_ndh(Box::new(situation))),
+            GoldenInput::SameVariance { x_sample, y_sample } => {
+                let outcome = SameVarianceHypothesis::new(x_sample, y_sample, self.significance)
+                    .outcome()
+                    .map_err(|e| e.to_string())?;
+                Ok(Decision {
+                    statistic: outcome.fisher_snedecor_observed,
+                    critical_value: outcome.fisher_snedecor_critical_value,
+                    accepted: outcome.accepted,
+                })
+            }
+            GoldenInput::SameMean {
+                x_sample,
+                y_sample,
+                variances,
+            } => {
+                let smh =
+                    SameMeanHypothesis::new(x_sample, y_sample, *variances, self.significance)
+                        .map_err(|e| e.to_string())?;
+                let (t, freedom_degrees) = smh.statistic();
+                let critical_value = critical_values::students_t_two_sided(
+                    freedom_degrees,
+                    self.significance,
+                    CriticalValueSource::Quantile,
+                )
+                .map_err(|e| e.to_string())?;
+                Ok(Decision {
+                    statistic: t,
+                    critical_value,
+                    accepted: smh.solve().map_err(|e| e.to_string())?,
+                })
+            }
+            GoldenInput::Independence { table } => {
+                let outcome = IndependenceHypothesis::new(table, self.significance)
+                    .and_then(|independence| independence.outcome())
+                    .map_err(|e| e.to_string())?;
+                Ok(Decision {
+                    statistic: outcome.chi_squared_observed,
+                    critical_value: outcome.chi_squared_critical_value,
+                    accepted: outcome.accepted,
+                })
+            }
+        }
+    }
+
+    /// Solves the problem and compares the result with the expected one.
+    pub fn check(&self) -> GoldenOutcome {
+        let actual = self.solve();
+        let passed = actual.as_ref().is_ok_and(|actual| {
+            (actual.statistic - self.expected.statistic).abs() <= self.tolerance
+                && (actual.critical_value - self.expected.critical_value).abs() <= self.tolerance
+                && actual.accepted == self.expected.accepted
+        });
+
+        GoldenOutcome {
+            name: self.name.clone(),
+            expected: self.expected,
+            actual,
+            passed,
+        }
+    }
+}
+
+/// The problem in the format of `fixtures/textbook.txt`, with the statistic and the critical value
+/// to 4 decimals.
+impl std::fmt::Display for GoldenProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let list = |values: &[f64]| {
+            values
+                .iter()
+                .map(f64::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        writeln!(f, "[{}]", self.name)?;
+        if !self.description.is_empty() {
+            writeln!(f, "description = {}", self.description)?;
+        }
+        match &self.input {
+            GoldenInput::NDHComplete {
+                empirical_sample,
+                theoretical_sample,
+            } => {
+                writeln!(f, "kind = ndh-complete")?;
+                writeln!(f, "empirical = {}", list(empirical_sample))?;
+                writeln!(f, "theoretical = {}", list(theoretical_sample))?;
+            }
+            GoldenInput::NDHGrouped {
+                random_value_ranges,
+                frequencies,
+            } => {
+                let ranges: Vec<_> = random_value_ranges
+                    .iter()
+                    .map(|(start, end)| format!("{start} {end}"))
+                    .collect();
+                writeln!(f, "kind = ndh-grouped")?;
+                writeln!(f, "ranges = {}", ranges.join("; "))?;
+                writeln!(f, "frequencies = {}", list(frequencies))?;
+            }
+            GoldenInput::SameVariance { x_sample, y_sample } => {
+                writeln!(f, "kind = same-variance")?;
+                writeln!(f, "x = {}", list(x_sample))?;
+                writeln!(f, "y = {}", list(y_sample))?;
+            }
+            GoldenInput::SameMean {
+                x_sample,
+                y_sample,
+                variances,
+            } => {
+                let kind = match variances {
+                    Variances::Equal => "student-t",
+                    Variances::Unequal => "welch-t",
+                };
+                writeln!(f, "kind = {kind}")?;
+                writeln!(f, "x = {}", list(x_sample))?;
+                writeln!(f, "y = {}", list(y_sample))?;
+            }
+            GoldenInput::Independence { table } => {
+                let rows: Vec<_> = table.iter().map(|row| list(row)).collect();
+                writeln!(f, "kind = independence")?;
+                writeln!(f, "table = {}", rows.join("; "))?;
+            }
+        }
+        writeln!(f, "alpha = {}", self.significance)?;
+        writeln!(f, "statistic = {:.4}", self.expected.statistic)?;
+        writeln!(f, "critical_value = {:.4}", self.expected.critical_value)?;
+        writeln!(f, "accepted = {}", self.expected.accepted)?;
+        if self.tolerance != DEFAULT_TOLERANCE {
+            writeln!(f, "tolerance = {}", self.tolerance)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn solve_ndh(situation: Box<dyn NDHProblemSituation>) -> Result<Decision, String> {
+    let outcome = NormalDistributionHypothesis::new(situation)
+        .and_then(|ndh| ndh.outcome())
+        .map_err(|e| e.to_string())?;
+
+    Ok(Decision {
+        statistic: outcome.chi_squared_observed,
+        critical_value: outcome.chi_squared_critical_value,
+        accepted: outcome.accepted,
+    })
+}
+
+/// Checks every problem of the collection.
+pub fn run(problems: &[GoldenProblem]) -> Vec<GoldenOutcome> {
+    problems.iter().map(GoldenProblem::check).collect()
+}
+
+/// The collection shipped with the crate.
+pub fn textbook_problems() -> Result<Vec<GoldenProblem>, GoldenError> {
+    parse(TEXTBOOK_PROBLEMS)
+}
+
+/// Reads a collection in the format of `fixtures/textbook.txt`.
+pub fn parse(text: &str) -> Result<Vec<GoldenProblem>, GoldenError> {
+    let mut problems = Vec::new();
+    let mut section: Option<Section> = None;
+
+    for (i, line) in text.lines().enumerate() {
+        let (number, line) = (i + 1, line.trim());
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = line
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            if let Some(section) = section.take() {
+                problems.push(section.into_problem()?);
+            }
+            section = Some(Section {
+                name: name.trim().to_string(),
+                line: number,
+                values: HashMap::new(),
+            });
+            continue;
+        }
+
+        let (Some(section), Some((key, value))) = (section.as_mut(), line.split_once('=')) else {
+            return Err(GoldenError::LineInvalid(number));
+        };
+        section
+            .values
+            .insert(key.trim().to_string(), (value.trim().to_string(), number));
+    }
+
+    if let Some(section) = section {
+        problems.push(section.into_problem()?);
+    }
+
+    Ok(problems)
+}
+
+/// Lines of one problem by key, with their line numbers.
+struct Section {
+    name: String,
+    line: usize,
+    values: HashMap<String, (String, usize)>,
+}
+
+impl Section {
+    fn get(&self, key: &str) -> Result<(&str, usize), GoldenError> {
+        self.values
+            .get(key)
+            .map(|(value, line)| (value.as_str(), *line))
+            .ok_or(GoldenError::KeyMissing(self.line))
+    }
+
+    fn number(&self, key: &str) -> Result<f64, GoldenError> {
+        let (value, line) = self.get(key)?;
+        value.parse().map_err(|_| GoldenError::ValueInvalid(line))
+    }
+
+    fn list(&self, key: &str) -> Result<Vec<f64>, GoldenError> {
+        let (value, line) = self.get(key)?;
+        parse_list(value, ',').ok_or(GoldenError::ValueInvalid(line))
+    }
+
+    fn rows(&self, key: &str) -> Result<Vec<Vec<f64>>, GoldenError> {
+        let (value, line) = self.get(key)?;
+        value
+            .split(';')
+            .map(|row| parse_list(row, ','))
+            .collect::<Option<_>>()
+            .ok_or(GoldenError::ValueInvalid(line))
+    }
+
+    fn into_problem(self) -> Result<GoldenProblem, GoldenError> {
+        let (kind, kind_line) = self.get("kind")?;
+        let input = match kind {
+            "ndh-complete" => GoldenInput::NDHComplete {
+                empirical_sample: self.list("empirical")?,
+                theoretical_sample: self.list("theoretical")?,
+            },
+            "ndh-grouped" => {
+                let (value, line) = self.get("ranges")?;
+                let random_value_ranges = value
+                    .split(';')
+                    .map(|range| match parse_list(range, ' ')?[..] {
+                        [start, end] => Some((start, end)),
+                        _ => None,
+                    })
+                    .collect::<Option<_>>()
+                    .ok_or(GoldenError::ValueInvalid(line))?;
+                GoldenInput::NDHGrouped {
+                    random_value_ranges,
+                    frequencies: self.list("frequencies")?,
+                }
+            }
+            "same-variance" => GoldenInput::SameVariance {
+                x_sample: self.list("x")?,
+                y_sample: self.list("y")?,
+            },
+            "student-t" | "welch-t" => GoldenInput::SameMean {
+                x_sample: self.list("x")?,
+                y_sample: self.list("y")?,
+                variances: if kind == "student-t" {
+                    Variances::Equal
+                } else {
+                    Variances::Unequal
+                },
+            },
+            "independence" => GoldenInput::Independence {
+                table: self.rows("table")?,
+            },
+            _ => return Err(GoldenError::KindUnknown(kind_line)),
+        };
+
+        let (accepted, accepted_line) = self.get("accepted")?;
+        let accepted = accepted
+            .parse()
+            .map_err(|_| GoldenError::ValueInvalid(accepted_line))?;
+        let tolerance = if self.values.contains_key("tolerance") {
+            self.number("tolerance")?
+        } else {
+            DEFAULT_TOLERANCE
+        };
+
+        Ok(GoldenProblem {
+            description: self
+                .get("description")
+                .map_or_else(|_| String::new(), |(value, _)| value.to_string()),
+            input,
+            significance: self.number("alpha")?,
+            expected: Decision {
+                statistic: self.number("statistic")?,
+                critical_value: self.number("critical_value")?,
+                accepted,
+            },
+            tolerance,
+            name: self.name,
+        })
+    }
+}
+
+fn parse_list(value: &str, separator: char) -> Option<Vec<f64>> {
+    value
+        .split(separator)
+        .filter(|item| !item.trim().is_empty())
+        .map(|item| item.trim().parse().ok())
+        .collect()
+}
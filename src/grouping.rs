@@ -0,0 +1,110 @@
+//! Splitting of labeled data, values with their group labels, into the samples of the groups
+//! that the `k`-sample tests take, such as [`OneWayAnova`](crate::anova::OneWayAnova).
+//!
+//! The groups keep the order of the first appearance of their labels, the order of the given levels,
+//! or the ascending order of the labels, so that reports list them the same way on every run.
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum GroupingError {
+    LengthsMismatch,
+    LabelUnknown,
+    GroupEmpty,
+}
+
+impl std::fmt::Display for GroupingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            GroupingError::LengthsMismatch => {
+                write!(f, "Each value must have exactly one group label")
+            }
+            GroupingError::LabelUnknown => {
+                write!(f, "Label is not one of the given levels")
+            }
+            GroupingError::GroupEmpty => {
+                write!(f, "Each group must contain at least one value")
+            }
+        }
+    }
+}
+
+/// Samples of the groups along with their labels.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Groups<L> {
+    pub labels: Vec<L>,
+    pub samples: Vec<Vec<f64>>,
+}
+
+impl<L: Clone + PartialEq> Groups<L> {
+    /// Groups in the order of the first appearance of their labels.
+    pub fn new(values: &[f64], labels: &[L]) -> Result<Self, GroupingError> {
+        if values.len() != labels.len() {
+            return Err(GroupingError::LengthsMismatch);
+        }
+
+        let mut groups = Self {
+            labels: Vec::new(),
+            samples: Vec::new(),
+        };
+        for (value, label) in values.iter().zip(labels) {
+            match groups.labels.iter().position(|group| group == label) {
+                Some(i) => groups.samples[i].push(*value),
+                None => {
+                    groups.labels.push(label.clone());
+                    groups.samples.push(vec![*value]);
+                }
+            }
+        }
+
+        Ok(groups)
+    }
+
+    /// Groups in the order of `levels`, every one of which must label at least one value.
+    pub fn with_levels(values: &[f64], labels: &[L], levels: &[L]) -> Result<Self, GroupingError> {
+        if values.len() != labels.len() {
+            return Err(GroupingError::LengthsMismatch);
+        }
+
+        let mut samples = vec![Vec::new(); levels.len()];
+        for (value, label) in values.iter().zip(labels) {
+            let i = levels
+                .iter()
+                .position(|level| level == label)
+                .ok_or(GroupingError::LabelUnknown)?;
+            samples[i].push(*value);
+        }
+
+        if samples.iter().any(Vec::is_empty) {
+            return Err(GroupingError::GroupEmpty);
+        }
+
+        Ok(Self {
+            labels: levels.to_owned(),
+            samples,
+        })
+    }
+
+    /// Sample of the group with the label.
+    pub fn group(&self, label: &L) -> Option<&[f64]> {
+        self.labels
+            .iter()
+            .position(|group| group == label)
+            .map(|i| self.samples[i].as_slice())
+    }
+
+    pub fn sizes(&self) -> Vec<usize> {
+        self.samples.iter().map(Vec::len).collect()
+    }
+}
+
+impl<L: Clone + Ord> Groups<L> {
+    /// The same groups in the ascending order of their labels.
+    pub fn sorted(self) -> Self {
+        let mut groups: Vec<_> = self.labels.into_iter().zip(self.samples).collect();
+        groups.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let (labels, samples) = groups.into_iter().unzip();
+
+        Self { labels, samples }
+    }
+}
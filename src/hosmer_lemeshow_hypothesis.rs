@@ -0,0 +1,250 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *probabilities predicted* by a model of a binary outcome, the *observed outcomes*.
+//! **To figure out**: Is it appropriate to **assume** that the model is calibrated, that its probabilities fit the outcomes?
+//!
+//! The Hosmer–Lemeshow test sorts the observations by the predicted probability, splits them into `g` groups
+//! of equal size, usually the deciles of risk, and compares the observed and expected numbers of positive outcomes
+//! by `Ĉ = Σ (O_g - E_g)² / (E_g (1 - E_g / n_g))`, approximately Chi-Squared with `g - 2` freedom degrees.
+//! The Pearson and deviance residuals of the single observations point out the poorly fitted ones.
+
+use statrs::distribution::{ChiSquared, ContinuousCDF};
+
+use crate::alpha;
+use crate::critical_values::{self, CriticalValueSource};
+use crate::hypotheses::{Alternative, Hypotheses};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum HLError {
+    SignificanceInvalid,
+    LengthsMismatch,
+    ProbabilitiesInvalid,
+    GroupsCountInvalid,
+}
+
+impl std::fmt::Display for HLError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            HLError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            HLError::LengthsMismatch => {
+                write!(
+                    f,
+                    "Each predicted probability must have exactly one outcome"
+                )
+            }
+            HLError::ProbabilitiesInvalid => {
+                write!(
+                    f,
+                    "Predicted probabilities must lie strictly between 0.0 and 1.0"
+                )
+            }
+            HLError::GroupsCountInvalid => {
+                write!(
+                    f,
+                    "Groups count must be at least 3 and at most the number of observations"
+                )
+            }
+        }
+    }
+}
+
+/// Group of observations with similar predicted probabilities.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RiskGroup {
+    pub size: usize,
+    /// The largest predicted probability of the group.
+    pub upper_probability: f64,
+    /// Number of positive outcomes.
+    pub observed: f64,
+    /// Sum of the predicted probabilities.
+    pub expected: f64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HLOutcome {
+    pub groups: Vec<RiskGroup>,
+    pub freedom_degrees: f64,
+    pub chi_squared_observed: f64,
+    pub chi_squared_critical_value: f64,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+/// Residuals of the single observations of a binary outcome.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BinaryResiduals {
+    /// `(yᵢ - pᵢ) / √(pᵢ (1 - pᵢ))`.
+    pub pearson: Vec<f64>,
+    /// `sign(yᵢ - pᵢ) √(-2 (yᵢ ln pᵢ + (1 - yᵢ) ln(1 - pᵢ)))`.
+    pub deviance: Vec<f64>,
+    /// Sum of the squared Pearson residuals.
+    pub pearson_chi_squared: f64,
+    /// Sum of the squared deviance residuals, the residual deviance of the model.
+    pub deviance_sum: f64,
+    /// Indices of the observations whose deviance residual exceeds `±2`.
+    pub poorly_fitted: Vec<usize>,
+}
+
+/// The deciles of risk.
+const DEFAULT_GROUPS_COUNT: usize = 10;
+
+/// Deviance residuals beyond this bound mark poorly fitted observations.
+const DEVIANCE_RESIDUAL_THRESHOLD: f64 = 2.;
+
+pub struct HosmerLemeshowHypothesis {
+    probabilities: Vec<f64>,
+    outcomes: Vec<bool>,
+    groups_count: usize,
+    significance: f64,
+}
+
+impl HosmerLemeshowHypothesis {
+    /// `outcomes[i]` is `true` for a positive outcome; the observations are split into deciles
+    /// unless the groups count is chosen with [`HosmerLemeshowHypothesis::with_groups_count`].
+    pub fn new(
+        probabilities: &[f64],
+        outcomes: &[bool],
+        significance: f64,
+    ) -> Result<Self, HLError> {
+        validate(probabilities, outcomes)?;
+
+        alpha::validate(significance, HLError::SignificanceInvalid)?;
+
+        Ok(Self {
+            probabilities: probabilities.to_owned(),
+            outcomes: outcomes.to_owned(),
+            groups_count: DEFAULT_GROUPS_COUNT,
+            significance,
+        })
+    }
+
+    pub fn with_groups_count(mut self, groups_count: usize) -> Self {
+        self.groups_count = groups_count;
+        self
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            "the predicted probabilities fit the outcomes",
+            "the predicted probabilities do not fit the outcomes",
+            Alternative::Greater,
+        )
+    }
+
+    pub fn solve(&self) -> Result<bool, HLError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<HLOutcome, HLError> {
+        let size = self.probabilities.len();
+        if self.groups_count < 3 || self.groups_count > size {
+            return Err(HLError::GroupsCountInvalid);
+        }
+
+        let mut order: Vec<_> = (0..size).collect();
+        order.sort_by(|&i, &j| self.probabilities[i].total_cmp(&self.probabilities[j]));
+
+        let groups: Vec<_> = (0..self.groups_count)
+            .map(|g| {
+                let members =
+                    &order[g * size / self.groups_count..(g + 1) * size / self.groups_count];
+                RiskGroup {
+                    size: members.len(),
+                    upper_probability: self.probabilities[members[members.len() - 1]],
+                    observed: members.iter().filter(|i| self.outcomes[**i]).count() as f64,
+                    expected: members.iter().map(|i| self.probabilities[*i]).sum(),
+                }
+            })
+            .collect();
+
+        let chi_squared_observed = groups
+            .iter()
+            .map(|group| {
+                (group.observed - group.expected).powi(2)
+                    / (group.expected * (1. - group.expected / group.size as f64))
+            })
+            .sum::<f64>();
+        let freedom_degrees = self.groups_count as f64 - 2.;
+        let chi_squared_critical_value = critical_values::chi_squared(
+            freedom_degrees,
+            self.significance,
+            CriticalValueSource::Quantile,
+        )
+        .map_err(|_| HLError::GroupsCountInvalid)?;
+        let p_value = ChiSquared::new(freedom_degrees)
+            .map_err(|_| HLError::GroupsCountInvalid)?
+            .sf(chi_squared_observed);
+
+        Ok(HLOutcome {
+            groups,
+            freedom_degrees,
+            chi_squared_observed,
+            chi_squared_critical_value,
+            p_value,
+            hypotheses: self.hypotheses(),
+            accepted: chi_squared_observed < chi_squared_critical_value,
+        })
+    }
+
+    pub fn residuals(&self) -> BinaryResiduals {
+        // The inputs were validated on construction.
+        binary_residuals(&self.probabilities, &self.outcomes).unwrap()
+    }
+}
+
+/// Pearson and deviance residuals of the predicted probabilities of binary outcomes.
+pub fn binary_residuals(
+    probabilities: &[f64],
+    outcomes: &[bool],
+) -> Result<BinaryResiduals, HLError> {
+    validate(probabilities, outcomes)?;
+
+    let pearson: Vec<_> = probabilities
+        .iter()
+        .zip(outcomes)
+        .map(|(p, y)| (f64::from(u8::from(*y)) - p) / (p * (1. - p)).sqrt())
+        .collect();
+    let deviance: Vec<_> = probabilities
+        .iter()
+        .zip(outcomes)
+        .map(|(p, y)| {
+            if *y {
+                (-2. * p.ln()).sqrt()
+            } else {
+                -(-2. * (1. - p).ln()).sqrt()
+            }
+        })
+        .collect();
+
+    Ok(BinaryResiduals {
+        pearson_chi_squared: pearson.iter().map(|r| r * r).sum(),
+        deviance_sum: deviance.iter().map(|d| d * d).sum(),
+        poorly_fitted: deviance
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| d.abs() > DEVIANCE_RESIDUAL_THRESHOLD)
+            .map(|(i, _)| i)
+            .collect(),
+        pearson,
+        deviance,
+    })
+}
+
+fn validate(probabilities: &[f64], outcomes: &[bool]) -> Result<(), HLError> {
+    if probabilities.len() != outcomes.len() {
+        return Err(HLError::LengthsMismatch);
+    }
+
+    if !probabilities.iter().all(|p| *p > 0. && *p < 1.) {
+        return Err(HLError::ProbabilitiesInvalid);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//! Formal statements of the null and alternative hypotheses with the parameters of the problem filled in.
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Alternative {
+    /// The critical region lies in both tails.
+    TwoSided,
+    /// The critical region lies in the upper tail.
+    Greater,
+    /// The critical region lies in the lower tail.
+    Less,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Hypotheses {
+    /// `H₀`, which is accepted or rejected.
+    pub null: String,
+    /// `H₁`, which is claimed when `H₀` is rejected.
+    pub alternative: String,
+    pub sidedness: Alternative,
+}
+
+impl Hypotheses {
+    pub fn new(
+        null: impl Into<String>,
+        alternative: impl Into<String>,
+        sidedness: Alternative,
+    ) -> Self {
+        Self {
+            null: null.into(),
+            alternative: alternative.into(),
+            sidedness,
+        }
+    }
+
+    /// Decision text naming the hypothesis that is kept.
+    pub fn decision(&self, accepted: bool) -> String {
+        if accepted {
+            format!("H₀ is accepted: {}", self.null)
+        } else {
+            format!("H₀ is rejected in favour of H₁: {}", self.alternative)
+        }
+    }
+}
+
+impl std::fmt::Display for Hypotheses {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "H₀: {}\nH₁: {}", self.null, self.alternative)
+    }
+}
@@ -0,0 +1,85 @@
+//! Solving algorithms for some types of simple statistics problems.
+
+pub mod agreement;
+pub mod alpha;
+pub mod anova;
+pub mod answers;
+#[cfg(feature = "arrow")]
+pub mod arrow_interop;
+pub mod bayes;
+pub mod box_plot;
+pub mod clt_demo;
+pub mod combinatorics;
+pub mod confidence;
+pub mod contingency;
+pub mod cramer_von_mises_hypothesis;
+pub mod critical_region;
+pub mod critical_values;
+pub mod cross_validation;
+pub mod descriptive;
+pub mod energy_hypothesis;
+pub mod equivalence_hypothesis;
+pub mod fairness;
+#[cfg(feature = "cdylib")]
+pub mod ffi;
+pub mod fit;
+pub mod generator;
+pub mod golden;
+pub mod grouping;
+pub mod hosmer_lemeshow_hypothesis;
+pub mod hypotheses;
+pub mod lifetime;
+pub mod mann_whitney_hypothesis;
+pub mod method_comparison;
+pub mod missing;
+pub mod model_selection;
+pub mod moment_hypothesis;
+pub mod multivariate;
+#[cfg(feature = "ndarray")]
+pub mod ndarray_interop;
+pub mod nonlinear_regression;
+pub mod normal_distribution_hypothesis;
+pub mod numeric;
+pub mod order_statistics;
+pub mod pca;
+pub mod pipeline;
+#[cfg(feature = "plot")]
+pub mod plot;
+pub mod poisson_rate_hypothesis;
+#[cfg(feature = "polars")]
+pub mod polars_interop;
+pub mod ppcc_hypothesis;
+#[cfg(feature = "precise")]
+pub mod precise;
+pub mod probability;
+pub mod proportion_hypothesis;
+#[cfg(feature = "pyo3")]
+pub mod python;
+pub mod quality_control;
+pub mod random_variable;
+pub mod recommend;
+pub mod regression;
+pub mod reliability;
+pub mod report;
+pub mod residuals;
+pub mod rng;
+pub mod roc;
+pub mod rounding;
+pub mod same_mean_hypothesis;
+pub mod same_variance_hypothesis;
+pub mod sampling;
+#[cfg(feature = "serve")]
+pub mod serve;
+pub mod shapiro_wilk_hypothesis;
+pub mod sign_hypothesis;
+pub mod special;
+pub mod split;
+pub mod sprt;
+pub mod tables;
+pub mod terminal_chart;
+pub mod time_series;
+pub mod transform;
+pub mod variance_value_hypothesis;
+pub mod verification;
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
@@ -0,0 +1,284 @@
+//! Algorithms for solving problems of the following kinds.
+//!
+//! -  **Given**: *lifetimes* of items, some of them *censored*: the item was still working when observed last.
+//!    **To figure out**: What are the parameters of the exponential or the Weibull lifetime distribution,
+//!    the reliability `R(t) = P(T > t)` and the mean lifetime?
+//!
+//! -  **Given**: *significance ratio*, *lifetimes*, possibly censored.
+//!    **To figure out**: Is it appropriate to **assume** that the lifetimes are exponential, that is that
+//!    the items do not age, against the Weibull alternative?
+//!
+//! The failures contribute their densities to the likelihood and the censored items their reliabilities.
+//! The exponential rate is `λ̂ = r / Σ tᵢ` with `r` failures; the Weibull shape solves
+//! `Σ tᵢᵏ ln tᵢ / Σ tᵢᵏ - 1/k = 1/r Σ_failed ln tᵢ` and the scale is `λ̂ = (Σ tᵢᵏ / r)^(1/k)`.
+//! Standard errors are asymptotic, from the observed information. The exponential distribution is
+//! the Weibull one with `k = 1`, tested by the likelihood ratio `2 (ln L_W - ln L_E)`, approximately
+//! Chi-Squared with 1 freedom degree.
+
+use statrs::distribution::{ChiSquared, ContinuousCDF};
+use statrs::function::gamma::ln_gamma;
+
+use crate::alpha;
+use crate::critical_values::{self, CriticalValueSource};
+use crate::fit::{Estimate, Family, Fit, Method, bisect_log};
+use crate::hypotheses::{Alternative, Hypotheses};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum LifetimeError {
+    SignificanceInvalid,
+    LengthsMismatch,
+    SupportInvalid,
+    FailuresTooFew,
+    SampleDegenerate,
+}
+
+impl std::fmt::Display for LifetimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LifetimeError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            LifetimeError::LengthsMismatch => {
+                write!(f, "Every lifetime must be marked as a failure or censored")
+            }
+            LifetimeError::SupportInvalid => {
+                write!(f, "Lifetimes must be positive")
+            }
+            LifetimeError::FailuresTooFew => {
+                write!(
+                    f,
+                    "At least one failure is required for the exponential model and two for the Weibull one"
+                )
+            }
+            LifetimeError::SampleDegenerate => {
+                write!(f, "Lifetimes must not all be equal")
+            }
+        }
+    }
+}
+
+/// Lifetime distribution fitted by maximum likelihood.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LifetimeFit {
+    /// [`Family::Exponential`] or [`Family::Weibull`], with [`Fit::sample_size`] counting the censored items too.
+    pub fit: Fit,
+    /// Number `r` of the items that failed.
+    pub failures: usize,
+    /// `ln L` of the failures and the censored items.
+    pub log_likelihood: f64,
+}
+
+impl LifetimeFit {
+    /// `R(t) = P(T > t)`.
+    pub fn reliability(&self, t: f64) -> f64 {
+        1. - self.fit.cdf(t)
+    }
+
+    /// `h(t) = f(t) / R(t)`, constant for the exponential distribution.
+    pub fn hazard(&self, t: f64) -> f64 {
+        let p = |i: usize| self.fit.parameters[i].value;
+        match self.fit.family {
+            Family::Weibull => p(0) / p(1) * (t / p(1)).powf(p(0) - 1.),
+            _ => p(0),
+        }
+    }
+
+    /// `E(T)`, the mean time to failure.
+    pub fn mean_lifetime(&self) -> f64 {
+        let p = |i: usize| self.fit.parameters[i].value;
+        match self.fit.family {
+            Family::Weibull => p(1) * ln_gamma(1. + 1. / p(0)).exp(),
+            _ => 1. / p(0),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ExponentialityOutcome {
+    pub exponential: LifetimeFit,
+    pub weibull: LifetimeFit,
+    /// `2 (ln L_W - ln L_E)`.
+    pub chi_squared_observed: f64,
+    pub chi_squared_critical_value: f64,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+/// Fits `Exp(λ)` to the lifetimes; `failures[i]` is `false` if the `i`-th item was censored.
+pub fn exponential(times: &[f64], failures: &[bool]) -> Result<LifetimeFit, LifetimeError> {
+    let failures_count = validate(times, failures, 1)?;
+    let r = failures_count as f64;
+
+    let rate = r / times.iter().sum::<f64>();
+
+    Ok(LifetimeFit {
+        fit: Fit {
+            family: Family::Exponential,
+            method: Method::MaximumLikelihood,
+            sample_size: times.len() as f64,
+            parameters: vec![Estimate {
+                name: "λ",
+                value: rate,
+                standard_error: rate / r.sqrt(),
+            }],
+        },
+        failures: failures_count,
+        log_likelihood: r * rate.ln() - r,
+    })
+}
+
+/// Fits `Weibull(k, λ)` to the lifetimes; `failures[i]` is `false` if the `i`-th item was censored.
+pub fn weibull(times: &[f64], failures: &[bool]) -> Result<LifetimeFit, LifetimeError> {
+    let failures_count = validate(times, failures, 2)?;
+    let r = failures_count as f64;
+
+    let largest = times.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if times.iter().all(|t| *t == largest) {
+        return Err(LifetimeError::SampleDegenerate);
+    }
+
+    // The shape does not depend on the unit of time; scaling by the largest lifetime keeps the powers finite.
+    let logarithms: Vec<_> = times.iter().map(|t| (t / largest).ln()).collect();
+    let failed_logarithms_sum = logarithms
+        .iter()
+        .zip(failures)
+        .filter(|(_, failed)| **failed)
+        .map(|(l, _)| l)
+        .sum::<f64>();
+    let score = |k: f64| {
+        let powers: Vec<_> = logarithms.iter().map(|l| (k * l).exp()).collect();
+        powers
+            .iter()
+            .zip(&logarithms)
+            .map(|(p, l)| p * l)
+            .sum::<f64>()
+            / powers.iter().sum::<f64>()
+            - 1. / k
+            - failed_logarithms_sum / r
+    };
+    let k = bisect_log(score, 1);
+    let scale = largest * (logarithms.iter().map(|l| (k * l).exp()).sum::<f64>() / r).powf(1. / k);
+
+    // Observed information of `(k, λ)` at the maximum, where `Σ zᵢᵏ = r` for `zᵢ = tᵢ / λ`.
+    let (mut powers_sum, mut log_powers_sum, mut squared_log_powers_sum) = (0., 0., 0.);
+    for t in times {
+        let u = (t / scale).ln();
+        let power = (k * u).exp();
+        powers_sum += power;
+        log_powers_sum += power * u;
+        squared_log_powers_sum += power * u * u;
+    }
+    let information_kk = r / (k * k) + squared_log_powers_sum;
+    let information_ll = (k * (k + 1.) * powers_sum - r * k) / (scale * scale);
+    let information_kl = (r - powers_sum - k * log_powers_sum) / scale;
+    let determinant = information_kk * information_ll - information_kl * information_kl;
+
+    let failed_log_times_sum = times
+        .iter()
+        .zip(failures)
+        .filter(|(_, failed)| **failed)
+        .map(|(t, _)| t.ln())
+        .sum::<f64>();
+
+    Ok(LifetimeFit {
+        fit: Fit {
+            family: Family::Weibull,
+            method: Method::MaximumLikelihood,
+            sample_size: times.len() as f64,
+            parameters: vec![
+                Estimate {
+                    name: "k",
+                    value: k,
+                    standard_error: (information_ll / determinant).sqrt(),
+                },
+                Estimate {
+                    name: "λ",
+                    value: scale,
+                    standard_error: (information_kk / determinant).sqrt(),
+                },
+            ],
+        },
+        failures: failures_count,
+        log_likelihood: r * (k.ln() - k * scale.ln()) + (k - 1.) * failed_log_times_sum
+            - powers_sum,
+    })
+}
+
+/// Likelihood ratio test of exponential lifetimes against Weibull ones.
+pub struct ExponentialityHypothesis {
+    times: Vec<f64>,
+    failures: Vec<bool>,
+    significance: f64,
+}
+
+impl ExponentialityHypothesis {
+    pub fn new(times: &[f64], failures: &[bool], significance: f64) -> Result<Self, LifetimeError> {
+        validate(times, failures, 2)?;
+
+        alpha::validate(significance, LifetimeError::SignificanceInvalid)?;
+
+        Ok(Self {
+            times: times.to_owned(),
+            failures: failures.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            "the lifetimes are exponential, k = 1",
+            "the lifetimes are Weibull with k ≠ 1",
+            Alternative::TwoSided,
+        )
+    }
+
+    pub fn solve(&self) -> Result<bool, LifetimeError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<ExponentialityOutcome, LifetimeError> {
+        let exponential = exponential(&self.times, &self.failures)?;
+        let weibull = weibull(&self.times, &self.failures)?;
+
+        // The Weibull likelihood is at least the exponential one up to round-off.
+        let chi_squared_observed =
+            (2. * (weibull.log_likelihood - exponential.log_likelihood)).max(0.);
+        // One freedom degree is always valid.
+        let chi_squared_critical_value =
+            critical_values::chi_squared(1., self.significance, CriticalValueSource::Quantile)
+                .unwrap();
+        let p_value = ChiSquared::new(1.).unwrap().sf(chi_squared_observed);
+
+        Ok(ExponentialityOutcome {
+            exponential,
+            weibull,
+            chi_squared_observed,
+            chi_squared_critical_value,
+            p_value,
+            hypotheses: self.hypotheses(),
+            accepted: chi_squared_observed < chi_squared_critical_value,
+        })
+    }
+}
+
+/// Number of the failures, at least `required`.
+fn validate(times: &[f64], failures: &[bool], required: usize) -> Result<usize, LifetimeError> {
+    if times.len() != failures.len() {
+        return Err(LifetimeError::LengthsMismatch);
+    }
+
+    if !times.iter().all(|t| *t > 0. && t.is_finite()) {
+        return Err(LifetimeError::SupportInvalid);
+    }
+
+    let failures_count = failures.iter().filter(|failed| **failed).count();
+    if failures_count < required {
+        return Err(LifetimeError::FailuresTooFew);
+    }
+
+    Ok(failures_count)
+}
@@ -1,10 +1,62 @@
-mod normal_distribution_hypothesis;
-mod same_variance_hypothesis;
+use std::collections::HashMap;
+use std::process::ExitCode;
 
-use normal_distribution_hypothesis::*;
-use same_variance_hypothesis::*;
+use statistics_problems::alpha::Alpha;
+use statistics_problems::box_plot::BoxPlot;
+use statistics_problems::missing::MissingPolicy;
+use statistics_problems::normal_distribution_hypothesis::*;
+use statistics_problems::recommend::*;
+use statistics_problems::same_variance_hypothesis::*;
+use statistics_problems::tables::{self, Format, Tails};
+use statistics_problems::terminal_chart;
 
-fn main() {
+const USAGE: &str = "\
+Usage:
+    statistics-problems
+        Solves the demonstration problems.
+    statistics-problems recommend --sizes N,M [--paired] [--categorical]
+                                  [--normal yes|no] [--equal-variances yes|no]
+        Suggests a test for the described data.
+    statistics-problems recommend --x X1,X2,... --y Y1,Y2,... [--paired] [--alpha A]
+                                  [--missing error|skip|pairwise]
+        Runs the assumption checks on the samples and suggests a test.
+    statistics-problems tables chi2|t|normal --alpha A1,A2,... [--df K1..K2] [--two-sided]
+                               [--format text|csv|latex] [--digits D]
+    statistics-problems tables f --alpha A --df1 K1..K2 --df2 K1..K2
+                               [--format text|csv|latex] [--digits D]
+        Generates a table of critical values.
+    statistics-problems chart histogram|box --x X1,X2,... [--width W]
+    statistics-problems chart frequencies --observed O1,O2,... --expected E1,E2,... [--width W]
+        Draws a chart in the terminal.
+    statistics-problems serve [--address HOST:PORT]
+        Serves the JSON API (requires the `serve` feature).";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        None => {
+            demo();
+            Ok(())
+        }
+        Some("recommend") => recommend_command(&args[1..]),
+        Some("tables") => tables_command(&args[1..]),
+        Some("chart") => chart_command(&args[1..]),
+        #[cfg(feature = "serve")]
+        Some("serve") => serve_command(&args[1..]),
+        Some(other) => Err(format!("Unknown subcommand `{other}`")),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{message}\n\n{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn demo() {
     let into_vec_f64 = |seq: &[i32]| seq.iter().map(|i| *i as f64).collect::<Vec<f64>>();
 
     // Normal Distribution Hypothesis with a Complete Problem Situation
@@ -34,3 +86,248 @@ fn main() {
     let svh = SameVarianceHypothesis::new(&x, &y, 0.05);
     println!("SVH: {:?}", svh.solve());
 }
+
+/// Splits `--key value` pairs and `--flag`s into a map.
+fn parse_options(args: &[String]) -> Result<HashMap<&str, Option<&str>>, String> {
+    let mut options = HashMap::new();
+    let mut args = args.iter().peekable();
+
+    while let Some(arg) = args.next() {
+        let key = arg
+            .strip_prefix("--")
+            .ok_or_else(|| format!("Unexpected argument `{arg}`"))?;
+        let value = args
+            .next_if(|value| !value.starts_with("--"))
+            .map(String::as_str);
+        options.insert(key, value);
+    }
+
+    Ok(options)
+}
+
+fn option_value<'a>(
+    options: &HashMap<&str, Option<&'a str>>,
+    key: &str,
+) -> Result<Option<&'a str>, String> {
+    match options.get(key) {
+        None => Ok(None),
+        Some(None) => Err(format!("Option `--{key}` requires a value")),
+        Some(Some(value)) => Ok(Some(value)),
+    }
+}
+
+fn parse_list<T: std::str::FromStr>(value: &str) -> Result<Vec<T>, String> {
+    value
+        .split(',')
+        .map(|item| {
+            item.trim()
+                .parse()
+                .map_err(|_| format!("Cannot parse `{item}`"))
+        })
+        .collect()
+}
+
+/// Parses either `K1..K2` or a single `K`.
+fn parse_range(value: &str) -> Result<std::ops::RangeInclusive<usize>, String> {
+    let parse = |bound: &str| {
+        bound
+            .trim()
+            .parse()
+            .map_err(|_| format!("Cannot parse `{bound}`"))
+    };
+
+    match value.split_once("..") {
+        Some((start, end)) => Ok(parse(start)?..=parse(end)?),
+        None => parse(value).map(|k| k..=k),
+    }
+}
+
+fn parse_yes_no(value: &str) -> Result<bool, String> {
+    match value {
+        "yes" | "true" => Ok(true),
+        "no" | "false" => Ok(false),
+        _ => Err(format!("Expected `yes` or `no`, got `{value}`")),
+    }
+}
+
+fn parse_missing_policy(value: &str) -> Result<MissingPolicy, String> {
+    match value {
+        "error" => Ok(MissingPolicy::Error),
+        "skip" => Ok(MissingPolicy::Skip),
+        "pairwise" => Ok(MissingPolicy::PairwiseDeletion),
+        _ => Err(format!(
+            "Expected `error`, `skip` or `pairwise`, got `{value}`"
+        )),
+    }
+}
+
+fn recommend_command(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    let design = if options.contains_key("paired") {
+        Design::Paired
+    } else {
+        Design::Independent
+    };
+
+    if let (Some(x), Some(y)) = (option_value(&options, "x")?, option_value(&options, "y")?) {
+        let significance = option_value(&options, "alpha")?
+            .map(|alpha| {
+                alpha
+                    .parse()
+                    .map_err(|e| format!("Cannot parse `{alpha}`: {e}"))
+            })
+            .transpose()?
+            .unwrap_or(Alpha::P05)
+            .value();
+        let missing_policy = option_value(&options, "missing")?
+            .map(parse_missing_policy)
+            .transpose()?
+            .unwrap_or_default();
+        let (description, recommendation) = recommend_for_samples(
+            &parse_list(x)?,
+            &parse_list(y)?,
+            design,
+            missing_policy,
+            significance,
+        )
+        .map_err(|e| e.to_string())?;
+
+        println!("{description:?}");
+        println!("{recommendation}");
+        return Ok(());
+    }
+
+    let sizes: Vec<usize> = parse_list(
+        option_value(&options, "sizes")?
+            .ok_or("Either `--sizes` or `--x` and `--y` are required")?,
+    )?;
+    let [n_1, n_2] = sizes[..] else {
+        return Err("`--sizes` requires exactly two values".to_string());
+    };
+    let description = DataDescription {
+        design,
+        measurement: if options.contains_key("categorical") {
+            Measurement::Categorical
+        } else {
+            Measurement::Continuous
+        },
+        sample_sizes: (n_1, n_2),
+        normality: option_value(&options, "normal")?
+            .map(parse_yes_no)
+            .transpose()?,
+        equal_variances: option_value(&options, "equal-variances")?
+            .map(parse_yes_no)
+            .transpose()?,
+    };
+
+    println!("{}", recommend(&description));
+    Ok(())
+}
+
+fn tables_command(args: &[String]) -> Result<(), String> {
+    let (distribution, args) = args
+        .split_first()
+        .ok_or("`tables` requires a distribution")?;
+    let options = parse_options(args)?;
+
+    let significances: Vec<f64> =
+        parse_list::<Alpha>(option_value(&options, "alpha")?.unwrap_or("0.05"))?
+            .into_iter()
+            .map(Alpha::value)
+            .collect();
+    let freedom_degrees = |key| {
+        option_value(&options, key)?
+            .map(parse_range)
+            .unwrap_or(Ok(1..=30))
+    };
+    let tails = if options.contains_key("two-sided") {
+        Tails::Two
+    } else {
+        Tails::One
+    };
+    let format = match option_value(&options, "format")?.unwrap_or("text") {
+        "text" => Format::Text,
+        "csv" => Format::Csv,
+        "latex" => Format::Latex,
+        other => return Err(format!("Unknown format `{other}`")),
+    };
+    let digits = option_value(&options, "digits")?
+        .map(|digits| {
+            digits
+                .parse()
+                .map_err(|_| format!("Cannot parse `{digits}`"))
+        })
+        .transpose()?
+        .unwrap_or(3);
+
+    let table = match distribution.as_str() {
+        "chi2" => tables::chi_squared_table(&significances, freedom_degrees("df")?),
+        "t" => tables::students_t_table(&significances, freedom_degrees("df")?, tails),
+        "normal" => tables::standard_normal_table(&significances, tails),
+        "f" => {
+            let [significance] = significances[..] else {
+                return Err("F table requires exactly one `--alpha`".to_string());
+            };
+            tables::fisher_snedecor_table(
+                significance,
+                freedom_degrees("df1")?,
+                freedom_degrees("df2")?,
+            )
+        }
+        other => return Err(format!("Unknown distribution `{other}`")),
+    }
+    .map_err(|e| e.to_string())?;
+
+    print!("{}", table.render(format, digits));
+    Ok(())
+}
+
+fn chart_command(args: &[String]) -> Result<(), String> {
+    let (kind, args) = args.split_first().ok_or("`chart` requires a chart kind")?;
+    let options = parse_options(args)?;
+
+    let width = option_value(&options, "width")?
+        .map(|width| width.parse().map_err(|_| format!("Cannot parse `{width}`")))
+        .transpose()?
+        .unwrap_or(50);
+    let list = |key| -> Result<Vec<f64>, String> {
+        parse_list(option_value(&options, key)?.ok_or(format!("`--{key}` is required"))?)
+    };
+
+    let chart = match kind.as_str() {
+        "histogram" => {
+            let grouped_sample = GroupedSample::sturges(&list("x")?).map_err(|e| e.to_string())?;
+            terminal_chart::histogram(
+                &grouped_sample.random_value_ranges,
+                &grouped_sample.frequencies,
+                width,
+            )
+        }
+        "box" => {
+            let box_plot = BoxPlot::new(&list("x")?).map_err(|e| e.to_string())?;
+            terminal_chart::box_plot(&[box_plot], width)
+        }
+        "frequencies" => {
+            terminal_chart::observed_expected(&list("observed")?, &list("expected")?, width)
+        }
+        other => return Err(format!("Unknown chart kind `{other}`")),
+    };
+
+    print!("{chart}");
+    Ok(())
+}
+
+#[cfg(feature = "serve")]
+fn serve_command(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    let address = option_value(&options, "address")?.unwrap_or("127.0.0.1:3000");
+    let address = address
+        .parse()
+        .map_err(|_| format!("Cannot parse `{address}`"))?;
+
+    let runtime = tokio::runtime::Runtime::new().map_err(|e| e.to_string())?;
+    println!("Listening on http://{address}");
+    runtime
+        .block_on(statistics_problems::serve::serve(address))
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,193 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *sample of a random variable **X***, *sample of a random variable **Y***.
+//! **To figure out**: Is it appropriate to **assume** that **X** and **Y** have the same distribution?
+//!
+//! The `U` statistic is compared through its normal approximation with tie and continuity corrections.
+//! The Hodges–Lehmann estimator of the shift between **X** and **Y** accompanies the test.
+
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::alpha;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+use crate::order_statistics::average_ranks;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum MWHError {
+    SignificanceInvalid,
+    SampleEmpty,
+    SampleDegenerate,
+    ValueMissing,
+}
+
+impl std::fmt::Display for MWHError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MWHError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            MWHError::SampleEmpty => {
+                write!(f, "Samples must not be empty")
+            }
+            MWHError::SampleDegenerate => {
+                write!(f, "Pooled sample must contain at least two distinct values")
+            }
+            MWHError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct HodgesLehmann {
+    /// `med (xᵢ - yⱼ)` over all the pairs, the estimate of the shift `Δ` of **X** against **Y**.
+    pub estimate: f64,
+    /// Distribution-free confidence interval for `Δ` at the `1 - significance` level or above.
+    pub confidence_interval: (f64, f64),
+    /// Whether the interval comes from the exact distribution of `U` rather than its normal approximation.
+    pub exact: bool,
+}
+
+/// Largest `n + m` for which the exact distribution of `U` is counted; the counts stay exact in `f64`.
+const EXACT_SIZE_LIMIT: usize = 40;
+
+pub struct MannWhitneyHypothesis {
+    x_sample: Vec<f64>,
+    y_sample: Vec<f64>,
+    significance: f64,
+}
+
+impl MannWhitneyHypothesis {
+    pub fn new(x_sample: &[f64], y_sample: &[f64], significance: f64) -> Result<Self, MWHError> {
+        MissingPolicy::Error
+            .apply(x_sample)
+            .and(MissingPolicy::Error.apply(y_sample))
+            .map_err(|_| MWHError::ValueMissing)?;
+
+        if x_sample.is_empty() || y_sample.is_empty() {
+            return Err(MWHError::SampleEmpty);
+        }
+
+        alpha::validate(significance, MWHError::SignificanceInvalid)?;
+
+        Ok(Self {
+            x_sample: x_sample.to_owned(),
+            y_sample: y_sample.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            "X and Y have the same distribution",
+            "X is stochastically greater or less than Y",
+            Alternative::TwoSided,
+        )
+    }
+
+    /// Returns the `U` statistic of the **X** sample and its standardized value.
+    pub fn statistic(&self) -> Result<(f64, f64), MWHError> {
+        let (n_x, n_y) = (self.x_sample.len() as f64, self.y_sample.len() as f64);
+        let n = n_x + n_y;
+
+        let pooled: Vec<_> = self
+            .x_sample
+            .iter()
+            .chain(self.y_sample.iter())
+            .copied()
+            .collect();
+        let (ranks, ties) = average_ranks(&pooled);
+
+        let x_rank_sum = ranks[..self.x_sample.len()].iter().sum::<f64>();
+        let u = x_rank_sum - n_x * (n_x + 1.) / 2.;
+
+        let mean = n_x * n_y / 2.;
+        let tie_correction = ties.iter().map(|t| t.powi(3) - t).sum::<f64>() / (n * (n - 1.));
+        let variance = n_x * n_y / 12. * ((n + 1.) - tie_correction);
+        if variance <= 0. {
+            return Err(MWHError::SampleDegenerate);
+        }
+
+        let continuity_correction = 0.5 * (u - mean).signum();
+        let z = (u - mean - continuity_correction) / variance.sqrt();
+
+        Ok((u, z))
+    }
+
+    pub fn solve(&self) -> Result<bool, MWHError> {
+        let (_, z_observed) = self.statistic()?;
+
+        // The critical region is two-sided, so each tail holds `significance / 2`.
+        let z_critical_value = Normal::standard().inverse_cdf(1. - self.significance / 2.);
+
+        Ok(z_observed.abs() < z_critical_value)
+    }
+
+    /// The interval runs from the `k`-th smallest to the `k`-th largest pairwise difference,
+    /// where `k` is the smallest count with `P(U <= k) >= significance / 2`.
+    pub fn hodges_lehmann(&self) -> HodgesLehmann {
+        let (n_x, n_y) = (self.x_sample.len(), self.y_sample.len());
+        let mut differences: Vec<_> = self
+            .x_sample
+            .iter()
+            .flat_map(|x| self.y_sample.iter().map(move |y| x - y))
+            .collect();
+        differences.sort_by(f64::total_cmp);
+
+        let pairs = n_x * n_y;
+        let middle = pairs / 2;
+        let estimate = if pairs % 2 == 0 {
+            (differences[middle - 1] + differences[middle]) / 2.
+        } else {
+            differences[middle]
+        };
+
+        let exact = n_x + n_y <= EXACT_SIZE_LIMIT;
+        let k = if exact {
+            let counts = u_counts(n_x, n_y);
+            let total = counts.iter().sum::<f64>();
+            let mut cumulative = 0.;
+            counts
+                .iter()
+                .position(|count| {
+                    cumulative += count / total;
+                    cumulative >= self.significance / 2.
+                })
+                .unwrap_or(0)
+        } else {
+            let (n_x, n_y) = (n_x as f64, n_y as f64);
+            let std_dev = (n_x * n_y * (n_x + n_y + 1.) / 12.).sqrt();
+            let z = Normal::standard().inverse_cdf(self.significance / 2.);
+            (n_x * n_y / 2. - 0.5 + z * std_dev).ceil().max(0.) as usize
+        }
+        .clamp(1, middle.max(1));
+
+        HodgesLehmann {
+            estimate,
+            confidence_interval: (differences[k - 1], differences[pairs - k]),
+            exact,
+        }
+    }
+}
+
+/// Numbers of arrangements of the samples giving each value `0..=n m` of `U`:
+/// the coefficients of the Gaussian binomial `[n + m, n]`, built as `Π (1 - qᵐ⁺ⁱ) / (1 - qⁱ)` for `i = 1..=n`.
+fn u_counts(n: usize, m: usize) -> Vec<f64> {
+    let mut counts = vec![0.; n * m + 1];
+    counts[0] = 1.;
+
+    for i in 1..=n {
+        for u in (m + i..counts.len()).rev() {
+            counts[u] -= counts[u - m - i];
+        }
+        for u in i..counts.len() {
+            counts[u] += counts[u - i];
+        }
+    }
+
+    counts
+}
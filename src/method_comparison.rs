@@ -0,0 +1,461 @@
+//! Algorithms for solving problems of the following kinds.
+//!
+//! **Given**: *significance ratio*, *measurements of the same items by two methods* **X** and **Y**,
+//! both subject to measurement error.
+//! **To figure out**: Is it appropriate to **assume** that the methods agree, `Y = X`,
+//! that is the line `Y = β₀ + β₁X` has `β₀ = 0` and `β₁ = 1`?
+//!
+//! Ordinary least squares attributes all the error to **Y** and biases the slope towards zero.
+//! Deming regression minimizes the distances to the line in the metric of the ratio `δ = σ²_Y / σ²_X`
+//! of the error variances, orthogonal for `δ = 1`; its confidence intervals use jackknife standard errors
+//! and Student's `t` with `n - 2` freedom degrees, as recommended by Linnet (1993).
+//! Passing–Bablok regression needs no assumption on the errors: its slope is the shifted median
+//! of the slopes between all pairs of items, robust to outliers.
+//!
+//! **Given**: *significance ratio*, *measurements of the same items by two methods* **X** and **Y**.
+//! **To figure out**: The bias `E(Y - X)` and the limits within which 95% of the differences lie,
+//! and whether it is appropriate to **assume** that there is no bias.
+//!
+//! The Bland–Altman analysis estimates the limits of agreement `d̄ ∓ 1.96 s` from the mean `d̄` and the standard
+//! deviation `s` of the differences, with confidence intervals by the standard errors `s / √n` of the bias
+//! and `s √(3 / n)` of the limits (Bland and Altman, 1986).
+
+use statrs::distribution::{ContinuousCDF, Normal, StudentsT};
+
+use crate::alpha;
+use crate::descriptive::median;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+use crate::numeric::{mean, unbiased_variance};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum MethodComparisonError {
+    SignificanceInvalid,
+    LengthsMismatch,
+    SampleTooSmall,
+    VarianceRatioInvalid,
+    SampleDegenerate,
+    ValueMissing,
+}
+
+impl std::fmt::Display for MethodComparisonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MethodComparisonError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            MethodComparisonError::LengthsMismatch => {
+                write!(f, "Both methods must measure the same items")
+            }
+            MethodComparisonError::SampleTooSmall => {
+                write!(f, "At least three items are required")
+            }
+            MethodComparisonError::VarianceRatioInvalid => {
+                write!(f, "Ratio of the error variances must be positive")
+            }
+            MethodComparisonError::SampleDegenerate => {
+                write!(f, "Measurements of the methods must be correlated")
+            }
+            MethodComparisonError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+/// Estimate with its confidence interval at the `1 - significance` level.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct IntervalEstimate {
+    pub estimate: f64,
+    /// Standard error the interval is based on, absent for the nonparametric intervals.
+    pub standard_error: Option<f64>,
+    pub confidence_interval: (f64, f64),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LineOutcome {
+    pub intercept: IntervalEstimate,
+    pub slope: IntervalEstimate,
+    pub hypotheses: Hypotheses,
+    /// Whether the interval of the intercept contains 0 and the interval of the slope contains 1.
+    pub accepted: bool,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BlandAltmanOutcome {
+    /// Means `(xᵢ + yᵢ) / 2` of the items, the abscissae of the Bland–Altman plot.
+    pub means: Vec<f64>,
+    /// Differences `yᵢ - xᵢ`, the ordinates of the Bland–Altman plot.
+    pub differences: Vec<f64>,
+    /// Mean difference `d̄`.
+    pub bias: IntervalEstimate,
+    /// `d̄ - 1.96 s`.
+    pub lower_limit: IntervalEstimate,
+    /// `d̄ + 1.96 s`.
+    pub upper_limit: IntervalEstimate,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+/// Share of the differences expected within the limits of agreement.
+const LIMITS_OF_AGREEMENT_COVERAGE: f64 = 0.95;
+
+pub struct DemingRegression {
+    x_sample: Vec<f64>,
+    y_sample: Vec<f64>,
+    variance_ratio: f64,
+    significance: f64,
+}
+
+impl DemingRegression {
+    /// Orthogonal regression, with equal error variances, unless the ratio is chosen
+    /// with [`DemingRegression::with_variance_ratio`].
+    pub fn new(
+        x_sample: &[f64],
+        y_sample: &[f64],
+        significance: f64,
+    ) -> Result<Self, MethodComparisonError> {
+        validate(x_sample, y_sample, significance)?;
+
+        Ok(Self {
+            x_sample: x_sample.to_owned(),
+            y_sample: y_sample.to_owned(),
+            variance_ratio: 1.,
+            significance,
+        })
+    }
+
+    /// Ratio `δ = σ²_Y / σ²_X` of the error variances, e.g. of the squared repeatability
+    /// standard deviations of the methods.
+    pub fn with_variance_ratio(mut self, variance_ratio: f64) -> Self {
+        self.variance_ratio = variance_ratio;
+        self
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        methods_agree()
+    }
+
+    pub fn solve(&self) -> Result<bool, MethodComparisonError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<LineOutcome, MethodComparisonError> {
+        if self.variance_ratio.is_nan() || self.variance_ratio <= 0. {
+            return Err(MethodComparisonError::VarianceRatioInvalid);
+        }
+
+        let (intercept, slope) = self.line(&self.x_sample, &self.y_sample)?;
+
+        // Jackknife: the line refitted without each item in turn.
+        let n = self.x_sample.len();
+        let leave_one_out = |sample: &[f64], i: usize| [&sample[..i], &sample[i + 1..]].concat();
+        let lines = (0..n)
+            .map(|i| {
+                self.line(
+                    &leave_one_out(&self.x_sample, i),
+                    &leave_one_out(&self.y_sample, i),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let jackknife_standard_error = |estimates: Vec<f64>| {
+            (unbiased_variance(&estimates) * (n - 1) as f64 * (n - 1) as f64 / n as f64).sqrt()
+        };
+        let intercept_standard_error =
+            jackknife_standard_error(lines.iter().map(|(intercept, _)| *intercept).collect());
+        let slope_standard_error =
+            jackknife_standard_error(lines.iter().map(|(_, slope)| *slope).collect());
+
+        Ok(line_outcome(
+            (intercept, intercept_standard_error),
+            (slope, slope_standard_error),
+            n,
+            self.significance,
+        ))
+    }
+
+    /// Deming's intercept and slope.
+    fn line(
+        &self,
+        x_sample: &[f64],
+        y_sample: &[f64],
+    ) -> Result<(f64, f64), MethodComparisonError> {
+        let (x_mean, y_mean) = (mean(x_sample), mean(y_sample));
+        let (x_variance, y_variance) = (unbiased_variance(x_sample), unbiased_variance(y_sample));
+        let covariance = x_sample
+            .iter()
+            .zip(y_sample)
+            .map(|(x, y)| (x - x_mean) * (y - y_mean))
+            .sum::<f64>()
+            / (x_sample.len() - 1) as f64;
+        if covariance == 0. {
+            return Err(MethodComparisonError::SampleDegenerate);
+        }
+
+        let difference = y_variance - self.variance_ratio * x_variance;
+        let slope = (difference
+            + (difference.powi(2) + 4. * self.variance_ratio * covariance.powi(2)).sqrt())
+            / (2. * covariance);
+
+        Ok((y_mean - slope * x_mean, slope))
+    }
+}
+
+pub struct PassingBablokRegression {
+    x_sample: Vec<f64>,
+    y_sample: Vec<f64>,
+    significance: f64,
+}
+
+impl PassingBablokRegression {
+    pub fn new(
+        x_sample: &[f64],
+        y_sample: &[f64],
+        significance: f64,
+    ) -> Result<Self, MethodComparisonError> {
+        validate(x_sample, y_sample, significance)?;
+
+        Ok(Self {
+            x_sample: x_sample.to_owned(),
+            y_sample: y_sample.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        methods_agree()
+    }
+
+    pub fn solve(&self) -> Result<bool, MethodComparisonError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<LineOutcome, MethodComparisonError> {
+        let n = self.x_sample.len();
+
+        // Slopes of the pairs of distinct items, except `-1`; vertical pairs have infinite slopes.
+        let mut slopes = Vec::with_capacity(n * (n - 1) / 2);
+        for i in 0..n {
+            for j in i + 1..n {
+                let (dx, dy) = (
+                    self.x_sample[j] - self.x_sample[i],
+                    self.y_sample[j] - self.y_sample[i],
+                );
+                let slope = if dx == 0. {
+                    if dy == 0. {
+                        continue;
+                    }
+                    f64::INFINITY.copysign(dy)
+                } else {
+                    dy / dx
+                };
+                if slope != -1. {
+                    slopes.push(slope);
+                }
+            }
+        }
+        if slopes.is_empty() {
+            return Err(MethodComparisonError::SampleDegenerate);
+        }
+        slopes.sort_by(f64::total_cmp);
+
+        // The median is shifted by the number of slopes below `-1`, so that the estimate does not
+        // depend on which method is **X**.
+        let shift = slopes.iter().filter(|slope| **slope < -1.).count() as isize;
+        let count = slopes.len() as isize;
+        let order_statistic = |k: isize| slopes[(k + shift - 1).clamp(0, count - 1) as usize];
+        let slope = if count % 2 == 1 {
+            order_statistic((count + 1) / 2)
+        } else {
+            (order_statistic(count / 2) + order_statistic(count / 2 + 1)) / 2.
+        };
+
+        let z = Normal::standard().inverse_cdf(1. - self.significance / 2.);
+        let size = n as f64;
+        let spread = z * (size * (size - 1.) * (2. * size + 5.) / 18.).sqrt();
+        let lower_rank = ((count as f64 - spread) / 2.).round() as isize;
+        let upper_rank = count - lower_rank + 1;
+        let slope_interval = (order_statistic(lower_rank), order_statistic(upper_rank));
+
+        let intercept = |slope: f64| {
+            median(
+                &self
+                    .x_sample
+                    .iter()
+                    .zip(&self.y_sample)
+                    .map(|(x, y)| y - slope * x)
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        Ok(agreement_outcome(
+            IntervalEstimate {
+                estimate: intercept(slope),
+                standard_error: None,
+                confidence_interval: (intercept(slope_interval.1), intercept(slope_interval.0)),
+            },
+            IntervalEstimate {
+                estimate: slope,
+                standard_error: None,
+                confidence_interval: slope_interval,
+            },
+        ))
+    }
+}
+
+pub struct BlandAltman {
+    x_sample: Vec<f64>,
+    y_sample: Vec<f64>,
+    significance: f64,
+}
+
+impl BlandAltman {
+    pub fn new(
+        x_sample: &[f64],
+        y_sample: &[f64],
+        significance: f64,
+    ) -> Result<Self, MethodComparisonError> {
+        validate(x_sample, y_sample, significance)?;
+
+        Ok(Self {
+            x_sample: x_sample.to_owned(),
+            y_sample: y_sample.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new("E(Y - X) = 0", "E(Y - X) ≠ 0", Alternative::TwoSided)
+    }
+
+    pub fn solve(&self) -> Result<bool, MethodComparisonError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<BlandAltmanOutcome, MethodComparisonError> {
+        let differences: Vec<_> = self
+            .x_sample
+            .iter()
+            .zip(&self.y_sample)
+            .map(|(x, y)| y - x)
+            .collect();
+        let means = self
+            .x_sample
+            .iter()
+            .zip(&self.y_sample)
+            .map(|(x, y)| (x + y) / 2.)
+            .collect();
+
+        let n = differences.len() as f64;
+        let (bias, std_dev) = (mean(&differences), unbiased_variance(&differences).sqrt());
+        if std_dev == 0. {
+            return Err(MethodComparisonError::SampleDegenerate);
+        }
+
+        let z = Normal::standard().inverse_cdf((1. + LIMITS_OF_AGREEMENT_COVERAGE) / 2.);
+        // `n >= 3` was validated, so the freedom degrees are positive.
+        let t_critical_value = StudentsT::new(0., 1., n - 1.)
+            .unwrap()
+            .inverse_cdf(1. - self.significance / 2.);
+        let limit_standard_error = std_dev * (3. / n).sqrt();
+        let bias = IntervalEstimate::symmetric(bias, std_dev / n.sqrt(), t_critical_value);
+
+        Ok(BlandAltmanOutcome {
+            means,
+            differences,
+            lower_limit: IntervalEstimate::symmetric(
+                bias.estimate - z * std_dev,
+                limit_standard_error,
+                t_critical_value,
+            ),
+            upper_limit: IntervalEstimate::symmetric(
+                bias.estimate + z * std_dev,
+                limit_standard_error,
+                t_critical_value,
+            ),
+            accepted: contains(bias.confidence_interval, 0.),
+            bias,
+            hypotheses: self.hypotheses(),
+        })
+    }
+}
+
+fn methods_agree() -> Hypotheses {
+    Hypotheses::new(
+        "β₀ = 0 and β₁ = 1",
+        "β₀ ≠ 0 or β₁ ≠ 1",
+        Alternative::TwoSided,
+    )
+}
+
+/// Outcome with the `t` intervals of the intercept and the slope from their standard errors.
+fn line_outcome(
+    (intercept, intercept_standard_error): (f64, f64),
+    (slope, slope_standard_error): (f64, f64),
+    size: usize,
+    significance: f64,
+) -> LineOutcome {
+    // `size >= 3` was validated, so the freedom degrees are positive.
+    let t_critical_value = StudentsT::new(0., 1., (size - 2) as f64)
+        .unwrap()
+        .inverse_cdf(1. - significance / 2.);
+    let intercept =
+        IntervalEstimate::symmetric(intercept, intercept_standard_error, t_critical_value);
+    let slope = IntervalEstimate::symmetric(slope, slope_standard_error, t_critical_value);
+
+    agreement_outcome(intercept, slope)
+}
+
+fn agreement_outcome(intercept: IntervalEstimate, slope: IntervalEstimate) -> LineOutcome {
+    LineOutcome {
+        accepted: contains(intercept.confidence_interval, 0.)
+            && contains(slope.confidence_interval, 1.),
+        intercept,
+        slope,
+        hypotheses: methods_agree(),
+    }
+}
+
+impl IntervalEstimate {
+    /// `estimate ∓ critical_value · standard_error`.
+    fn symmetric(estimate: f64, standard_error: f64, critical_value: f64) -> Self {
+        Self {
+            estimate,
+            standard_error: Some(standard_error),
+            confidence_interval: (
+                estimate - critical_value * standard_error,
+                estimate + critical_value * standard_error,
+            ),
+        }
+    }
+}
+
+fn contains((lower, upper): (f64, f64), value: f64) -> bool {
+    lower <= value && value <= upper
+}
+
+fn validate(
+    x_sample: &[f64],
+    y_sample: &[f64],
+    significance: f64,
+) -> Result<(), MethodComparisonError> {
+    if x_sample.len() != y_sample.len() {
+        return Err(MethodComparisonError::LengthsMismatch);
+    }
+
+    MissingPolicy::Error
+        .apply_paired(x_sample, y_sample)
+        .map_err(|_| MethodComparisonError::ValueMissing)?;
+
+    if x_sample.len() < 3 {
+        return Err(MethodComparisonError::SampleTooSmall);
+    }
+
+    alpha::validate(significance, MethodComparisonError::SignificanceInvalid)?;
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+//! Handling of missing values, marked by `NaN`, in raw samples.
+//!
+//! A `NaN` left in a sample does not fail any comparison with a critical value, it makes every one
+//! of them false, so the situations built from raw data apply a [`MissingPolicy`] first.
+//! The constructors of the tests reject missing values, as [`MissingPolicy::Error`] does;
+//! `IncompleteNDHProblemSituation::from_raw_sample_with_policy`, `SameVarianceHypothesis::with_missing_policy`,
+//! the means comparison pipeline and the test recommendation take any policy. The paired method comparisons
+//! check their pairs with [`MissingPolicy::apply_paired`].
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum MissingError {
+    ValueMissing,
+    LengthsMismatch,
+    PairingBroken,
+}
+
+impl std::fmt::Display for MissingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MissingError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+            MissingError::LengthsMismatch => {
+                write!(f, "Lengths of paired samples are different")
+            }
+            MissingError::PairingBroken => {
+                write!(
+                    f,
+                    "Skipping missing values of paired samples breaks the pairs, use pairwise deletion"
+                )
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum MissingPolicy {
+    /// Drops the missing values of every sample on its own.
+    ///
+    /// Paired samples with missing values are rejected, since their pairs would no longer line up.
+    Skip,
+    /// Rejects any sample with a missing value.
+    #[default]
+    Error,
+    /// Drops every pair with a missing value in either sample.
+    ///
+    /// A single sample is treated as by [`MissingPolicy::Skip`].
+    PairwiseDeletion,
+}
+
+impl MissingPolicy {
+    /// The sample without its missing values, or an error if the policy rejects them.
+    pub fn apply(&self, sample: &[f64]) -> Result<Vec<f64>, MissingError> {
+        match self {
+            MissingPolicy::Error if sample.iter().any(|value| value.is_nan()) => {
+                Err(MissingError::ValueMissing)
+            }
+            _ => Ok(sample
+                .iter()
+                .copied()
+                .filter(|value| !value.is_nan())
+                .collect()),
+        }
+    }
+
+    /// The paired samples without their missing values, or an error if the policy rejects them.
+    pub fn apply_paired(
+        &self,
+        x_sample: &[f64],
+        y_sample: &[f64],
+    ) -> Result<(Vec<f64>, Vec<f64>), MissingError> {
+        if x_sample.len() != y_sample.len() {
+            return Err(MissingError::LengthsMismatch);
+        }
+
+        let complete = |(x, y): &(f64, f64)| !x.is_nan() && !y.is_nan();
+        let pairs: Vec<_> = x_sample
+            .iter()
+            .copied()
+            .zip(y_sample.iter().copied())
+            .collect();
+        if !pairs.iter().all(complete) {
+            match self {
+                MissingPolicy::Skip => return Err(MissingError::PairingBroken),
+                MissingPolicy::Error => return Err(MissingError::ValueMissing),
+                MissingPolicy::PairwiseDeletion => {}
+            }
+        }
+
+        Ok(pairs.into_iter().filter(complete).unzip())
+    }
+}
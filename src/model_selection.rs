@@ -0,0 +1,210 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, two linear models fitted to the same observations, the *reduced* one
+//! nested in the *full* one, which adds `q` predictors.
+//! **To figure out**: Is it appropriate to **assume** that the added predictors do not affect the response,
+//! that is should they be left out?
+//!
+//! The partial `F` test compares the drop of the residual sum of squares with the residual variance
+//! of the full model, `F = ((RSS_R - RSS_F) / q) / (RSS_F / (n - p_F))`, which is exactly `F(q, n - p_F)`
+//! for normal errors. The likelihood ratio test compares `Λ = 2 (ln L_F - ln L_R) = n ln(RSS_R / RSS_F)`
+//! with Chi-Squared with `q` freedom degrees, asymptotically. The information criteria `AIC = 2k - 2 ln L`
+//! and `BIC = k ln n - 2 ln L` with `k = p + 1` estimated parameters, the variance included, weigh the fit
+//! against the complexity; the model with the smaller criterion is preferred.
+
+use statrs::distribution::{ChiSquared, ContinuousCDF, FisherSnedecor};
+
+use crate::alpha;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::regression::RegressionOutcome;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum ModelSelectionError {
+    SignificanceInvalid,
+    SampleSizesMismatch,
+    ModelsNotNested,
+}
+
+impl std::fmt::Display for ModelSelectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ModelSelectionError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            ModelSelectionError::SampleSizesMismatch => {
+                write!(f, "Both models must be fitted to the same observations")
+            }
+            ModelSelectionError::ModelsNotNested => {
+                write!(
+                    f,
+                    "Full model must have more coefficients and fit at least as well as the reduced one"
+                )
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum NestedTest {
+    PartialF,
+    LikelihoodRatio,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct InformationCriteria {
+    /// `ln L` of the normal errors at the maximum likelihood variance `RSS / n`,
+    /// up to a constant of the weights for weighted least squares.
+    pub log_likelihood: f64,
+    /// `AIC = 2k - 2 ln L`.
+    pub aic: f64,
+    /// `BIC = k ln n - 2 ln L`.
+    pub bic: f64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct NestedModelsOutcome {
+    pub reduced: InformationCriteria,
+    pub full: InformationCriteria,
+    /// `AIC_F - AIC_R`, negative if the added predictors are worth their complexity.
+    pub aic_delta: f64,
+    /// `BIC_F - BIC_R`.
+    pub bic_delta: f64,
+    pub test: NestedTest,
+    /// `F` or `Λ`, depending on the test.
+    pub statistic_observed: f64,
+    /// `q`, the number of added coefficients.
+    pub freedom_degrees: f64,
+    /// `n - p_F`, the denominator freedom degrees of the partial `F` test.
+    pub residual_freedom_degrees: f64,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+/// Log-likelihood and information criteria of a fitted linear model with normal errors.
+pub fn information_criteria(outcome: &RegressionOutcome) -> InformationCriteria {
+    let n = outcome.residuals.len() as f64;
+    let k = outcome.coefficients.len() as f64 + 1.;
+    let log_likelihood =
+        -n / 2. * ((2. * std::f64::consts::PI * residual_squares_sum(outcome) / n).ln() + 1.);
+
+    InformationCriteria {
+        log_likelihood,
+        aic: 2. * k - 2. * log_likelihood,
+        bic: k * n.ln() - 2. * log_likelihood,
+    }
+}
+
+/// Test of whether the predictors the full model adds to the reduced one can be left out.
+///
+/// The predictors of the reduced model must be among those of the full one; only the numbers of coefficients
+/// and the fits can be checked. Both tests use the residual sums of squares, whatever covariance estimator
+/// the models were fitted with.
+pub struct NestedModelsHypothesis {
+    reduced: RegressionOutcome,
+    full: RegressionOutcome,
+    test: NestedTest,
+    significance: f64,
+}
+
+impl NestedModelsHypothesis {
+    pub fn new(
+        reduced: &RegressionOutcome,
+        full: &RegressionOutcome,
+        significance: f64,
+    ) -> Result<Self, ModelSelectionError> {
+        if reduced.residuals.len() != full.residuals.len() {
+            return Err(ModelSelectionError::SampleSizesMismatch);
+        }
+
+        // Round-off may leave the sum of squares of the full model a little above that of the reduced one.
+        let (reduced_sum, full_sum) = (residual_squares_sum(reduced), residual_squares_sum(full));
+        if full.coefficients.len() <= reduced.coefficients.len()
+            || full_sum > reduced_sum * (1. + 1e-9)
+        {
+            return Err(ModelSelectionError::ModelsNotNested);
+        }
+
+        alpha::validate(significance, ModelSelectionError::SignificanceInvalid)?;
+
+        Ok(Self {
+            reduced: reduced.clone(),
+            full: full.clone(),
+            test: NestedTest::PartialF,
+            significance,
+        })
+    }
+
+    pub fn with_test(mut self, test: NestedTest) -> Self {
+        self.test = test;
+        self
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            "the added coefficients are 0",
+            "some added coefficient ≠ 0",
+            Alternative::Greater,
+        )
+    }
+
+    pub fn solve(&self) -> Result<bool, ModelSelectionError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<NestedModelsOutcome, ModelSelectionError> {
+        let n = self.full.residuals.len() as f64;
+        let freedom_degrees =
+            (self.full.coefficients.len() - self.reduced.coefficients.len()) as f64;
+        let residual_freedom_degrees = self.full.freedom_degrees;
+        let reduced_sum = residual_squares_sum(&self.reduced);
+        let full_sum = residual_squares_sum(&self.full).min(reduced_sum);
+
+        let (statistic_observed, p_value) = match self.test {
+            NestedTest::PartialF => {
+                let f_observed = (reduced_sum - full_sum)
+                    / freedom_degrees
+                    / (full_sum / residual_freedom_degrees);
+                let p_value = FisherSnedecor::new(freedom_degrees, residual_freedom_degrees)
+                    .map_err(|_| ModelSelectionError::ModelsNotNested)?
+                    .sf(f_observed);
+                (f_observed, p_value)
+            }
+            NestedTest::LikelihoodRatio => {
+                let lambda_observed = n * (reduced_sum / full_sum).ln();
+                let p_value = ChiSquared::new(freedom_degrees)
+                    .map_err(|_| ModelSelectionError::ModelsNotNested)?
+                    .sf(lambda_observed);
+                (lambda_observed, p_value)
+            }
+        };
+
+        let (reduced, full) = (
+            information_criteria(&self.reduced),
+            information_criteria(&self.full),
+        );
+
+        Ok(NestedModelsOutcome {
+            aic_delta: full.aic - reduced.aic,
+            bic_delta: full.bic - reduced.bic,
+            reduced,
+            full,
+            test: self.test,
+            statistic_observed,
+            freedom_degrees,
+            residual_freedom_degrees,
+            p_value,
+            hypotheses: self.hypotheses(),
+            accepted: p_value > self.significance,
+        })
+    }
+}
+
+/// `RSS = Σ wᵢ eᵢ²`, recovered from `σ̂² (n - p)`.
+fn residual_squares_sum(outcome: &RegressionOutcome) -> f64 {
+    outcome.residual_variance * outcome.freedom_degrees
+}
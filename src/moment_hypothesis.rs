@@ -0,0 +1,198 @@
+//! Algorithms for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *sample of a random variable **X***.
+//! **To figure out**: Is it appropriate to **assume** that the skewness, or the excess kurtosis, of **X** is `0`?
+//!
+//! The adjusted sample skewness `G₁` and excess kurtosis `G₂` are divided by their standard errors
+//! under normality, and each ratio is compared with the standard normal distribution on its own,
+//! so that a sample can be found skewed but not heavy-tailed, or the other way round.
+
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::alpha;
+use crate::descriptive::standardized_moment;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum MomentError {
+    SignificanceInvalid,
+    SampleTooSmall,
+    SampleDegenerate,
+    ValueMissing,
+}
+
+impl std::fmt::Display for MomentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MomentError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            MomentError::SampleTooSmall => {
+                write!(
+                    f,
+                    "Sample must contain at least 3 values for the skewness and 4 for the kurtosis"
+                )
+            }
+            MomentError::SampleDegenerate => {
+                write!(f, "Sample must take at least two distinct values")
+            }
+            MomentError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MomentOutcome {
+    /// `G₁` or `G₂`, the adjusted sample skewness or excess kurtosis.
+    pub estimate: f64,
+    /// Standard error of the estimate under normality.
+    pub standard_error: f64,
+    /// `z = G / SE`.
+    pub z_observed: f64,
+    pub z_critical_value: f64,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+/// Test of `H₀: γ₁ = 0` for the skewness `γ₁`.
+pub struct SkewnessHypothesis {
+    sample: Vec<f64>,
+    significance: f64,
+}
+
+impl SkewnessHypothesis {
+    pub fn new(sample: &[f64], significance: f64) -> Result<Self, MomentError> {
+        validate(sample, 3, significance)?;
+
+        Ok(Self {
+            sample: sample.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new("γ₁ = 0", "γ₁ ≠ 0", Alternative::TwoSided)
+    }
+
+    /// Returns `G₁ = g₁ √(n (n - 1)) / (n - 2)` with `g₁ = m₃ / m₂^(3/2)`
+    /// and its standard error `√(6n (n - 1) / ((n - 2)(n + 1)(n + 3)))`.
+    pub fn statistic(&self) -> (f64, f64) {
+        let n = self.sample.len() as f64;
+        let g = standardized_moment(&self.sample, 3);
+
+        (
+            g * (n * (n - 1.)).sqrt() / (n - 2.),
+            skewness_standard_error(n),
+        )
+    }
+
+    pub fn solve(&self) -> Result<bool, MomentError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<MomentOutcome, MomentError> {
+        let (estimate, standard_error) = self.statistic();
+        Ok(decide(
+            estimate,
+            standard_error,
+            self.hypotheses(),
+            self.significance,
+        ))
+    }
+}
+
+/// Test of `H₀: γ₂ = 0` for the excess kurtosis `γ₂`.
+pub struct KurtosisHypothesis {
+    sample: Vec<f64>,
+    significance: f64,
+}
+
+impl KurtosisHypothesis {
+    pub fn new(sample: &[f64], significance: f64) -> Result<Self, MomentError> {
+        validate(sample, 4, significance)?;
+
+        Ok(Self {
+            sample: sample.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new("γ₂ = 0", "γ₂ ≠ 0", Alternative::TwoSided)
+    }
+
+    /// Returns `G₂ = (n - 1) ((n + 1) g₂ + 6) / ((n - 2)(n - 3))` with `g₂ = m₄ / m₂² - 3`
+    /// and its standard error `2 SE(G₁) √((n² - 1) / ((n - 3)(n + 5)))`.
+    pub fn statistic(&self) -> (f64, f64) {
+        let n = self.sample.len() as f64;
+        let g = standardized_moment(&self.sample, 4) - 3.;
+
+        (
+            (n - 1.) * ((n + 1.) * g + 6.) / ((n - 2.) * (n - 3.)),
+            2. * skewness_standard_error(n) * ((n * n - 1.) / ((n - 3.) * (n + 5.))).sqrt(),
+        )
+    }
+
+    pub fn solve(&self) -> Result<bool, MomentError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<MomentOutcome, MomentError> {
+        let (estimate, standard_error) = self.statistic();
+        Ok(decide(
+            estimate,
+            standard_error,
+            self.hypotheses(),
+            self.significance,
+        ))
+    }
+}
+
+fn validate(sample: &[f64], size: usize, significance: f64) -> Result<(), MomentError> {
+    MissingPolicy::Error
+        .apply(sample)
+        .map_err(|_| MomentError::ValueMissing)?;
+
+    if sample.len() < size {
+        return Err(MomentError::SampleTooSmall);
+    }
+
+    alpha::validate(significance, MomentError::SignificanceInvalid)?;
+
+    if sample.iter().all(|x| *x == sample[0]) {
+        return Err(MomentError::SampleDegenerate);
+    }
+
+    Ok(())
+}
+
+fn skewness_standard_error(n: f64) -> f64 {
+    (6. * n * (n - 1.) / ((n - 2.) * (n + 1.) * (n + 3.))).sqrt()
+}
+
+fn decide(
+    estimate: f64,
+    standard_error: f64,
+    hypotheses: Hypotheses,
+    significance: f64,
+) -> MomentOutcome {
+    let standard_normal = Normal::standard();
+    let z_observed = estimate / standard_error;
+    let z_critical_value = standard_normal.inverse_cdf(1. - significance / 2.);
+
+    MomentOutcome {
+        estimate,
+        standard_error,
+        z_observed,
+        z_critical_value,
+        p_value: 2. * standard_normal.sf(z_observed.abs()),
+        hypotheses,
+        accepted: z_observed.abs() < z_critical_value,
+    }
+}
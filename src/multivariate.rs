@@ -0,0 +1,350 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *sample of a random vector **X*** and either
+//! *a hypothetical mean vector* `μ₀` or *sample of a random vector **Y***, normally distributed.
+//! **To figure out**: Is it appropriate to **assume** `E(X) = μ₀`, or `E(X) = E(Y)`?
+//!
+//! Hotelling's `T²` is transformed into the Fisher–Snedecor distributed statistic.
+//!
+//! **Given**: *significance ratio*, *sample of a random vector **X***.
+//! **To figure out**: Is it appropriate to **assume** that **X** is normally distributed?
+//!
+//! Mardia's multivariate skewness and kurtosis are compared with their asymptotic
+//! Chi-Squared and Normal distributions.
+
+use nalgebra::{DMatrix, DVector};
+use statrs::distribution::{ChiSquared, ContinuousCDF, FisherSnedecor, Normal};
+
+use crate::alpha;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum MultivariateError {
+    SampleTooSmall,
+    DimensionsMismatch,
+    SignificanceInvalid,
+    CovarianceSingular,
+    FreedomDegreesInvalid,
+    ValueMissing,
+}
+
+impl std::fmt::Display for MultivariateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MultivariateError::SampleTooSmall => {
+                write!(f, "Sample must contain more observations than variables")
+            }
+            MultivariateError::DimensionsMismatch => {
+                write!(f, "All observations must have the same positive dimension")
+            }
+            MultivariateError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            MultivariateError::CovarianceSingular => {
+                write!(f, "Covariance matrix is singular")
+            }
+            MultivariateError::FreedomDegreesInvalid => {
+                write!(
+                    f,
+                    "Freedom Degrees led to fail in initialization of Fisher-Snedecor"
+                )
+            }
+            MultivariateError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+/// Sample of a random vector as an `n × p` matrix with observations in rows.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MultiSample {
+    data: DMatrix<f64>,
+}
+
+impl MultiSample {
+    pub fn new(observations: &[Vec<f64>]) -> Result<Self, MultivariateError> {
+        if observations.is_empty() {
+            return Err(MultivariateError::SampleTooSmall);
+        }
+
+        let dimension = observations[0].len();
+        if dimension == 0
+            || observations
+                .iter()
+                .any(|observation| observation.len() != dimension)
+        {
+            return Err(MultivariateError::DimensionsMismatch);
+        }
+
+        Self::from_matrix(DMatrix::from_fn(observations.len(), dimension, |i, j| {
+            observations[i][j]
+        }))
+    }
+
+    pub fn from_matrix(data: DMatrix<f64>) -> Result<Self, MultivariateError> {
+        if data.nrows() == 0 {
+            return Err(MultivariateError::SampleTooSmall);
+        }
+
+        if data.ncols() == 0 {
+            return Err(MultivariateError::DimensionsMismatch);
+        }
+
+        MissingPolicy::Error
+            .apply(data.as_slice())
+            .map_err(|_| MultivariateError::ValueMissing)?;
+
+        Ok(Self { data })
+    }
+
+    pub fn data(&self) -> &DMatrix<f64> {
+        &self.data
+    }
+
+    /// Number of observations `n`.
+    pub fn size(&self) -> usize {
+        self.data.nrows()
+    }
+
+    /// Number of variables `p`.
+    pub fn dimension(&self) -> usize {
+        self.data.ncols()
+    }
+
+    pub fn mean(&self) -> DVector<f64> {
+        self.data.row_mean().transpose()
+    }
+
+    /// Covariance matrix with the `1 / (n - 1)` normalization.
+    pub fn covariance(&self) -> DMatrix<f64> {
+        let centered = self.centered();
+        centered.transpose() * &centered / (self.size() as f64 - 1.)
+    }
+
+    /// Correlation matrix; `NaN` entries mark constant variables.
+    pub fn correlation(&self) -> DMatrix<f64> {
+        let covariance = self.covariance();
+        let std_devs = covariance.diagonal().map(f64::sqrt);
+        DMatrix::from_fn(self.dimension(), self.dimension(), |i, j| {
+            covariance[(i, j)] / (std_devs[i] * std_devs[j])
+        })
+    }
+
+    /// Observations with the mean vector subtracted.
+    pub fn centered(&self) -> DMatrix<f64> {
+        let mean = self.data.row_mean();
+        let mut centered = self.data.clone();
+        for mut row in centered.row_iter_mut() {
+            row -= &mean;
+        }
+        centered
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct HotellingOutcome {
+    pub t_squared: f64,
+    pub fisher_snedecor_observed: f64,
+    /// Freedom degrees of the numerator and of the denominator.
+    pub freedom_degrees: (f64, f64),
+    pub fisher_snedecor_critical_value: f64,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    /// Whether the hypothesis of equal mean vectors is accepted.
+    pub accepted: bool,
+}
+
+pub struct HotellingHypothesis {
+    t_squared: f64,
+    freedom_degrees: (f64, f64),
+    /// `F = scale · T²`.
+    scale: f64,
+    hypotheses: Hypotheses,
+    significance: f64,
+}
+
+impl HotellingHypothesis {
+    /// `T² = n (x̄ - μ₀)ᵀ S⁻¹ (x̄ - μ₀)` with `(n - p) / (p (n - 1)) · T² ~ F(p, n - p)`.
+    pub fn one_sample(
+        sample: &MultiSample,
+        mu: &[f64],
+        significance: f64,
+    ) -> Result<Self, MultivariateError> {
+        let (n, p) = (sample.size(), sample.dimension());
+        if mu.len() != p {
+            return Err(MultivariateError::DimensionsMismatch);
+        }
+
+        if n <= p {
+            return Err(MultivariateError::SampleTooSmall);
+        }
+
+        validate(significance)?;
+
+        let difference = sample.mean() - DVector::from_column_slice(mu);
+        let t_squared = n as f64 * quadratic_form(&sample.covariance(), &difference)?;
+
+        let (n, p) = (n as f64, p as f64);
+        Ok(Self {
+            t_squared,
+            freedom_degrees: (p, n - p),
+            scale: (n - p) / (p * (n - 1.)),
+            hypotheses: Hypotheses::new(
+                format!("E(X) = {mu:?}"),
+                format!("E(X) ≠ {mu:?}"),
+                Alternative::Greater,
+            ),
+            significance,
+        })
+    }
+
+    /// `T² = n m / (n + m) · dᵀ S⁻¹ d` with the pooled covariance `S`
+    /// and `(n + m - p - 1) / (p (n + m - 2)) · T² ~ F(p, n + m - p - 1)`.
+    pub fn two_sample(
+        x_sample: &MultiSample,
+        y_sample: &MultiSample,
+        significance: f64,
+    ) -> Result<Self, MultivariateError> {
+        let (n, m, p) = (x_sample.size(), y_sample.size(), x_sample.dimension());
+        if y_sample.dimension() != p {
+            return Err(MultivariateError::DimensionsMismatch);
+        }
+
+        if n < 2 || m < 2 || n + m <= p + 1 {
+            return Err(MultivariateError::SampleTooSmall);
+        }
+
+        validate(significance)?;
+
+        let (n, m, p) = (n as f64, m as f64, p as f64);
+        let pooled_covariance =
+            (x_sample.covariance() * (n - 1.) + y_sample.covariance() * (m - 1.)) / (n + m - 2.);
+        let difference = x_sample.mean() - y_sample.mean();
+        let t_squared = n * m / (n + m) * quadratic_form(&pooled_covariance, &difference)?;
+
+        Ok(Self {
+            t_squared,
+            freedom_degrees: (p, n + m - p - 1.),
+            scale: (n + m - p - 1.) / (p * (n + m - 2.)),
+            hypotheses: Hypotheses::new("E(X) = E(Y)", "E(X) ≠ E(Y)", Alternative::Greater),
+            significance,
+        })
+    }
+
+    pub fn solve(&self) -> Result<bool, MultivariateError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<HotellingOutcome, MultivariateError> {
+        let fisher_snedecor_dist =
+            FisherSnedecor::new(self.freedom_degrees.0, self.freedom_degrees.1)
+                .map_err(|_| MultivariateError::FreedomDegreesInvalid)?;
+        let fisher_snedecor_observed = self.scale * self.t_squared;
+        let fisher_snedecor_critical_value =
+            fisher_snedecor_dist.inverse_cdf(1. - self.significance);
+
+        Ok(HotellingOutcome {
+            t_squared: self.t_squared,
+            fisher_snedecor_observed,
+            freedom_degrees: self.freedom_degrees,
+            fisher_snedecor_critical_value,
+            p_value: fisher_snedecor_dist.sf(fisher_snedecor_observed),
+            hypotheses: self.hypotheses.clone(),
+            accepted: fisher_snedecor_observed < fisher_snedecor_critical_value,
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct MardiaOutcome {
+    /// `b₁,ₚ = 1 / n² · Σᵢ Σⱼ dᵢⱼ³` with `dᵢⱼ = (xᵢ - x̄)ᵀ S⁻¹ (xⱼ - x̄)` and the biased `S`.
+    pub skewness: f64,
+    /// `b₂,ₚ = 1 / n · Σᵢ dᵢᵢ²`.
+    pub kurtosis: f64,
+    /// `n b₁,ₚ / 6 ~ χ²(p (p + 1) (p + 2) / 6)`.
+    pub skewness_chi_squared: f64,
+    pub skewness_freedom_degrees: f64,
+    pub skewness_p_value: f64,
+    /// `(b₂,ₚ - p (p + 2)) / √(8 p (p + 2) / n) ~ N(0, 1)`.
+    pub kurtosis_z: f64,
+    pub kurtosis_p_value: f64,
+    /// Whether the hypothesis of normality is accepted by both the skewness and the kurtosis.
+    pub accepted: bool,
+}
+
+pub struct MardiaHypothesis {
+    sample: MultiSample,
+    significance: f64,
+}
+
+impl MardiaHypothesis {
+    pub fn new(sample: &MultiSample, significance: f64) -> Result<Self, MultivariateError> {
+        if sample.size() <= sample.dimension() {
+            return Err(MultivariateError::SampleTooSmall);
+        }
+
+        validate(significance)?;
+
+        Ok(Self {
+            sample: sample.clone(),
+            significance,
+        })
+    }
+
+    pub fn solve(&self) -> Result<bool, MultivariateError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<MardiaOutcome, MultivariateError> {
+        let (n, p) = (self.sample.size() as f64, self.sample.dimension() as f64);
+        let centered = self.sample.centered();
+        let biased_covariance = centered.transpose() * &centered / n;
+
+        let cholesky = biased_covariance
+            .cholesky()
+            .ok_or(MultivariateError::CovarianceSingular)?;
+        let distances = &centered * cholesky.solve(&centered.transpose());
+
+        let skewness = distances.iter().map(|d| d.powi(3)).sum::<f64>() / n.powi(2);
+        let kurtosis = distances.diagonal().iter().map(|d| d.powi(2)).sum::<f64>() / n;
+
+        let skewness_chi_squared = n * skewness / 6.;
+        let skewness_freedom_degrees = p * (p + 1.) * (p + 2.) / 6.;
+        let skewness_p_value = ChiSquared::new(skewness_freedom_degrees)
+            .map_err(|_| MultivariateError::FreedomDegreesInvalid)?
+            .sf(skewness_chi_squared);
+
+        let kurtosis_z = (kurtosis - p * (p + 2.)) / (8. * p * (p + 2.) / n).sqrt();
+        let kurtosis_p_value = 2. * Normal::standard().sf(kurtosis_z.abs());
+
+        Ok(MardiaOutcome {
+            skewness,
+            kurtosis,
+            skewness_chi_squared,
+            skewness_freedom_degrees,
+            skewness_p_value,
+            kurtosis_z,
+            kurtosis_p_value,
+            accepted: skewness_p_value > self.significance && kurtosis_p_value > self.significance,
+        })
+    }
+}
+
+fn validate(significance: f64) -> Result<(), MultivariateError> {
+    alpha::validate(significance, MultivariateError::SignificanceInvalid)?;
+
+    Ok(())
+}
+
+/// `vᵀ A⁻¹ v` for a symmetric positive definite `A`.
+fn quadratic_form(matrix: &DMatrix<f64>, vector: &DVector<f64>) -> Result<f64, MultivariateError> {
+    let cholesky = matrix
+        .clone()
+        .cholesky()
+        .ok_or(MultivariateError::CovarianceSingular)?;
+
+    Ok(vector.dot(&cholesky.solve(vector)))
+}
@@ -0,0 +1,196 @@
+//! Constructors from `ndarray` arrays, for callers already working in the Rust scientific stack.
+
+use ndarray::{Array2, ArrayView1, ArrayView2};
+
+use crate::anova::{
+    AnovaError, GamesHowell, IntraclassCorrelation, LeveneHypothesis, OneWayAnova,
+    RandomEffectsAnova, RepeatedMeasuresAnova, WelchAnova,
+};
+use crate::contingency::{ContingencyError, IndependenceHypothesis};
+use crate::mann_whitney_hypothesis::{MWHError, MannWhitneyHypothesis};
+use crate::multivariate::{MultiSample, MultivariateError};
+use crate::normal_distribution_hypothesis::{
+    CompleteNDHProblemSituation, IncompleteNDHProblemSituation, NDHError,
+};
+use crate::same_mean_hypothesis::{SMHError, SameMeanHypothesis, Variances, YuenTTest};
+use crate::same_variance_hypothesis::SameVarianceHypothesis;
+
+impl CompleteNDHProblemSituation {
+    pub fn from_arrays(
+        empirical_sample: ArrayView1<f64>,
+        theoretical_sample: ArrayView1<f64>,
+        significance: f64,
+    ) -> Result<Self, NDHError> {
+        Self::new(
+            &empirical_sample.to_vec(),
+            &theoretical_sample.to_vec(),
+            significance,
+        )
+    }
+}
+
+impl IncompleteNDHProblemSituation {
+    /// `edges` are the `k + 1` boundaries of the `k` random value ranges, as produced by histogram routines.
+    pub fn from_arrays(
+        edges: ArrayView1<f64>,
+        empirical_sample: ArrayView1<f64>,
+        significance: f64,
+    ) -> Result<Self, NDHError> {
+        if edges.len() != empirical_sample.len() + 1 {
+            return Err(NDHError::NonEqualSamplesLengths);
+        }
+
+        let random_value_ranges: Vec<_> = edges
+            .windows(2)
+            .into_iter()
+            .map(|edge| (edge[0], edge[1]))
+            .collect();
+
+        Self::new(
+            &random_value_ranges,
+            &empirical_sample.to_vec(),
+            significance,
+        )
+    }
+
+    pub fn from_raw_array(sample: ArrayView1<f64>, significance: f64) -> Result<Self, NDHError> {
+        Self::from_raw_sample(&sample.to_vec(), significance)
+    }
+}
+
+impl IndependenceHypothesis {
+    pub fn from_array(table: ArrayView2<f64>, significance: f64) -> Result<Self, ContingencyError> {
+        Self::new(&owned_rows(table), significance)
+    }
+}
+
+impl SameVarianceHypothesis {
+    pub fn from_arrays(
+        x_sample: ArrayView1<f64>,
+        y_sample: ArrayView1<f64>,
+        significance: f64,
+    ) -> Self {
+        Self::new(&x_sample.to_vec(), &y_sample.to_vec(), significance)
+    }
+}
+
+impl SameMeanHypothesis {
+    pub fn from_arrays(
+        x_sample: ArrayView1<f64>,
+        y_sample: ArrayView1<f64>,
+        variances: Variances,
+        significance: f64,
+    ) -> Result<Self, SMHError> {
+        Self::new(
+            &x_sample.to_vec(),
+            &y_sample.to_vec(),
+            variances,
+            significance,
+        )
+    }
+}
+
+impl YuenTTest {
+    pub fn from_arrays(
+        x_sample: ArrayView1<f64>,
+        y_sample: ArrayView1<f64>,
+        trimming: f64,
+        significance: f64,
+    ) -> Result<Self, SMHError> {
+        Self::new(
+            &x_sample.to_vec(),
+            &y_sample.to_vec(),
+            trimming,
+            significance,
+        )
+    }
+}
+
+impl MannWhitneyHypothesis {
+    pub fn from_arrays(
+        x_sample: ArrayView1<f64>,
+        y_sample: ArrayView1<f64>,
+        significance: f64,
+    ) -> Result<Self, MWHError> {
+        Self::new(&x_sample.to_vec(), &y_sample.to_vec(), significance)
+    }
+}
+
+impl OneWayAnova {
+    pub fn from_arrays(groups: &[ArrayView1<f64>], significance: f64) -> Result<Self, AnovaError> {
+        Self::new(&owned_groups(groups), significance)
+    }
+}
+
+impl LeveneHypothesis {
+    pub fn from_arrays(groups: &[ArrayView1<f64>], significance: f64) -> Result<Self, AnovaError> {
+        Self::new(&owned_groups(groups), significance)
+    }
+}
+
+impl WelchAnova {
+    pub fn from_arrays(groups: &[ArrayView1<f64>], significance: f64) -> Result<Self, AnovaError> {
+        Self::new(&owned_groups(groups), significance)
+    }
+}
+
+impl RandomEffectsAnova {
+    pub fn from_arrays(groups: &[ArrayView1<f64>], significance: f64) -> Result<Self, AnovaError> {
+        Self::new(&owned_groups(groups), significance)
+    }
+}
+
+impl GamesHowell {
+    pub fn from_arrays(groups: &[ArrayView1<f64>], significance: f64) -> Result<Self, AnovaError> {
+        Self::new(&owned_groups(groups), significance)
+    }
+}
+
+impl RepeatedMeasuresAnova {
+    /// Subjects are the rows of the array, the conditions its columns.
+    pub fn from_array(subjects: ArrayView2<f64>, significance: f64) -> Result<Self, AnovaError> {
+        Self::new(&owned_rows(subjects), significance)
+    }
+}
+
+impl IntraclassCorrelation {
+    /// Subjects are the rows of the array, the raters its columns.
+    pub fn from_array(subjects: ArrayView2<f64>, significance: f64) -> Result<Self, AnovaError> {
+        Self::new(&owned_rows(subjects), significance)
+    }
+}
+
+fn owned_groups(groups: &[ArrayView1<f64>]) -> Vec<Vec<f64>> {
+    groups.iter().map(|group| group.to_vec()).collect()
+}
+
+fn owned_rows(array: ArrayView2<f64>) -> Vec<Vec<f64>> {
+    array.rows().into_iter().map(|row| row.to_vec()).collect()
+}
+
+impl TryFrom<ArrayView2<'_, f64>> for MultiSample {
+    type Error = MultivariateError;
+
+    /// Observations are the rows of the array.
+    fn try_from(observations: ArrayView2<f64>) -> Result<Self, Self::Error> {
+        let (n, p) = observations.dim();
+        Self::from_matrix(nalgebra::DMatrix::from_fn(n, p, |i, j| {
+            observations[(i, j)]
+        }))
+    }
+}
+
+impl TryFrom<Array2<f64>> for MultiSample {
+    type Error = MultivariateError;
+
+    fn try_from(observations: Array2<f64>) -> Result<Self, Self::Error> {
+        Self::try_from(observations.view())
+    }
+}
+
+impl From<&MultiSample> for Array2<f64> {
+    fn from(sample: &MultiSample) -> Self {
+        let data = sample.data();
+        Array2::from_shape_fn((data.nrows(), data.ncols()), |(i, j)| data[(i, j)])
+    }
+}
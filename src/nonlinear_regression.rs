@@ -0,0 +1,439 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *observations* `(xᵢ, yᵢ)` and a *model* `y = f(x; θ) + ε` nonlinear in its parameters `θ`.
+//! **To figure out**: What are the parameters minimizing `Σ (yᵢ - f(xᵢ; θ))²`, and how precise are they?
+//!
+//! The sum of squares is minimized by the Levenberg–Marquardt algorithm, which blends Gauss–Newton steps
+//! with gradient descent steps scaled by the diagonal of `JᵀJ`, starting from an initial guess.
+//! The built-in models guess their starting values from linearizations of the data and have analytic
+//! derivatives; models given as closures need an initial guess and are differentiated numerically.
+//! Standard errors are asymptotic, the square roots of the diagonal of `σ̂² (JᵀJ)⁻¹` at the optimum
+//! with `σ̂² = Σ eᵢ² / (n - p)`.
+
+use nalgebra::{DMatrix, DVector};
+
+use crate::fit::Estimate;
+use crate::missing::MissingPolicy;
+use crate::numeric::mean;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum NonlinearError {
+    DimensionsMismatch,
+    SampleTooSmall,
+    InitialGuessInvalid,
+    NotConverged,
+    JacobianSingular,
+    ValueMissing,
+}
+
+impl std::fmt::Display for NonlinearError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            NonlinearError::DimensionsMismatch => {
+                write!(f, "Every observation must have exactly one response")
+            }
+            NonlinearError::SampleTooSmall => {
+                write!(f, "Sample must contain more observations than parameters")
+            }
+            NonlinearError::InitialGuessInvalid => {
+                write!(
+                    f,
+                    "Initial guess must give a finite value to every parameter of the model"
+                )
+            }
+            NonlinearError::NotConverged => {
+                write!(
+                    f,
+                    "Levenberg–Marquardt iterations did not converge; try another initial guess"
+                )
+            }
+            NonlinearError::JacobianSingular => {
+                write!(
+                    f,
+                    "Parameters cannot be identified from the data at the optimum"
+                )
+            }
+            NonlinearError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+/// Built-in models, with their parameters in the order of [`Model::parameter_names`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Model {
+    /// `a e^(bx)`, growth for `b > 0` and decay for `b < 0`.
+    Exponential,
+    /// `K / (1 + e^(-r(x - m)))` with the capacity `K`, the rate `r` and the midpoint `m`.
+    Logistic,
+    /// `V x / (K + x)` with the maximum rate `V` and the Michaelis constant `K`.
+    MichaelisMenten,
+}
+
+impl Model {
+    pub fn parameter_names(&self) -> &'static [&'static str] {
+        match self {
+            Model::Exponential => &["a", "b"],
+            Model::Logistic => &["K", "r", "m"],
+            Model::MichaelisMenten => &["V", "K"],
+        }
+    }
+
+    /// `f(x; θ)`.
+    pub fn evaluate(&self, x: f64, parameters: &[f64]) -> f64 {
+        match *self {
+            Model::Exponential => parameters[0] * (parameters[1] * x).exp(),
+            Model::Logistic => parameters[0] / (1. + (-parameters[1] * (x - parameters[2])).exp()),
+            Model::MichaelisMenten => parameters[0] * x / (parameters[1] + x),
+        }
+    }
+
+    /// `∂f(x; θ) / ∂θ`.
+    pub fn gradient(&self, x: f64, parameters: &[f64]) -> Vec<f64> {
+        match *self {
+            Model::Exponential => {
+                let growth = (parameters[1] * x).exp();
+                vec![growth, parameters[0] * x * growth]
+            }
+            Model::Logistic => {
+                let (capacity, rate, midpoint) = (parameters[0], parameters[1], parameters[2]);
+                let decay = (-rate * (x - midpoint)).exp();
+                let denominator = (1. + decay).powi(2);
+                vec![
+                    1. / (1. + decay),
+                    capacity * decay * (x - midpoint) / denominator,
+                    -capacity * decay * rate / denominator,
+                ]
+            }
+            Model::MichaelisMenten => {
+                let (rate, constant) = (parameters[0], parameters[1]);
+                vec![x / (constant + x), -rate * x / (constant + x).powi(2)]
+            }
+        }
+    }
+
+    /// Starting values of the parameters guessed from the observations.
+    pub fn initial_guess(&self, x: &[f64], y: &[f64]) -> Vec<f64> {
+        match self {
+            Model::Exponential => {
+                // `ln |y| = ln |a| + bx` when all the responses have the same sign.
+                let sign = if y.iter().all(|y| *y < 0.) { -1. } else { 1. };
+                let logarithms: Vec<_> = y.iter().map(|y| (sign * y).ln()).collect();
+                match line(x, &logarithms) {
+                    Some((intercept, slope)) => vec![sign * intercept.exp(), slope],
+                    None => vec![mean(y), 0.],
+                }
+            }
+            Model::Logistic => {
+                // `ln(y / (K - y)) = r(x - m)` for a capacity slightly above the largest response.
+                let capacity = 1.05 * y.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                let (abscissae, logits): (Vec<_>, Vec<_>) = x
+                    .iter()
+                    .zip(y)
+                    .filter(|(_, y)| **y > 0. && **y < capacity)
+                    .map(|(x, y)| (*x, (y / (capacity - y)).ln()))
+                    .unzip();
+                match line(&abscissae, &logits) {
+                    Some((intercept, slope)) if slope != 0. => {
+                        vec![capacity, slope, -intercept / slope]
+                    }
+                    _ => vec![capacity, 1., mean(x)],
+                }
+            }
+            Model::MichaelisMenten => {
+                // The maximum rate is about the largest response, reached halfway at `x = K`.
+                let rate = y.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                let halfway = x
+                    .iter()
+                    .zip(y)
+                    .min_by(|(_, a), (_, b)| {
+                        (*a - rate / 2.).abs().total_cmp(&(*b - rate / 2.).abs())
+                    })
+                    .map_or(1., |(x, _)| *x);
+                vec![rate, halfway]
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct NonlinearOutcome {
+    /// Estimates with their asymptotic standard errors.
+    pub parameters: Vec<Estimate>,
+    /// Asymptotic covariance matrix of the parameters, by rows.
+    pub parameter_covariance: Vec<Vec<f64>>,
+    pub fitted: Vec<f64>,
+    pub residuals: Vec<f64>,
+    /// `σ̂² = Σ eᵢ² / (n - p)`.
+    pub residual_variance: f64,
+    /// `n - p`.
+    pub freedom_degrees: f64,
+    /// Levenberg–Marquardt iterations taken.
+    pub iterations: usize,
+}
+
+impl NonlinearOutcome {
+    /// Values of the estimated parameters.
+    pub fn values(&self) -> Vec<f64> {
+        self.parameters
+            .iter()
+            .map(|estimate| estimate.value)
+            .collect()
+    }
+}
+
+/// `f(x; θ)` given as a closure.
+type Function<'a> = Box<dyn Fn(f64, &[f64]) -> f64 + 'a>;
+
+const DEFAULT_MAX_ITERATIONS: usize = 200;
+
+/// Relative change of the sum of squares or of the parameters at which the iterations stop.
+const TOLERANCE: f64 = 1e-10;
+
+/// Damping beyond which no step can decrease the sum of squares any more.
+const MAX_DAMPING: f64 = 1e16;
+
+pub struct NonlinearRegression<'a> {
+    x_sample: Vec<f64>,
+    y_sample: Vec<f64>,
+    names: Vec<&'static str>,
+    function: Function<'a>,
+    /// Analytic derivatives of the built-in models.
+    model: Option<Model>,
+    initial_guess: Vec<f64>,
+    max_iterations: usize,
+}
+
+impl<'a> NonlinearRegression<'a> {
+    /// Fits a built-in model, starting from [`Model::initial_guess`] unless another one is given
+    /// with [`NonlinearRegression::with_initial_guess`].
+    pub fn new(x: &[f64], y: &[f64], model: Model) -> Result<Self, NonlinearError> {
+        validate(x, y, model.parameter_names().len())?;
+
+        Ok(Self {
+            x_sample: x.to_owned(),
+            y_sample: y.to_owned(),
+            names: model.parameter_names().to_owned(),
+            function: Box::new(move |x, parameters| model.evaluate(x, parameters)),
+            model: Some(model),
+            initial_guess: model.initial_guess(x, y),
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+        })
+    }
+
+    /// Fits `y = function(x, θ)` with the parameters named by `names`, starting from `initial_guess`.
+    pub fn custom(
+        x: &[f64],
+        y: &[f64],
+        names: &[&'static str],
+        initial_guess: &[f64],
+        function: impl Fn(f64, &[f64]) -> f64 + 'a,
+    ) -> Result<Self, NonlinearError> {
+        validate(x, y, names.len())?;
+
+        Self {
+            x_sample: x.to_owned(),
+            y_sample: y.to_owned(),
+            names: names.to_owned(),
+            function: Box::new(function),
+            model: None,
+            initial_guess: Vec::new(),
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+        }
+        .with_initial_guess(initial_guess)
+    }
+
+    pub fn with_initial_guess(mut self, initial_guess: &[f64]) -> Result<Self, NonlinearError> {
+        if initial_guess.len() != self.names.len() || !initial_guess.iter().all(|p| p.is_finite()) {
+            return Err(NonlinearError::InitialGuessInvalid);
+        }
+
+        self.initial_guess = initial_guess.to_owned();
+        Ok(self)
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    pub fn outcome(&self) -> Result<NonlinearOutcome, NonlinearError> {
+        let (n, p) = (self.x_sample.len(), self.names.len());
+
+        let mut parameters = DVector::from_column_slice(&self.initial_guess);
+        let mut squares_sum = self.squares_sum(parameters.as_slice());
+        if !squares_sum.is_finite() {
+            return Err(NonlinearError::InitialGuessInvalid);
+        }
+
+        let mut damping = 1e-3;
+        let mut iterations = 0;
+        loop {
+            if iterations == self.max_iterations {
+                return Err(NonlinearError::NotConverged);
+            }
+            iterations += 1;
+
+            let jacobian = self.jacobian(parameters.as_slice());
+            let residuals = DVector::from_iterator(n, self.residuals(parameters.as_slice()));
+            let normal = jacobian.transpose() * &jacobian;
+            let gradient = jacobian.transpose() * residuals;
+
+            // Marquardt's scaling by the diagonal makes the steps invariant to the units of the parameters.
+            let step = loop {
+                let mut damped = normal.clone();
+                for j in 0..p {
+                    damped[(j, j)] += damping * normal[(j, j)].max(f64::MIN_POSITIVE);
+                }
+                let step = damped.lu().solve(&gradient);
+                match step {
+                    Some(step)
+                        if self.squares_sum((&parameters + &step).as_slice()) < squares_sum =>
+                    {
+                        damping = (damping / 10.).max(f64::EPSILON);
+                        break Some(step);
+                    }
+                    _ if damping < MAX_DAMPING => damping *= 10.,
+                    _ => break None,
+                }
+            };
+
+            // No step decreases the sum of squares: the parameters are at the minimum.
+            let Some(step) = step else {
+                break;
+            };
+            let candidate = &parameters + &step;
+            let candidate_sum = self.squares_sum(candidate.as_slice());
+            let converged = squares_sum - candidate_sum <= TOLERANCE * (squares_sum + TOLERANCE)
+                || step.norm() <= TOLERANCE * (parameters.norm() + TOLERANCE);
+            parameters = candidate;
+            squares_sum = candidate_sum;
+            if converged {
+                break;
+            }
+        }
+
+        let freedom_degrees = (n - p) as f64;
+        let residual_variance = squares_sum / freedom_degrees;
+        let jacobian = self.jacobian(parameters.as_slice());
+        let covariance = (jacobian.transpose() * &jacobian)
+            .try_inverse()
+            .ok_or(NonlinearError::JacobianSingular)?
+            * residual_variance;
+
+        let fitted: Vec<_> = self
+            .x_sample
+            .iter()
+            .map(|x| (self.function)(*x, parameters.as_slice()))
+            .collect();
+
+        Ok(NonlinearOutcome {
+            parameters: self
+                .names
+                .iter()
+                .enumerate()
+                .map(|(j, &name)| Estimate {
+                    name,
+                    value: parameters[j],
+                    standard_error: covariance[(j, j)].sqrt(),
+                })
+                .collect(),
+            parameter_covariance: covariance
+                .row_iter()
+                .map(|row| row.iter().copied().collect())
+                .collect(),
+            residuals: self
+                .y_sample
+                .iter()
+                .zip(&fitted)
+                .map(|(y, fitted)| y - fitted)
+                .collect(),
+            fitted,
+            residual_variance,
+            freedom_degrees,
+            iterations,
+        })
+    }
+
+    fn residuals(&self, parameters: &[f64]) -> impl Iterator<Item = f64> {
+        self.x_sample
+            .iter()
+            .zip(&self.y_sample)
+            .map(move |(x, y)| y - (self.function)(*x, parameters))
+    }
+
+    /// `Σ eᵢ²`, infinite if the model is undefined at the parameters.
+    fn squares_sum(&self, parameters: &[f64]) -> f64 {
+        let sum = self.residuals(parameters).map(|e| e * e).sum::<f64>();
+        if sum.is_nan() { f64::INFINITY } else { sum }
+    }
+
+    /// `n × p` matrix of `∂f(xᵢ; θ) / ∂θⱼ`, by central differences for the closures.
+    fn jacobian(&self, parameters: &[f64]) -> DMatrix<f64> {
+        let (n, p) = (self.x_sample.len(), parameters.len());
+        match self.model {
+            Some(model) => DMatrix::from_row_iterator(
+                n,
+                p,
+                self.x_sample
+                    .iter()
+                    .flat_map(|x| model.gradient(*x, parameters)),
+            ),
+            None => DMatrix::from_fn(n, p, |i, j| {
+                let step = f64::EPSILON.cbrt() * parameters[j].abs().max(1.);
+                let mut shifted = parameters.to_owned();
+                shifted[j] = parameters[j] + step;
+                let forward = (self.function)(self.x_sample[i], &shifted);
+                shifted[j] = parameters[j] - step;
+                let backward = (self.function)(self.x_sample[i], &shifted);
+                (forward - backward) / (2. * step)
+            }),
+        }
+    }
+}
+
+/// Least squares line `(intercept, slope)`, if there are two distinct abscissae.
+fn line(x: &[f64], y: &[f64]) -> Option<(f64, f64)> {
+    if x.len() < 2 || y.iter().any(|y| !y.is_finite()) {
+        return None;
+    }
+
+    let (x_mean, y_mean) = (mean(x), mean(y));
+    let spread = x.iter().map(|x| (x - x_mean).powi(2)).sum::<f64>();
+    if spread == 0. {
+        return None;
+    }
+    let slope = x
+        .iter()
+        .zip(y)
+        .map(|(x, y)| (x - x_mean) * (y - y_mean))
+        .sum::<f64>()
+        / spread;
+
+    Some((y_mean - slope * x_mean, slope))
+}
+
+fn validate(x: &[f64], y: &[f64], parameters_count: usize) -> Result<(), NonlinearError> {
+    MissingPolicy::Error
+        .apply(x)
+        .and(MissingPolicy::Error.apply(y))
+        .map_err(|_| NonlinearError::ValueMissing)?;
+
+    if x.len() != y.len() {
+        return Err(NonlinearError::DimensionsMismatch);
+    }
+
+    if parameters_count == 0 {
+        return Err(NonlinearError::InitialGuessInvalid);
+    }
+
+    if x.len() <= parameters_count {
+        return Err(NonlinearError::SampleTooSmall);
+    }
+
+    Ok(())
+}
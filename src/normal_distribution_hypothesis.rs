@@ -4,11 +4,24 @@
 //!    **To figure out**: Is it appropriate to **assume** that the empirical sample is a sample of a **Normal Distribution**?
 //!
 //! -  **Given**: *significance ratio*, *empirical frequency sample*, *random value
-//!             ranges* corresponding to the *empirical frequency sample*.  
+//!    ranges* corresponding to the *empirical frequency sample*.  
 //!    **To figure out**: Is it appropriate to **assume** that the sample is a sample of a **Normal Distribution**?
 //!     
 
-use statrs::distribution::{ChiSquared, ContinuousCDF, Normal};
+use statistics_problems_core::decisions::{self, DecisionError};
+use statrs::distribution::{ChiSquared, ContinuousCDF};
+
+use crate::alpha;
+use crate::answers::{self, Solution, UserAnswer, Verification};
+use crate::critical_region::CriticalRegion;
+use crate::critical_values::{CriticalValueError, CriticalValueSource, StatrsQuantiles};
+use crate::descriptive::{grouped_central_moment, grouped_moment, grouped_standardized_moment};
+use crate::fit::{Estimate, Family, Fit, FitError, Method, fit_grouped};
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+use crate::numeric::compensated_sum;
+use crate::residuals::Residuals;
+use crate::rounding::RoundingPolicy;
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
 #[non_exhaustive]
@@ -16,6 +29,11 @@ pub enum NDHError {
     NonEqualSamplesLengths,
     SignificanceInvalid,
     FreedomDegreesInvalid,
+    SampleDegenerate,
+    CriticalValueNotTabulated,
+    ProportionsInvalid,
+    ValueMissing,
+    StatisticNotFinite,
 }
 
 impl std::fmt::Display for NDHError {
@@ -33,6 +51,27 @@ impl std::fmt::Display for NDHError {
                     "Freedom Degrees led to fail in initialization of Gamma underlying Chi"
                 )
             }
+            NDHError::SampleDegenerate => {
+                write!(f, "Sample must contain at least two distinct values")
+            }
+            NDHError::CriticalValueNotTabulated => {
+                write!(
+                    f,
+                    "Standard tables hold no critical value for these freedom degrees and significance"
+                )
+            }
+            NDHError::ProportionsInvalid => {
+                write!(f, "Proportions must be positive and sum to 1")
+            }
+            NDHError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+            NDHError::StatisticNotFinite => {
+                write!(
+                    f,
+                    "Chi-Squared statistic is not finite: an expected frequency is zero or undefined"
+                )
+            }
         }
     }
 }
@@ -41,8 +80,77 @@ pub trait NDHProblemSituation {
     fn empirical_sample(&self) -> Vec<f64>;
     fn theoretical_sample(&self) -> Vec<f64>;
     fn significance(&self) -> f64;
+
+    /// Random value ranges of the classes, if the situation knows them.
+    fn random_value_ranges(&self) -> Option<&[(f64, f64)]> {
+        None
+    }
+
+    /// Distribution the sample is assumed to follow, as stated in `H₀`.
+    fn null_distribution(&self) -> String {
+        "the theoretical distribution".to_string()
+    }
+
+    /// Number of parameters of the distribution estimated from the sample,
+    /// each of which takes a freedom degree away; the mean and the standard deviation by default.
+    fn estimated_parameters(&self) -> usize {
+        2
+    }
+
+    /// Confidence interval of `σ` at the `1 - significance` level, if the situation estimates `σ`.
+    fn std_dev_confidence_interval(&self) -> Option<(f64, f64)> {
+        None
+    }
+}
+
+/// Expected frequencies of the classes, given either as counts or as proportions of the observed total.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Expected<'a> {
+    Counts(&'a [f64]),
+    /// Must sum to `1`; they are scaled by the total of the empirical frequencies.
+    Proportions(&'a [f64]),
+}
+
+/// Tolerance of the sum of the proportions to `1`.
+const PROPORTIONS_SUM_TOLERANCE: f64 = 1e-6;
+
+impl Expected<'_> {
+    fn len(&self) -> usize {
+        match self {
+            Expected::Counts(counts) => counts.len(),
+            Expected::Proportions(proportions) => proportions.len(),
+        }
+    }
+
+    /// Proportions of the classes, validated or derived from the counts.
+    fn proportions(&self) -> Result<Vec<f64>, NDHError> {
+        let (values, total) = match self {
+            Expected::Counts(counts) => (counts, counts.iter().sum::<f64>()),
+            Expected::Proportions(proportions) => {
+                if (proportions.iter().sum::<f64>() - 1.).abs() > PROPORTIONS_SUM_TOLERANCE {
+                    return Err(NDHError::ProportionsInvalid);
+                }
+                (proportions, 1.)
+            }
+        };
+
+        if values.iter().any(|v| v.is_nan() || *v <= 0.) {
+            return Err(NDHError::ProportionsInvalid);
+        }
+
+        Ok(values.iter().map(|v| v / total).collect())
+    }
+
+    /// Expected counts for the observed `total`.
+    fn counts(&self, total: f64) -> Result<Vec<f64>, NDHError> {
+        match self {
+            Expected::Counts(counts) => Ok(counts.to_vec()),
+            Expected::Proportions(_) => Ok(self.proportions()?.iter().map(|p| total * p).collect()),
+        }
+    }
 }
 
+#[derive(Clone, PartialEq, Debug)]
 pub struct CompleteNDHProblemSituation {
     empirical_sample: Vec<f64>,
     theoretical_sample: Vec<f64>,
@@ -55,17 +163,29 @@ impl CompleteNDHProblemSituation {
         theoretical_sample: &[f64],
         significance: f64,
     ) -> Result<Self, NDHError> {
-        if empirical_sample.len() != theoretical_sample.len() {
+        Self::with_expected(
+            empirical_sample,
+            Expected::Counts(theoretical_sample),
+            significance,
+        )
+    }
+
+    /// Takes the theoretical sample either as counts or as proportions of the empirical total.
+    pub fn with_expected(
+        empirical_sample: &[f64],
+        expected: Expected,
+        significance: f64,
+    ) -> Result<Self, NDHError> {
+        if empirical_sample.len() != expected.len() {
             return Err(NDHError::NonEqualSamplesLengths);
         }
 
-        if !(significance > 0. && significance < 1.) {
-            return Err(NDHError::SignificanceInvalid);
-        }
+        alpha::validate(significance, NDHError::SignificanceInvalid)?;
 
         Ok(Self {
             empirical_sample: empirical_sample.to_owned(),
-            theoretical_sample: theoretical_sample.to_owned(),
+            theoretical_sample: expected
+                .counts(compensated_sum(empirical_sample.iter().copied()))?,
             significance,
         })
     }
@@ -85,10 +205,172 @@ impl NDHProblemSituation for CompleteNDHProblemSituation {
     }
 }
 
+/// Observed frequencies of categories against fully specified probabilities of the categories,
+/// none of which is estimated, so the freedom degrees are `k - 1` for `k` categories.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SpecifiedProportionsGoodnessOfFit {
+    empirical_sample: Vec<f64>,
+    proportions: Vec<f64>,
+    significance: f64,
+}
+
+impl SpecifiedProportionsGoodnessOfFit {
+    /// `proportions` are the probabilities of the categories and must sum to `1`.
+    pub fn new(
+        empirical_sample: &[f64],
+        proportions: &[f64],
+        significance: f64,
+    ) -> Result<Self, NDHError> {
+        Self::with_expected(
+            empirical_sample,
+            Expected::Proportions(proportions),
+            significance,
+        )
+    }
+
+    /// Normalizes ratios such as Mendel's `9 : 3 : 3 : 1` into proportions.
+    pub fn from_ratios(
+        empirical_sample: &[f64],
+        ratios: &[f64],
+        significance: f64,
+    ) -> Result<Self, NDHError> {
+        Self::with_expected(empirical_sample, Expected::Counts(ratios), significance)
+    }
+
+    /// Takes the probabilities of the categories either as proportions or as expected counts.
+    pub fn with_expected(
+        empirical_sample: &[f64],
+        expected: Expected,
+        significance: f64,
+    ) -> Result<Self, NDHError> {
+        if empirical_sample.len() != expected.len() {
+            return Err(NDHError::NonEqualSamplesLengths);
+        }
+
+        alpha::validate(significance, NDHError::SignificanceInvalid)?;
+
+        Ok(Self {
+            empirical_sample: empirical_sample.to_owned(),
+            proportions: expected.proportions()?,
+            significance,
+        })
+    }
+}
+
+impl NDHProblemSituation for SpecifiedProportionsGoodnessOfFit {
+    fn empirical_sample(&self) -> Vec<f64> {
+        self.empirical_sample.to_owned()
+    }
+
+    fn theoretical_sample(&self) -> Vec<f64> {
+        let total = compensated_sum(self.empirical_sample.iter().copied());
+        self.proportions.iter().map(|p| total * p).collect()
+    }
+
+    fn significance(&self) -> f64 {
+        self.significance
+    }
+
+    fn null_distribution(&self) -> String {
+        let proportions: Vec<_> = self.proportions.iter().map(|p| format!("{p:.4}")).collect();
+        format!("the proportions {}", proportions.join(" : "))
+    }
+
+    fn estimated_parameters(&self) -> usize {
+        0
+    }
+}
+
+/// Raw sample grouped into random value ranges with their frequencies.
+#[derive(Clone, PartialEq, Debug)]
+pub struct GroupedSample {
+    pub random_value_ranges: Vec<(f64, f64)>,
+    pub frequencies: Vec<f64>,
+}
+
+impl GroupedSample {
+    /// Groups a raw sample into equal-width intervals, the number of which is chosen by Sturges' rule.
+    ///
+    /// A sample with missing values is rejected; see [`MissingPolicy`] for dropping them first.
+    pub fn sturges(sample: &[f64]) -> Result<Self, NDHError> {
+        if sample.iter().any(|value| value.is_nan()) {
+            return Err(NDHError::ValueMissing);
+        }
+
+        let (min, max) = sample
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                (min.min(*value), max.max(*value))
+            });
+        if max <= min {
+            return Err(NDHError::SampleDegenerate);
+        }
+
+        let intervals_count = (1. + (sample.len() as f64).log2()).ceil() as usize;
+        let width = (max - min) / intervals_count as f64;
+        let random_value_ranges = (0..intervals_count)
+            .map(|i| (min + i as f64 * width, min + (i + 1) as f64 * width))
+            .collect();
+
+        let mut frequencies = vec![0.; intervals_count];
+        for value in sample {
+            let i = (((value - min) / width) as usize).min(intervals_count - 1);
+            frequencies[i] += 1.;
+        }
+
+        Ok(Self {
+            random_value_ranges,
+            frequencies,
+        })
+    }
+
+    /// Midpoints of the intervals, which stand for the values grouped into them.
+    pub fn midpoints(&self) -> Vec<f64> {
+        self.random_value_ranges
+            .iter()
+            .map(|(a, b)| (a + b) / 2.)
+            .collect()
+    }
+
+    /// Raw moment of the given order over the midpoints.
+    pub fn moment(&self, order: u32) -> f64 {
+        grouped_moment(&self.midpoints(), &self.frequencies, order)
+    }
+
+    /// Central moment of the given order over the midpoints.
+    pub fn central_moment(&self, order: u32) -> f64 {
+        grouped_central_moment(&self.midpoints(), &self.frequencies, order)
+    }
+
+    /// Standardized moment of the given order over the midpoints.
+    pub fn standardized_moment(&self, order: u32) -> f64 {
+        grouped_standardized_moment(&self.midpoints(), &self.frequencies, order)
+    }
+
+    /// Fits the family over the midpoints.
+    pub fn fit(&self, family: Family, method: Method) -> Result<Fit, FitError> {
+        fit_grouped(&self.midpoints(), &self.frequencies, family, method)
+    }
+}
+
+/// Estimator of the variance of the grouped sample, since textbooks differ in the choice.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum VarianceEstimator {
+    /// `Σ fᵢ (xᵢ - x̄)² / n`, the maximum likelihood estimate.
+    Biased,
+    /// `Σ fᵢ (xᵢ - x̄)² / (n - 1)`.
+    Unbiased,
+}
+
+#[derive(Clone, PartialEq, Debug)]
 pub struct IncompleteNDHProblemSituation {
     random_value_ranges: Vec<(f64, f64)>,
     empirical_sample: Vec<f64>,
     significance: f64,
+    variance_estimator: VarianceEstimator,
+    sheppard_correction: bool,
+    rounding: RoundingPolicy,
 }
 
 // Possible improvement: Implement merging intervals with small frequencies.
@@ -102,16 +384,156 @@ impl IncompleteNDHProblemSituation {
             return Err(NDHError::NonEqualSamplesLengths);
         }
 
-        if !(significance > 0. && significance < 1.) {
-            return Err(NDHError::SignificanceInvalid);
-        }
+        alpha::validate(significance, NDHError::SignificanceInvalid)?;
 
         Ok(Self {
             random_value_ranges: random_value_ranges.to_owned(),
             empirical_sample: empirical_sample.to_owned(),
             significance,
+            variance_estimator: VarianceEstimator::Biased,
+            sheppard_correction: false,
+            rounding: RoundingPolicy::EXACT,
         })
     }
+
+    /// Chooses the estimator of the variance, [`VarianceEstimator::Biased`] by default.
+    pub fn with_variance_estimator(mut self, variance_estimator: VarianceEstimator) -> Self {
+        self.variance_estimator = variance_estimator;
+        self
+    }
+
+    /// Subtracts Sheppard's correction `Σ fᵢ hᵢ² / (12 n)` for the widths `hᵢ` of the ranges,
+    /// `h² / 12` for equal widths, from the variance, which grouping into the middles inflates.
+    pub fn with_sheppard_correction(mut self, sheppard_correction: bool) -> Self {
+        self.sheppard_correction = sheppard_correction;
+        self
+    }
+
+    /// Rounds the grouped mean and standard deviation, and with them the theoretical frequencies.
+    ///
+    /// The Chi-Squared statistic is rounded by [`NormalDistributionHypothesis::with_rounding`].
+    pub fn with_rounding(mut self, rounding: RoundingPolicy) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    pub fn rounding(&self) -> RoundingPolicy {
+        self.rounding
+    }
+
+    /// Groups a raw sample into equal-width intervals, the number of which is chosen by Sturges' rule.
+    ///
+    /// Missing values are rejected, as by [`MissingPolicy::Error`].
+    pub fn from_raw_sample(sample: &[f64], significance: f64) -> Result<Self, NDHError> {
+        Self::from_raw_sample_with_policy(sample, significance, MissingPolicy::Error)
+    }
+
+    /// Groups a raw sample by Sturges' rule after the missing values are handled by the policy.
+    pub fn from_raw_sample_with_policy(
+        sample: &[f64],
+        significance: f64,
+        missing_policy: MissingPolicy,
+    ) -> Result<Self, NDHError> {
+        let sample = missing_policy
+            .apply(sample)
+            .map_err(|_| NDHError::ValueMissing)?;
+        let grouped_sample = GroupedSample::sturges(&sample)?;
+
+        Self::new(
+            &grouped_sample.random_value_ranges,
+            &grouped_sample.frequencies,
+            significance,
+        )
+    }
+
+    pub fn random_value_ranges(&self) -> &[(f64, f64)] {
+        &self.random_value_ranges
+    }
+
+    /// Total of the empirical frequencies.
+    pub fn sample_size(&self) -> f64 {
+        compensated_sum(self.empirical_sample.iter().copied())
+    }
+
+    /// Middles of the random value ranges.
+    fn middles(&self) -> Vec<f64> {
+        self.random_value_ranges
+            .iter()
+            .map(|(x_1, x_2)| (x_2 + x_1) / 2.)
+            .collect()
+    }
+
+    /// Normal distribution fitted over the middles of the ranges, with the standard errors of its parameters.
+    ///
+    /// The fit is by maximum likelihood unless another variance estimator or Sheppard's correction is chosen.
+    /// The parameters are rounded by the rounding policy.
+    pub fn fit(&self) -> Result<Fit, FitError> {
+        let mut fit = self.unrounded_fit()?;
+        for parameter in &mut fit.parameters {
+            parameter.value = self.rounding.intermediate(parameter.value);
+        }
+
+        Ok(fit)
+    }
+
+    fn unrounded_fit(&self) -> Result<Fit, FitError> {
+        let mut fit = fit_grouped(
+            &self.middles(),
+            &self.empirical_sample,
+            Family::Normal,
+            Method::MaximumLikelihood,
+        )?;
+        if self.variance_estimator == VarianceEstimator::Biased && !self.sheppard_correction {
+            return Ok(fit);
+        }
+
+        let n = fit.sample_size;
+        let mut variance = fit.parameters[1].value.powi(2) * n / self.variance_divisor();
+        if self.sheppard_correction {
+            variance -= compensated_sum(
+                self.random_value_ranges
+                    .iter()
+                    .zip(&self.empirical_sample)
+                    .map(|((x_1, x_2), f)| f * (x_2 - x_1).powi(2)),
+            ) / (12. * n);
+        }
+        if variance.is_nan() || variance <= 0. {
+            return Err(FitError::SampleDegenerate);
+        }
+
+        let sigma = variance.sqrt();
+        fit.method = Method::Moments;
+        fit.parameters = vec![
+            Estimate {
+                standard_error: sigma / n.sqrt(),
+                ..fit.parameters[0].clone()
+            },
+            Estimate {
+                name: "σ",
+                value: sigma,
+                standard_error: sigma / (2. * n).sqrt(),
+            },
+        ];
+        Ok(fit)
+    }
+
+    /// `n` or `n - 1`, the divisor of the sum of the squared deviations chosen by the variance estimator.
+    fn variance_divisor(&self) -> f64 {
+        match self.variance_estimator {
+            VarianceEstimator::Biased => self.sample_size(),
+            VarianceEstimator::Unbiased => self.sample_size() - 1.,
+        }
+    }
+
+    /// Sample mean estimated over the middles of the ranges.
+    pub fn mean(&self) -> f64 {
+        self.fit().map_or(f64::NAN, |fit| fit.parameters[0].value)
+    }
+
+    /// Sample standard deviation estimated over the middles of the ranges.
+    pub fn std_dev(&self) -> f64 {
+        self.fit().map_or(f64::NAN, |fit| fit.parameters[1].value)
+    }
 }
 
 impl NDHProblemSituation for IncompleteNDHProblemSituation {
@@ -120,85 +542,235 @@ impl NDHProblemSituation for IncompleteNDHProblemSituation {
     }
 
     fn theoretical_sample(&self) -> Vec<f64> {
-        let mean = (1. / self.empirical_sample.iter().sum::<f64>())
-            * self
-                .random_value_ranges
-                .iter()
-                .zip(self.empirical_sample.iter())
-                .map(|((x_1, x_2), m)| m * (x_2 + x_1) / 2.)
-                .sum::<f64>();
-
-        let variance = (1. / (self.empirical_sample.iter().sum::<f64>()))
-            * self
-                .random_value_ranges
-                .iter()
-                .zip(self.empirical_sample.iter())
-                .map(|((x_1, x_2), m)| m * (((x_2 + x_1) / 2.) - mean).powi(2))
-                .sum::<f64>();
-        let std_dev = variance.sqrt();
-
-        let normal_distribution = Normal::new(mean, std_dev).unwrap();
-        let result = self
-            .random_value_ranges
-            .iter()
-            .map(|(x1, x2)| {
-                self.empirical_sample.iter().sum::<f64>()
-                    * (normal_distribution.cdf(*x2) - normal_distribution.cdf(*x1))
-            })
-            .collect();
-        println!("{result:?}");
-        result
+        // The unrounded maximum likelihood fit is the one of the core.
+        if self.variance_estimator == VarianceEstimator::Biased
+            && !self.sheppard_correction
+            && self.rounding == RoundingPolicy::EXACT
+        {
+            return decisions::normal_expected_frequencies(
+                &self.random_value_ranges,
+                &self.empirical_sample,
+            );
+        }
+
+        self.fit().map_or_else(
+            |_| vec![f64::NAN; self.random_value_ranges.len()],
+            |fit| fit.expected_frequencies(&self.random_value_ranges),
+        )
     }
 
     fn significance(&self) -> f64 {
         self.significance
     }
+
+    fn random_value_ranges(&self) -> Option<&[(f64, f64)]> {
+        Some(&self.random_value_ranges)
+    }
+
+    fn null_distribution(&self) -> String {
+        format!("N(μ = {:.4}, σ = {:.4})", self.mean(), self.std_dev())
+    }
+
+    /// `(√(Q / χ²_{1-α/2}), √(Q / χ²_{α/2}))` with `n - 1` freedom degrees, `Q` being the sum of the squared
+    /// deviations of the middles from the mean, reduced by Sheppard's correction if chosen.
+    fn std_dev_confidence_interval(&self) -> Option<(f64, f64)> {
+        let n = self.sample_size();
+        let squares_sum = self.variance_divisor() * self.std_dev().powi(2);
+        let chi_squared = ChiSquared::new(n - 1.).ok()?;
+        if squares_sum.is_nan() {
+            return None;
+        }
+
+        Some((
+            (squares_sum / chi_squared.inverse_cdf(1. - self.significance / 2.)).sqrt(),
+            (squares_sum / chi_squared.inverse_cdf(self.significance / 2.)).sqrt(),
+        ))
+    }
+}
+
+/// Contribution of one class into the Chi-Squared statistic.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BinContribution {
+    pub range: Option<(f64, f64)>,
+    pub observed: f64,
+    pub expected: f64,
+    /// `(observed - expected)² / expected`.
+    pub chi_term: f64,
+    pub residuals: Residuals,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct NDHOutcome {
+    pub freedom_degrees: f64,
+    pub chi_squared_observed: f64,
+    /// The upper critical value, or the lower one for a region in the lower tail only.
+    pub chi_squared_critical_value: f64,
+    /// Values of the statistic for which the hypothesis of normality is rejected, with both bounds if two-tailed.
+    pub critical_region: CriticalRegion,
+    pub bins: Vec<BinContribution>,
+    /// Chi-Squared confidence interval of `σ` at the `1 - significance` level, if `σ` is estimated.
+    pub std_dev_confidence_interval: Option<(f64, f64)>,
+    pub hypotheses: Hypotheses,
+    /// Whether the hypothesis of normality is accepted.
+    pub accepted: bool,
 }
 
 pub struct NormalDistributionHypothesis {
     situation: Box<dyn NDHProblemSituation>,
+    rounding: RoundingPolicy,
+    critical_value_source: CriticalValueSource,
+    alternative: Alternative,
 }
 
 impl NormalDistributionHypothesis {
     pub fn new(situation: Box<dyn NDHProblemSituation>) -> Result<Self, NDHError> {
-        Ok(Self { situation })
+        Ok(Self {
+            situation,
+            rounding: RoundingPolicy::EXACT,
+            critical_value_source: CriticalValueSource::Quantile,
+            alternative: Alternative::Greater,
+        })
+    }
+
+    /// Rounds the theoretical frequencies, the Chi-Squared terms, the statistic and the critical value.
+    pub fn with_rounding(mut self, rounding: RoundingPolicy) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Chooses whether the critical value is computed or looked up in the standard tables.
+    pub fn with_critical_value_source(mut self, source: CriticalValueSource) -> Self {
+        self.critical_value_source = source;
+        self
+    }
+
+    /// Tails of **Chi-Squared** in which the hypothesis is rejected, the upper one by default;
+    /// [`Alternative::TwoSided`] also rejects a fit too good to be true.
+    pub fn with_alternative(mut self, alternative: Alternative) -> Self {
+        self.alternative = alternative;
+        self
     }
 
     pub fn solve(&self) -> Result<bool, NDHError> {
-        let freedom_degrees = self.situation.empirical_sample().len() as f64 - 2.0 - 1.0;
-        let chi_squared_critical_value =
-            calculate_chi_squared_critical_value(freedom_degrees, self.situation.significance())?;
+        Ok(self.outcome()?.accepted)
+    }
+
+    /// Compares an answer computed by hand with the solution, step by step.
+    pub fn verify(&self, answer: &UserAnswer) -> Result<Verification, NDHError> {
+        let outcome = self.outcome()?;
+
+        Ok(answers::verify(
+            &Solution {
+                expected_frequencies: outcome.bins.iter().map(|bin| bin.expected).collect(),
+                statistic: outcome.chi_squared_observed,
+                freedom_degrees: outcome.freedom_degrees,
+                critical_value: outcome.chi_squared_critical_value,
+                rejected: !outcome.accepted,
+            },
+            answer,
+        ))
+    }
 
-        let chi_squared_observed: f64 = self
+    /// Solves the problem keeping the intermediate quantities.
+    pub fn outcome(&self) -> Result<NDHOutcome, NDHError> {
+        let empirical_sample = self.situation.empirical_sample();
+        let theoretical_sample: Vec<_> = self
             .situation
-            .empirical_sample()
+            .theoretical_sample()
             .iter()
-            .zip(self.situation.theoretical_sample().iter())
-            .map(|(e, t)| (e - t).powi(2) / t)
-            .sum();
+            .map(|t| self.rounding.intermediate(*t))
+            .collect();
+        let estimated_parameters = self.situation.estimated_parameters();
 
-        println!("{chi_squared_observed} < {chi_squared_critical_value}");
+        // The exact upper-tail test with the computed critical value is decided by the core;
+        // the rounding, the tables and the other tails are applied on top of it.
+        let decision = decisions::goodness_of_fit(
+            &empirical_sample,
+            &theoretical_sample,
+            estimated_parameters,
+            self.situation.significance(),
+            &StatrsQuantiles,
+        )
+        .map_err(|e| match e {
+            DecisionError::SignificanceInvalid => NDHError::SignificanceInvalid,
+            DecisionError::LengthsMismatch => NDHError::NonEqualSamplesLengths,
+            _ => NDHError::FreedomDegreesInvalid,
+        })?;
+        let freedom_degrees = (empirical_sample.len() - estimated_parameters - 1) as f64;
+        let critical_region = match (self.alternative, self.critical_value_source) {
+            (Alternative::Greater, CriticalValueSource::Quantile) => {
+                CriticalRegion::Upper(decision.critical_value)
+            }
+            _ => chi_squared_critical_region(
+                freedom_degrees,
+                self.situation.significance(),
+                self.alternative,
+                self.critical_value_source,
+            )?,
+        }
+        .map(|value| self.rounding.critical_value(value));
+        // Every region has a bound.
+        let chi_squared_critical_value =
+            critical_region.upper().or(critical_region.lower()).unwrap();
+
+        let ranges = self.situation.random_value_ranges();
+        let total = compensated_sum(empirical_sample.iter().copied());
+        let bins: Vec<_> = empirical_sample
+            .iter()
+            .zip(theoretical_sample.iter())
+            .enumerate()
+            .map(|(i, (e, t))| BinContribution {
+                range: ranges.map(|ranges| ranges[i]),
+                observed: *e,
+                expected: *t,
+                chi_term: self.rounding.intermediate((e - t).powi(2) / t),
+                residuals: Residuals::goodness_of_fit(*e, *t, total),
+            })
+            .collect();
+        let chi_squared_observed = if self.rounding == RoundingPolicy::EXACT {
+            decision.statistic
+        } else {
+            self.rounding
+                .intermediate(compensated_sum(bins.iter().map(|bin| bin.chi_term)))
+        };
+        if !chi_squared_observed.is_finite() {
+            return Err(NDHError::StatisticNotFinite);
+        }
 
-        Ok(chi_squared_observed < chi_squared_critical_value)
+        Ok(NDHOutcome {
+            freedom_degrees,
+            chi_squared_observed,
+            chi_squared_critical_value,
+            critical_region,
+            bins,
+            std_dev_confidence_interval: self.situation.std_dev_confidence_interval(),
+            hypotheses: Hypotheses::new(
+                format!("the sample follows {}", self.situation.null_distribution()),
+                format!(
+                    "the sample does not follow {}",
+                    self.situation.null_distribution()
+                ),
+                self.alternative,
+            ),
+            accepted: !critical_region.contains(chi_squared_observed),
+        })
     }
 }
 
-fn calculate_chi_squared_critical_value(
+fn chi_squared_critical_region(
     freedom_degrees: f64,
     significance: f64,
-) -> Result<f64, NDHError> {
-    if !(significance > 0.0 && significance < 1.0) {
-        return Err(NDHError::SignificanceInvalid);
-    }
+    alternative: Alternative,
+    source: CriticalValueSource,
+) -> Result<CriticalRegion, NDHError> {
+    alpha::validate(significance, NDHError::SignificanceInvalid)?;
 
-    let chi_squared_dist =
-        ChiSquared::new(freedom_degrees).map_err(|_| NDHError::FreedomDegreesInvalid)?;
-
-    // Critical values corresponds to `(1 - significance)`-quantile.
-    // `inverse_cdf(p)` finds `x` such that `P(X <= x) = p`.
-    // We need `P(X > x_crit) = significance`, which is equivalent `P(X <= x_crit) = 1 - significance`
-    let probability = 1.0 - significance;
-    let critical_value = chi_squared_dist.inverse_cdf(probability);
-
-    Ok(critical_value)
+    CriticalRegion::chi_squared(freedom_degrees, significance, alternative, source).map_err(|e| {
+        match e {
+            CriticalValueError::NotTabulated => NDHError::CriticalValueNotTabulated,
+            _ => NDHError::FreedomDegreesInvalid,
+        }
+    })
 }
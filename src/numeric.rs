@@ -0,0 +1,9 @@
+//! Numerically stable summation and moments.
+//!
+//! Naive accumulation loses precision on long samples and on values sharing a big offset
+//! (e.g. measurements around `1e9` differing in the last digits); these helpers keep the error
+//! independent of the sample length.
+//!
+//! The helpers live in the `no_std` core crate and are re-exported here.
+
+pub use statistics_problems_core::numeric::*;
@@ -0,0 +1,82 @@
+//! Ranks, order statistics and normal scores of raw samples.
+//!
+//! The normal scores approximate the expected values, or the medians, of the standard normal
+//! order statistics; they are the abscissae of normal Q-Q plots and the weights of the rank-based
+//! normality tests.
+
+use statrs::distribution::{ContinuousCDF, Normal};
+
+/// Approximations of the `i`-th of `n` standard normal order statistics by `Φ⁻¹(pᵢ)`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum NormalScores {
+    /// Blom's `pᵢ = (i - 3/8) / (n + 1/4)`, close to the expected values.
+    Blom,
+    /// `pᵢ = (i - 1/2) / n`.
+    Rankit,
+    /// Filliben's medians: `pₙ = 0.5^(1/n)`, `p₁ = 1 - pₙ` and `pᵢ = (i - 0.3175) / (n + 0.365)` otherwise.
+    Filliben,
+}
+
+/// Ranks starting from 1 with ties replaced by their average rank, and the sizes of the tie groups.
+pub fn average_ranks(sample: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let mut order: Vec<_> = (0..sample.len()).collect();
+    order.sort_by(|&i, &j| sample[i].total_cmp(&sample[j]));
+
+    let mut ranks = vec![0.; sample.len()];
+    let mut ties = Vec::new();
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && sample[order[end]] == sample[order[start]] {
+            end += 1;
+        }
+
+        let average_rank = (start + end + 1) as f64 / 2.;
+        for &i in &order[start..end] {
+            ranks[i] = average_rank;
+        }
+        ties.push((end - start) as f64);
+        start = end;
+    }
+
+    (ranks, ties)
+}
+
+/// Ordered sample `x₍₁₎ ≤ x₍₂₎ ≤ … ≤ x₍ₙ₎`.
+pub fn order_statistics(sample: &[f64]) -> Vec<f64> {
+    let mut sorted = sample.to_owned();
+    sorted.sort_by(f64::total_cmp);
+    sorted
+}
+
+/// `k`-th order statistic `x₍ₖ₎`, counted from 1.
+///
+/// Returns `NaN` for `k` outside `1..=n`.
+pub fn order_statistic(sample: &[f64], k: usize) -> f64 {
+    if !(1..=sample.len()).contains(&k) {
+        return f64::NAN;
+    }
+
+    order_statistics(sample)[k - 1]
+}
+
+/// Normal scores of the order statistics of a sample of size `n`.
+pub fn normal_scores(n: usize, scores: NormalScores) -> Vec<f64> {
+    let size = n as f64;
+    let last = 0.5f64.powf(1. / size);
+    let standard_normal = Normal::standard();
+
+    (1..=n)
+        .map(|i| match scores {
+            NormalScores::Blom => (i as f64 - 0.375) / (size + 0.25),
+            NormalScores::Rankit => (i as f64 - 0.5) / size,
+            NormalScores::Filliben => match i {
+                1 => 1. - last,
+                i if i == n => last,
+                i => (i as f64 - 0.3175) / (size + 0.365),
+            },
+        })
+        .map(|p| standard_normal.inverse_cdf(p))
+        .collect()
+}
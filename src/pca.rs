@@ -0,0 +1,238 @@
+//! Principal component analysis of the correlation matrix, with the checks of its suitability.
+//!
+//! **Given**: *significance ratio*, *sample of a random vector **X***.
+//! **To figure out**: Is it appropriate to **assume** that the correlation matrix is the identity,
+//! i.e. that the variables share nothing for the components to extract?
+//!
+//! Bartlett's test of sphericity answers the question; the Kaiser–Meyer–Olkin measure
+//! tells how much of the correlation is not explained away by partial correlations.
+
+use nalgebra::{DMatrix, SymmetricEigen};
+use statrs::distribution::{ChiSquared, ContinuousCDF};
+
+use crate::alpha;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::multivariate::MultiSample;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum PCAError {
+    SampleTooSmall,
+    DimensionTooSmall,
+    VariableConstant,
+    CorrelationSingular,
+    SignificanceInvalid,
+    FreedomDegreesInvalid,
+}
+
+impl std::fmt::Display for PCAError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PCAError::SampleTooSmall => {
+                write!(f, "Sample must contain more observations than variables")
+            }
+            PCAError::DimensionTooSmall => {
+                write!(f, "At least two variables are required")
+            }
+            PCAError::VariableConstant => {
+                write!(f, "Every variable must take at least two distinct values")
+            }
+            PCAError::CorrelationSingular => {
+                write!(f, "Correlation matrix is singular")
+            }
+            PCAError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            PCAError::FreedomDegreesInvalid => {
+                write!(
+                    f,
+                    "Freedom Degrees led to fail in initialization of Gamma underlying Chi"
+                )
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct PrincipalComponents {
+    /// Variances of the components in decreasing order.
+    pub eigenvalues: Vec<f64>,
+    /// Eigenvectors of the correlation matrix in columns, in the order of `eigenvalues`,
+    /// each oriented so that its largest entry is positive.
+    pub loadings: DMatrix<f64>,
+    /// Proportions of the total variance `p` explained by each component.
+    pub explained_variance: Vec<f64>,
+}
+
+impl PrincipalComponents {
+    /// Running sums of `explained_variance`.
+    pub fn cumulative_explained_variance(&self) -> Vec<f64> {
+        self.explained_variance
+            .iter()
+            .scan(0., |total, proportion| {
+                *total += proportion;
+                Some(*total)
+            })
+            .collect()
+    }
+
+    /// Number of components with eigenvalues above `1` by the Kaiser criterion.
+    pub fn kaiser_components(&self) -> usize {
+        self.eigenvalues
+            .iter()
+            .filter(|lambda| **lambda > 1.)
+            .count()
+    }
+}
+
+pub fn principal_components(sample: &MultiSample) -> Result<PrincipalComponents, PCAError> {
+    let correlation = correlation(sample)?;
+    let p = correlation.nrows();
+
+    let eigen = SymmetricEigen::new(correlation);
+    let mut order: Vec<usize> = (0..p).collect();
+    order.sort_by(|i, j| eigen.eigenvalues[*j].total_cmp(&eigen.eigenvalues[*i]));
+
+    let eigenvalues: Vec<f64> = order.iter().map(|i| eigen.eigenvalues[*i]).collect();
+    let loadings =
+        DMatrix::from_fn(p, p, |row, column| {
+            let vector = eigen.eigenvectors.column(order[column]);
+            let largest = vector.iter().copied().fold(0f64, |largest, x| {
+                if x.abs() > largest.abs() { x } else { largest }
+            });
+            vector[row] * largest.signum()
+        });
+
+    Ok(PrincipalComponents {
+        explained_variance: eigenvalues.iter().map(|lambda| lambda / p as f64).collect(),
+        eigenvalues,
+        loadings,
+    })
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct BartlettSphericityOutcome {
+    /// `-(n - 1 - (2p + 5) / 6) · ln|R|`.
+    pub chi_squared_observed: f64,
+    pub freedom_degrees: f64,
+    pub chi_squared_critical_value: f64,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    /// Whether the hypothesis of the identity correlation matrix is accepted.
+    pub accepted: bool,
+}
+
+pub struct BartlettSphericityHypothesis {
+    sample: MultiSample,
+    significance: f64,
+}
+
+impl BartlettSphericityHypothesis {
+    pub fn new(sample: &MultiSample, significance: f64) -> Result<Self, PCAError> {
+        alpha::validate(significance, PCAError::SignificanceInvalid)?;
+
+        Ok(Self {
+            sample: sample.clone(),
+            significance,
+        })
+    }
+
+    pub fn solve(&self) -> Result<bool, PCAError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<BartlettSphericityOutcome, PCAError> {
+        let correlation = correlation(&self.sample)?;
+        let (n, p) = (self.sample.size() as f64, self.sample.dimension() as f64);
+
+        let determinant = correlation.determinant();
+        if determinant <= 0. {
+            return Err(PCAError::CorrelationSingular);
+        }
+
+        let chi_squared_observed = -(n - 1. - (2. * p + 5.) / 6.) * determinant.ln();
+        let freedom_degrees = p * (p - 1.) / 2.;
+        let chi_squared_dist =
+            ChiSquared::new(freedom_degrees).map_err(|_| PCAError::FreedomDegreesInvalid)?;
+        let chi_squared_critical_value = chi_squared_dist.inverse_cdf(1. - self.significance);
+
+        Ok(BartlettSphericityOutcome {
+            chi_squared_observed,
+            freedom_degrees,
+            chi_squared_critical_value,
+            p_value: chi_squared_dist.sf(chi_squared_observed),
+            hypotheses: Hypotheses::new(
+                "the correlation matrix is the identity",
+                "the variables are correlated",
+                Alternative::Greater,
+            ),
+            accepted: chi_squared_observed < chi_squared_critical_value,
+        })
+    }
+}
+
+/// Kaiser–Meyer–Olkin measure of sampling adequacy.
+#[derive(Clone, PartialEq, Debug)]
+pub struct KMO {
+    /// `Σ r²ᵢⱼ / (Σ r²ᵢⱼ + Σ a²ᵢⱼ)` over `i ≠ j` with the partial correlations `aᵢⱼ`.
+    pub overall: f64,
+    /// The same ratio restricted to the row of every variable.
+    pub per_variable: Vec<f64>,
+}
+
+pub fn kaiser_meyer_olkin(sample: &MultiSample) -> Result<KMO, PCAError> {
+    let correlation = correlation(sample)?;
+    let p = correlation.nrows();
+
+    let inverse = correlation
+        .clone()
+        .try_inverse()
+        .ok_or(PCAError::CorrelationSingular)?;
+    let partial = DMatrix::from_fn(p, p, |i, j| {
+        -inverse[(i, j)] / (inverse[(i, i)] * inverse[(j, j)]).sqrt()
+    });
+
+    let off_diagonal_squares = |matrix: &DMatrix<f64>, i: usize| {
+        (0..p)
+            .filter(|j| *j != i)
+            .map(|j| matrix[(i, j)].powi(2))
+            .sum::<f64>()
+    };
+    let (correlation_sums, partial_sums): (Vec<f64>, Vec<f64>) = (0..p)
+        .map(|i| {
+            (
+                off_diagonal_squares(&correlation, i),
+                off_diagonal_squares(&partial, i),
+            )
+        })
+        .unzip();
+
+    let correlation_total = correlation_sums.iter().sum::<f64>();
+    let partial_total = partial_sums.iter().sum::<f64>();
+
+    Ok(KMO {
+        overall: correlation_total / (correlation_total + partial_total),
+        per_variable: correlation_sums
+            .iter()
+            .zip(partial_sums.iter())
+            .map(|(r, a)| r / (r + a))
+            .collect(),
+    })
+}
+
+fn correlation(sample: &MultiSample) -> Result<DMatrix<f64>, PCAError> {
+    if sample.dimension() < 2 {
+        return Err(PCAError::DimensionTooSmall);
+    }
+
+    if sample.size() <= sample.dimension() {
+        return Err(PCAError::SampleTooSmall);
+    }
+
+    let correlation = sample.correlation();
+    if correlation.iter().any(|r| r.is_nan()) {
+        return Err(PCAError::VariableConstant);
+    }
+
+    Ok(correlation)
+}
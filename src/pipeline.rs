@@ -0,0 +1,209 @@
+//! Workflow for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *sample of a random variable **X***, *sample of a random variable **Y***.
+//! **To figure out**: Is it appropriate to **assume** that **X** and **Y** are located equally?
+//!
+//! The assumption checks run first and their outcomes choose the final comparison:
+//! Student's t-test, Welch's t-test or the Mann–Whitney U test.
+//!
+//! ```ignore
+//! let outcome = Pipeline::compare_means(&x, &y)
+//!     .check_normality(NormalityTest::ShapiroWilk)
+//!     .check_variances()
+//!     .alpha(0.05)
+//!     .run()?;
+//! ```
+
+use crate::mann_whitney_hypothesis::{MWHError, MannWhitneyHypothesis};
+use crate::missing::{MissingError, MissingPolicy};
+use crate::normal_distribution_hypothesis::{
+    IncompleteNDHProblemSituation, NDHError, NormalDistributionHypothesis,
+};
+use crate::recommend::{DataDescription, Design, Measurement, Recommendation, recommend};
+use crate::same_mean_hypothesis::{SMHError, SameMeanHypothesis, Variances};
+use crate::same_variance_hypothesis::{SVHError, SameVarianceHypothesis};
+use crate::shapiro_wilk_hypothesis::{SWError, ShapiroWilkHypothesis};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum PipelineError {
+    ShapiroWilk(SWError),
+    NormalDistribution(NDHError),
+    SameVariance(SVHError),
+    SameMean(SMHError),
+    MannWhitney(MWHError),
+    Missing(MissingError),
+}
+
+impl std::fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PipelineError::ShapiroWilk(e) => {
+                write!(f, "Shapiro–Wilk normality check failed: {e}")
+            }
+            PipelineError::NormalDistribution(e) => {
+                write!(f, "Chi-squared normality check failed: {e}")
+            }
+            PipelineError::SameVariance(e) => {
+                write!(f, "Variance check failed: {e}")
+            }
+            PipelineError::SameMean(e) => {
+                write!(f, "Means comparison failed: {e}")
+            }
+            PipelineError::MannWhitney(e) => {
+                write!(f, "Mann–Whitney comparison failed: {e}")
+            }
+            PipelineError::Missing(e) => {
+                write!(f, "Missing values: {e}")
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum NormalityTest {
+    ShapiroWilk,
+    /// Normal Distribution Hypothesis over the Sturges-grouped sample.
+    ChiSquared,
+}
+
+impl NormalityTest {
+    /// Whether the test accepts the normality of the raw sample.
+    pub fn solve(&self, sample: &[f64], significance: f64) -> Result<bool, PipelineError> {
+        match self {
+            NormalityTest::ShapiroWilk => ShapiroWilkHypothesis::new(sample, significance)
+                .and_then(|swh| swh.solve())
+                .map_err(PipelineError::ShapiroWilk),
+            NormalityTest::ChiSquared => {
+                IncompleteNDHProblemSituation::from_raw_sample(sample, significance)
+                    .and_then(|situation| NormalDistributionHypothesis::new(Box::new(situation)))
+                    .and_then(|ndh| ndh.solve())
+                    .map_err(PipelineError::NormalDistribution)
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct PipelineOutcome {
+    /// Normality decisions for **X** and **Y**, if normality was checked.
+    pub normality: Option<(bool, bool)>,
+    /// Variance homogeneity decision, if variances were checked.
+    pub equal_variances: Option<bool>,
+    /// The test chosen for the final comparison.
+    pub test: Recommendation,
+    /// Whether the final comparison accepts the hypothesis of equal location.
+    pub same_location: bool,
+}
+
+pub struct Pipeline {
+    x_sample: Vec<f64>,
+    y_sample: Vec<f64>,
+    normality_test: Option<NormalityTest>,
+    variances_check: bool,
+    missing_policy: MissingPolicy,
+    significance: f64,
+}
+
+impl Pipeline {
+    /// Starts a pipeline with the default significance of `0.05`, no assumption checks
+    /// and missing values rejected.
+    pub fn compare_means(x_sample: &[f64], y_sample: &[f64]) -> Self {
+        Self {
+            x_sample: x_sample.to_owned(),
+            y_sample: y_sample.to_owned(),
+            normality_test: None,
+            variances_check: false,
+            missing_policy: MissingPolicy::Error,
+            significance: 0.05,
+        }
+    }
+
+    pub fn check_normality(mut self, normality_test: NormalityTest) -> Self {
+        self.normality_test = Some(normality_test);
+        self
+    }
+
+    pub fn check_variances(mut self) -> Self {
+        self.variances_check = true;
+        self
+    }
+
+    /// Policy applied to the missing values of both samples before any test.
+    pub fn missing(mut self, missing_policy: MissingPolicy) -> Self {
+        self.missing_policy = missing_policy;
+        self
+    }
+
+    /// Significance used by every test of the pipeline.
+    pub fn alpha(mut self, significance: f64) -> Self {
+        self.significance = significance;
+        self
+    }
+
+    /// Runs the configured checks and the comparison they lead to.
+    ///
+    /// Without a normality check the samples are assumed normal;
+    /// without a variance check the variances are assumed unequal, which leads to Welch's t-test.
+    pub fn run(&self) -> Result<PipelineOutcome, PipelineError> {
+        let x_sample = self
+            .missing_policy
+            .apply(&self.x_sample)
+            .map_err(PipelineError::Missing)?;
+        let y_sample = self
+            .missing_policy
+            .apply(&self.y_sample)
+            .map_err(PipelineError::Missing)?;
+
+        let normality = match self.normality_test {
+            None => None,
+            Some(normality_test) => Some((
+                normality_test.solve(&x_sample, self.significance)?,
+                normality_test.solve(&y_sample, self.significance)?,
+            )),
+        };
+        let both_normal = normality.is_none_or(|(x_normal, y_normal)| x_normal && y_normal);
+
+        let equal_variances = if self.variances_check && both_normal {
+            let svh = SameVarianceHypothesis::new(&x_sample, &y_sample, self.significance);
+            Some(svh.solve().map_err(PipelineError::SameVariance)?)
+        } else {
+            None
+        };
+
+        let description = DataDescription {
+            design: Design::Independent,
+            measurement: Measurement::Continuous,
+            sample_sizes: (x_sample.len(), y_sample.len()),
+            normality: Some(both_normal),
+            equal_variances: Some(equal_variances.unwrap_or(false)),
+        };
+        let test = recommend(&description);
+
+        let same_location = match test {
+            Recommendation::MannWhitney => {
+                MannWhitneyHypothesis::new(&x_sample, &y_sample, self.significance)
+                    .and_then(|mwh| mwh.solve())
+                    .map_err(PipelineError::MannWhitney)?
+            }
+            _ => {
+                let variances = if test == Recommendation::StudentT {
+                    Variances::Equal
+                } else {
+                    Variances::Unequal
+                };
+                SameMeanHypothesis::new(&x_sample, &y_sample, variances, self.significance)
+                    .and_then(|smh| smh.solve())
+                    .map_err(PipelineError::SameMean)?
+            }
+        };
+
+        Ok(PipelineOutcome {
+            normality,
+            equal_variances,
+            test,
+            same_location,
+        })
+    }
+}
@@ -0,0 +1,355 @@
+//! SVG rendering of the plots accompanying the normality decision.
+//!
+//! - Histogram of the sample with the fitted Normal density overlay.
+//! - Normal Q-Q plot of the sample.
+//! - Box plots of one or many samples.
+//!
+//! Both accept raw samples or grouped samples (random value ranges with frequencies).
+//! The SVG is generated directly, so the feature brings no dependencies.
+//!
+//! PNG is not produced: rasterizing the text of the axes would need a font renderer, i.e. a dependency
+//! such as `plotters`. A raster image can be had by converting the SVG with an external tool, e.g. `resvg`.
+
+use std::fmt::Write;
+
+use statrs::distribution::{Continuous, ContinuousCDF, Normal};
+
+use crate::box_plot::BoxPlot;
+use crate::normal_distribution_hypothesis::GroupedSample;
+use crate::order_statistics::{NormalScores, normal_scores, order_statistics};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum PlotError {
+    NonEqualSamplesLengths,
+    SampleDegenerate,
+}
+
+impl std::fmt::Display for PlotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PlotError::NonEqualSamplesLengths => {
+                write!(f, "Lengths of samples are different")
+            }
+            PlotError::SampleDegenerate => {
+                write!(f, "Sample must contain at least two distinct values")
+            }
+        }
+    }
+}
+
+const WIDTH: f64 = 640.;
+const HEIGHT: f64 = 480.;
+const MARGIN: f64 = 50.;
+const TICKS: usize = 5;
+
+/// Histogram of a raw sample grouped by Sturges' rule, with the fitted Normal density.
+pub fn histogram(sample: &[f64]) -> Result<String, PlotError> {
+    let grouped_sample = GroupedSample::sturges(sample).map_err(|_| PlotError::SampleDegenerate)?;
+    grouped_histogram(
+        &grouped_sample.random_value_ranges,
+        &grouped_sample.frequencies,
+    )
+}
+
+/// Histogram of a grouped sample, with the Normal density fitted over the middles of the ranges.
+pub fn grouped_histogram(
+    random_value_ranges: &[(f64, f64)],
+    frequencies: &[f64],
+) -> Result<String, PlotError> {
+    if random_value_ranges.len() != frequencies.len() {
+        return Err(PlotError::NonEqualSamplesLengths);
+    }
+    let (mean, std_dev) = grouped_mean_std_dev(random_value_ranges, frequencies);
+    if random_value_ranges.is_empty() || std_dev.is_nan() || std_dev <= 0. {
+        return Err(PlotError::SampleDegenerate);
+    }
+
+    let n = frequencies.iter().sum::<f64>();
+    let densities: Vec<_> = random_value_ranges
+        .iter()
+        .zip(frequencies.iter())
+        .map(|((x_1, x_2), m)| m / (n * (x_2 - x_1)))
+        .collect();
+    let normal_distribution = Normal::new(mean, std_dev).unwrap();
+
+    let x_min = random_value_ranges[0].0.min(mean - 3. * std_dev);
+    let x_max = random_value_ranges[random_value_ranges.len() - 1]
+        .1
+        .max(mean + 3. * std_dev);
+    let y_max = densities
+        .iter()
+        .copied()
+        .fold(normal_distribution.pdf(mean), f64::max)
+        * 1.1;
+    let mut canvas = Canvas::new("Histogram", (x_min, x_max), (0., y_max));
+
+    for ((x_1, x_2), density) in random_value_ranges.iter().zip(densities.iter()) {
+        canvas.rectangle((*x_1, 0.), (*x_2, *density));
+    }
+    let density_curve: Vec<_> = (0..=200)
+        .map(|i| {
+            let x = x_min + (x_max - x_min) * i as f64 / 200.;
+            (x, normal_distribution.pdf(x))
+        })
+        .collect();
+    canvas.polyline(&density_curve, "crimson");
+
+    Ok(canvas.finish())
+}
+
+/// Normal Q-Q plot of a raw sample: ordered values against the Standard Normal quantiles.
+pub fn qq_plot(sample: &[f64]) -> Result<String, PlotError> {
+    let sample = order_statistics(sample);
+    let n = sample.len();
+    if n < 2 || sample[0] == sample[n - 1] {
+        return Err(PlotError::SampleDegenerate);
+    }
+
+    let points: Vec<_> = normal_scores(n, NormalScores::Blom)
+        .into_iter()
+        .zip(sample.iter().copied())
+        .collect();
+
+    let mean = sample.iter().sum::<f64>() / n as f64;
+    let std_dev = (sample.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n as f64 - 1.)).sqrt();
+
+    Ok(qq_canvas(&points, mean, std_dev))
+}
+
+/// Normal Q-Q plot of a grouped sample: the right ends of the ranges against
+/// the Standard Normal quantiles of the cumulative relative frequencies.
+pub fn grouped_qq_plot(
+    random_value_ranges: &[(f64, f64)],
+    frequencies: &[f64],
+) -> Result<String, PlotError> {
+    if random_value_ranges.len() != frequencies.len() {
+        return Err(PlotError::NonEqualSamplesLengths);
+    }
+    let (mean, std_dev) = grouped_mean_std_dev(random_value_ranges, frequencies);
+    if random_value_ranges.len() < 2 || std_dev.is_nan() || std_dev <= 0. {
+        return Err(PlotError::SampleDegenerate);
+    }
+
+    let n = frequencies.iter().sum::<f64>();
+    let standard_normal = Normal::standard();
+    let points: Vec<_> = random_value_ranges
+        .iter()
+        .zip(frequencies.iter())
+        .scan(0., |cumulative, ((_, x_2), m)| {
+            *cumulative += m;
+            Some((*cumulative / n, *x_2))
+        })
+        .filter(|(p, _)| *p > 0. && *p < 1.)
+        .map(|(p, x)| (standard_normal.inverse_cdf(p), x))
+        .collect();
+
+    Ok(qq_canvas(&points, mean, std_dev))
+}
+
+/// Vertical box plots side by side on a shared scale, outliers drawn as points.
+pub fn box_plots(box_plots: &[BoxPlot]) -> String {
+    let (y_min, y_max) = bounds(
+        box_plots
+            .iter()
+            .flat_map(|box_plot| [box_plot.min, box_plot.max]),
+    );
+    let mut canvas = Canvas::new("Box plot", (0., box_plots.len() as f64), (y_min, y_max));
+
+    for (i, box_plot) in box_plots.iter().enumerate() {
+        let (left, middle, right) = (i as f64 + 0.25, i as f64 + 0.5, i as f64 + 0.75);
+        canvas.polyline(
+            &[
+                (middle, box_plot.lower_whisker),
+                (middle, box_plot.first_quartile),
+            ],
+            "black",
+        );
+        canvas.polyline(
+            &[
+                (middle, box_plot.third_quartile),
+                (middle, box_plot.upper_whisker),
+            ],
+            "black",
+        );
+        canvas.rectangle(
+            (left, box_plot.first_quartile),
+            (right, box_plot.third_quartile),
+        );
+        canvas.polyline(
+            &[(left, box_plot.median), (right, box_plot.median)],
+            "crimson",
+        );
+        for outlier in &box_plot.outliers {
+            canvas.circle((middle, *outlier));
+        }
+    }
+
+    canvas.finish()
+}
+
+fn qq_canvas(points: &[(f64, f64)], mean: f64, std_dev: f64) -> String {
+    let (z_min, z_max) = bounds(points.iter().map(|(z, _)| *z));
+    let (x_min, x_max) = bounds(points.iter().map(|(_, x)| *x));
+    let mut canvas = Canvas::new("Normal Q-Q plot", (z_min, z_max), (x_min, x_max));
+
+    canvas.polyline(
+        &[
+            (z_min, mean + std_dev * z_min),
+            (z_max, mean + std_dev * z_max),
+        ],
+        "crimson",
+    );
+    for point in points {
+        canvas.circle(*point);
+    }
+
+    canvas.finish()
+}
+
+fn bounds(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+        (min.min(value), max.max(value))
+    });
+    let padding = (max - min).max(f64::EPSILON) * 0.05;
+    (min - padding, max + padding)
+}
+
+fn grouped_mean_std_dev(random_value_ranges: &[(f64, f64)], frequencies: &[f64]) -> (f64, f64) {
+    let n = frequencies.iter().sum::<f64>();
+    let middles = random_value_ranges
+        .iter()
+        .map(|(x_1, x_2)| (x_1 + x_2) / 2.);
+    let mean = middles
+        .clone()
+        .zip(frequencies.iter())
+        .map(|(x, m)| m * x)
+        .sum::<f64>()
+        / n;
+    let variance = middles
+        .zip(frequencies.iter())
+        .map(|(x, m)| m * (x - mean).powi(2))
+        .sum::<f64>()
+        / n;
+
+    (mean, variance.sqrt())
+}
+
+/// Plotting area mapping data coordinates onto the SVG ones.
+struct Canvas {
+    svg: String,
+    x_range: (f64, f64),
+    y_range: (f64, f64),
+}
+
+impl Canvas {
+    fn new(title: &str, x_range: (f64, f64), y_range: (f64, f64)) -> Self {
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{HEIGHT}" font-family="sans-serif" font-size="12">"#
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"<rect width="{WIDTH}" height="{HEIGHT}" fill="white"/>"#
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"<text x="{}" y="{}" text-anchor="middle" font-size="16">{title}</text>"#,
+            WIDTH / 2.,
+            MARGIN / 2.
+        )
+        .unwrap();
+
+        let mut canvas = Self {
+            svg,
+            x_range,
+            y_range,
+        };
+        canvas.axes();
+        canvas
+    }
+
+    fn x(&self, x: f64) -> f64 {
+        MARGIN + (x - self.x_range.0) / (self.x_range.1 - self.x_range.0) * (WIDTH - 2. * MARGIN)
+    }
+
+    fn y(&self, y: f64) -> f64 {
+        HEIGHT
+            - MARGIN
+            - (y - self.y_range.0) / (self.y_range.1 - self.y_range.0) * (HEIGHT - 2. * MARGIN)
+    }
+
+    fn axes(&mut self) {
+        let (left, right, top, bottom) = (MARGIN, WIDTH - MARGIN, MARGIN, HEIGHT - MARGIN);
+        writeln!(
+            self.svg,
+            r#"<path d="M{left},{top} L{left},{bottom} L{right},{bottom}" fill="none" stroke="black"/>"#
+        )
+        .unwrap();
+
+        for i in 0..=TICKS {
+            let t = i as f64 / TICKS as f64;
+            let x_value = self.x_range.0 + t * (self.x_range.1 - self.x_range.0);
+            let y_value = self.y_range.0 + t * (self.y_range.1 - self.y_range.0);
+            let (x, y) = (self.x(x_value), self.y(y_value));
+            writeln!(
+                self.svg,
+                r#"<line x1="{x:.1}" y1="{bottom}" x2="{x:.1}" y2="{}" stroke="black"/><text x="{x:.1}" y="{}" text-anchor="middle">{x_value:.2}</text>"#,
+                bottom + 5.,
+                bottom + 18.
+            )
+            .unwrap();
+            writeln!(
+                self.svg,
+                r#"<line x1="{}" y1="{y:.1}" x2="{left}" y2="{y:.1}" stroke="black"/><text x="{}" y="{:.1}" text-anchor="end">{y_value:.2}</text>"#,
+                left - 5.,
+                left - 8.,
+                y + 4.
+            )
+            .unwrap();
+        }
+    }
+
+    fn rectangle(&mut self, (x_1, y_1): (f64, f64), (x_2, y_2): (f64, f64)) {
+        let (left, right) = (self.x(x_1), self.x(x_2));
+        let (top, bottom) = (self.y(y_2), self.y(y_1));
+        writeln!(
+            self.svg,
+            r#"<rect x="{left:.1}" y="{top:.1}" width="{:.1}" height="{:.1}" fill="steelblue" fill-opacity="0.6" stroke="black"/>"#,
+            right - left,
+            bottom - top
+        )
+        .unwrap();
+    }
+
+    fn polyline(&mut self, points: &[(f64, f64)], color: &str) {
+        let points = points
+            .iter()
+            .map(|(x, y)| format!("{:.1},{:.1}", self.x(*x), self.y(*y)))
+            .collect::<Vec<_>>()
+            .join(" ");
+        writeln!(
+            self.svg,
+            r#"<polyline points="{points}" fill="none" stroke="{color}" stroke-width="2"/>"#
+        )
+        .unwrap();
+    }
+
+    fn circle(&mut self, (x, y): (f64, f64)) {
+        writeln!(
+            self.svg,
+            r#"<circle cx="{:.1}" cy="{:.1}" r="3" fill="steelblue"/>"#,
+            self.x(x),
+            self.y(y)
+        )
+        .unwrap();
+    }
+
+    fn finish(mut self) -> String {
+        self.svg += "</svg>\n";
+        self.svg
+    }
+}
@@ -0,0 +1,487 @@
+//! Algorithms for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *numbers of events* counted over *exposures* (time, area, population),
+//! the events occurring as a Poisson process.
+//! **To figure out**: Is it appropriate to **assume** that the rate of the events is `λ₀`,
+//! or that the rates of two processes are equal?
+//!
+//! One rate is tested exactly through the Poisson distribution of the count or by its normal approximation.
+//! Two rates are compared either conditionally on the total count, which is binomial under `λ_X = λ_Y`,
+//! or by the E-test of Krishnamoorthy and Thomson (2004), which is more powerful for small counts.
+//!
+//! Whether counts are Poisson at all, rather than overdispersed as negative binomial ones, is checked
+//! by the index of dispersion or by the regression of Cameron and Trivedi (1990).
+
+use statrs::distribution::{ChiSquared, ContinuousCDF, Normal, StudentsT};
+use statrs::function::gamma::ln_gamma;
+
+use crate::alpha;
+use crate::hypotheses::{Alternative, Hypotheses};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum RateError {
+    SignificanceInvalid,
+    ExposureInvalid,
+    RateInvalid,
+    CountsDegenerate,
+    SampleTooSmall,
+    LengthsMismatch,
+}
+
+impl std::fmt::Display for RateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RateError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            RateError::ExposureInvalid => {
+                write!(f, "Exposure must be positive")
+            }
+            RateError::RateInvalid => {
+                write!(f, "Hypothesized rate must be positive")
+            }
+            RateError::CountsDegenerate => {
+                write!(f, "At least one event must be counted")
+            }
+            RateError::SampleTooSmall => {
+                write!(f, "Sample must contain at least two counts")
+            }
+            RateError::LengthsMismatch => {
+                write!(f, "Every count must have its fitted mean")
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum RateMethod {
+    /// Probabilities of the Poisson counts no more probable than the observed one.
+    Exact,
+    /// `z = (x - λ₀t) / √(λ₀t)`.
+    Normal,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum RatesMethod {
+    /// Exact binomial test of `x_X` out of `x_X + x_Y` with `p = t_X / (t_X + t_Y)`.
+    ConditionalBinomial,
+    /// The E-test with the pooled rate plugged into the distribution of the Wald statistic.
+    ETest,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RateOutcome {
+    /// `λ̂ = x / t`.
+    pub rate: f64,
+    /// Exact (Garwood) confidence interval for `λ` at the `1 - significance` level.
+    pub confidence_interval: (f64, f64),
+    /// The `z` statistic, absent for the exact test.
+    pub z_observed: Option<f64>,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RatesOutcome {
+    /// `λ̂_X` and `λ̂_Y`.
+    pub rates: (f64, f64),
+    /// `λ̂_X / λ̂_Y`.
+    pub ratio: f64,
+    /// The Wald statistic `(λ̂_X - λ̂_Y) / √(x_X / t_X² + x_Y / t_Y²)` of the E-test, absent for the conditional test.
+    pub statistic: Option<f64>,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+/// Test of `H₀: λ = λ₀` for `count` events over `exposure`.
+pub struct PoissonRateTest {
+    count: u64,
+    exposure: f64,
+    rate: f64,
+    method: RateMethod,
+    significance: f64,
+}
+
+impl PoissonRateTest {
+    pub fn new(count: u64, exposure: f64, rate: f64, significance: f64) -> Result<Self, RateError> {
+        if exposure.is_nan() || exposure <= 0. {
+            return Err(RateError::ExposureInvalid);
+        }
+
+        if rate.is_nan() || rate <= 0. {
+            return Err(RateError::RateInvalid);
+        }
+
+        validate(significance)?;
+
+        Ok(Self {
+            count,
+            exposure,
+            rate,
+            method: RateMethod::Exact,
+            significance,
+        })
+    }
+
+    /// Exact by default.
+    pub fn with_method(mut self, method: RateMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            format!("λ = {}", self.rate),
+            format!("λ ≠ {}", self.rate),
+            Alternative::TwoSided,
+        )
+    }
+
+    pub fn solve(&self) -> Result<bool, RateError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<RateOutcome, RateError> {
+        let expected = self.rate * self.exposure;
+        let (z_observed, p_value) = match self.method {
+            RateMethod::Exact => (None, poisson_two_sided_p_value(self.count, expected)),
+            RateMethod::Normal => {
+                let z = (self.count as f64 - expected) / expected.sqrt();
+                (Some(z), 2. * Normal::standard().sf(z.abs()))
+            }
+        };
+
+        // `χ²(2x) / 2` and `χ²(2x + 2) / 2` quantiles bound the mean of the count.
+        let half_chi_squared_quantile = |freedom_degrees: f64, p: f64| {
+            ChiSquared::new(freedom_degrees)
+                .map_or(0., |chi_squared| chi_squared.inverse_cdf(p) / 2.)
+        };
+        let count = self.count as f64;
+        let confidence_interval = (
+            half_chi_squared_quantile(2. * count, self.significance / 2.) / self.exposure,
+            half_chi_squared_quantile(2. * count + 2., 1. - self.significance / 2.) / self.exposure,
+        );
+
+        Ok(RateOutcome {
+            rate: count / self.exposure,
+            confidence_interval,
+            z_observed,
+            p_value,
+            hypotheses: self.hypotheses(),
+            accepted: p_value > self.significance,
+        })
+    }
+}
+
+/// Test of `H₀: λ_X = λ_Y` for the counts of two processes over their exposures.
+pub struct PoissonRatesComparison {
+    x_count: u64,
+    x_exposure: f64,
+    y_count: u64,
+    y_exposure: f64,
+    method: RatesMethod,
+    significance: f64,
+}
+
+impl PoissonRatesComparison {
+    pub fn new(
+        x_count: u64,
+        x_exposure: f64,
+        y_count: u64,
+        y_exposure: f64,
+        significance: f64,
+    ) -> Result<Self, RateError> {
+        if !(x_exposure > 0. && y_exposure > 0.) {
+            return Err(RateError::ExposureInvalid);
+        }
+
+        if x_count + y_count == 0 {
+            return Err(RateError::CountsDegenerate);
+        }
+
+        validate(significance)?;
+
+        Ok(Self {
+            x_count,
+            x_exposure,
+            y_count,
+            y_exposure,
+            method: RatesMethod::ConditionalBinomial,
+            significance,
+        })
+    }
+
+    /// Conditional binomial by default.
+    pub fn with_method(mut self, method: RatesMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new("λ_X = λ_Y", "λ_X ≠ λ_Y", Alternative::TwoSided)
+    }
+
+    pub fn solve(&self) -> Result<bool, RateError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<RatesOutcome, RateError> {
+        let (t_x, t_y) = (self.x_exposure, self.y_exposure);
+        let rates = (self.x_count as f64 / t_x, self.y_count as f64 / t_y);
+        let wald = |x: u64, y: u64| {
+            let (x, y) = (x as f64, y as f64);
+            let variance = x / (t_x * t_x) + y / (t_y * t_y);
+            if variance == 0. {
+                0.
+            } else {
+                (x / t_x - y / t_y) / variance.sqrt()
+            }
+        };
+
+        let (statistic, p_value) = match self.method {
+            RatesMethod::ConditionalBinomial => (
+                None,
+                binomial_two_sided_p_value(
+                    self.x_count,
+                    self.x_count + self.y_count,
+                    t_x / (t_x + t_y),
+                ),
+            ),
+            RatesMethod::ETest => {
+                let observed = wald(self.x_count, self.y_count);
+                let pooled = (self.x_count + self.y_count) as f64 / (t_x + t_y);
+                let (x_mean, y_mean) = (pooled * t_x, pooled * t_y);
+
+                // The Poisson probabilities beyond these counts are negligible.
+                let upper = |mean: f64| (mean + 12. * mean.sqrt() + 12.).ceil() as u64;
+                let y_probabilities: Vec<_> = (0..=upper(y_mean))
+                    .map(|y| poisson_probability(y, y_mean))
+                    .collect();
+                let p_value = (0..=upper(x_mean))
+                    .map(|x| {
+                        poisson_probability(x, x_mean)
+                            * y_probabilities
+                                .iter()
+                                .enumerate()
+                                .filter(|(y, _)| {
+                                    wald(x, *y as u64).abs() >= observed.abs() * (1. - 1e-9)
+                                })
+                                .map(|(_, p)| p)
+                                .sum::<f64>()
+                    })
+                    .sum::<f64>();
+                (Some(observed), p_value.min(1.))
+            }
+        };
+
+        Ok(RatesOutcome {
+            rates,
+            ratio: rates.0 / rates.1,
+            statistic,
+            p_value,
+            hypotheses: self.hypotheses(),
+            accepted: p_value > self.significance,
+        })
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum DispersionMethod {
+    /// `D = Σ (xᵢ - x̄)² / x̄ ~ χ²(n - 1)`.
+    IndexOfDispersion,
+    /// The t-test of `α` in the regression `((xᵢ - μᵢ)² - xᵢ) / μᵢ = α μᵢ + εᵢ` without intercept,
+    /// `Var(X) = μ + α μ²` being the variance of the negative binomial counts.
+    CameronTrivedi,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DispersionOutcome {
+    /// `s² / x̄`, which is `1` for Poisson counts.
+    pub dispersion_index: f64,
+    /// `α̂` of the negative binomial variance, estimated by the regression.
+    pub overdispersion: Option<f64>,
+    /// `D` for the index of dispersion and `t` for the regression.
+    pub statistic: f64,
+    pub freedom_degrees: f64,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    /// Whether the Poisson hypothesis of equal variance and mean is accepted.
+    pub accepted: bool,
+}
+
+/// Test of `H₀: Var(X) = E(X)` against overdispersion `Var(X) > E(X)` of the counts.
+pub struct DispersionTest {
+    counts: Vec<u64>,
+    means: Option<Vec<f64>>,
+    method: DispersionMethod,
+    significance: f64,
+}
+
+impl DispersionTest {
+    pub fn new(counts: &[u64], significance: f64) -> Result<Self, RateError> {
+        if counts.len() < 2 {
+            return Err(RateError::SampleTooSmall);
+        }
+
+        if counts.iter().all(|count| *count == 0) {
+            return Err(RateError::CountsDegenerate);
+        }
+
+        validate(significance)?;
+
+        Ok(Self {
+            counts: counts.to_owned(),
+            means: None,
+            method: DispersionMethod::IndexOfDispersion,
+            significance,
+        })
+    }
+
+    /// Index of dispersion by default.
+    pub fn with_method(mut self, method: DispersionMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Means `μᵢ` fitted to the counts, e.g. by a Poisson regression, for the regression-based test;
+    /// all equal to `x̄` by default.
+    pub fn with_fitted_means(mut self, means: &[f64]) -> Result<Self, RateError> {
+        if means.len() != self.counts.len() {
+            return Err(RateError::LengthsMismatch);
+        }
+
+        if means.iter().any(|mean| mean.is_nan() || *mean <= 0.) {
+            return Err(RateError::RateInvalid);
+        }
+
+        self.means = Some(means.to_owned());
+        Ok(self)
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            "Var(X) = E(X), the counts are Poisson",
+            "Var(X) > E(X), the counts are overdispersed",
+            Alternative::Greater,
+        )
+    }
+
+    pub fn solve(&self) -> Result<bool, RateError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<DispersionOutcome, RateError> {
+        let counts: Vec<_> = self.counts.iter().map(|count| *count as f64).collect();
+        let n = counts.len() as f64;
+        let mean = counts.iter().sum::<f64>() / n;
+        let squares = counts.iter().map(|x| (x - mean).powi(2)).sum::<f64>();
+        let dispersion_index = squares / (n - 1.) / mean;
+
+        let (overdispersion, statistic, p_value) = match self.method {
+            DispersionMethod::IndexOfDispersion => {
+                let statistic = squares / mean;
+                let p_value = ChiSquared::new(n - 1.)
+                    .map_err(|_| RateError::SampleTooSmall)?
+                    .sf(statistic);
+                (None, statistic, p_value)
+            }
+            DispersionMethod::CameronTrivedi => {
+                let means = self
+                    .means
+                    .clone()
+                    .unwrap_or_else(|| vec![mean; counts.len()]);
+                let responses: Vec<_> = counts
+                    .iter()
+                    .zip(&means)
+                    .map(|(x, mu)| ((x - mu).powi(2) - x) / mu)
+                    .collect();
+                let means_squares = means.iter().map(|mu| mu * mu).sum::<f64>();
+                let alpha = responses
+                    .iter()
+                    .zip(&means)
+                    .map(|(z, mu)| z * mu)
+                    .sum::<f64>()
+                    / means_squares;
+                let residual_variance = responses
+                    .iter()
+                    .zip(&means)
+                    .map(|(z, mu)| (z - alpha * mu).powi(2))
+                    .sum::<f64>()
+                    / (n - 1.);
+
+                let t = alpha / (residual_variance / means_squares).sqrt();
+                let p_value = StudentsT::new(0., 1., n - 1.)
+                    .map_err(|_| RateError::SampleTooSmall)?
+                    .sf(t);
+                (Some(alpha), t, p_value)
+            }
+        };
+
+        Ok(DispersionOutcome {
+            dispersion_index,
+            overdispersion,
+            statistic,
+            freedom_degrees: n - 1.,
+            p_value,
+            hypotheses: self.hypotheses(),
+            accepted: p_value > self.significance,
+        })
+    }
+}
+
+fn validate(significance: f64) -> Result<(), RateError> {
+    alpha::validate(significance, RateError::SignificanceInvalid)?;
+
+    Ok(())
+}
+
+fn poisson_probability(count: u64, mean: f64) -> f64 {
+    let count = count as f64;
+    let ln_count = if count == 0. { 0. } else { count * mean.ln() };
+    (ln_count - mean - ln_gamma(count + 1.)).exp()
+}
+
+/// Total probability of the counts no more probable than the observed one.
+fn poisson_two_sided_p_value(count: u64, mean: f64) -> f64 {
+    let observed = poisson_probability(count, mean);
+    let spread = 40. * mean.sqrt() + 40.;
+    let lower = (mean - spread).max(0.) as u64;
+    let upper = (mean + spread).max(count as f64) as u64;
+
+    let p_value = (lower..=upper)
+        .map(|k| poisson_probability(k, mean))
+        // The relative tolerance keeps the counts as probable as the observed one despite rounding.
+        .filter(|p| *p <= observed * (1. + 1e-7))
+        .sum::<f64>();
+
+    p_value.min(1.)
+}
+
+/// Total probability of the binomial counts no more probable than the observed one.
+pub(crate) fn binomial_two_sided_p_value(successes: u64, trials: u64, p: f64) -> f64 {
+    let probability = |k: u64| {
+        let (n, k) = (trials as f64, k as f64);
+        let ln_powers = |count: f64, p: f64| if count == 0. { 0. } else { count * p.ln() };
+        (ln_gamma(n + 1.) - ln_gamma(k + 1.) - ln_gamma(n - k + 1.)
+            + ln_powers(k, p)
+            + ln_powers(n - k, 1. - p))
+        .exp()
+    };
+
+    let observed = probability(successes);
+    let p_value = (0..=trials)
+        .map(probability)
+        .filter(|q| *q <= observed * (1. + 1e-7))
+        .sum::<f64>();
+
+    p_value.min(1.)
+}
@@ -0,0 +1,164 @@
+//! Constructors from Polars data frames, so that tabular data feeds the tests directly.
+//!
+//! - A grouped sample is read from a column of intervals and a column of frequencies.
+//! - A raw sample is read from a numeric column.
+//! - Samples of several groups are split by a column of group labels, in the order of first appearance.
+
+use polars::prelude::{DataFrame, DataType};
+
+use crate::grouping::Groups;
+use crate::normal_distribution_hypothesis::{IncompleteNDHProblemSituation, NDHError};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum PolarsInteropError {
+    ColumnMissing,
+    ColumnTypeInvalid,
+    ValuesMissing,
+    IntervalInvalid,
+    GroupsCountInvalid,
+    NormalDistribution(NDHError),
+}
+
+impl std::fmt::Display for PolarsInteropError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PolarsInteropError::ColumnMissing => {
+                write!(f, "Data frame has no column of the given name")
+            }
+            PolarsInteropError::ColumnTypeInvalid => {
+                write!(f, "Column cannot be converted to the required type")
+            }
+            PolarsInteropError::ValuesMissing => {
+                write!(f, "Column must not contain nulls")
+            }
+            PolarsInteropError::IntervalInvalid => {
+                write!(
+                    f,
+                    "Intervals must be written as `[a; b)`, `[a, b)`, `a..b` or `a; b`"
+                )
+            }
+            PolarsInteropError::GroupsCountInvalid => {
+                write!(f, "Group column must contain exactly two distinct labels")
+            }
+            PolarsInteropError::NormalDistribution(e) => {
+                write!(f, "{e}")
+            }
+        }
+    }
+}
+
+impl IncompleteNDHProblemSituation {
+    /// Reads the random value ranges from the string column `interval_column` and their frequencies
+    /// from the numeric column `frequency_column`.
+    pub fn from_grouped_data_frame(
+        data_frame: &DataFrame,
+        interval_column: &str,
+        frequency_column: &str,
+        significance: f64,
+    ) -> Result<Self, PolarsInteropError> {
+        let random_value_ranges = strings(data_frame, interval_column)?
+            .iter()
+            .map(|interval| parse_interval(interval))
+            .collect::<Result<Vec<_>, _>>()?;
+        let frequencies = numbers(data_frame, frequency_column)?;
+
+        Self::new(&random_value_ranges, &frequencies, significance)
+            .map_err(PolarsInteropError::NormalDistribution)
+    }
+
+    /// Groups the raw sample of the numeric column `column` by Sturges' rule.
+    pub fn from_data_frame(
+        data_frame: &DataFrame,
+        column: &str,
+        significance: f64,
+    ) -> Result<Self, PolarsInteropError> {
+        Self::from_raw_sample(&numbers(data_frame, column)?, significance)
+            .map_err(PolarsInteropError::NormalDistribution)
+    }
+}
+
+/// Values of `value_column` split by the labels of `group_column`, e.g. for the one-way analysis of variance.
+pub fn groups(
+    data_frame: &DataFrame,
+    value_column: &str,
+    group_column: &str,
+) -> Result<Vec<(String, Vec<f64>)>, PolarsInteropError> {
+    let values = numbers(data_frame, value_column)?;
+    let labels = strings(data_frame, group_column)?;
+
+    // Both columns come from the same data frame, so their lengths match.
+    let groups = Groups::new(&values, &labels).map_err(|_| PolarsInteropError::ValuesMissing)?;
+
+    Ok(groups.labels.into_iter().zip(groups.samples).collect())
+}
+
+/// Samples **X** and **Y** of a two-sample test from a column with exactly two group labels.
+pub fn two_samples(
+    data_frame: &DataFrame,
+    value_column: &str,
+    group_column: &str,
+) -> Result<(Vec<f64>, Vec<f64>), PolarsInteropError> {
+    let mut groups = groups(data_frame, value_column, group_column)?;
+    if groups.len() != 2 {
+        return Err(PolarsInteropError::GroupsCountInvalid);
+    }
+
+    let (_, y_sample) = groups.pop().unwrap();
+    let (_, x_sample) = groups.pop().unwrap();
+    Ok((x_sample, y_sample))
+}
+
+fn numbers(data_frame: &DataFrame, column: &str) -> Result<Vec<f64>, PolarsInteropError> {
+    let column = data_frame
+        .column(column)
+        .map_err(|_| PolarsInteropError::ColumnMissing)?
+        .cast(&DataType::Float64)
+        .map_err(|_| PolarsInteropError::ColumnTypeInvalid)?;
+
+    column
+        .f64()
+        .map_err(|_| PolarsInteropError::ColumnTypeInvalid)?
+        .iter()
+        .map(|value| value.ok_or(PolarsInteropError::ValuesMissing))
+        .collect()
+}
+
+fn strings(data_frame: &DataFrame, column: &str) -> Result<Vec<String>, PolarsInteropError> {
+    let column = data_frame
+        .column(column)
+        .map_err(|_| PolarsInteropError::ColumnMissing)?
+        .cast(&DataType::String)
+        .map_err(|_| PolarsInteropError::ColumnTypeInvalid)?;
+
+    column
+        .str()
+        .map_err(|_| PolarsInteropError::ColumnTypeInvalid)?
+        .iter()
+        .map(|value| {
+            value
+                .map(str::to_string)
+                .ok_or(PolarsInteropError::ValuesMissing)
+        })
+        .collect()
+}
+
+fn parse_interval(interval: &str) -> Result<(f64, f64), PolarsInteropError> {
+    let interval = interval
+        .trim()
+        .trim_start_matches(['[', '('])
+        .trim_end_matches([']', ')']);
+    let (start, end) = interval
+        .split_once("..")
+        .or_else(|| interval.split_once(';'))
+        .or_else(|| interval.split_once(','))
+        .ok_or(PolarsInteropError::IntervalInvalid)?;
+
+    let parse = |bound: &str| {
+        bound
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| PolarsInteropError::IntervalInvalid)
+    };
+    Ok((parse(start)?, parse(end)?))
+}
@@ -0,0 +1,195 @@
+//! Extended precision mode of the Normal Distribution Hypothesis, for verifying borderline answers
+//! where the decision flips within `f64` rounding.
+//!
+//! The inputs are taken by their shortest decimal representation (`0.05` is exactly `5/100`, not the
+//! nearest binary fraction), then the Chi-Squared statistic, the critical value and their comparison
+//! are carried out with the requested number of mantissa bits. The critical value is refined by
+//! Newton's method over the series of the regularized incomplete Gamma function, starting from the
+//! `f64` quantile.
+//!
+//! Theoretical frequencies the situation computes itself (the incomplete situation) are `f64`.
+
+use astro_float::{BigFloat, Consts, Radix, RoundingMode};
+use statrs::distribution::{ChiSquared, ContinuousCDF};
+
+use crate::alpha;
+use crate::normal_distribution_hypothesis::NDHProblemSituation;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum PreciseError {
+    SignificanceInvalid,
+    FreedomDegreesInvalid,
+    FrequenciesInvalid,
+    PrecisionInvalid,
+}
+
+impl std::fmt::Display for PreciseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            PreciseError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            PreciseError::FreedomDegreesInvalid => {
+                write!(f, "Freedom Degrees must be positive")
+            }
+            PreciseError::FrequenciesInvalid => {
+                write!(
+                    f,
+                    "Frequencies must be finite and theoretical ones positive"
+                )
+            }
+            PreciseError::PrecisionInvalid => {
+                write!(f, "Precision must be at least 64 bits")
+            }
+        }
+    }
+}
+
+const ROUNDING: RoundingMode = RoundingMode::ToEven;
+const NEWTON_ITERATIONS: usize = 8;
+
+#[derive(Clone, Debug)]
+pub struct PreciseNDHOutcome {
+    pub freedom_degrees: usize,
+    pub chi_squared_observed: BigFloat,
+    pub chi_squared_critical_value: BigFloat,
+    /// Whether the hypothesis of normality is accepted.
+    pub accepted: bool,
+}
+
+/// Solves the Normal Distribution Hypothesis with `precision` mantissa bits.
+pub fn solve_normal_distribution_hypothesis(
+    situation: &dyn NDHProblemSituation,
+    precision: usize,
+) -> Result<PreciseNDHOutcome, PreciseError> {
+    if precision < 64 {
+        return Err(PreciseError::PrecisionInvalid);
+    }
+
+    let significance = situation.significance();
+    alpha::validate(significance, PreciseError::SignificanceInvalid)?;
+
+    let empirical_sample = situation.empirical_sample();
+    let theoretical_sample = situation.theoretical_sample();
+    let freedom_degrees = empirical_sample
+        .len()
+        .checked_sub(3)
+        .filter(|k| *k > 0)
+        .ok_or(PreciseError::FreedomDegreesInvalid)?;
+    if empirical_sample
+        .iter()
+        .chain(theoretical_sample.iter())
+        .any(|m| !m.is_finite())
+        || theoretical_sample.iter().any(|t| *t <= 0.)
+    {
+        return Err(PreciseError::FrequenciesInvalid);
+    }
+
+    let mut cc = Consts::new().map_err(|_| PreciseError::PrecisionInvalid)?;
+    let p = precision;
+
+    let mut chi_squared_observed = BigFloat::from_word(0, p);
+    for (e, t) in empirical_sample.iter().zip(theoretical_sample.iter()) {
+        let (e, t) = (decimal(*e, p, &mut cc), decimal(*t, p, &mut cc));
+        let difference = e.sub(&t, p, ROUNDING);
+        let term = difference
+            .mul(&difference, p, ROUNDING)
+            .div(&t, p, ROUNDING);
+        chi_squared_observed = chi_squared_observed.add(&term, p, ROUNDING);
+    }
+
+    let chi_squared_critical_value = chi_squared_critical_value(
+        freedom_degrees,
+        &decimal(significance, p, &mut cc),
+        significance,
+        p,
+        &mut cc,
+    );
+
+    Ok(PreciseNDHOutcome {
+        freedom_degrees,
+        accepted: chi_squared_observed < chi_squared_critical_value,
+        chi_squared_observed,
+        chi_squared_critical_value,
+    })
+}
+
+/// `value` parsed from its shortest round-trip decimal representation.
+fn decimal(value: f64, p: usize, cc: &mut Consts) -> BigFloat {
+    BigFloat::parse(&format!("{value:e}"), Radix::Dec, p, ROUNDING, cc)
+}
+
+/// `Γ(k / 2)` for a positive integer `k`, built up from `Γ(1) = 1` or `Γ(1/2) = √π`.
+fn gamma_half_integer(k: usize, p: usize, cc: &mut Consts) -> BigFloat {
+    let (mut gamma, mut a) = if k.is_multiple_of(2) {
+        (BigFloat::from_word(1, p), BigFloat::from_word(1, p))
+    } else {
+        let half = BigFloat::from_f64(0.5, p);
+        (cc.pi(p, ROUNDING).sqrt(p, ROUNDING), half)
+    };
+
+    let one = BigFloat::from_word(1, p);
+    let target = BigFloat::from_f64(k as f64 / 2., p);
+    while a < target {
+        gamma = gamma.mul(&a, p, ROUNDING);
+        a = a.add(&one, p, ROUNDING);
+    }
+    gamma
+}
+
+/// `P(X <= x)` and the density at `x` of the Chi-Squared distribution with `k` freedom degrees.
+fn chi_squared_cdf_pdf(k: usize, x: &BigFloat, p: usize, cc: &mut Consts) -> (BigFloat, BigFloat) {
+    let two = BigFloat::from_word(2, p);
+    let a = BigFloat::from_f64(k as f64 / 2., p);
+    let half_x = x.div(&two, p, ROUNDING);
+
+    // x^a e^(-x) / Γ(a) with `x` standing for `x / 2`.
+    let gamma_a = gamma_half_integer(k, p, cc);
+    let power_term = a
+        .mul(&half_x.ln(p, ROUNDING, cc), p, ROUNDING)
+        .sub(&half_x, p, ROUNDING)
+        .exp(p, ROUNDING, cc)
+        .div(&gamma_a, p, ROUNDING);
+    let pdf = power_term.div(x, p, ROUNDING);
+
+    // P(a, x) = x^a e^(-x) / Γ(a + 1) · Σ xⁿ / ((a + 1)…(a + n)).
+    let one = BigFloat::from_word(1, p);
+    let epsilon = BigFloat::from_word(1, p).div(&two.powi(p, p, ROUNDING), p, ROUNDING);
+    let mut term = power_term.div(&a, p, ROUNDING);
+    let mut sum = term.clone();
+    let mut denominator = a.clone();
+    while term > sum.mul(&epsilon, p, ROUNDING) {
+        denominator = denominator.add(&one, p, ROUNDING);
+        term = term
+            .mul(&half_x, p, ROUNDING)
+            .div(&denominator, p, ROUNDING);
+        sum = sum.add(&term, p, ROUNDING);
+    }
+
+    (sum, pdf)
+}
+
+fn chi_squared_critical_value(
+    k: usize,
+    significance: &BigFloat,
+    significance_f64: f64,
+    p: usize,
+    cc: &mut Consts,
+) -> BigFloat {
+    let target = BigFloat::from_word(1, p).sub(significance, p, ROUNDING);
+    let start = ChiSquared::new(k as f64)
+        .unwrap()
+        .inverse_cdf(1. - significance_f64);
+
+    let mut x = BigFloat::from_f64(start, p);
+    for _ in 0..NEWTON_ITERATIONS {
+        let (cdf, pdf) = chi_squared_cdf_pdf(k, &x, p, cc);
+        x = x.sub(
+            &cdf.sub(&target, p, ROUNDING).div(&pdf, p, ROUNDING),
+            p,
+            ROUNDING,
+        );
+    }
+    x
+}
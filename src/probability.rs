@@ -0,0 +1,121 @@
+//! Probabilities of events of the common distributions, the direct questions that accompany
+//! the hypothesis problems.
+//!
+//! **Given**: the parameters of a normal, binomial or Poisson distribution and an event.
+//! **To figure out**: What is the probability of the event, e.g. that `a < X < b` for `X ~ N(μ, σ²)`,
+//! or that at least `k` of `n` trials succeed?
+//!
+//! Upper tails are computed from the survival functions rather than as `1 - F(x)`, which keeps small
+//! probabilities accurate.
+
+use statrs::distribution::{Binomial, ContinuousCDF, Discrete, DiscreteCDF, Normal, Poisson};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum ProbabilityError {
+    DeviationInvalid,
+    ProbabilityInvalid,
+    RateInvalid,
+}
+
+impl std::fmt::Display for ProbabilityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ProbabilityError::DeviationInvalid => {
+                write!(f, "Standard deviation must be positive and the mean finite")
+            }
+            ProbabilityError::ProbabilityInvalid => {
+                write!(f, "Probability of success must be between 0.0 and 1.0")
+            }
+            ProbabilityError::RateInvalid => {
+                write!(f, "Rate of the Poisson distribution must be positive")
+            }
+        }
+    }
+}
+
+/// `P(X < x)` for `X ~ N(μ, σ²)`.
+pub fn normal_below(x: f64, mean: f64, std_dev: f64) -> Result<f64, ProbabilityError> {
+    Ok(normal(mean, std_dev)?.cdf(x))
+}
+
+/// `P(X > x)` for `X ~ N(μ, σ²)`.
+pub fn normal_above(x: f64, mean: f64, std_dev: f64) -> Result<f64, ProbabilityError> {
+    Ok(normal(mean, std_dev)?.sf(x))
+}
+
+/// `P(a < X < b)` for `X ~ N(μ, σ²)`, zero if `a ≥ b`.
+pub fn normal_between(a: f64, b: f64, mean: f64, std_dev: f64) -> Result<f64, ProbabilityError> {
+    let normal = normal(mean, std_dev)?;
+    if a >= b {
+        return Ok(0.);
+    }
+
+    // Both bounds above the mean lose less precision in the upper tail.
+    let probability = if a > mean {
+        normal.sf(a) - normal.sf(b)
+    } else {
+        normal.cdf(b) - normal.cdf(a)
+    };
+
+    Ok(probability.max(0.))
+}
+
+/// `P(|X - μ| < δ)` for `X ~ N(μ, σ²)`, `2 Φ₀(δ / σ)` in terms of the Laplace function.
+pub fn normal_within(deviation: f64, mean: f64, std_dev: f64) -> Result<f64, ProbabilityError> {
+    normal_between(mean - deviation, mean + deviation, mean, std_dev)
+}
+
+/// `P(X = k)` for `X ~ B(n, p)`.
+pub fn exactly_k_successes(n: u64, p: f64, k: u64) -> Result<f64, ProbabilityError> {
+    Ok(binomial(n, p)?.pmf(k))
+}
+
+/// `P(X ≤ k)` for `X ~ B(n, p)`.
+pub fn at_most_k_successes(n: u64, p: f64, k: u64) -> Result<f64, ProbabilityError> {
+    Ok(binomial(n, p)?.cdf(k))
+}
+
+/// `P(X ≥ k)` for `X ~ B(n, p)`.
+pub fn at_least_k_successes(n: u64, p: f64, k: u64) -> Result<f64, ProbabilityError> {
+    let binomial = binomial(n, p)?;
+    Ok(match k {
+        0 => 1.,
+        k => binomial.sf(k - 1),
+    })
+}
+
+/// `P(X = k)` for `X ~ Poisson(λ)`.
+pub fn poisson_exactly(k: u64, rate: f64) -> Result<f64, ProbabilityError> {
+    Ok(poisson(rate)?.pmf(k))
+}
+
+/// `P(X ≤ k)` for `X ~ Poisson(λ)`.
+pub fn poisson_at_most(k: u64, rate: f64) -> Result<f64, ProbabilityError> {
+    Ok(poisson(rate)?.cdf(k))
+}
+
+/// `P(X ≥ k)` for `X ~ Poisson(λ)`.
+pub fn poisson_at_least(k: u64, rate: f64) -> Result<f64, ProbabilityError> {
+    let poisson = poisson(rate)?;
+    Ok(match k {
+        0 => 1.,
+        k => poisson.sf(k - 1),
+    })
+}
+
+fn normal(mean: f64, std_dev: f64) -> Result<Normal, ProbabilityError> {
+    if !(std_dev > 0. && std_dev.is_finite() && mean.is_finite()) {
+        return Err(ProbabilityError::DeviationInvalid);
+    }
+
+    Normal::new(mean, std_dev).map_err(|_| ProbabilityError::DeviationInvalid)
+}
+
+fn binomial(n: u64, p: f64) -> Result<Binomial, ProbabilityError> {
+    Binomial::new(p, n).map_err(|_| ProbabilityError::ProbabilityInvalid)
+}
+
+fn poisson(rate: f64) -> Result<Poisson, ProbabilityError> {
+    Poisson::new(rate).map_err(|_| ProbabilityError::RateInvalid)
+}
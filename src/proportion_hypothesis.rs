@@ -0,0 +1,243 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *numbers of successes out of numbers of trials* in two independent samples.
+//! **To figure out**: Is it appropriate to **assume** that the probabilities of success `p_X` and `p_Y` are equal?
+//!
+//! The pooled z-test compares the difference of the proportions with its standard error under `p_X = p_Y`,
+//! optionally with Yates' continuity correction; Fisher's exact test sums the hypergeometric probabilities
+//! of the tables with the same margins that are no more probable than the observed one.
+//! If the samples are drawn without replacement from finite populations, the z-tests reduce the variance
+//! of each proportion by the finite population correction `1 - n / N`.
+
+use num_bigint::BigUint;
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::alpha;
+use crate::combinatorics::{n_choose_k, ratio};
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::sampling::finite_population_correction;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum ProportionError {
+    SignificanceInvalid,
+    CountsInvalid,
+    ProportionsDegenerate,
+    PopulationSizeInvalid,
+}
+
+impl std::fmt::Display for ProportionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ProportionError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            ProportionError::CountsInvalid => {
+                write!(
+                    f,
+                    "Each sample must have at least one trial and no more successes than trials"
+                )
+            }
+            ProportionError::ProportionsDegenerate => {
+                write!(
+                    f,
+                    "All trials are successes, or none is, so the z statistic is undefined"
+                )
+            }
+            ProportionError::PopulationSizeInvalid => {
+                write!(
+                    f,
+                    "Each population must hold at least its sample, and one of them more"
+                )
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ProportionMethod {
+    /// `z = (p̂_X - p̂_Y) / √(p̂ (1 - p̂) (1/n_X + 1/n_Y))` with the pooled proportion `p̂`,
+    /// each term multiplied by `1 - n / N` for finite populations.
+    PooledZ,
+    /// The pooled z-test with `|p̂_X - p̂_Y|` reduced by `(1/n_X + 1/n_Y) / 2`.
+    ContinuityCorrectedZ,
+    /// Fisher's exact test of the 2 × 2 table.
+    FisherExact,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ProportionsOutcome {
+    pub method: ProportionMethod,
+    /// `p̂_X` and `p̂_Y`.
+    pub proportions: (f64, f64),
+    /// `p̂_X - p̂_Y`.
+    pub difference: f64,
+    /// The `z` statistic, absent for the exact test.
+    pub z_observed: Option<f64>,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+/// Smallest count expected under `p_X = p_Y` for which the normal approximation is trusted.
+const EXPECTED_COUNT_MIN: f64 = 5.;
+/// Smallest count expected under `p_X = p_Y` for which the continuity correction is not needed.
+const EXPECTED_COUNT_UNCORRECTED_MIN: f64 = 10.;
+
+pub struct CompareProportions {
+    x_successes: u64,
+    x_trials: u64,
+    y_successes: u64,
+    y_trials: u64,
+    method: ProportionMethod,
+    significance: f64,
+    population_sizes: Option<(f64, f64)>,
+}
+
+impl CompareProportions {
+    /// The method is the recommended one unless chosen with [`CompareProportions::with_method`].
+    pub fn new(
+        x_successes: u64,
+        x_trials: u64,
+        y_successes: u64,
+        y_trials: u64,
+        significance: f64,
+    ) -> Result<Self, ProportionError> {
+        if x_trials == 0 || y_trials == 0 || x_successes > x_trials || y_successes > y_trials {
+            return Err(ProportionError::CountsInvalid);
+        }
+
+        alpha::validate(significance, ProportionError::SignificanceInvalid)?;
+
+        let mut comparison = Self {
+            x_successes,
+            x_trials,
+            y_successes,
+            y_trials,
+            method: ProportionMethod::PooledZ,
+            significance,
+            population_sizes: None,
+        };
+        comparison.method = comparison.recommended_method();
+
+        Ok(comparison)
+    }
+
+    pub fn with_method(mut self, method: ProportionMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Sizes of the finite populations the samples are drawn from without replacement;
+    /// `f64::INFINITY` leaves a sample uncorrected. Fisher's exact test is conditional
+    /// on the margins and does not depend on them.
+    pub fn with_population_sizes(
+        mut self,
+        x_size: f64,
+        y_size: f64,
+    ) -> Result<Self, ProportionError> {
+        let (n_x, n_y) = (self.x_trials as f64, self.y_trials as f64);
+        if !(x_size >= n_x && y_size >= n_y) || (x_size == n_x && y_size == n_y) {
+            return Err(ProportionError::PopulationSizeInvalid);
+        }
+
+        self.population_sizes = Some((x_size, y_size));
+        Ok(self)
+    }
+
+    /// Fisher's exact test if a count expected under `p_X = p_Y` is below 5,
+    /// the continuity-corrected z-test if one is below 10, and the pooled z-test otherwise.
+    pub fn recommended_method(&self) -> ProportionMethod {
+        let (n_x, n_y) = (self.x_trials as f64, self.y_trials as f64);
+        let pooled = (self.x_successes + self.y_successes) as f64 / (n_x + n_y);
+        let smallest_expected = n_x.min(n_y) * pooled.min(1. - pooled);
+
+        if smallest_expected < EXPECTED_COUNT_MIN {
+            ProportionMethod::FisherExact
+        } else if smallest_expected < EXPECTED_COUNT_UNCORRECTED_MIN {
+            ProportionMethod::ContinuityCorrectedZ
+        } else {
+            ProportionMethod::PooledZ
+        }
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new("p_X = p_Y", "p_X ≠ p_Y", Alternative::TwoSided)
+    }
+
+    pub fn solve(&self) -> Result<bool, ProportionError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<ProportionsOutcome, ProportionError> {
+        let (n_x, n_y) = (self.x_trials as f64, self.y_trials as f64);
+        let proportions = (self.x_successes as f64 / n_x, self.y_successes as f64 / n_y);
+        let difference = proportions.0 - proportions.1;
+
+        let (z_observed, p_value) = match self.method {
+            ProportionMethod::PooledZ | ProportionMethod::ContinuityCorrectedZ => {
+                let pooled = (self.x_successes + self.y_successes) as f64 / (n_x + n_y);
+                if pooled == 0. || pooled == 1. {
+                    return Err(ProportionError::ProportionsDegenerate);
+                }
+
+                let correction = if self.method == ProportionMethod::ContinuityCorrectedZ {
+                    (1. / n_x + 1. / n_y) / 2.
+                } else {
+                    0.
+                };
+                let (x_fpc, y_fpc) = match self.population_sizes {
+                    Some((x_size, y_size)) => (
+                        finite_population_correction(n_x, x_size),
+                        finite_population_correction(n_y, y_size),
+                    ),
+                    None => (1., 1.),
+                };
+                let z = difference.signum() * (difference.abs() - correction).max(0.)
+                    / (pooled * (1. - pooled) * (x_fpc / n_x + y_fpc / n_y)).sqrt();
+                (Some(z), 2. * Normal::standard().sf(z.abs()))
+            }
+            ProportionMethod::FisherExact => (None, self.fisher_exact_p_value()),
+        };
+
+        Ok(ProportionsOutcome {
+            method: self.method,
+            proportions,
+            difference,
+            z_observed,
+            p_value,
+            hypotheses: self.hypotheses(),
+            accepted: p_value > self.significance,
+        })
+    }
+
+    /// Two-sided p-value: the total probability of the tables no more probable than the observed one.
+    ///
+    /// The table with `a` successes of **X** has the probability `C(n_X, a) C(n_Y, s - a) / C(n_X + n_Y, s)`;
+    /// the numerators are compared exactly.
+    fn fisher_exact_p_value(&self) -> f64 {
+        let (n_x, n_y) = (self.x_trials, self.y_trials);
+        let successes = self.x_successes + self.y_successes;
+        let (first, last) = (successes.saturating_sub(n_y), successes.min(n_x));
+
+        // `C(n_X, a + 1) = C(n_X, a) (n_X - a) / (a + 1)` and `C(n_Y, s - a - 1) = C(n_Y, s - a) (s - a) / (n_Y - s + a + 1)`,
+        // each division exact.
+        let mut weights = vec![n_choose_k(n_x, first) * n_choose_k(n_y, successes - first)];
+        for a in first..last {
+            let weight = &weights[weights.len() - 1] * (n_x - a) * (successes - a)
+                / (a + 1)
+                / (n_y + a + 1 - successes);
+            weights.push(weight);
+        }
+
+        let observed = &weights[(self.x_successes - first) as usize];
+        let favourable = weights
+            .iter()
+            .filter(|weight| *weight <= observed)
+            .sum::<BigUint>();
+
+        ratio(&favourable, &n_choose_k(n_x + n_y, successes)).min(1.)
+    }
+}
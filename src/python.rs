@@ -0,0 +1,399 @@
+//! Python module `statistics_problems`, so the solvers can be called from Jupyter notebooks.
+//!
+//! The classes mirror the Rust API: problem situations are passed to the hypotheses,
+//! `solve()` returns the decision and `outcome()` returns the intermediate quantities.
+//! Errors are raised as `ValueError` with the message of the Rust error.
+//!
+//! ```python
+//! from statistics_problems import IncompleteNDHProblemSituation, NormalDistributionHypothesis
+//!
+//! situation = IncompleteNDHProblemSituation([(22, 24), (24, 26), (26, 28)], [2, 12, 34], 0.01)
+//! outcome = NormalDistributionHypothesis(situation).outcome()
+//! print(outcome.hypotheses, outcome.accepted)
+//! ```
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::contingency::{IndependenceHypothesis, IndependenceOutcome};
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::mann_whitney_hypothesis::MannWhitneyHypothesis;
+use crate::normal_distribution_hypothesis::{
+    CompleteNDHProblemSituation, IncompleteNDHProblemSituation, NDHOutcome, NDHProblemSituation,
+    NormalDistributionHypothesis,
+};
+use crate::same_mean_hypothesis::{SameMeanHypothesis, Variances};
+use crate::same_variance_hypothesis::{SVHOutcome, SameVarianceHypothesis};
+use crate::shapiro_wilk_hypothesis::ShapiroWilkHypothesis;
+
+fn value_error(e: impl std::fmt::Display) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+#[pyclass(name = "Hypotheses", get_all, frozen)]
+#[derive(Clone)]
+pub struct PyHypotheses {
+    null: String,
+    alternative: String,
+    /// `"two-sided"`, `"greater"` or `"less"`.
+    sidedness: String,
+}
+
+impl From<Hypotheses> for PyHypotheses {
+    fn from(hypotheses: Hypotheses) -> Self {
+        let sidedness = match hypotheses.sidedness {
+            Alternative::TwoSided => "two-sided",
+            Alternative::Greater => "greater",
+            Alternative::Less => "less",
+        };
+
+        Self {
+            null: hypotheses.null,
+            alternative: hypotheses.alternative,
+            sidedness: sidedness.to_string(),
+        }
+    }
+}
+
+#[pymethods]
+impl PyHypotheses {
+    fn __str__(&self) -> String {
+        format!("H₀: {}\nH₁: {}", self.null, self.alternative)
+    }
+}
+
+#[pyclass(name = "CompleteNDHProblemSituation", frozen)]
+#[derive(Clone)]
+pub struct PyCompleteNDHProblemSituation(CompleteNDHProblemSituation);
+
+#[pymethods]
+impl PyCompleteNDHProblemSituation {
+    #[new]
+    fn new(empirical_sample: Vec<f64>, theoretical_sample: Vec<f64>, alpha: f64) -> PyResult<Self> {
+        CompleteNDHProblemSituation::new(&empirical_sample, &theoretical_sample, alpha)
+            .map(Self)
+            .map_err(value_error)
+    }
+}
+
+#[pyclass(name = "IncompleteNDHProblemSituation", frozen)]
+#[derive(Clone)]
+pub struct PyIncompleteNDHProblemSituation(IncompleteNDHProblemSituation);
+
+#[pymethods]
+impl PyIncompleteNDHProblemSituation {
+    #[new]
+    fn new(
+        random_value_ranges: Vec<(f64, f64)>,
+        frequencies: Vec<f64>,
+        alpha: f64,
+    ) -> PyResult<Self> {
+        IncompleteNDHProblemSituation::new(&random_value_ranges, &frequencies, alpha)
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    /// Groups a raw sample by Sturges' rule.
+    #[staticmethod]
+    fn from_raw_sample(sample: Vec<f64>, alpha: f64) -> PyResult<Self> {
+        IncompleteNDHProblemSituation::from_raw_sample(&sample, alpha)
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    fn mean(&self) -> f64 {
+        self.0.mean()
+    }
+
+    fn std_dev(&self) -> f64 {
+        self.0.std_dev()
+    }
+}
+
+#[pyclass(name = "NDHOutcome", get_all, frozen)]
+pub struct PyNDHOutcome {
+    freedom_degrees: f64,
+    chi_squared_observed: f64,
+    chi_squared_critical_value: f64,
+    /// Lower and upper critical values, `None` for a tail outside the region.
+    critical_region: (Option<f64>, Option<f64>),
+    observed: Vec<f64>,
+    expected: Vec<f64>,
+    chi_terms: Vec<f64>,
+    std_dev_confidence_interval: Option<(f64, f64)>,
+    hypotheses: PyHypotheses,
+    accepted: bool,
+}
+
+impl From<NDHOutcome> for PyNDHOutcome {
+    fn from(outcome: NDHOutcome) -> Self {
+        Self {
+            freedom_degrees: outcome.freedom_degrees,
+            chi_squared_observed: outcome.chi_squared_observed,
+            chi_squared_critical_value: outcome.chi_squared_critical_value,
+            critical_region: (
+                outcome.critical_region.lower(),
+                outcome.critical_region.upper(),
+            ),
+            observed: outcome.bins.iter().map(|bin| bin.observed).collect(),
+            expected: outcome.bins.iter().map(|bin| bin.expected).collect(),
+            chi_terms: outcome.bins.iter().map(|bin| bin.chi_term).collect(),
+            std_dev_confidence_interval: outcome.std_dev_confidence_interval,
+            hypotheses: outcome.hypotheses.into(),
+            accepted: outcome.accepted,
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Situation {
+    Complete(CompleteNDHProblemSituation),
+    Incomplete(IncompleteNDHProblemSituation),
+}
+
+#[pyclass(name = "NormalDistributionHypothesis", frozen)]
+pub struct PyNormalDistributionHypothesis {
+    situation: Situation,
+}
+
+#[pymethods]
+impl PyNormalDistributionHypothesis {
+    /// Accepts either a complete or an incomplete problem situation.
+    #[new]
+    fn new(situation: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let situation = match situation.extract::<PyCompleteNDHProblemSituation>() {
+            Ok(complete) => Situation::Complete(complete.0),
+            Err(_) => {
+                Situation::Incomplete(situation.extract::<PyIncompleteNDHProblemSituation>()?.0)
+            }
+        };
+
+        Ok(Self { situation })
+    }
+
+    fn solve(&self) -> PyResult<bool> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    fn outcome(&self) -> PyResult<PyNDHOutcome> {
+        let situation: Box<dyn NDHProblemSituation> = match self.situation.clone() {
+            Situation::Complete(complete) => Box::new(complete),
+            Situation::Incomplete(incomplete) => Box::new(incomplete),
+        };
+
+        NormalDistributionHypothesis::new(situation)
+            .and_then(|ndh| ndh.outcome())
+            .map(PyNDHOutcome::from)
+            .map_err(value_error)
+    }
+}
+
+#[pyclass(name = "SVHOutcome", get_all, frozen)]
+pub struct PySVHOutcome {
+    variances: (f64, f64),
+    freedom_degrees: (f64, f64),
+    fisher_snedecor_observed: f64,
+    fisher_snedecor_critical_value: f64,
+    hypotheses: PyHypotheses,
+    accepted: bool,
+}
+
+impl From<SVHOutcome> for PySVHOutcome {
+    fn from(outcome: SVHOutcome) -> Self {
+        Self {
+            variances: outcome.variances,
+            freedom_degrees: outcome.freedom_degrees,
+            fisher_snedecor_observed: outcome.fisher_snedecor_observed,
+            fisher_snedecor_critical_value: outcome.fisher_snedecor_critical_value,
+            hypotheses: outcome.hypotheses.into(),
+            accepted: outcome.accepted,
+        }
+    }
+}
+
+#[pyclass(name = "SameVarianceHypothesis", frozen)]
+pub struct PySameVarianceHypothesis(SameVarianceHypothesis);
+
+#[pymethods]
+impl PySameVarianceHypothesis {
+    /// `ratio` is the hypothesized ratio of the variance of **X** to the variance of **Y**.
+    #[new]
+    #[pyo3(signature = (x_sample, y_sample, alpha, ratio = 1.))]
+    fn new(x_sample: Vec<f64>, y_sample: Vec<f64>, alpha: f64, ratio: f64) -> PyResult<Self> {
+        SameVarianceHypothesis::new(&x_sample, &y_sample, alpha)
+            .with_ratio(ratio)
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    fn solve(&self) -> PyResult<bool> {
+        self.0.solve().map_err(value_error)
+    }
+
+    fn outcome(&self) -> PyResult<PySVHOutcome> {
+        self.0
+            .outcome()
+            .map(PySVHOutcome::from)
+            .map_err(value_error)
+    }
+}
+
+#[pyclass(name = "SameMeanHypothesis", frozen)]
+pub struct PySameMeanHypothesis(SameMeanHypothesis);
+
+#[pymethods]
+impl PySameMeanHypothesis {
+    /// Student's t-test if `equal_variances`, Welch's t-test otherwise.
+    #[new]
+    #[pyo3(signature = (x_sample, y_sample, alpha, equal_variances = false))]
+    fn new(
+        x_sample: Vec<f64>,
+        y_sample: Vec<f64>,
+        alpha: f64,
+        equal_variances: bool,
+    ) -> PyResult<Self> {
+        let variances = if equal_variances {
+            Variances::Equal
+        } else {
+            Variances::Unequal
+        };
+
+        SameMeanHypothesis::new(&x_sample, &y_sample, variances, alpha)
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    fn hypotheses(&self) -> PyHypotheses {
+        self.0.hypotheses().into()
+    }
+
+    /// Returns the `t` statistic and its freedom degrees.
+    fn statistic(&self) -> (f64, f64) {
+        self.0.statistic()
+    }
+
+    fn solve(&self) -> PyResult<bool> {
+        self.0.solve().map_err(value_error)
+    }
+}
+
+#[pyclass(name = "MannWhitneyHypothesis", frozen)]
+pub struct PyMannWhitneyHypothesis(MannWhitneyHypothesis);
+
+#[pymethods]
+impl PyMannWhitneyHypothesis {
+    #[new]
+    fn new(x_sample: Vec<f64>, y_sample: Vec<f64>, alpha: f64) -> PyResult<Self> {
+        MannWhitneyHypothesis::new(&x_sample, &y_sample, alpha)
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    fn hypotheses(&self) -> PyHypotheses {
+        self.0.hypotheses().into()
+    }
+
+    /// Returns the `U` statistic of the **X** sample and its standardized value.
+    fn statistic(&self) -> PyResult<(f64, f64)> {
+        self.0.statistic().map_err(value_error)
+    }
+
+    fn solve(&self) -> PyResult<bool> {
+        self.0.solve().map_err(value_error)
+    }
+}
+
+#[pyclass(name = "ShapiroWilkHypothesis", frozen)]
+pub struct PyShapiroWilkHypothesis(ShapiroWilkHypothesis);
+
+#[pymethods]
+impl PyShapiroWilkHypothesis {
+    #[new]
+    fn new(sample: Vec<f64>, alpha: f64) -> PyResult<Self> {
+        ShapiroWilkHypothesis::new(&sample, alpha)
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    fn hypotheses(&self) -> PyHypotheses {
+        self.0.hypotheses().into()
+    }
+
+    /// Returns the `W` statistic and its p-value.
+    fn statistic(&self) -> (f64, f64) {
+        self.0.statistic()
+    }
+
+    fn solve(&self) -> PyResult<bool> {
+        self.0.solve().map_err(value_error)
+    }
+}
+
+#[pyclass(name = "IndependenceOutcome", get_all, frozen)]
+pub struct PyIndependenceOutcome {
+    freedom_degrees: f64,
+    chi_squared_observed: f64,
+    chi_squared_critical_value: f64,
+    /// Expected frequencies in the layout of the table.
+    expected: Vec<Vec<f64>>,
+    hypotheses: PyHypotheses,
+    accepted: bool,
+}
+
+impl From<IndependenceOutcome> for PyIndependenceOutcome {
+    fn from(outcome: IndependenceOutcome) -> Self {
+        Self {
+            freedom_degrees: outcome.freedom_degrees,
+            chi_squared_observed: outcome.chi_squared_observed,
+            chi_squared_critical_value: outcome.chi_squared_critical_value,
+            expected: outcome
+                .cells
+                .iter()
+                .map(|row| row.iter().map(|cell| cell.expected).collect())
+                .collect(),
+            hypotheses: outcome.hypotheses.into(),
+            accepted: outcome.accepted,
+        }
+    }
+}
+
+#[pyclass(name = "IndependenceHypothesis", frozen)]
+pub struct PyIndependenceHypothesis(IndependenceHypothesis);
+
+#[pymethods]
+impl PyIndependenceHypothesis {
+    #[new]
+    fn new(table: Vec<Vec<f64>>, alpha: f64) -> PyResult<Self> {
+        IndependenceHypothesis::new(&table, alpha)
+            .map(Self)
+            .map_err(value_error)
+    }
+
+    fn solve(&self) -> PyResult<bool> {
+        self.0.solve().map_err(value_error)
+    }
+
+    fn outcome(&self) -> PyResult<PyIndependenceOutcome> {
+        self.0
+            .outcome()
+            .map(PyIndependenceOutcome::from)
+            .map_err(value_error)
+    }
+}
+
+#[pymodule]
+fn statistics_problems(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyHypotheses>()?;
+    module.add_class::<PyCompleteNDHProblemSituation>()?;
+    module.add_class::<PyIncompleteNDHProblemSituation>()?;
+    module.add_class::<PyNDHOutcome>()?;
+    module.add_class::<PyNormalDistributionHypothesis>()?;
+    module.add_class::<PySVHOutcome>()?;
+    module.add_class::<PySameVarianceHypothesis>()?;
+    module.add_class::<PySameMeanHypothesis>()?;
+    module.add_class::<PyMannWhitneyHypothesis>()?;
+    module.add_class::<PyShapiroWilkHypothesis>()?;
+    module.add_class::<PyIndependenceOutcome>()?;
+    module.add_class::<PyIndependenceHypothesis>()?;
+    Ok(())
+}
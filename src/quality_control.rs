@@ -0,0 +1,633 @@
+//! Statistical process control of the following kind.
+//!
+//! **Given**: *measurements taken in subgroups of equal size* over time.
+//! **To figure out**: Is the process **in control**, and which subgroups signal otherwise?
+//!
+//! Shewhart X̄–R and X̄–S charts estimate the process dispersion from the subgroup ranges
+//! or standard deviations; out-of-control signals follow the Western Electric rules.
+//!
+//! CUSUM and EWMA charts accumulate the history of individual observations
+//! to detect small sustained shifts of the mean that Shewhart charts miss.
+//!
+//! Process capability indices compare the specification limits with the natural spread `6σ` of the process.
+
+use statrs::distribution::{ChiSquared, ContinuousCDF, Normal};
+use statrs::function::gamma::ln_gamma;
+
+use crate::alpha;
+use crate::missing::MissingPolicy;
+use crate::normal_distribution_hypothesis::{
+    IncompleteNDHProblemSituation, NDHError, NormalDistributionHypothesis,
+};
+use crate::numeric::{mean, unbiased_variance};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum QCError {
+    SubgroupsTooFew,
+    SubgroupSizeInvalid,
+    SampleTooSmall,
+    SpecificationInvalid,
+    StdDevInvalid,
+    LevelInvalid,
+    ParameterInvalid,
+    Normality(NDHError),
+    ValueMissing,
+}
+
+impl std::fmt::Display for QCError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            QCError::SubgroupsTooFew => {
+                write!(f, "At least two subgroups are required")
+            }
+            QCError::SubgroupSizeInvalid => {
+                write!(
+                    f,
+                    "Subgroups must be of equal size between {MIN_SUBGROUP_SIZE} and {MAX_SUBGROUP_SIZE}"
+                )
+            }
+            QCError::SampleTooSmall => {
+                write!(f, "Sample must contain at least two distinct values")
+            }
+            QCError::SpecificationInvalid => {
+                write!(f, "Lower specification limit must be below the upper one")
+            }
+            QCError::StdDevInvalid => {
+                write!(f, "Standard deviation must be positive")
+            }
+            QCError::LevelInvalid => {
+                write!(f, "Confidence level must be between 0.0 and 1.0")
+            }
+            QCError::ParameterInvalid => {
+                write!(
+                    f,
+                    "Chart parameters must be positive, with the smoothing constant up to 1.0"
+                )
+            }
+            QCError::Normality(e) => {
+                write!(f, "Normality check failed: {e}")
+            }
+            QCError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+pub const MIN_SUBGROUP_SIZE: usize = 2;
+pub const MAX_SUBGROUP_SIZE: usize = 25;
+
+/// `d₂(n)`, the mean of the range of `n` standard normal values, for `n = 2..=25`.
+const D2: [f64; 24] = [
+    1.128, 1.693, 2.059, 2.326, 2.534, 2.704, 2.847, 2.970, 3.078, 3.173, 3.258, 3.336, 3.407,
+    3.472, 3.532, 3.588, 3.640, 3.689, 3.735, 3.778, 3.819, 3.858, 3.895, 3.931,
+];
+
+/// `d₃(n)`, the standard deviation of the range of `n` standard normal values, for `n = 2..=25`.
+const D3: [f64; 24] = [
+    0.853, 0.888, 0.880, 0.864, 0.848, 0.833, 0.820, 0.808, 0.797, 0.787, 0.778, 0.770, 0.763,
+    0.756, 0.750, 0.744, 0.739, 0.734, 0.729, 0.724, 0.720, 0.716, 0.712, 0.708,
+];
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ControlLimits {
+    pub lower: f64,
+    pub center: f64,
+    pub upper: f64,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum WesternElectricRule {
+    /// One point beyond the 3σ limits.
+    BeyondLimits,
+    /// Two of three consecutive points beyond 2σ on the same side.
+    TwoOfThreeBeyondTwoSigma,
+    /// Four of five consecutive points beyond 1σ on the same side.
+    FourOfFiveBeyondOneSigma,
+    /// Eight consecutive points on the same side of the center line.
+    EightOnOneSide,
+}
+
+/// A rule violated by the pattern ending at the subgroup `point`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Signal {
+    pub point: usize,
+    pub rule: WesternElectricRule,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ControlChart {
+    pub limits: ControlLimits,
+    /// Plotted statistic of every subgroup.
+    pub points: Vec<f64>,
+    pub signals: Vec<Signal>,
+}
+
+impl ControlChart {
+    pub fn in_control(&self) -> bool {
+        self.signals.is_empty()
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ShewhartCharts {
+    pub mean_chart: ControlChart,
+    /// Chart of the subgroup ranges or standard deviations.
+    pub dispersion_chart: ControlChart,
+    /// Process standard deviation estimated from the dispersion chart.
+    pub std_dev: f64,
+}
+
+/// X̄–R charts: `X̄ ± A₂·R̄` and `[D₃·R̄; D₄·R̄]`.
+pub fn x_bar_r(subgroups: &[Vec<f64>]) -> Result<ShewhartCharts, QCError> {
+    let n = validate(subgroups)?;
+    let (d2, d3) = (D2[n - MIN_SUBGROUP_SIZE], D3[n - MIN_SUBGROUP_SIZE]);
+
+    let ranges: Vec<f64> = subgroups
+        .iter()
+        .map(|subgroup| {
+            subgroup.iter().copied().fold(f64::NEG_INFINITY, f64::max)
+                - subgroup.iter().copied().fold(f64::INFINITY, f64::min)
+        })
+        .collect();
+    let mean_range = mean(&ranges);
+    let std_dev = mean_range / d2;
+
+    let dispersion_limits = ControlLimits {
+        lower: (mean_range * (1. - 3. * d3 / d2)).max(0.),
+        center: mean_range,
+        upper: mean_range * (1. + 3. * d3 / d2),
+    };
+
+    Ok(ShewhartCharts {
+        mean_chart: mean_chart(subgroups, std_dev),
+        dispersion_chart: dispersion_chart(ranges, dispersion_limits),
+        std_dev,
+    })
+}
+
+/// X̄–S charts: `X̄ ± A₃·S̄` and `[B₃·S̄; B₄·S̄]`.
+pub fn x_bar_s(subgroups: &[Vec<f64>]) -> Result<ShewhartCharts, QCError> {
+    let n = validate(subgroups)?;
+    let c4 = c4(n);
+
+    let std_devs: Vec<f64> = subgroups
+        .iter()
+        .map(|subgroup| unbiased_variance(subgroup).sqrt())
+        .collect();
+    let mean_std_dev = mean(&std_devs);
+    let std_dev = mean_std_dev / c4;
+
+    let spread = 3. * (1. - c4.powi(2)).sqrt() / c4;
+    let dispersion_limits = ControlLimits {
+        lower: (mean_std_dev * (1. - spread)).max(0.),
+        center: mean_std_dev,
+        upper: mean_std_dev * (1. + spread),
+    };
+
+    Ok(ShewhartCharts {
+        mean_chart: mean_chart(subgroups, std_dev),
+        dispersion_chart: dispersion_chart(std_devs, dispersion_limits),
+        std_dev,
+    })
+}
+
+/// Signals of all four Western Electric rules, with the zones split evenly between the center and each limit.
+pub fn western_electric_signals(points: &[f64], limits: &ControlLimits) -> Vec<Signal> {
+    let upper_sigma = (limits.upper - limits.center) / 3.;
+    let lower_sigma = (limits.center - limits.lower) / 3.;
+    // Signed distance from the center line in units of σ of the corresponding side.
+    let zone = |x: f64| {
+        if x >= limits.center {
+            (x - limits.center) / upper_sigma
+        } else {
+            (x - limits.center) / lower_sigma
+        }
+    };
+    let zones: Vec<f64> = points.iter().map(|x| zone(*x)).collect();
+
+    // Points of the window beyond `sigmas` on the side of `side`.
+    let count_beyond = |window: &[f64], sigmas: f64, side: f64| {
+        window.iter().filter(|z| **z * side > sigmas).count()
+    };
+
+    let mut signals = Vec::new();
+    for (i, z) in zones.iter().enumerate() {
+        let window = |length: usize| &zones[(i + 1).saturating_sub(length)..=i];
+        let side = z.signum();
+
+        if z.abs() > 3. {
+            signals.push(Signal {
+                point: i,
+                rule: WesternElectricRule::BeyondLimits,
+            });
+        }
+        if i >= 2 && z.abs() > 2. && count_beyond(window(3), 2., side) >= 2 {
+            signals.push(Signal {
+                point: i,
+                rule: WesternElectricRule::TwoOfThreeBeyondTwoSigma,
+            });
+        }
+        if i >= 4 && z.abs() > 1. && count_beyond(window(5), 1., side) >= 4 {
+            signals.push(Signal {
+                point: i,
+                rule: WesternElectricRule::FourOfFiveBeyondOneSigma,
+            });
+        }
+        if i >= 7 && count_beyond(window(8), 0., side) == 8 {
+            signals.push(Signal {
+                point: i,
+                rule: WesternElectricRule::EightOnOneSide,
+            });
+        }
+    }
+
+    signals
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Shift {
+    Up,
+    Down,
+}
+
+/// A shift of the mean detected at the observation `point`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct ShiftSignal {
+    pub point: usize,
+    pub shift: Shift,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct CusumChart {
+    /// `C⁺ᵢ = max(0, xᵢ - (μ₀ + kσ) + C⁺ᵢ₋₁)`.
+    pub upper: Vec<f64>,
+    /// `C⁻ᵢ = max(0, (μ₀ - kσ) - xᵢ + C⁻ᵢ₋₁)`.
+    pub lower: Vec<f64>,
+    /// Decision interval `hσ`.
+    pub threshold: f64,
+    pub signals: Vec<ShiftSignal>,
+}
+
+/// Tabular CUSUM of individual observations around the target mean.
+pub struct Cusum {
+    target: f64,
+    std_dev: f64,
+    reference: f64,
+    decision_interval: f64,
+}
+
+impl Cusum {
+    /// Starts with `k = 0.5` and `h = 5`, tuned to a shift of `1σ`.
+    pub fn new(target: f64, std_dev: f64) -> Self {
+        Self {
+            target,
+            std_dev,
+            reference: 0.5,
+            decision_interval: 5.,
+        }
+    }
+
+    /// Allowance `k` in units of σ, usually half of the shift to detect.
+    pub fn reference(mut self, reference: f64) -> Self {
+        self.reference = reference;
+        self
+    }
+
+    /// Decision interval `h` in units of σ.
+    pub fn decision_interval(mut self, decision_interval: f64) -> Self {
+        self.decision_interval = decision_interval;
+        self
+    }
+
+    pub fn chart(&self, observations: &[f64]) -> Result<CusumChart, QCError> {
+        if !(self.std_dev > 0. && self.reference >= 0. && self.decision_interval > 0.) {
+            return Err(QCError::ParameterInvalid);
+        }
+
+        let allowance = self.reference * self.std_dev;
+        let threshold = self.decision_interval * self.std_dev;
+
+        let (mut upper, mut lower) = (Vec::new(), Vec::new());
+        let mut signals = Vec::new();
+        let (mut c_upper, mut c_lower) = (0f64, 0f64);
+        for (point, x) in observations.iter().enumerate() {
+            c_upper = (c_upper + x - (self.target + allowance)).max(0.);
+            c_lower = (c_lower + (self.target - allowance) - x).max(0.);
+
+            if c_upper > threshold {
+                signals.push(ShiftSignal {
+                    point,
+                    shift: Shift::Up,
+                });
+            }
+            if c_lower > threshold {
+                signals.push(ShiftSignal {
+                    point,
+                    shift: Shift::Down,
+                });
+            }
+
+            upper.push(c_upper);
+            lower.push(c_lower);
+        }
+
+        Ok(CusumChart {
+            upper,
+            lower,
+            threshold,
+            signals,
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct EwmaChart {
+    /// `zᵢ = λxᵢ + (1 - λ)zᵢ₋₁` with `z₀ = μ₀`.
+    pub points: Vec<f64>,
+    /// Exact limits of every point, widening towards the asymptotic ones.
+    pub limits: Vec<ControlLimits>,
+    pub signals: Vec<ShiftSignal>,
+}
+
+/// Exponentially weighted moving average of individual observations around the target mean.
+pub struct Ewma {
+    target: f64,
+    std_dev: f64,
+    smoothing: f64,
+    width: f64,
+}
+
+impl Ewma {
+    /// Starts with `λ = 0.2` and `L = 3`.
+    pub fn new(target: f64, std_dev: f64) -> Self {
+        Self {
+            target,
+            std_dev,
+            smoothing: 0.2,
+            width: 3.,
+        }
+    }
+
+    /// Weight `λ` of the newest observation; `λ = 1` turns the chart into the Shewhart chart of individuals.
+    pub fn smoothing(mut self, smoothing: f64) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    /// Width `L` of the limits in units of the standard deviation of `zᵢ`.
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn chart(&self, observations: &[f64]) -> Result<EwmaChart, QCError> {
+        if !(self.std_dev > 0. && self.smoothing > 0. && self.smoothing <= 1. && self.width > 0.) {
+            return Err(QCError::ParameterInvalid);
+        }
+
+        let lambda = self.smoothing;
+        let (mut points, mut limits) = (Vec::new(), Vec::new());
+        let mut signals = Vec::new();
+        let mut z = self.target;
+        for (point, x) in observations.iter().enumerate() {
+            z = lambda * x + (1. - lambda) * z;
+            let spread = self.width
+                * self.std_dev
+                * (lambda / (2. - lambda) * (1. - (1. - lambda).powi(2 * (point as i32 + 1))))
+                    .sqrt();
+            let point_limits = ControlLimits {
+                lower: self.target - spread,
+                center: self.target,
+                upper: self.target + spread,
+            };
+
+            if z > point_limits.upper {
+                signals.push(ShiftSignal {
+                    point,
+                    shift: Shift::Up,
+                });
+            } else if z < point_limits.lower {
+                signals.push(ShiftSignal {
+                    point,
+                    shift: Shift::Down,
+                });
+            }
+
+            points.push(z);
+            limits.push(point_limits);
+        }
+
+        Ok(EwmaChart {
+            points,
+            limits,
+            signals,
+        })
+    }
+}
+
+/// Point estimate of a capability index with its confidence interval.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CapabilityIndex {
+    pub value: f64,
+    pub interval: (f64, f64),
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CapabilityOutcome {
+    /// `(USL - LSL) / 6σ_within`.
+    pub cp: CapabilityIndex,
+    /// `min(USL - μ, μ - LSL) / 3σ_within`.
+    pub cpk: CapabilityIndex,
+    /// `(USL - LSL) / 6σ_overall`.
+    pub pp: CapabilityIndex,
+    /// `min(USL - μ, μ - LSL) / 3σ_overall`.
+    pub ppk: CapabilityIndex,
+    /// Whether the Normal Distribution Hypothesis is accepted for the sample, `None` if the check
+    /// cannot be run, e.g. on a sample too small to be grouped; the indices are meaningful only for
+    /// a normal process.
+    pub normal: Option<bool>,
+}
+
+pub struct ProcessCapability {
+    sample: Vec<f64>,
+    specification_limits: (f64, f64),
+    within_std_dev: Option<f64>,
+    level: f64,
+    significance: f64,
+}
+
+impl ProcessCapability {
+    /// Starts with the confidence level `0.95` and the significance `0.05` of the normality check.
+    pub fn new(
+        sample: &[f64],
+        lower_specification_limit: f64,
+        upper_specification_limit: f64,
+    ) -> Result<Self, QCError> {
+        MissingPolicy::Error
+            .apply(sample)
+            .map_err(|_| QCError::ValueMissing)?;
+
+        if lower_specification_limit >= upper_specification_limit
+            || lower_specification_limit.is_nan()
+            || upper_specification_limit.is_nan()
+        {
+            return Err(QCError::SpecificationInvalid);
+        }
+
+        if sample.len() < 2 || unbiased_variance(sample) <= 0. {
+            return Err(QCError::SampleTooSmall);
+        }
+
+        Ok(Self {
+            sample: sample.to_owned(),
+            specification_limits: (lower_specification_limit, upper_specification_limit),
+            within_std_dev: None,
+            level: 0.95,
+            significance: 0.05,
+        })
+    }
+
+    /// Short-term σ for `Cp` and `Cpk`, e.g. [`ShewhartCharts::std_dev`];
+    /// without it, the overall σ of the sample is used and `Cp = Pp`.
+    pub fn with_within_std_dev(mut self, std_dev: f64) -> Result<Self, QCError> {
+        if std_dev <= 0. || !std_dev.is_finite() {
+            return Err(QCError::StdDevInvalid);
+        }
+
+        self.within_std_dev = Some(std_dev);
+        Ok(self)
+    }
+
+    /// Confidence level of the intervals.
+    pub fn level(mut self, level: f64) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Significance of the normality check.
+    pub fn alpha(mut self, significance: f64) -> Self {
+        self.significance = significance;
+        self
+    }
+
+    /// Intervals for `Cp` and `Pp` follow from the Chi-Squared distribution of the sample variance,
+    /// intervals for `Cpk` and `Ppk` are Bissell's normal approximation; both take `n - 1` freedom degrees.
+    pub fn outcome(&self) -> Result<CapabilityOutcome, QCError> {
+        if !(self.level > 0. && self.level < 1.) {
+            return Err(QCError::LevelInvalid);
+        }
+
+        alpha::validate(
+            self.significance,
+            QCError::Normality(NDHError::SignificanceInvalid),
+        )?;
+        let normal =
+            IncompleteNDHProblemSituation::from_raw_sample(&self.sample, self.significance)
+                .and_then(|situation| NormalDistributionHypothesis::new(Box::new(situation)))
+                .and_then(|ndh| ndh.solve())
+                .ok();
+
+        let n = self.sample.len() as f64;
+        let sample_mean = mean(&self.sample);
+        let overall_std_dev = unbiased_variance(&self.sample).sqrt();
+        let within_std_dev = self.within_std_dev.unwrap_or(overall_std_dev);
+        let (lower, upper) = self.specification_limits;
+
+        let freedom_degrees = n - 1.;
+        let chi_squared_dist = ChiSquared::new(freedom_degrees).unwrap();
+        let tail = (1. - self.level) / 2.;
+        let z = Normal::standard().inverse_cdf(1. - tail);
+
+        let potential = |std_dev: f64| {
+            let value = (upper - lower) / (6. * std_dev);
+            CapabilityIndex {
+                value,
+                interval: (
+                    value * (chi_squared_dist.inverse_cdf(tail) / freedom_degrees).sqrt(),
+                    value * (chi_squared_dist.inverse_cdf(1. - tail) / freedom_degrees).sqrt(),
+                ),
+            }
+        };
+        let actual = |std_dev: f64| {
+            let value = (upper - sample_mean).min(sample_mean - lower) / (3. * std_dev);
+            let spread = z * (1. / (9. * n) + value.powi(2) / (2. * freedom_degrees)).sqrt();
+            CapabilityIndex {
+                value,
+                interval: (value - spread, value + spread),
+            }
+        };
+
+        Ok(CapabilityOutcome {
+            cp: potential(within_std_dev),
+            cpk: actual(within_std_dev),
+            pp: potential(overall_std_dev),
+            ppk: actual(overall_std_dev),
+            normal,
+        })
+    }
+}
+
+/// `c₄(n) = √(2 / (n - 1)) · Γ(n / 2) / Γ((n - 1) / 2)`, the bias of the sample standard deviation.
+fn c4(n: usize) -> f64 {
+    let n = n as f64;
+    (2. / (n - 1.)).sqrt() * (ln_gamma(n / 2.) - ln_gamma((n - 1.) / 2.)).exp()
+}
+
+fn validate(subgroups: &[Vec<f64>]) -> Result<usize, QCError> {
+    for subgroup in subgroups {
+        MissingPolicy::Error
+            .apply(subgroup)
+            .map_err(|_| QCError::ValueMissing)?;
+    }
+
+    if subgroups.len() < 2 {
+        return Err(QCError::SubgroupsTooFew);
+    }
+
+    let n = subgroups[0].len();
+    if !(MIN_SUBGROUP_SIZE..=MAX_SUBGROUP_SIZE).contains(&n)
+        || subgroups.iter().any(|subgroup| subgroup.len() != n)
+    {
+        return Err(QCError::SubgroupSizeInvalid);
+    }
+
+    Ok(n)
+}
+
+fn mean_chart(subgroups: &[Vec<f64>], std_dev: f64) -> ControlChart {
+    let means: Vec<f64> = subgroups.iter().map(|subgroup| mean(subgroup)).collect();
+    let grand_mean = mean(&means);
+    let spread = 3. * std_dev / (subgroups[0].len() as f64).sqrt();
+    let limits = ControlLimits {
+        lower: grand_mean - spread,
+        center: grand_mean,
+        upper: grand_mean + spread,
+    };
+
+    ControlChart {
+        signals: western_electric_signals(&means, &limits),
+        limits,
+        points: means,
+    }
+}
+
+/// Only points beyond the limits are signalled, as the zones of a skewed dispersion statistic are not symmetric.
+fn dispersion_chart(points: Vec<f64>, limits: ControlLimits) -> ControlChart {
+    let signals = points
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| **x < limits.lower || **x > limits.upper)
+        .map(|(point, _)| Signal {
+            point,
+            rule: WesternElectricRule::BeyondLimits,
+        })
+        .collect();
+
+    ControlChart {
+        limits,
+        points,
+        signals,
+    }
+}
@@ -0,0 +1,211 @@
+//! Laws of distribution of discrete random variables.
+//!
+//! **Given**: the *values* of a discrete random variable **X** and their *probabilities*, or two independent
+//! random variables **X** and **Y**.
+//! **To figure out**: What are `E(X)`, `D(X)` and `σ(X)`? What are the laws of `aX + b`, of the standardized
+//! `(X - E(X)) / σ(X)`, of `X + Y` and of `XY`?
+//!
+//! `E(X) = Σ xᵢ pᵢ` and `D(X) = Σ (xᵢ - E(X))² pᵢ`. The law of a sum or a product of independent variables
+//! assigns `pᵢ qⱼ` to `xᵢ + yⱼ` or `xᵢ yⱼ`, adding up the probabilities of equal values, the convolution
+//! of the laws. Values are kept in ascending order, each once.
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum RandomVariableError {
+    LengthsMismatch,
+    ValuesMissing,
+    ValueInvalid,
+    ProbabilityInvalid,
+    ProbabilitiesSumInvalid,
+    VariableDegenerate,
+}
+
+impl std::fmt::Display for RandomVariableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RandomVariableError::LengthsMismatch => {
+                write!(f, "Every value must have its probability")
+            }
+            RandomVariableError::ValuesMissing => {
+                write!(f, "At least one value is required")
+            }
+            RandomVariableError::ValueInvalid => {
+                write!(f, "Values must be finite")
+            }
+            RandomVariableError::ProbabilityInvalid => {
+                write!(f, "Probabilities must be between 0.0 and 1.0")
+            }
+            RandomVariableError::ProbabilitiesSumInvalid => {
+                write!(f, "Probabilities must sum to 1")
+            }
+            RandomVariableError::VariableDegenerate => {
+                write!(
+                    f,
+                    "Variable with a single value has zero variance and cannot be standardized"
+                )
+            }
+        }
+    }
+}
+
+/// Largest departure of the sum of the probabilities from 1 put down to rounding.
+const PROBABILITIES_SUM_TOLERANCE: f64 = 1e-9;
+
+/// Discrete random variable given by its law of distribution.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DiscreteRandomVariable {
+    values: Vec<f64>,
+    probabilities: Vec<f64>,
+}
+
+impl DiscreteRandomVariable {
+    /// Values may come in any order and repeat; the probabilities of equal values are added up.
+    pub fn new(values: &[f64], probabilities: &[f64]) -> Result<Self, RandomVariableError> {
+        if values.len() != probabilities.len() {
+            return Err(RandomVariableError::LengthsMismatch);
+        }
+
+        if values.is_empty() {
+            return Err(RandomVariableError::ValuesMissing);
+        }
+
+        if !values.iter().all(|x| x.is_finite()) {
+            return Err(RandomVariableError::ValueInvalid);
+        }
+
+        if !probabilities.iter().all(|p| (0. ..=1.).contains(p)) {
+            return Err(RandomVariableError::ProbabilityInvalid);
+        }
+
+        if (probabilities.iter().sum::<f64>() - 1.).abs() > PROBABILITIES_SUM_TOLERANCE {
+            return Err(RandomVariableError::ProbabilitiesSumInvalid);
+        }
+
+        Ok(Self::from_pairs(
+            values.iter().copied().zip(probabilities.iter().copied()),
+        ))
+    }
+
+    /// Values in ascending order.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Probabilities of the values.
+    pub fn probabilities(&self) -> &[f64] {
+        &self.probabilities
+    }
+
+    /// `P(X = x)`.
+    pub fn probability(&self, x: f64) -> f64 {
+        self.values
+            .iter()
+            .position(|value| *value == x)
+            .map_or(0., |i| self.probabilities[i])
+    }
+
+    /// `F(x) = P(X ≤ x)`.
+    pub fn cdf(&self, x: f64) -> f64 {
+        self.values
+            .iter()
+            .zip(&self.probabilities)
+            .take_while(|(value, _)| **value <= x)
+            .map(|(_, p)| p)
+            .sum::<f64>()
+            .min(1.)
+    }
+
+    /// `E(X) = Σ xᵢ pᵢ`.
+    pub fn expectation(&self) -> f64 {
+        self.values
+            .iter()
+            .zip(&self.probabilities)
+            .map(|(x, p)| x * p)
+            .sum()
+    }
+
+    /// `D(X) = Σ (xᵢ - E(X))² pᵢ`.
+    pub fn variance(&self) -> f64 {
+        let expectation = self.expectation();
+        self.values
+            .iter()
+            .zip(&self.probabilities)
+            .map(|(x, p)| (x - expectation).powi(2) * p)
+            .sum()
+    }
+
+    /// `σ(X) = √D(X)`.
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Law of `aX + b`.
+    pub fn affine(&self, a: f64, b: f64) -> Self {
+        Self::from_pairs(
+            self.values
+                .iter()
+                .zip(&self.probabilities)
+                .map(|(x, p)| (a * x + b, *p)),
+        )
+    }
+
+    /// Law of `(X - E(X)) / σ(X)`, with zero expectation and unit variance.
+    pub fn standardized(&self) -> Result<Self, RandomVariableError> {
+        let std_dev = self.std_dev();
+        if std_dev == 0. {
+            return Err(RandomVariableError::VariableDegenerate);
+        }
+
+        Ok(self.affine(1. / std_dev, -self.expectation() / std_dev))
+    }
+
+    /// Law of `X + Y` for independent **X** and **Y**.
+    pub fn sum(&self, other: &Self) -> Self {
+        self.combine(other, |x, y| x + y)
+    }
+
+    /// Law of `XY` for independent **X** and **Y**.
+    pub fn product(&self, other: &Self) -> Self {
+        self.combine(other, |x, y| x * y)
+    }
+
+    /// Law of `f(X, Y)` for independent **X** and **Y**.
+    fn combine(&self, other: &Self, f: fn(f64, f64) -> f64) -> Self {
+        Self::from_pairs(
+            self.values
+                .iter()
+                .zip(&self.probabilities)
+                .flat_map(|(x, p)| {
+                    other
+                        .values
+                        .iter()
+                        .zip(&other.probabilities)
+                        .map(move |(y, q)| (f(*x, *y), p * q))
+                }),
+        )
+    }
+
+    /// Sorts the values and adds up the probabilities of equal ones.
+    fn from_pairs(pairs: impl Iterator<Item = (f64, f64)>) -> Self {
+        let mut pairs: Vec<_> = pairs.collect();
+        pairs.sort_by(|(x, _), (y, _)| x.total_cmp(y));
+
+        let (mut values, mut probabilities): (Vec<f64>, Vec<f64>) = (vec![], vec![]);
+        for (x, p) in pairs {
+            match values.last() {
+                // `-0.0` and `0.0` are the same value.
+                Some(last) if *last == x => *probabilities.last_mut().unwrap() += p,
+                _ => {
+                    values.push(x);
+                    probabilities.push(p);
+                }
+            }
+        }
+
+        Self {
+            values,
+            probabilities,
+        }
+    }
+}
@@ -0,0 +1,212 @@
+//! Assistant for problems of the following kind.
+//!
+//! **Given**: *description of the data* — paired or independent samples, *sample sizes*,
+//! results of the *normality check* and of the *variance check*, categorical or continuous measurements.
+//! **To figure out**: Which test is appropriate to run?
+//!
+//! The checks may also be chained automatically from raw samples:
+//! normality → variance homogeneity → Student's t vs Welch's t vs Mann–Whitney.
+
+use crate::missing::{MissingError, MissingPolicy};
+use crate::normal_distribution_hypothesis::{
+    IncompleteNDHProblemSituation, NDHError, NormalDistributionHypothesis,
+};
+use crate::same_variance_hypothesis::{SVHError, SameVarianceHypothesis};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum RecommendError {
+    NonEqualSamplesLengths,
+    Normality(NDHError),
+    Variance(SVHError),
+    Missing(MissingError),
+}
+
+impl std::fmt::Display for RecommendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RecommendError::NonEqualSamplesLengths => {
+                write!(f, "Lengths of paired samples are different")
+            }
+            RecommendError::Normality(e) => {
+                write!(f, "Normality check failed: {e}")
+            }
+            RecommendError::Variance(e) => {
+                write!(f, "Variance check failed: {e}")
+            }
+            RecommendError::Missing(e) => {
+                write!(f, "Missing values: {e}")
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Design {
+    Paired,
+    Independent,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Measurement {
+    Continuous,
+    Categorical,
+}
+
+/// What is known about the data. `None` in a check result means that the check has not been run yet.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct DataDescription {
+    pub design: Design,
+    pub measurement: Measurement,
+    pub sample_sizes: (usize, usize),
+    pub normality: Option<bool>,
+    pub equal_variances: Option<bool>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum Recommendation {
+    /// Normality has to be checked first, e.g. with the Normal Distribution Hypothesis.
+    NormalityCheck,
+    /// Variances have to be compared first, e.g. with the Same Variance Hypothesis.
+    VarianceCheck,
+    StudentT,
+    WelchT,
+    MannWhitney,
+    PairedT,
+    WilcoxonSignedRank,
+    ChiSquaredIndependence,
+    FisherExact,
+    McNemar,
+}
+
+impl std::fmt::Display for Recommendation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Recommendation::NormalityCheck => {
+                write!(f, "Check normality of the samples first")
+            }
+            Recommendation::VarianceCheck => {
+                write!(f, "Check equality of the variances first")
+            }
+            Recommendation::StudentT => {
+                write!(f, "Student's t-test (pooled variance)")
+            }
+            Recommendation::WelchT => {
+                write!(f, "Welch's t-test (unequal variances)")
+            }
+            Recommendation::MannWhitney => {
+                write!(f, "Mann–Whitney U test")
+            }
+            Recommendation::PairedT => {
+                write!(f, "Paired t-test")
+            }
+            Recommendation::WilcoxonSignedRank => {
+                write!(f, "Wilcoxon signed-rank test")
+            }
+            Recommendation::ChiSquaredIndependence => {
+                write!(f, "Chi-squared test of independence")
+            }
+            Recommendation::FisherExact => {
+                write!(f, "Fisher's exact test")
+            }
+            Recommendation::McNemar => {
+                write!(f, "McNemar's test")
+            }
+        }
+    }
+}
+
+/// Total sample size below which the chi-squared approximation is considered unreliable for categorical data.
+const CHI_SQUARED_MIN_TOTAL: usize = 40;
+
+pub fn recommend(description: &DataDescription) -> Recommendation {
+    let (n_1, n_2) = description.sample_sizes;
+
+    match (description.measurement, description.design) {
+        (Measurement::Categorical, Design::Paired) => Recommendation::McNemar,
+        (Measurement::Categorical, Design::Independent) => {
+            if n_1 + n_2 < CHI_SQUARED_MIN_TOTAL {
+                Recommendation::FisherExact
+            } else {
+                Recommendation::ChiSquaredIndependence
+            }
+        }
+        (Measurement::Continuous, Design::Paired) => match description.normality {
+            None => Recommendation::NormalityCheck,
+            Some(true) => Recommendation::PairedT,
+            Some(false) => Recommendation::WilcoxonSignedRank,
+        },
+        (Measurement::Continuous, Design::Independent) => {
+            match (description.normality, description.equal_variances) {
+                (None, _) => Recommendation::NormalityCheck,
+                (Some(false), _) => Recommendation::MannWhitney,
+                (Some(true), None) => Recommendation::VarianceCheck,
+                (Some(true), Some(true)) => Recommendation::StudentT,
+                (Some(true), Some(false)) => Recommendation::WelchT,
+            }
+        }
+    }
+}
+
+/// Runs the assumption checks on raw continuous samples and recommends the final test.
+///
+/// Normality is checked with the Normal Distribution Hypothesis over the Sturges-grouped samples
+/// (over the differences, if the samples are paired), variances with the Same Variance Hypothesis.
+/// Missing values are handled by the policy first, pairwise for paired samples.
+pub fn recommend_for_samples(
+    x_sample: &[f64],
+    y_sample: &[f64],
+    design: Design,
+    missing_policy: MissingPolicy,
+    significance: f64,
+) -> Result<(DataDescription, Recommendation), RecommendError> {
+    if design == Design::Paired && x_sample.len() != y_sample.len() {
+        return Err(RecommendError::NonEqualSamplesLengths);
+    }
+
+    let (x_sample, y_sample) = match design {
+        Design::Paired => missing_policy.apply_paired(x_sample, y_sample),
+        Design::Independent => missing_policy
+            .apply(x_sample)
+            .and_then(|x_sample| Ok((x_sample, missing_policy.apply(y_sample)?))),
+    }
+    .map_err(RecommendError::Missing)?;
+
+    let is_normal = |sample: &[f64]| {
+        let situation = IncompleteNDHProblemSituation::from_raw_sample(sample, significance)?;
+        NormalDistributionHypothesis::new(Box::new(situation))?.solve()
+    };
+
+    let mut description = DataDescription {
+        design,
+        measurement: Measurement::Continuous,
+        sample_sizes: (x_sample.len(), y_sample.len()),
+        normality: None,
+        equal_variances: None,
+    };
+
+    match design {
+        Design::Paired => {
+            let differences: Vec<_> = x_sample
+                .iter()
+                .zip(y_sample.iter())
+                .map(|(x, y)| x - y)
+                .collect();
+            description.normality =
+                Some(is_normal(&differences).map_err(RecommendError::Normality)?);
+        }
+        Design::Independent => {
+            let normality = is_normal(&x_sample).map_err(RecommendError::Normality)?
+                && is_normal(&y_sample).map_err(RecommendError::Normality)?;
+            description.normality = Some(normality);
+
+            if normality {
+                let svh = SameVarianceHypothesis::new(&x_sample, &y_sample, significance);
+                description.equal_variances = Some(svh.solve().map_err(RecommendError::Variance)?);
+            }
+        }
+    }
+
+    Ok((description, recommend(&description)))
+}
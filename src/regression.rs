@@ -0,0 +1,487 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *observations of predictors* `x₁, …, xₖ` with a *response* `y`,
+//! optionally *weights* of the observations.
+//! **To figure out**: The linear model `y = β₀ + β₁x₁ + … + βₖxₖ + ε`, and whether it is appropriate
+//! to **assume** that the predictors do not affect the response, `β₁ = … = βₖ = 0`.
+//!
+//! The coefficients are estimated by least squares, weighted by `wᵢ` if the variance of `εᵢ` is
+//! proportional to `1 / wᵢ`. Their covariance is either the classical `σ̂² (XᵀWX)⁻¹`, valid for
+//! homoscedastic errors, or one of White's heteroscedasticity-consistent sandwich estimators HC0–HC3,
+//! which stay valid when the variance of the errors depends on the predictors.
+//! The coefficients are tested by `t` statistics and jointly by the Wald `F` statistic.
+//! The fitted model gives pointwise confidence bands of the mean response and prediction bands
+//! of new observations over a grid of predictors.
+//!
+//! The Theil–Sen estimator is a robust alternative for a single predictor: its slope is the median
+//! of the slopes between all pairs of observations, and its distribution-free confidence interval
+//! is bounded by the order statistics of the slopes given by the null distribution of Kendall's `τ`.
+//! It tolerates outliers in up to 29% of the observations and needs no assumption on the errors.
+
+use nalgebra::{DMatrix, DVector};
+use statrs::distribution::{ContinuousCDF, FisherSnedecor, Normal, StudentsT};
+
+use crate::alpha;
+use crate::descriptive::median;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum RegressionError {
+    SignificanceInvalid,
+    DimensionsMismatch,
+    SampleTooSmall,
+    WeightsInvalid,
+    DesignSingular,
+    ValueMissing,
+}
+
+impl std::fmt::Display for RegressionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RegressionError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            RegressionError::DimensionsMismatch => {
+                write!(
+                    f,
+                    "Every observation must have the same positive number of predictors and one response"
+                )
+            }
+            RegressionError::SampleTooSmall => {
+                write!(f, "Sample must contain more observations than coefficients")
+            }
+            RegressionError::WeightsInvalid => {
+                write!(f, "Weights must be positive, one per observation")
+            }
+            RegressionError::DesignSingular => {
+                write!(f, "Predictors are linearly dependent")
+            }
+            RegressionError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+/// Estimator of the covariance of the coefficients.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum CovarianceEstimator {
+    /// `σ̂² (XᵀWX)⁻¹`, assuming homoscedastic errors.
+    Classical,
+    /// White's sandwich with the squared residuals `eᵢ²`.
+    HC0,
+    /// HC0 scaled by `n / (n - p)`.
+    HC1,
+    /// The squared residuals divided by `1 - hᵢ`, with the leverages `hᵢ`.
+    HC2,
+    /// The squared residuals divided by `(1 - hᵢ)²`, the choice for small samples.
+    HC3,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CoefficientTest {
+    pub estimate: f64,
+    pub standard_error: f64,
+    /// Confidence interval at the `1 - significance` level.
+    pub confidence_interval: (f64, f64),
+    /// `t` statistic of `βⱼ = 0`.
+    pub t_observed: f64,
+    pub p_value: f64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RegressionOutcome {
+    /// `β₀, β₁, …, βₖ`, the intercept first.
+    pub coefficients: Vec<CoefficientTest>,
+    /// Covariance matrix of the coefficients, by rows.
+    pub coefficient_covariance: Vec<Vec<f64>>,
+    pub covariance_estimator: CovarianceEstimator,
+    pub fitted: Vec<f64>,
+    pub residuals: Vec<f64>,
+    /// `σ̂² = Σ wᵢ eᵢ² / (n - p)`.
+    pub residual_variance: f64,
+    /// `n - p`.
+    pub freedom_degrees: f64,
+    /// Share of the (weighted) variance of the response explained by the predictors.
+    pub r_squared: f64,
+    /// Wald statistic of `β₁ = … = βₖ = 0`, the usual `F` statistic with the classical covariance.
+    pub f_observed: f64,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+/// Bands over a grid of predictors at the `1 - significance` level, parallel to the grid.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Bands {
+    pub grid: Vec<Vec<f64>>,
+    pub fitted: Vec<f64>,
+    /// Bounds of the mean response, `ŷ ∓ t √(xᵀVx)`.
+    pub confidence_lower: Vec<f64>,
+    pub confidence_upper: Vec<f64>,
+    /// Bounds of a new observation of unit weight, `ŷ ∓ t √(σ̂² + xᵀVx)`.
+    pub prediction_lower: Vec<f64>,
+    pub prediction_upper: Vec<f64>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TheilSenOutcome {
+    /// `median(yᵢ - β₁xᵢ)`.
+    pub intercept: f64,
+    /// Median of the pairwise slopes.
+    pub slope: f64,
+    /// Distribution-free confidence interval of the slope at the `1 - significance` level.
+    pub slope_confidence_interval: (f64, f64),
+    pub hypotheses: Hypotheses,
+    /// Whether the interval of the slope contains 0.
+    pub accepted: bool,
+}
+
+impl RegressionOutcome {
+    /// Response predicted for the predictors `x₁, …, xₖ`.
+    pub fn predict(&self, predictors: &[f64]) -> f64 {
+        self.coefficients[0].estimate
+            + self.coefficients[1..]
+                .iter()
+                .zip(predictors)
+                .map(|(coefficient, x)| coefficient.estimate * x)
+                .sum::<f64>()
+    }
+
+    /// Pointwise confidence and prediction bands at the predictors of the grid.
+    pub fn bands(&self, grid: &[Vec<f64>], significance: f64) -> Result<Bands, RegressionError> {
+        let dimension = self.coefficients.len() - 1;
+        if grid.iter().any(|predictors| predictors.len() != dimension) {
+            return Err(RegressionError::DimensionsMismatch);
+        }
+
+        alpha::validate(significance, RegressionError::SignificanceInvalid)?;
+
+        let t_critical_value = StudentsT::new(0., 1., self.freedom_degrees)
+            .map_err(|_| RegressionError::SampleTooSmall)?
+            .inverse_cdf(1. - significance / 2.);
+        let mut bands = Bands {
+            grid: grid.to_owned(),
+            fitted: Vec::with_capacity(grid.len()),
+            confidence_lower: Vec::with_capacity(grid.len()),
+            confidence_upper: Vec::with_capacity(grid.len()),
+            prediction_lower: Vec::with_capacity(grid.len()),
+            prediction_upper: Vec::with_capacity(grid.len()),
+        };
+        for predictors in grid {
+            let x: Vec<_> = std::iter::once(1.)
+                .chain(predictors.iter().copied())
+                .collect();
+            let mean_variance = x
+                .iter()
+                .zip(&self.coefficient_covariance)
+                .map(|(x_i, row)| x_i * row.iter().zip(&x).map(|(v, x_j)| v * x_j).sum::<f64>())
+                .sum::<f64>();
+            let fitted = self.predict(predictors);
+            let confidence = t_critical_value * mean_variance.sqrt();
+            let prediction = t_critical_value * (self.residual_variance + mean_variance).sqrt();
+
+            bands.fitted.push(fitted);
+            bands.confidence_lower.push(fitted - confidence);
+            bands.confidence_upper.push(fitted + confidence);
+            bands.prediction_lower.push(fitted - prediction);
+            bands.prediction_upper.push(fitted + prediction);
+        }
+
+        Ok(bands)
+    }
+
+    /// Bands of a regression on a single predictor at the values `x` of the grid.
+    pub fn simple_bands(&self, grid: &[f64], significance: f64) -> Result<Bands, RegressionError> {
+        let grid: Vec<_> = grid.iter().map(|x| vec![*x]).collect();
+        self.bands(&grid, significance)
+    }
+}
+
+/// Predictors `x, x², …, xᵈ` of the polynomial regression of degree `d` on a single predictor `x`.
+pub fn polynomial_predictors(x: &[f64], degree: usize) -> Vec<Vec<f64>> {
+    x.iter()
+        .map(|x| (1..=degree as i32).map(|power| x.powi(power)).collect())
+        .collect()
+}
+
+pub struct LinearRegression {
+    /// `n × p` design matrix with the intercept column first.
+    design: DMatrix<f64>,
+    responses: DVector<f64>,
+    weights: DVector<f64>,
+    covariance_estimator: CovarianceEstimator,
+    significance: f64,
+}
+
+impl LinearRegression {
+    /// `predictors[i]` holds the predictors of the `i`-th observation; the intercept is added.
+    pub fn new(
+        predictors: &[Vec<f64>],
+        responses: &[f64],
+        significance: f64,
+    ) -> Result<Self, RegressionError> {
+        for observation in predictors {
+            MissingPolicy::Error
+                .apply(observation)
+                .map_err(|_| RegressionError::ValueMissing)?;
+        }
+
+        MissingPolicy::Error
+            .apply(responses)
+            .map_err(|_| RegressionError::ValueMissing)?;
+
+        let dimension = predictors.first().map_or(0, Vec::len);
+        if dimension == 0
+            || predictors.len() != responses.len()
+            || predictors
+                .iter()
+                .any(|observation| observation.len() != dimension)
+        {
+            return Err(RegressionError::DimensionsMismatch);
+        }
+
+        if responses.len() <= dimension + 1 {
+            return Err(RegressionError::SampleTooSmall);
+        }
+
+        alpha::validate(significance, RegressionError::SignificanceInvalid)?;
+
+        Ok(Self {
+            design: DMatrix::from_fn(responses.len(), dimension + 1, |i, j| match j {
+                0 => 1.,
+                j => predictors[i][j - 1],
+            }),
+            responses: DVector::from_column_slice(responses),
+            weights: DVector::repeat(responses.len(), 1.),
+            covariance_estimator: CovarianceEstimator::Classical,
+            significance,
+        })
+    }
+
+    /// Regression on a single predictor.
+    pub fn simple(x: &[f64], y: &[f64], significance: f64) -> Result<Self, RegressionError> {
+        let predictors: Vec<_> = x.iter().map(|x| vec![*x]).collect();
+        Self::new(&predictors, y, significance)
+    }
+
+    /// Weighted least squares, with the weights inversely proportional to the variances of the errors.
+    pub fn with_weights(mut self, weights: &[f64]) -> Result<Self, RegressionError> {
+        if weights.len() != self.responses.len() || !weights.iter().all(|w| *w > 0.) {
+            return Err(RegressionError::WeightsInvalid);
+        }
+
+        self.weights = DVector::from_column_slice(weights);
+        Ok(self)
+    }
+
+    pub fn with_covariance(mut self, covariance_estimator: CovarianceEstimator) -> Self {
+        self.covariance_estimator = covariance_estimator;
+        self
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            "β₁ = … = βₖ = 0",
+            "βⱼ ≠ 0 for some j ≥ 1",
+            Alternative::Greater,
+        )
+    }
+
+    pub fn solve(&self) -> Result<bool, RegressionError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<RegressionOutcome, RegressionError> {
+        let (n, p) = self.design.shape();
+        let freedom_degrees = (n - p) as f64;
+
+        // Weighted least squares is ordinary least squares of the rows scaled by `√wᵢ`.
+        let roots = self.weights.map(f64::sqrt);
+        let design = DMatrix::from_fn(n, p, |i, j| roots[i] * self.design[(i, j)]);
+        let responses = self.responses.component_mul(&roots);
+
+        let gram_inverse = (design.transpose() * &design)
+            .try_inverse()
+            .ok_or(RegressionError::DesignSingular)?;
+        let estimates = &gram_inverse * design.transpose() * &responses;
+        let scaled_residuals = &responses - &design * &estimates;
+        let residual_variance = scaled_residuals.norm_squared() / freedom_degrees;
+
+        let covariance = match self.covariance_estimator {
+            CovarianceEstimator::Classical => &gram_inverse * residual_variance,
+            estimator => {
+                let leverages: Vec<_> = (0..n)
+                    .map(|i| {
+                        let row = design.row(i);
+                        (row * &gram_inverse * row.transpose())[(0, 0)]
+                    })
+                    .collect();
+                let squares = DVector::from_fn(n, |i, _| {
+                    let square = scaled_residuals[i].powi(2);
+                    match estimator {
+                        CovarianceEstimator::HC1 => square * n as f64 / freedom_degrees,
+                        CovarianceEstimator::HC2 => square / (1. - leverages[i]),
+                        CovarianceEstimator::HC3 => square / (1. - leverages[i]).powi(2),
+                        _ => square,
+                    }
+                });
+                let meat =
+                    design.transpose() * DMatrix::from_fn(n, p, |i, j| squares[i] * design[(i, j)]);
+                &gram_inverse * meat * &gram_inverse
+            }
+        };
+
+        let students_t =
+            StudentsT::new(0., 1., freedom_degrees).map_err(|_| RegressionError::SampleTooSmall)?;
+        let t_critical_value = students_t.inverse_cdf(1. - self.significance / 2.);
+        let coefficients = (0..p)
+            .map(|j| {
+                let (estimate, standard_error) = (estimates[j], covariance[(j, j)].sqrt());
+                let t_observed = estimate / standard_error;
+                CoefficientTest {
+                    estimate,
+                    standard_error,
+                    confidence_interval: (
+                        estimate - t_critical_value * standard_error,
+                        estimate + t_critical_value * standard_error,
+                    ),
+                    t_observed,
+                    p_value: 2. * students_t.sf(t_observed.abs()),
+                }
+            })
+            .collect();
+
+        let fitted = &self.design * &estimates;
+        let residuals = &self.responses - &fitted;
+        let weights_sum = self.weights.sum();
+        let response_mean = self.weights.dot(&self.responses) / weights_sum;
+        let total_sum = self
+            .responses
+            .iter()
+            .zip(self.weights.iter())
+            .map(|(y, w)| w * (y - response_mean).powi(2))
+            .sum::<f64>();
+        let r_squared = 1. - scaled_residuals.norm_squared() / total_sum;
+
+        let slopes = estimates.rows(1, p - 1).into_owned();
+        let slopes_covariance = covariance.view((1, 1), (p - 1, p - 1)).into_owned();
+        let f_observed = slopes_covariance
+            .try_inverse()
+            .map(|inverse| (slopes.transpose() * inverse * &slopes)[(0, 0)] / (p - 1) as f64)
+            .ok_or(RegressionError::DesignSingular)?;
+        let p_value = FisherSnedecor::new((p - 1) as f64, freedom_degrees)
+            .map_err(|_| RegressionError::SampleTooSmall)?
+            .sf(f_observed);
+
+        Ok(RegressionOutcome {
+            coefficients,
+            coefficient_covariance: covariance
+                .row_iter()
+                .map(|row| row.iter().copied().collect())
+                .collect(),
+            covariance_estimator: self.covariance_estimator,
+            fitted: fitted.iter().copied().collect(),
+            residuals: residuals.iter().copied().collect(),
+            residual_variance,
+            freedom_degrees,
+            r_squared,
+            f_observed,
+            p_value,
+            hypotheses: self.hypotheses(),
+            accepted: p_value > self.significance,
+        })
+    }
+}
+
+pub struct TheilSenRegression {
+    x_sample: Vec<f64>,
+    y_sample: Vec<f64>,
+    significance: f64,
+}
+
+impl TheilSenRegression {
+    pub fn new(x: &[f64], y: &[f64], significance: f64) -> Result<Self, RegressionError> {
+        MissingPolicy::Error
+            .apply(x)
+            .and(MissingPolicy::Error.apply(y))
+            .map_err(|_| RegressionError::ValueMissing)?;
+
+        if x.len() != y.len() {
+            return Err(RegressionError::DimensionsMismatch);
+        }
+
+        if x.len() < 3 {
+            return Err(RegressionError::SampleTooSmall);
+        }
+
+        alpha::validate(significance, RegressionError::SignificanceInvalid)?;
+
+        Ok(Self {
+            x_sample: x.to_owned(),
+            y_sample: y.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new("β₁ = 0", "β₁ ≠ 0", Alternative::TwoSided)
+    }
+
+    pub fn solve(&self) -> Result<bool, RegressionError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<TheilSenOutcome, RegressionError> {
+        let n = self.x_sample.len();
+
+        // Pairs with tied predictors have no slope.
+        let mut slopes = Vec::with_capacity(n * (n - 1) / 2);
+        for i in 0..n {
+            for j in i + 1..n {
+                if self.x_sample[j] != self.x_sample[i] {
+                    slopes.push(
+                        (self.y_sample[j] - self.y_sample[i])
+                            / (self.x_sample[j] - self.x_sample[i]),
+                    );
+                }
+            }
+        }
+        if slopes.is_empty() {
+            return Err(RegressionError::DesignSingular);
+        }
+        slopes.sort_by(f64::total_cmp);
+
+        let slope = median(&slopes);
+        let count = slopes.len();
+        let size = n as f64;
+        let spread = Normal::standard().inverse_cdf(1. - self.significance / 2.)
+            * (size * (size - 1.) * (2. * size + 5.) / 18.).sqrt();
+        let lower_rank = (((count as f64 - spread) / 2.).round() as usize).clamp(1, count);
+        let slope_confidence_interval = (slopes[lower_rank - 1], slopes[count - lower_rank]);
+
+        let intercept = median(
+            &self
+                .x_sample
+                .iter()
+                .zip(&self.y_sample)
+                .map(|(x, y)| y - slope * x)
+                .collect::<Vec<_>>(),
+        );
+
+        Ok(TheilSenOutcome {
+            intercept,
+            slope,
+            slope_confidence_interval,
+            hypotheses: self.hypotheses(),
+            accepted: slope_confidence_interval.0 <= 0. && 0. <= slope_confidence_interval.1,
+        })
+    }
+}
@@ -0,0 +1,151 @@
+//! Reliability of questionnaires and tests made of several items.
+//!
+//! **Given**: *scores* of every respondent on every item.
+//! **To figure out**: How consistently do the items measure the same quantity?
+//!
+//! Cronbach's `α` compares the variances of the items with the variance of the total score,
+//! and the `α` of the scale without each item shows which items weaken it.
+
+use statrs::distribution::{ContinuousCDF, FisherSnedecor};
+
+use crate::alpha;
+use crate::missing::MissingPolicy;
+use crate::numeric::unbiased_variance;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum ReliabilityError {
+    SignificanceInvalid,
+    ItemsCountInvalid,
+    RespondentsCountInvalid,
+    LengthsMismatch,
+    TotalDegenerate,
+    ValueMissing,
+}
+
+impl std::fmt::Display for ReliabilityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ReliabilityError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            ReliabilityError::ItemsCountInvalid => {
+                write!(f, "At least two items are required")
+            }
+            ReliabilityError::RespondentsCountInvalid => {
+                write!(f, "At least two respondents are required")
+            }
+            ReliabilityError::LengthsMismatch => {
+                write!(f, "Every item must be scored by every respondent")
+            }
+            ReliabilityError::TotalDegenerate => {
+                write!(f, "Total scores must take at least two distinct values")
+            }
+            ReliabilityError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CronbachOutcome {
+    /// `α = k / (k - 1) · (1 - Σ sᵢ² / s²_total)` for `k` items.
+    pub alpha: f64,
+    /// Feldt's confidence interval for `α` at the `1 - significance` level.
+    pub confidence_interval: (f64, f64),
+    /// `α` of the scale without each item in turn, `NaN` when a single item would be left.
+    pub alphas_if_dropped: Vec<f64>,
+}
+
+pub struct CronbachAlpha {
+    items: Vec<Vec<f64>>,
+    significance: f64,
+}
+
+impl CronbachAlpha {
+    /// `items[i][r]` is the score of respondent `r` on item `i`.
+    pub fn new(items: &[Vec<f64>], significance: f64) -> Result<Self, ReliabilityError> {
+        for item in items {
+            MissingPolicy::Error
+                .apply(item)
+                .map_err(|_| ReliabilityError::ValueMissing)?;
+        }
+
+        if items.len() < 2 {
+            return Err(ReliabilityError::ItemsCountInvalid);
+        }
+
+        let respondents = items[0].len();
+        if items.iter().any(|item| item.len() != respondents) {
+            return Err(ReliabilityError::LengthsMismatch);
+        }
+
+        if respondents < 2 {
+            return Err(ReliabilityError::RespondentsCountInvalid);
+        }
+
+        alpha::validate(significance, ReliabilityError::SignificanceInvalid)?;
+
+        if unbiased_variance(&total_scores(&items.iter().collect::<Vec<_>>())) == 0. {
+            return Err(ReliabilityError::TotalDegenerate);
+        }
+
+        Ok(Self {
+            items: items.to_owned(),
+            significance,
+        })
+    }
+
+    /// `(1 - α) / (1 - α̂) ~ F(n - 1, (n - 1)(k - 1))` for `n` respondents bounds `α`.
+    pub fn outcome(&self) -> Result<CronbachOutcome, ReliabilityError> {
+        let alpha = cronbach_alpha(&self.items.iter().collect::<Vec<_>>());
+
+        let (n, k) = (self.items[0].len() as f64, self.items.len() as f64);
+        let fisher_snedecor_dist = FisherSnedecor::new(n - 1., (n - 1.) * (k - 1.))
+            .map_err(|_| ReliabilityError::RespondentsCountInvalid)?;
+        let confidence_interval = (
+            1. - (1. - alpha) * fisher_snedecor_dist.inverse_cdf(1. - self.significance / 2.),
+            1. - (1. - alpha) * fisher_snedecor_dist.inverse_cdf(self.significance / 2.),
+        );
+
+        let alphas_if_dropped = (0..self.items.len())
+            .map(|dropped| {
+                let remaining: Vec<_> = self
+                    .items
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != dropped)
+                    .map(|(_, item)| item)
+                    .collect();
+                cronbach_alpha(&remaining)
+            })
+            .collect();
+
+        Ok(CronbachOutcome {
+            alpha,
+            confidence_interval,
+            alphas_if_dropped,
+        })
+    }
+}
+
+fn cronbach_alpha(items: &[&Vec<f64>]) -> f64 {
+    let k = items.len() as f64;
+    if k < 2. {
+        return f64::NAN;
+    }
+
+    let items_variance = items
+        .iter()
+        .map(|item| unbiased_variance(item))
+        .sum::<f64>();
+    k / (k - 1.) * (1. - items_variance / unbiased_variance(&total_scores(items)))
+}
+
+fn total_scores(items: &[&Vec<f64>]) -> Vec<f64> {
+    (0..items[0].len())
+        .map(|r| items.iter().map(|item| item[r]).sum())
+        .collect()
+}
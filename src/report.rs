@@ -0,0 +1,470 @@
+//! Human-readable reports on the solving steps.
+//!
+//! The reports are written in the [`Locale`] of the course: the fixed phrases come from a message catalog
+//! and the hypothesis statements are translated phrase by phrase, the formulas left as they are.
+//! The symbols follow the [`NotationStyle`] of the textbook, independently of the language.
+
+use std::fmt::Write;
+
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::contingency::IndependenceOutcome;
+use crate::hypotheses::Hypotheses;
+use crate::normal_distribution_hypothesis::{IncompleteNDHProblemSituation, NDHError, NDHOutcome};
+use crate::rounding::round;
+use crate::same_variance_hypothesis::SVHOutcome;
+use crate::special::laplace_table;
+
+/// Language of the reports.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Locale {
+    #[default]
+    English,
+    Russian,
+}
+
+/// Symbols of the statistics in the reports.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum NotationStyle {
+    /// `χ²_obs`, `F_crit`, `s²_x`, as in English-language textbooks.
+    #[default]
+    English,
+    /// `χ²_набл`, `F_кр`, `D̂_x`, as in Russian textbooks.
+    Russian,
+}
+
+impl NotationStyle {
+    /// The observed value of the statistic, e.g. `χ²_obs`.
+    pub fn observed(self, statistic: &str) -> String {
+        match self {
+            NotationStyle::English => format!("{statistic}_obs"),
+            NotationStyle::Russian => format!("{statistic}_набл"),
+        }
+    }
+
+    /// The critical value of the statistic, e.g. `χ²_crit`.
+    pub fn critical(self, statistic: &str) -> String {
+        match self {
+            NotationStyle::English => format!("{statistic}_crit"),
+            NotationStyle::Russian => format!("{statistic}_кр"),
+        }
+    }
+
+    /// The unbiased sample variance of the variable, e.g. `s²_x`.
+    pub fn variance(self, variable: &str) -> String {
+        match self {
+            NotationStyle::English => format!("s²_{variable}"),
+            NotationStyle::Russian => format!("D̂_{variable}"),
+        }
+    }
+}
+
+/// Fixed phrases of the reports.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+enum Message {
+    Accepted,
+    RejectedInFavour,
+    So,
+    AdjustedResidual,
+    Row,
+}
+
+fn message(locale: Locale, message: Message) -> &'static str {
+    match (locale, message) {
+        (Locale::English, Message::Accepted) => "H₀ is accepted",
+        (Locale::English, Message::RejectedInFavour) => "H₀ is rejected in favour of H₁",
+        (Locale::English, Message::So) => "so",
+        (Locale::English, Message::AdjustedResidual) => "adjusted residual",
+        (Locale::English, Message::Row) => "row",
+        (Locale::Russian, Message::Accepted) => "H₀ принимается",
+        (Locale::Russian, Message::RejectedInFavour) => "H₀ отвергается в пользу H₁",
+        (Locale::Russian, Message::So) => "следовательно",
+        (Locale::Russian, Message::AdjustedResidual) => "скорректированный остаток",
+        (Locale::Russian, Message::Row) => "строка",
+    }
+}
+
+/// Russian phrases of the hypothesis statements, the longer ones before those they contain.
+const RUSSIAN_STATEMENTS: [(&str, &str); 41] = [
+    (
+        "the sample does not follow a Normal distribution",
+        "выборка не подчиняется нормальному распределению",
+    ),
+    (
+        "the sample follows a Normal distribution",
+        "выборка подчиняется нормальному распределению",
+    ),
+    (
+        "the sample does not follow the theoretical distribution",
+        "выборка не подчиняется теоретическому распределению",
+    ),
+    (
+        "the sample follows the theoretical distribution",
+        "выборка подчиняется теоретическому распределению",
+    ),
+    (
+        "the sample does not follow the proportions",
+        "выборка не подчиняется пропорциям",
+    ),
+    (
+        "the sample follows the proportions",
+        "выборка подчиняется пропорциям",
+    ),
+    (
+        "the sample does not follow",
+        "выборка не подчиняется распределению",
+    ),
+    ("the sample follows", "выборка подчиняется распределению"),
+    ("X and Y are independent", "X и Y независимы"),
+    ("X and Y are dependent", "X и Y зависимы"),
+    (
+        "X and Y have the same distribution",
+        "X и Y имеют одинаковое распределение",
+    ),
+    (
+        "X and Y have different distributions",
+        "X и Y имеют разные распределения",
+    ),
+    (
+        "X is stochastically greater or less than Y",
+        "X стохастически больше или меньше Y",
+    ),
+    ("under all conditions", "при всех условиях"),
+    ("for some conditions", "для некоторых условий"),
+    ("for some", "для некоторых"),
+    (
+        "the counts are Poisson",
+        "счётчики распределены по Пуассону",
+    ),
+    ("the counts are overdispersed", "счётчики сверхдисперсны"),
+    ("every face is equally likely", "все грани равновероятны"),
+    (
+        "some faces are more likely than others",
+        "некоторые грани вероятнее других",
+    ),
+    (
+        "some added coefficient",
+        "некоторый добавленный коэффициент",
+    ),
+    (
+        "the added coefficients are 0",
+        "добавленные коэффициенты равны 0",
+    ),
+    (
+        "the correlation matrix is the identity",
+        "корреляционная матрица единичная",
+    ),
+    ("the variables are correlated", "переменные коррелированы"),
+    (
+        "the lifetimes are Weibull with",
+        "время жизни распределено по Вейбуллу с",
+    ),
+    (
+        "the lifetimes are exponential",
+        "время жизни распределено экспоненциально",
+    ),
+    (
+        "the predicted probabilities do not fit the outcomes",
+        "предсказанные вероятности не согласуются с исходами",
+    ),
+    (
+        "the predicted probabilities fit the outcomes",
+        "предсказанные вероятности согласуются с исходами",
+    ),
+    (
+        "the series has no monotonic trend",
+        "ряд не имеет монотонного тренда",
+    ),
+    (
+        "the series has a monotonic trend",
+        "ряд имеет монотонный тренд",
+    ),
+    ("the series has a unit root", "ряд имеет единичный корень"),
+    (
+        "the series is stationary around a level",
+        "ряд стационарен относительно уровня",
+    ),
+    (
+        "the series is stationary around a trend",
+        "ряд стационарен относительно тренда",
+    ),
+    ("the series is stationary", "ряд стационарен"),
+    ("the raters agree by chance", "согласие экспертов случайно"),
+    (
+        "the raters agree beyond chance",
+        "согласие экспертов не случайно",
+    ),
+    ("σ²_between", "σ²_межгр"),
+    ("adjusted", "скорректированное"),
+    ("trimmed", "усечённое"),
+    (" and ", " и "),
+    (" or ", " или "),
+];
+
+/// The hypothesis statement in the locale.
+///
+/// Statements built by the crate are translated; phrases missing from the catalog are kept in English.
+pub fn statement(statement: &str, locale: Locale) -> String {
+    match locale {
+        Locale::English => statement.to_string(),
+        Locale::Russian => RUSSIAN_STATEMENTS
+            .iter()
+            .fold(statement.to_string(), |statement, (english, russian)| {
+                statement.replace(english, russian)
+            }),
+    }
+}
+
+/// `H₀` and `H₁` in the locale.
+pub fn hypotheses(hypotheses: &Hypotheses, locale: Locale) -> String {
+    format!(
+        "H₀: {}\nH₁: {}",
+        statement(&hypotheses.null, locale),
+        statement(&hypotheses.alternative, locale)
+    )
+}
+
+/// Decision text naming the hypothesis that is kept, as [`Hypotheses::decision`] does, in the locale.
+pub fn decision(hypotheses: &Hypotheses, accepted: bool, locale: Locale) -> String {
+    if accepted {
+        format!(
+            "{}: {}",
+            message(locale, Message::Accepted),
+            statement(&hypotheses.null, locale)
+        )
+    } else {
+        format!(
+            "{}: {}",
+            message(locale, Message::RejectedInFavour),
+            statement(&hypotheses.alternative, locale)
+        )
+    }
+}
+
+/// The comparison of the statistic with the critical value followed by the decision,
+/// e.g. `χ²_obs < χ²_crit, so H₀ is accepted: …`.
+///
+/// The comparison is written by the caller in its [`NotationStyle`].
+pub fn conclusion(
+    comparison: &str,
+    hypotheses: &Hypotheses,
+    accepted: bool,
+    locale: Locale,
+) -> String {
+    format!(
+        "{comparison}, {} {}",
+        message(locale, Message::So),
+        decision(hypotheses, accepted, locale)
+    )
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum ExpectedFrequencyStyle {
+    /// `n'ᵢ = n · (F(xᵢ₊₁) - F(xᵢ))` with the fitted Normal CDF `F`.
+    Cdf,
+    /// `n'ᵢ = n · (Φ₀(zᵢ₊₁) - Φ₀(zᵢ))` with `zᵢ = (xᵢ - x̄) / σ` and table rounding.
+    Laplace,
+}
+
+/// Derivation of the theoretical frequencies of an incomplete Normal Distribution Hypothesis situation,
+/// rounded by the rounding policy of the situation.
+///
+/// A sample whose standard deviation is not positive has no Normal fit to derive them from.
+pub fn expected_frequencies(
+    situation: &IncompleteNDHProblemSituation,
+    style: ExpectedFrequencyStyle,
+) -> Result<String, NDHError> {
+    let (n, mean, std_dev) = (
+        situation.sample_size(),
+        situation.mean(),
+        situation.std_dev(),
+    );
+    if std_dev.is_nan() || std_dev <= 0. {
+        return Err(NDHError::SampleDegenerate);
+    }
+    let rounding = situation.rounding();
+
+    let mut report = String::new();
+    writeln!(report, "n = {n}, x̄ = {mean:.4}, σ = {std_dev:.4}").unwrap();
+
+    match style {
+        ExpectedFrequencyStyle::Cdf => {
+            // The standard deviation is positive.
+            let normal_distribution = Normal::new(mean, std_dev).unwrap();
+            for (x_1, x_2) in situation.random_value_ranges() {
+                let (f_1, f_2) = (normal_distribution.cdf(*x_1), normal_distribution.cdf(*x_2));
+                writeln!(
+                    report,
+                    "[{x_1}; {x_2}): n' = {n} · (F({x_2}) - F({x_1})) = {n} · ({f_2:.4} - {f_1:.4}) = {:.2}",
+                    rounding.intermediate(n * (f_2 - f_1))
+                )
+                .unwrap();
+            }
+        }
+        ExpectedFrequencyStyle::Laplace => {
+            for (x_1, x_2) in situation.random_value_ranges() {
+                let (z_1, z_2) = (
+                    round((x_1 - mean) / std_dev, 2),
+                    round((x_2 - mean) / std_dev, 2),
+                );
+                let (phi_1, phi_2) = (laplace_table(z_1), laplace_table(z_2));
+                writeln!(
+                    report,
+                    "[{x_1}; {x_2}): z = ({z_1:.2}; {z_2:.2}), n' = {n} · (Φ₀({z_2:.2}) - Φ₀({z_1:.2})) = {n} · ({phi_2:.4} - ({phi_1:.4})) = {:.2}",
+                    rounding.intermediate(n * (phi_2 - phi_1))
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Table of the classes with their contributions into the Chi-Squared statistic, and the decision.
+pub fn normal_distribution_hypothesis(
+    outcome: &NDHOutcome,
+    locale: Locale,
+    notation: NotationStyle,
+) -> String {
+    let mut report = String::new();
+    writeln!(report, "{}", hypotheses(&outcome.hypotheses, locale)).unwrap();
+    for (i, bin) in outcome.bins.iter().enumerate() {
+        let class = match bin.range {
+            Some((x_1, x_2)) => format!("[{x_1}; {x_2})"),
+            None => (i + 1).to_string(),
+        };
+        writeln!(
+            report,
+            "{class}: n = {}, n' = {:.2}, (n - n')² / n' = {:.4}, {} = {:.2}{}",
+            bin.observed,
+            bin.expected,
+            bin.chi_term,
+            message(locale, Message::AdjustedResidual),
+            bin.residuals.adjusted,
+            if bin.residuals.exceeds_threshold() {
+                " *"
+            } else {
+                ""
+            }
+        )
+        .unwrap();
+    }
+    let (observed, critical) = (notation.observed("χ²"), notation.critical("χ²"));
+    writeln!(
+        report,
+        "{observed} = {:.4}, {critical}({}) = {:.4}",
+        outcome.chi_squared_observed, outcome.freedom_degrees, outcome.chi_squared_critical_value
+    )
+    .unwrap();
+    writeln!(
+        report,
+        "{}",
+        conclusion(
+            &comparison(&observed, &critical, outcome.accepted),
+            &outcome.hypotheses,
+            outcome.accepted,
+            locale
+        )
+    )
+    .unwrap();
+
+    report
+}
+
+/// Variances, the Fisher–Snedecor statistic against its critical value, and the decision.
+pub fn same_variance_hypothesis(
+    outcome: &SVHOutcome,
+    locale: Locale,
+    notation: NotationStyle,
+) -> String {
+    let mut report = String::new();
+    writeln!(report, "{}", hypotheses(&outcome.hypotheses, locale)).unwrap();
+    writeln!(
+        report,
+        "{} = {:.4}, {} = {:.4}",
+        notation.variance("x"),
+        outcome.variances.0,
+        notation.variance("y"),
+        outcome.variances.1
+    )
+    .unwrap();
+    let (observed, critical) = (notation.observed("F"), notation.critical("F"));
+    writeln!(
+        report,
+        "{observed} = {:.4}, {critical}({}, {}) = {:.4}",
+        outcome.fisher_snedecor_observed,
+        outcome.freedom_degrees.0,
+        outcome.freedom_degrees.1,
+        outcome.fisher_snedecor_critical_value
+    )
+    .unwrap();
+    writeln!(
+        report,
+        "{}",
+        conclusion(
+            &comparison(&observed, &critical, outcome.accepted),
+            &outcome.hypotheses,
+            outcome.accepted,
+            locale
+        )
+    )
+    .unwrap();
+
+    report
+}
+
+/// Theoretical frequencies of the cells, the Chi-Squared statistic against its critical value, and the decision.
+pub fn independence_hypothesis(
+    outcome: &IndependenceOutcome,
+    locale: Locale,
+    notation: NotationStyle,
+) -> String {
+    let mut report = String::new();
+    writeln!(report, "{}", hypotheses(&outcome.hypotheses, locale)).unwrap();
+    for (i, row) in outcome.cells.iter().enumerate() {
+        let expected: Vec<_> = row
+            .iter()
+            .map(|cell| format!("{:.2}", cell.expected))
+            .collect();
+        writeln!(
+            report,
+            "{} {}: n' = {}",
+            message(locale, Message::Row),
+            i + 1,
+            expected.join(", ")
+        )
+        .unwrap();
+    }
+    let (observed, critical) = (notation.observed("χ²"), notation.critical("χ²"));
+    writeln!(
+        report,
+        "{observed} = {:.4}, {critical}({}) = {:.4}",
+        outcome.chi_squared_observed, outcome.freedom_degrees, outcome.chi_squared_critical_value
+    )
+    .unwrap();
+    writeln!(
+        report,
+        "{}",
+        conclusion(
+            &comparison(&observed, &critical, outcome.accepted),
+            &outcome.hypotheses,
+            outcome.accepted,
+            locale
+        )
+    )
+    .unwrap();
+
+    report
+}
+
+/// `observed < critical` if `H₀` is accepted, `observed >= critical` otherwise.
+pub(crate) fn comparison(observed: &str, critical: &str, accepted: bool) -> String {
+    if accepted {
+        format!("{observed} < {critical}")
+    } else {
+        format!("{observed} >= {critical}")
+    }
+}
@@ -0,0 +1,48 @@
+//! Cell residuals of the Chi-Squared tests, telling where the departure from the null hypothesis comes from.
+
+/// Residuals beyond `±1.96` are significant at the `0.05` level, since adjusted residuals are
+/// approximately Standard Normal under the null hypothesis.
+pub const RESIDUAL_THRESHOLD: f64 = 1.96;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Residuals {
+    /// `(observed - expected) / √expected`, the signed root of the Chi-Squared term.
+    pub standardized: f64,
+    /// The standardized residual divided by its standard error.
+    pub adjusted: f64,
+}
+
+impl Residuals {
+    /// Residuals of a goodness-of-fit class holding the `expected / total` share of the total.
+    pub fn goodness_of_fit(observed: f64, expected: f64, total: f64) -> Self {
+        let standardized = (observed - expected) / expected.sqrt();
+
+        Self {
+            standardized,
+            adjusted: standardized / (1. - expected / total).sqrt(),
+        }
+    }
+
+    /// Residuals of a contingency table cell with the given row and column totals.
+    pub fn contingency(
+        observed: f64,
+        expected: f64,
+        row_total: f64,
+        column_total: f64,
+        total: f64,
+    ) -> Self {
+        let standardized = (observed - expected) / expected.sqrt();
+
+        Self {
+            standardized,
+            adjusted: standardized
+                / ((1. - row_total / total) * (1. - column_total / total)).sqrt(),
+        }
+    }
+
+    /// Whether the adjusted residual exceeds `±1.96`.
+    pub fn exceeds_threshold(&self) -> bool {
+        self.adjusted.abs() > RESIDUAL_THRESHOLD
+    }
+}
@@ -0,0 +1,53 @@
+//! Source of randomness for the stochastic features: permutation tests, bootstrap, Monte Carlo and simulations.
+//!
+//! Every stochastic API takes an [`RngSource`], so each result is reproducible:
+//! either from a seed, which restarts the same stream on every call,
+//! or from a caller's generator, which keeps advancing across calls.
+
+use std::cell::RefCell;
+
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+pub enum RngSource {
+    /// A fresh `StdRng` seeded with the value on every call.
+    Seed(u64),
+    /// A caller-provided generator, shared across calls.
+    Rng(RefCell<Box<dyn RngCore + Send>>),
+}
+
+impl RngSource {
+    pub fn from_rng(rng: impl RngCore + Send + 'static) -> Self {
+        RngSource::Rng(RefCell::new(Box::new(rng)))
+    }
+
+    /// Runs `f` with the generator of the source.
+    pub fn with_rng<T>(&self, f: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+        match self {
+            RngSource::Seed(seed) => f(&mut StdRng::seed_from_u64(*seed)),
+            RngSource::Rng(rng) => f(rng.borrow_mut().as_mut()),
+        }
+    }
+}
+
+/// The seed `0`.
+impl Default for RngSource {
+    fn default() -> Self {
+        RngSource::Seed(0)
+    }
+}
+
+impl From<u64> for RngSource {
+    fn from(seed: u64) -> Self {
+        RngSource::Seed(seed)
+    }
+}
+
+impl std::fmt::Debug for RngSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RngSource::Seed(seed) => f.debug_tuple("Seed").field(seed).finish(),
+            RngSource::Rng(_) => f.debug_tuple("Rng").finish_non_exhaustive(),
+        }
+    }
+}
@@ -0,0 +1,330 @@
+//! Algorithms for solving problems of the following kinds.
+//!
+//! -  **Given**: *significance ratio*, *scores* of observations with *binary labels*.
+//!    **To figure out**: Is it appropriate to **assume** that the scores do not discriminate the labels, `AUC = 1/2`?
+//!
+//! -  **Given**: *significance ratio*, *two scores* of the same observations with *binary labels*.
+//!    **To figure out**: Is it appropriate to **assume** that the scores discriminate equally well, `AUC_X = AUC_Y`?
+//!
+//! The area under the ROC curve is the Mann–Whitney probability that a positive observation scores higher
+//! than a negative one, ties counting half. Its standard error, and the covariance of two areas over the same
+//! observations, are estimated by the placement values of DeLong, DeLong and Clarke-Pearson (1988).
+
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::alpha;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum RocError {
+    SignificanceInvalid,
+    LengthsMismatch,
+    ClassesMissing,
+    ValueMissing,
+}
+
+impl std::fmt::Display for RocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RocError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            RocError::LengthsMismatch => {
+                write!(f, "Each score must have exactly one label")
+            }
+            RocError::ClassesMissing => {
+                write!(
+                    f,
+                    "At least two positive and two negative observations are required"
+                )
+            }
+            RocError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+/// Point of the ROC curve: the rates of the observations scoring at least the threshold.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RocPoint {
+    pub threshold: f64,
+    pub false_positive_rate: f64,
+    pub true_positive_rate: f64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AucOutcome {
+    pub auc: f64,
+    /// DeLong's standard error of the AUC.
+    pub standard_error: f64,
+    /// Wald confidence interval at the `1 - significance` level, clamped to `[0, 1]`.
+    pub confidence_interval: (f64, f64),
+    pub z_observed: f64,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DeLongOutcome {
+    /// `AUC_X` and `AUC_Y`.
+    pub aucs: (f64, f64),
+    /// `AUC_X - AUC_Y`.
+    pub difference: f64,
+    pub standard_error: f64,
+    pub z_observed: f64,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+/// ROC curve from `(0, 0)` to `(1, 1)`, one point per distinct score in descending order;
+/// the first point has an infinite threshold.
+pub fn roc_curve(scores: &[f64], labels: &[bool]) -> Result<Vec<RocPoint>, RocError> {
+    let (positives, negatives) = classes(scores, labels)?;
+    let (m, n) = (positives.len() as f64, negatives.len() as f64);
+
+    let mut order: Vec<_> = (0..scores.len()).collect();
+    order.sort_by(|&i, &j| scores[j].total_cmp(&scores[i]));
+
+    let mut curve = vec![RocPoint {
+        threshold: f64::INFINITY,
+        false_positive_rate: 0.,
+        true_positive_rate: 0.,
+    }];
+    let (mut true_positives, mut false_positives) = (0., 0.);
+    for (k, &i) in order.iter().enumerate() {
+        if labels[i] {
+            true_positives += 1.;
+        } else {
+            false_positives += 1.;
+        }
+
+        // Tied scores make a single step of the curve.
+        if order.get(k + 1).is_none_or(|&j| scores[j] != scores[i]) {
+            curve.push(RocPoint {
+                threshold: scores[i],
+                false_positive_rate: false_positives / n,
+                true_positive_rate: true_positives / m,
+            });
+        }
+    }
+
+    Ok(curve)
+}
+
+/// Test of `H₀: AUC = 1/2` by the Wald statistic with DeLong's standard error.
+pub struct AucHypothesis {
+    scores: Vec<f64>,
+    labels: Vec<bool>,
+    significance: f64,
+}
+
+impl AucHypothesis {
+    /// `labels[i]` is `true` for the positive observations.
+    pub fn new(scores: &[f64], labels: &[bool], significance: f64) -> Result<Self, RocError> {
+        classes(scores, labels)?;
+        validate(significance)?;
+
+        Ok(Self {
+            scores: scores.to_owned(),
+            labels: labels.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new("AUC = 1/2", "AUC ≠ 1/2", Alternative::TwoSided)
+    }
+
+    pub fn solve(&self) -> Result<bool, RocError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<AucOutcome, RocError> {
+        let placements = Placements::new(&self.scores, &self.labels)?;
+        let auc = placements.auc();
+        let standard_error = placements.covariance(&placements).sqrt();
+
+        let z = Normal::standard().inverse_cdf(1. - self.significance / 2.);
+        let (z_observed, p_value) = wald(auc - 0.5, standard_error);
+
+        Ok(AucOutcome {
+            auc,
+            standard_error,
+            confidence_interval: (
+                (auc - z * standard_error).max(0.),
+                (auc + z * standard_error).min(1.),
+            ),
+            z_observed,
+            p_value,
+            hypotheses: self.hypotheses(),
+            accepted: p_value > self.significance,
+        })
+    }
+}
+
+/// DeLong's test of `H₀: AUC_X = AUC_Y` for two scores of the same observations.
+pub struct DeLongHypothesis {
+    x_scores: Vec<f64>,
+    y_scores: Vec<f64>,
+    labels: Vec<bool>,
+    significance: f64,
+}
+
+impl DeLongHypothesis {
+    pub fn new(
+        x_scores: &[f64],
+        y_scores: &[f64],
+        labels: &[bool],
+        significance: f64,
+    ) -> Result<Self, RocError> {
+        classes(x_scores, labels)?;
+        classes(y_scores, labels)?;
+        validate(significance)?;
+
+        Ok(Self {
+            x_scores: x_scores.to_owned(),
+            y_scores: y_scores.to_owned(),
+            labels: labels.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new("AUC_X = AUC_Y", "AUC_X ≠ AUC_Y", Alternative::TwoSided)
+    }
+
+    pub fn solve(&self) -> Result<bool, RocError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<DeLongOutcome, RocError> {
+        let x_placements = Placements::new(&self.x_scores, &self.labels)?;
+        let y_placements = Placements::new(&self.y_scores, &self.labels)?;
+        let aucs = (x_placements.auc(), y_placements.auc());
+        let difference = aucs.0 - aucs.1;
+
+        let variance = x_placements.covariance(&x_placements)
+            + y_placements.covariance(&y_placements)
+            - 2. * x_placements.covariance(&y_placements);
+        let standard_error = variance.max(0.).sqrt();
+        let (z_observed, p_value) = wald(difference, standard_error);
+
+        Ok(DeLongOutcome {
+            aucs,
+            difference,
+            standard_error,
+            z_observed,
+            p_value,
+            hypotheses: self.hypotheses(),
+            accepted: p_value > self.significance,
+        })
+    }
+}
+
+/// DeLong's placement values: the share of the negatives each positive outscores,
+/// and the share of the positives that outscore each negative.
+struct Placements {
+    positives: Vec<f64>,
+    negatives: Vec<f64>,
+}
+
+impl Placements {
+    fn new(scores: &[f64], labels: &[bool]) -> Result<Self, RocError> {
+        let (positives, negatives) = classes(scores, labels)?;
+        let kernel = |x: f64, y: f64| {
+            if x > y {
+                1.
+            } else if x == y {
+                0.5
+            } else {
+                0.
+            }
+        };
+
+        Ok(Self {
+            positives: positives
+                .iter()
+                .map(|x| negatives.iter().map(|y| kernel(*x, *y)).sum::<f64>())
+                .map(|outscored| outscored / negatives.len() as f64)
+                .collect(),
+            negatives: negatives
+                .iter()
+                .map(|y| positives.iter().map(|x| kernel(*x, *y)).sum::<f64>())
+                .map(|outscoring| outscoring / positives.len() as f64)
+                .collect(),
+        })
+    }
+
+    fn auc(&self) -> f64 {
+        self.positives.iter().sum::<f64>() / self.positives.len() as f64
+    }
+
+    /// Covariance of the AUCs of two scores, `S₁₀ / m + S₀₁ / n`.
+    fn covariance(&self, other: &Self) -> f64 {
+        let sample_covariance = |a: &[f64], b: &[f64]| {
+            let (a_mean, b_mean) = (
+                a.iter().sum::<f64>() / a.len() as f64,
+                b.iter().sum::<f64>() / b.len() as f64,
+            );
+            a.iter()
+                .zip(b)
+                .map(|(a, b)| (a - a_mean) * (b - b_mean))
+                .sum::<f64>()
+                / (a.len() as f64 - 1.)
+        };
+
+        sample_covariance(&self.positives, &other.positives) / self.positives.len() as f64
+            + sample_covariance(&self.negatives, &other.negatives) / self.negatives.len() as f64
+    }
+}
+
+/// Wald statistic and its two-sided p-value. A zero standard error leaves no doubt:
+/// a zero difference is certain to hold and any other is certain to fail.
+fn wald(difference: f64, standard_error: f64) -> (f64, f64) {
+    if standard_error == 0. {
+        return if difference == 0. {
+            (0., 1.)
+        } else {
+            (difference.signum() * f64::INFINITY, 0.)
+        };
+    }
+
+    let z_observed = difference / standard_error;
+    (z_observed, 2. * Normal::standard().sf(z_observed.abs()))
+}
+
+/// Scores of the positive and of the negative observations.
+fn classes(scores: &[f64], labels: &[bool]) -> Result<(Vec<f64>, Vec<f64>), RocError> {
+    if scores.len() != labels.len() {
+        return Err(RocError::LengthsMismatch);
+    }
+
+    MissingPolicy::Error
+        .apply(scores)
+        .map_err(|_| RocError::ValueMissing)?;
+
+    let (positives, negatives): (Vec<_>, Vec<_>) =
+        scores.iter().zip(labels).partition(|(_, label)| **label);
+    if positives.len() < 2 || negatives.len() < 2 {
+        return Err(RocError::ClassesMissing);
+    }
+
+    Ok((
+        positives.into_iter().map(|(score, _)| *score).collect(),
+        negatives.into_iter().map(|(score, _)| *score).collect(),
+    ))
+}
+
+fn validate(significance: f64) -> Result<(), RocError> {
+    alpha::validate(significance, RocError::SignificanceInvalid)?;
+
+    Ok(())
+}
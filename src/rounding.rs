@@ -0,0 +1,54 @@
+//! Decimal rounding policy reproducing the by-hand computation.
+//!
+//! Textbooks round intermediate quantities to a few decimals and read critical values from tables
+//! printed with 2–4 decimals, so a decision near the boundary may differ from the exact one.
+//!
+//! The policy is honoured where it is set with `with_rounding`:
+//! - the grouped mean and standard deviation of an incomplete Normal Distribution Hypothesis situation,
+//!   and the theoretical frequencies derived from them, also in [`crate::report::expected_frequencies`];
+//! - the theoretical frequencies, the Chi-Squared terms and statistic, and the critical value of the
+//!   Normal Distribution Hypothesis;
+//! - the variances, the statistic and the critical value of the Same Variance Hypothesis;
+//! - the statistic and the critical value of the Same Mean Hypothesis.
+//!
+//! The report renderers print the quantities of the outcomes, rounded or not, with fixed decimals.
+
+/// Number of decimals to keep; `None` keeps the full `f64` precision.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
+pub struct RoundingPolicy {
+    /// Decimals of the intermediate quantities: frequencies, variances, statistics.
+    pub intermediate_digits: Option<i32>,
+    /// Decimals of the critical values.
+    pub critical_value_digits: Option<i32>,
+}
+
+impl RoundingPolicy {
+    /// No rounding at all.
+    pub const EXACT: Self = Self {
+        intermediate_digits: None,
+        critical_value_digits: None,
+    };
+
+    pub fn new(intermediate_digits: i32, critical_value_digits: i32) -> Self {
+        Self {
+            intermediate_digits: Some(intermediate_digits),
+            critical_value_digits: Some(critical_value_digits),
+        }
+    }
+
+    pub fn intermediate(&self, value: f64) -> f64 {
+        self.intermediate_digits
+            .map_or(value, |digits| round(value, digits))
+    }
+
+    pub fn critical_value(&self, value: f64) -> f64 {
+        self.critical_value_digits
+            .map_or(value, |digits| round(value, digits))
+    }
+}
+
+/// `value` rounded half away from zero to `digits` decimals.
+pub fn round(value: f64, digits: i32) -> f64 {
+    let scale = 10f64.powi(digits);
+    (value * scale).round() / scale
+}
@@ -0,0 +1,354 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *sample of a random variable **X***, *sample of a random variable **Y***,
+//! both variables are normally distributed.
+//! **To figure out**: Is it appropriate to **assume** `E(X) = E(Y)`?
+//!
+//! Student's t-test is used if the variances are assumed equal, Welch's t-test otherwise.
+//! The alternative is `E(X) ≠ E(Y)` by default, either one-sided alternative rejecting in one tail only.
+//! For heavy-tailed samples, Yuen's t-test compares the trimmed means instead.
+//! If the samples are drawn without replacement from finite populations of sizes `N_X` and `N_Y`,
+//! the variance of each mean is reduced by the finite population correction `1 - n / N`.
+
+use statistics_problems_core::decisions::t_statistic;
+
+use crate::alpha;
+use crate::critical_region::CriticalRegion;
+use crate::critical_values::{CriticalValueError, CriticalValueSource};
+use crate::descriptive::{trimmed_mean, winsorized_variance};
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+use crate::numeric::{mean, unbiased_variance};
+use crate::rounding::RoundingPolicy;
+use crate::sampling::finite_population_correction;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum SMHError {
+    SignificanceInvalid,
+    SampleTooSmall,
+    FreedomDegreesInvalid,
+    CriticalValueNotTabulated,
+    TrimmingInvalid,
+    PopulationSizeInvalid,
+    ValueMissing,
+}
+
+impl std::fmt::Display for SMHError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SMHError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            SMHError::SampleTooSmall => {
+                write!(f, "Each sample must contain at least two values")
+            }
+            SMHError::FreedomDegreesInvalid => {
+                write!(
+                    f,
+                    "Freedom Degrees led to fail in initialization of Student's T"
+                )
+            }
+            SMHError::CriticalValueNotTabulated => {
+                write!(
+                    f,
+                    "Standard tables hold no critical value for these freedom degrees and significance"
+                )
+            }
+            SMHError::TrimmingInvalid => {
+                write!(
+                    f,
+                    "Trimming must be between 0.0 and 0.5 and leave at least two values of each sample"
+                )
+            }
+            SMHError::PopulationSizeInvalid => {
+                write!(
+                    f,
+                    "Each population must hold at least its sample, and one of them more"
+                )
+            }
+            SMHError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SMHOutcome {
+    pub t_observed: f64,
+    pub freedom_degrees: f64,
+    /// The upper critical value, or the lower one for a region in the lower tail only.
+    pub t_critical_value: f64,
+    /// Values of the statistic for which the hypothesis of equal means is rejected, `|t| ≥ t_crit` if two-tailed.
+    pub critical_region: CriticalRegion,
+    pub hypotheses: Hypotheses,
+    /// Whether the hypothesis of equal means is accepted.
+    pub accepted: bool,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Variances {
+    /// Student's t-test with the pooled variance.
+    Equal,
+    /// Welch's t-test with the Welch–Satterthwaite freedom degrees.
+    Unequal,
+}
+
+pub struct SameMeanHypothesis {
+    x_sample: Vec<f64>,
+    y_sample: Vec<f64>,
+    variances: Variances,
+    significance: f64,
+    critical_value_source: CriticalValueSource,
+    alternative: Alternative,
+    population_sizes: Option<(f64, f64)>,
+    rounding: RoundingPolicy,
+}
+
+impl SameMeanHypothesis {
+    pub fn new(
+        x_sample: &[f64],
+        y_sample: &[f64],
+        variances: Variances,
+        significance: f64,
+    ) -> Result<Self, SMHError> {
+        MissingPolicy::Error
+            .apply(x_sample)
+            .and(MissingPolicy::Error.apply(y_sample))
+            .map_err(|_| SMHError::ValueMissing)?;
+
+        if x_sample.len() < 2 || y_sample.len() < 2 {
+            return Err(SMHError::SampleTooSmall);
+        }
+
+        alpha::validate(significance, SMHError::SignificanceInvalid)?;
+
+        Ok(Self {
+            x_sample: x_sample.to_owned(),
+            y_sample: y_sample.to_owned(),
+            variances,
+            significance,
+            critical_value_source: CriticalValueSource::Quantile,
+            alternative: Alternative::TwoSided,
+            population_sizes: None,
+            rounding: RoundingPolicy::EXACT,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        let sign = match self.alternative {
+            Alternative::TwoSided => "≠",
+            Alternative::Greater => ">",
+            Alternative::Less => "<",
+        };
+
+        Hypotheses::new("E(X) = E(Y)", format!("E(X) {sign} E(Y)"), self.alternative)
+    }
+
+    /// Returns the `t` statistic and its freedom degrees.
+    pub fn statistic(&self) -> (f64, f64) {
+        let Some((x_size, y_size)) = self.population_sizes else {
+            return t_statistic(
+                &self.x_sample,
+                &self.y_sample,
+                self.variances == Variances::Equal,
+            );
+        };
+
+        let (n_x, n_y) = (self.x_sample.len() as f64, self.y_sample.len() as f64);
+        let (x_fpc, y_fpc) = (
+            finite_population_correction(n_x, x_size),
+            finite_population_correction(n_y, y_size),
+        );
+        let (x_usv, y_usv) = (
+            unbiased_variance(&self.x_sample),
+            unbiased_variance(&self.y_sample),
+        );
+        let mean_difference = mean(&self.x_sample) - mean(&self.y_sample);
+
+        match self.variances {
+            Variances::Equal => {
+                let freedom_degrees = n_x + n_y - 2.;
+                let pooled_variance = ((n_x - 1.) * x_usv + (n_y - 1.) * y_usv) / freedom_degrees;
+                let t = mean_difference / (pooled_variance * (x_fpc / n_x + y_fpc / n_y)).sqrt();
+                (t, freedom_degrees)
+            }
+            Variances::Unequal => {
+                let (x_term, y_term) = (x_fpc * x_usv / n_x, y_fpc * y_usv / n_y);
+                let freedom_degrees = (x_term + y_term).powi(2)
+                    / (x_term.powi(2) / (n_x - 1.) + y_term.powi(2) / (n_y - 1.));
+                let t = mean_difference / (x_term + y_term).sqrt();
+                (t, freedom_degrees)
+            }
+        }
+    }
+
+    /// Sizes of the finite populations the samples are drawn from without replacement;
+    /// `f64::INFINITY` leaves a sample uncorrected.
+    pub fn with_population_sizes(mut self, x_size: f64, y_size: f64) -> Result<Self, SMHError> {
+        let (n_x, n_y) = (self.x_sample.len() as f64, self.y_sample.len() as f64);
+        if !(x_size >= n_x && y_size >= n_y) || (x_size == n_x && y_size == n_y) {
+            return Err(SMHError::PopulationSizeInvalid);
+        }
+
+        self.population_sizes = Some((x_size, y_size));
+        Ok(self)
+    }
+
+    /// Chooses whether the critical value is computed or looked up in the standard tables.
+    pub fn with_critical_value_source(mut self, source: CriticalValueSource) -> Self {
+        self.critical_value_source = source;
+        self
+    }
+
+    /// Tails of **Student's T** in which the hypothesis is rejected, both by default.
+    pub fn with_alternative(mut self, alternative: Alternative) -> Self {
+        self.alternative = alternative;
+        self
+    }
+
+    /// Rounds the statistic and the critical value.
+    pub fn with_rounding(mut self, rounding: RoundingPolicy) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    pub fn solve(&self) -> Result<bool, SMHError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    /// Solves the problem keeping the intermediate quantities.
+    ///
+    /// In the table, Welch's freedom degrees are truncated to an integer.
+    pub fn outcome(&self) -> Result<SMHOutcome, SMHError> {
+        let (t_observed, freedom_degrees) = self.statistic();
+        let t_observed = self.rounding.intermediate(t_observed);
+        let critical_region = students_t_critical_region(
+            freedom_degrees,
+            self.significance,
+            self.alternative,
+            self.critical_value_source,
+        )?
+        .map(|value| self.rounding.critical_value(value));
+        // Every region has a bound.
+        let t_critical_value = critical_region.upper().or(critical_region.lower()).unwrap();
+
+        Ok(SMHOutcome {
+            t_observed,
+            freedom_degrees,
+            t_critical_value,
+            critical_region,
+            hypotheses: self.hypotheses(),
+            accepted: !critical_region.contains(t_observed),
+        })
+    }
+}
+
+/// Yuen's t-test of equal trimmed means, which does not assume equal variances.
+pub struct YuenTTest {
+    x_sample: Vec<f64>,
+    y_sample: Vec<f64>,
+    trimming: f64,
+    significance: f64,
+}
+
+impl YuenTTest {
+    /// `trimming` is the proportion `γ` cut off each end of each sample, `0.2` being the usual choice.
+    pub fn new(
+        x_sample: &[f64],
+        y_sample: &[f64],
+        trimming: f64,
+        significance: f64,
+    ) -> Result<Self, SMHError> {
+        MissingPolicy::Error
+            .apply(x_sample)
+            .and(MissingPolicy::Error.apply(y_sample))
+            .map_err(|_| SMHError::ValueMissing)?;
+
+        if x_sample.len() < 2 || y_sample.len() < 2 {
+            return Err(SMHError::SampleTooSmall);
+        }
+
+        let kept = |n: usize| n - 2 * (trimming * n as f64).floor() as usize;
+        if !(0. ..0.5).contains(&trimming) || kept(x_sample.len()) < 2 || kept(y_sample.len()) < 2 {
+            return Err(SMHError::TrimmingInvalid);
+        }
+
+        alpha::validate(significance, SMHError::SignificanceInvalid)?;
+
+        Ok(Self {
+            x_sample: x_sample.to_owned(),
+            y_sample: y_sample.to_owned(),
+            trimming,
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            "trimmed E(X) = trimmed E(Y)",
+            "trimmed E(X) ≠ trimmed E(Y)",
+            Alternative::TwoSided,
+        )
+    }
+
+    /// Returns the `t` statistic and its freedom degrees.
+    ///
+    /// `t = (x̄_t - ȳ_t) / √(d_x + d_y)` with `d = (n - 1) s²_w / (h (h - 1))`,
+    /// the winsorized variance `s²_w` and `h` values left after the trimming.
+    pub fn statistic(&self) -> (f64, f64) {
+        let kept = |sample: &[f64]| {
+            let n = sample.len() as f64;
+            n - 2. * (self.trimming * n).floor()
+        };
+        let squared_error = |sample: &[f64]| {
+            let h = kept(sample);
+            (sample.len() as f64 - 1.) * winsorized_variance(sample, self.trimming) / (h * (h - 1.))
+        };
+        let (d_x, d_y) = (squared_error(&self.x_sample), squared_error(&self.y_sample));
+
+        let t = (trimmed_mean(&self.x_sample, self.trimming)
+            - trimmed_mean(&self.y_sample, self.trimming))
+            / (d_x + d_y).sqrt();
+        let freedom_degrees = (d_x + d_y).powi(2)
+            / (d_x.powi(2) / (kept(&self.x_sample) - 1.)
+                + d_y.powi(2) / (kept(&self.y_sample) - 1.));
+
+        (t, freedom_degrees)
+    }
+
+    pub fn solve(&self) -> Result<bool, SMHError> {
+        let (t_observed, freedom_degrees) = self.statistic();
+        let critical_region = students_t_critical_region(
+            freedom_degrees,
+            self.significance,
+            Alternative::TwoSided,
+            CriticalValueSource::Quantile,
+        )?;
+
+        Ok(!critical_region.contains(t_observed))
+    }
+}
+
+fn students_t_critical_region(
+    freedom_degrees: f64,
+    significance: f64,
+    alternative: Alternative,
+    source: CriticalValueSource,
+) -> Result<CriticalRegion, SMHError> {
+    alpha::validate(significance, SMHError::SignificanceInvalid)?;
+
+    let freedom_degrees = match source {
+        CriticalValueSource::Quantile => freedom_degrees,
+        CriticalValueSource::Table => freedom_degrees.trunc(),
+    };
+
+    CriticalRegion::students_t(freedom_degrees, significance, alternative, source).map_err(|e| {
+        match e {
+            CriticalValueError::NotTabulated => SMHError::CriticalValueNotTabulated,
+            _ => SMHError::FreedomDegreesInvalid,
+        }
+    })
+}
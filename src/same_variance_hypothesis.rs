@@ -2,14 +2,26 @@
 //!
 //! **Given**: *significance ratio*, *sample of a random variable **X***, *sample of a random variable **Y***.
 //! **To figure out**: Is it appropriate to **assume** `Var(X) = Var(Y)`?
+//!
+//! The generalized variant assumes `Var(X) / Var(Y) = r₀` for a given ratio `r₀`.
+
+use statistics_problems_core::decisions::{self, DecisionError};
 
-use statrs::distribution::{ContinuousCDF, FisherSnedecor};
+use crate::critical_region::CriticalRegion;
+use crate::critical_values::{CriticalValueError, CriticalValueSource, StatrsQuantiles};
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+use crate::numeric::unbiased_variance;
+use crate::rounding::RoundingPolicy;
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
 #[non_exhaustive]
 pub enum SVHError {
     SignificanceInvalid,
     FreedomDegreesInvalid,
+    CriticalValueNotTabulated,
+    RatioInvalid,
+    ValueMissing,
 }
 
 impl std::fmt::Display for SVHError {
@@ -24,14 +36,47 @@ impl std::fmt::Display for SVHError {
                     "Freedom Degrees led to fail in initialization of Fisher-Snedecor"
                 )
             }
+            SVHError::CriticalValueNotTabulated => {
+                write!(
+                    f,
+                    "Standard tables hold no critical value for these freedom degrees and significance"
+                )
+            }
+            SVHError::RatioInvalid => {
+                write!(f, "Ratio of the variances must be positive and finite")
+            }
+            SVHError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
         }
     }
 }
 
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SVHOutcome {
+    /// Unbiased sample variances of **X** and **Y**.
+    pub variances: (f64, f64),
+    /// Freedom degrees of the numerator and of the denominator.
+    pub freedom_degrees: (f64, f64),
+    pub fisher_snedecor_observed: f64,
+    pub fisher_snedecor_critical_value: f64,
+    /// Values of the statistic for which the hypothesis is rejected, the upper tail.
+    pub critical_region: CriticalRegion,
+    /// `H₁` names the sample with the larger variance, since the critical region is the upper tail.
+    pub hypotheses: Hypotheses,
+    /// Whether the hypothesis of equal variances is accepted.
+    pub accepted: bool,
+}
+
 pub struct SameVarianceHypothesis {
     x_sample: Vec<f64>,
     y_sample: Vec<f64>,
     significance: f64,
+    ratio: f64,
+    rounding: RoundingPolicy,
+    critical_value_source: CriticalValueSource,
+    missing_policy: MissingPolicy,
 }
 
 impl SameVarianceHypothesis {
@@ -40,69 +85,146 @@ impl SameVarianceHypothesis {
             x_sample: x_sample.to_owned(),
             y_sample: y_sample.to_owned(),
             significance,
+            ratio: 1.,
+            rounding: RoundingPolicy::EXACT,
+            critical_value_source: CriticalValueSource::Quantile,
+            missing_policy: MissingPolicy::Error,
+        }
+    }
+
+    /// Hypothesized ratio `r₀` of the variance of **X** to the variance of **Y**, `1` by default.
+    pub fn with_ratio(mut self, ratio: f64) -> Result<Self, SVHError> {
+        if ratio <= 0. || !ratio.is_finite() {
+            return Err(SVHError::RatioInvalid);
         }
+
+        self.ratio = ratio;
+        Ok(self)
+    }
+
+    /// Rounds the variances, the statistic and the critical value.
+    pub fn with_rounding(mut self, rounding: RoundingPolicy) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Chooses whether the critical value is computed or looked up in the standard tables.
+    pub fn with_critical_value_source(mut self, source: CriticalValueSource) -> Self {
+        self.critical_value_source = source;
+        self
+    }
+
+    /// Handling of the missing values of the samples, which are rejected by default.
+    pub fn with_missing_policy(mut self, missing_policy: MissingPolicy) -> Self {
+        self.missing_policy = missing_policy;
+        self
     }
 
     pub fn solve(&self) -> Result<bool, SVHError> {
-        let sample_mean =
-            |sample: &[f64]| (1f64 / sample.len() as f64) * sample.iter().sum::<f64>();
-
-        let unbiased_sample_variance = |sample: &[f64]| {
-            let mean = sample_mean(&sample);
-            (1f64 / (sample.len() as f64 - 1f64))
-                * sample
-                    .iter()
-                    .map(|value| (value - mean).abs().powi(2))
-                    .sum::<f64>()
+        Ok(self.outcome()?.accepted)
+    }
+
+    /// Solves the problem keeping the intermediate quantities.
+    pub fn outcome(&self) -> Result<SVHOutcome, SVHError> {
+        let apply = |sample| {
+            self.missing_policy
+                .apply(sample)
+                .map_err(|_| SVHError::ValueMissing)
         };
+        let (x_sample, y_sample) = (apply(&self.x_sample)?, apply(&self.y_sample)?);
+        // The exact test with the computed critical value is decided by the core;
+        // the rounding and the tables are applied on top of it.
+        let decision = decisions::variance_ratio(
+            &x_sample,
+            &y_sample,
+            self.ratio,
+            self.significance,
+            &StatrsQuantiles,
+        )
+        .map_err(|e| match e {
+            DecisionError::SignificanceInvalid => SVHError::SignificanceInvalid,
+            _ => SVHError::FreedomDegreesInvalid,
+        })?;
         let (x_usv, y_usv) = (
-            unbiased_sample_variance(&self.x_sample),
-            unbiased_sample_variance(&self.y_sample),
+            self.rounding.intermediate(unbiased_variance(&x_sample)),
+            self.rounding.intermediate(unbiased_variance(&y_sample)),
         );
 
-        let (max_usv, min_usv) = (x_usv.max(y_usv), x_usv.min(y_usv));
-        let (freedom_degrees_1, freedom_degrees_2) = if max_usv == x_usv {
-            (
-                self.x_sample.len() as f64 - 1f64,
-                self.y_sample.len() as f64 - 1f64,
-            )
+        // Under `H₀`, `σ²(X) / r₀` equals `σ²(Y)`, and the larger of the two is put into the numerator.
+        let x_scaled_usv = x_usv / self.ratio;
+        let x_larger = x_scaled_usv >= y_usv;
+        let hypotheses = match (self.ratio == 1., x_larger) {
+            (true, true) => Hypotheses::new("σ²(X) = σ²(Y)", "σ²(X) > σ²(Y)", Alternative::Greater),
+            (true, false) => {
+                Hypotheses::new("σ²(Y) = σ²(X)", "σ²(Y) > σ²(X)", Alternative::Greater)
+            }
+            (false, true) => Hypotheses::new(
+                format!("σ²(X) / σ²(Y) = {}", self.ratio),
+                format!("σ²(X) / σ²(Y) > {}", self.ratio),
+                Alternative::Greater,
+            ),
+            (false, false) => Hypotheses::new(
+                format!("σ²(X) / σ²(Y) = {}", self.ratio),
+                format!("σ²(X) / σ²(Y) < {}", self.ratio),
+                Alternative::Less,
+            ),
+        };
+        let (freedom_degrees_1, freedom_degrees_2) = if x_larger {
+            (x_sample.len() as f64 - 1f64, y_sample.len() as f64 - 1f64)
         } else {
-            (
-                self.y_sample.len() as f64 - 1f64,
-                self.x_sample.len() as f64 - 1f64,
-            )
+            (y_sample.len() as f64 - 1f64, x_sample.len() as f64 - 1f64)
         };
 
-        let fisher_snedecor_observed = max_usv / min_usv;
-        let fisher_snedecor_critical_value = calculate_fished_snedecor_critical_value(
-            freedom_degrees_1,
-            freedom_degrees_2,
-            self.significance,
-        )?;
-
-        println!("{fisher_snedecor_observed} < {fisher_snedecor_critical_value}");
+        let fisher_snedecor_observed = if self.rounding == RoundingPolicy::EXACT {
+            decision.statistic
+        } else {
+            self.rounding.intermediate(if x_larger {
+                x_scaled_usv / y_usv
+            } else {
+                y_usv / x_scaled_usv
+            })
+        };
+        let critical_region = match self.critical_value_source {
+            CriticalValueSource::Quantile => CriticalRegion::Upper(decision.critical_value),
+            CriticalValueSource::Table => fisher_snedecor_critical_region(
+                freedom_degrees_1,
+                freedom_degrees_2,
+                self.significance,
+                self.critical_value_source,
+            )?,
+        }
+        .map(|value| self.rounding.critical_value(value));
+        // The region is the upper tail.
+        let fisher_snedecor_critical_value = critical_region.upper().unwrap();
 
-        Ok(fisher_snedecor_observed < fisher_snedecor_critical_value)
+        Ok(SVHOutcome {
+            variances: (x_usv, y_usv),
+            freedom_degrees: (freedom_degrees_1, freedom_degrees_2),
+            fisher_snedecor_observed,
+            fisher_snedecor_critical_value,
+            critical_region,
+            hypotheses,
+            accepted: !critical_region.contains(fisher_snedecor_observed),
+        })
     }
 }
 
-fn calculate_fished_snedecor_critical_value(
+/// The larger variance is put into the numerator, so `H₀` is rejected in the upper tail only.
+fn fisher_snedecor_critical_region(
     freedom_degrees_1: f64,
     freedom_degrees_2: f64,
     significance: f64,
-) -> Result<f64, SVHError> {
-    if !(significance > 0.0 && significance < 1.0) {
-        return Err(SVHError::SignificanceInvalid);
-    }
-
-    let fisher_snedecor_dist = FisherSnedecor::new(freedom_degrees_1, freedom_degrees_2)
-        .map_err(|_| SVHError::FreedomDegreesInvalid)?;
-
-    // Critical values corresponds to `(1 - significance)`-quantile.
-    // `inverse_cdf(p)` finds `x` such that `P(X <= x) = p`.
-    // We need `P(X > x_crit) = significance`, which is equivalent `P(X <= x_crit) = 1 - significance`
-    let probability = 1.0 - significance;
-    let critical_value = fisher_snedecor_dist.inverse_cdf(probability);
-
-    Ok(critical_value)
+    source: CriticalValueSource,
+) -> Result<CriticalRegion, SVHError> {
+    CriticalRegion::fisher_snedecor(
+        freedom_degrees_1,
+        freedom_degrees_2,
+        significance,
+        Alternative::Greater,
+        source,
+    )
+    .map_err(|e| match e {
+        CriticalValueError::NotTabulated => SVHError::CriticalValueNotTabulated,
+        _ => SVHError::FreedomDegreesInvalid,
+    })
 }
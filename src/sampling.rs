@@ -0,0 +1,553 @@
+//! Estimation from samples drawn from finite populations.
+//!
+//! **Given**: *significance ratio*, a population divided into *strata* of known sizes `N_h`, and a simple random
+//! sample without replacement of `n_h` values from every stratum.
+//! **To figure out**: What are the population mean, total or proportion, and how precise are they?
+//! How should a sample of size `n` be allocated among the strata? How large must a sample be for
+//! a given margin of error?
+//!
+//! The stratified mean `ȳ_st = Σ W_h ȳ_h` with the weights `W_h = N_h / N` has the variance
+//! `Σ W_h² (1 - f_h) s_h² / n_h`, where `1 - f_h = 1 - n_h / N_h` is the finite population correction;
+//! the total is `N ȳ_st` and a proportion is the mean of `0`/`1` values. Confidence intervals are normal,
+//! as usual for survey samples. Proportional allocation takes `n_h ∝ N_h`; the optimal allocation for
+//! the costs `c_h` per value takes `n_h ∝ N_h S_h / √c_h`, Neyman's allocation for equal costs.
+//!
+//! Single-stage cluster sampling draws `n` of `M` clusters and observes all their units. The total is
+//! `M / n Σ yᵢ` from the cluster totals `yᵢ`, and the mean per unit the ratio `r = Σ yᵢ / Σ mᵢ` to the cluster
+//! sizes, with the variance `(1 - n / M) / (n m̄²) Σ (yᵢ - r mᵢ)² / (n - 1)`. The design effect compares it with
+//! the variance of a simple random sample of as many units, `deff ≈ 1 + (m̄ - 1) ρ` with the intracluster
+//! correlation `ρ`. Systematic samples, every `k`-th unit of an ordered list, have no unbiased variance
+//! estimator; it is approximated as for a simple random sample, or by the successive differences
+//! `Σ (yᵢ - yᵢ₋₁)² / (2 (n - 1))` in place of `s²`, which follow a trend in the list.
+//!
+//! The margin of error `E` is the half-width `z √((1 - n / N) S² / n)` of the confidence interval. It is at most
+//! `E` for `n ≥ n₀ / (1 + n₀ / N)` with `n₀ = (z S / E)²`, the size for an infinite population. For a proportion,
+//! `S² = N / (N - 1) p (1 - p)` gives Cochran's `n₀ / (1 + (n₀ - 1) / N)` with `n₀ = z² p (1 - p) / E²`.
+
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::alpha;
+use crate::numeric::{mean, unbiased_variance};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum SamplingError {
+    SignificanceInvalid,
+    LengthsMismatch,
+    StrataMissing,
+    StratumTooSmall,
+    PopulationSizeInvalid,
+    ProportionInvalid,
+    DeviationInvalid,
+    CostInvalid,
+    SampleTooSmall,
+    ClusterEmpty,
+    MarginInvalid,
+}
+
+impl std::fmt::Display for SamplingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SamplingError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            SamplingError::LengthsMismatch => {
+                write!(f, "Every stratum must have its size and its sample")
+            }
+            SamplingError::StrataMissing => {
+                write!(f, "At least one stratum is required")
+            }
+            SamplingError::StratumTooSmall => {
+                write!(
+                    f,
+                    "Sample of every stratum must contain at least two values"
+                )
+            }
+            SamplingError::PopulationSizeInvalid => {
+                write!(
+                    f,
+                    "Size of every population or stratum must be at least the size of its sample"
+                )
+            }
+            SamplingError::ProportionInvalid => {
+                write!(
+                    f,
+                    "Proportions must be between 0.0 and 1.0 and are estimated from values 0 and 1 only"
+                )
+            }
+            SamplingError::DeviationInvalid => {
+                write!(f, "Standard deviations must be non-negative, not all zero")
+            }
+            SamplingError::CostInvalid => {
+                write!(f, "Costs must be positive")
+            }
+            SamplingError::SampleTooSmall => {
+                write!(f, "Sample must contain at least two values or clusters")
+            }
+            SamplingError::ClusterEmpty => {
+                write!(f, "Every sampled cluster must contain at least one unit")
+            }
+            SamplingError::MarginInvalid => {
+                write!(f, "Margin of error must be positive")
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SamplingEstimate {
+    pub estimate: f64,
+    pub standard_error: f64,
+    /// Normal confidence interval at the `1 - significance` level.
+    pub confidence_interval: (f64, f64),
+}
+
+/// Stratified simple random sample without replacement.
+pub struct StratifiedSample {
+    population_sizes: Vec<f64>,
+    samples: Vec<Vec<f64>>,
+}
+
+impl StratifiedSample {
+    /// `samples[h]` is drawn from the `h`-th stratum of `population_sizes[h]` units.
+    pub fn new(population_sizes: &[f64], samples: &[Vec<f64>]) -> Result<Self, SamplingError> {
+        if population_sizes.len() != samples.len() {
+            return Err(SamplingError::LengthsMismatch);
+        }
+
+        if samples.is_empty() {
+            return Err(SamplingError::StrataMissing);
+        }
+
+        if samples.iter().any(|sample| sample.len() < 2) {
+            return Err(SamplingError::StratumTooSmall);
+        }
+
+        if population_sizes
+            .iter()
+            .zip(samples)
+            .any(|(size, sample)| !size.is_finite() || *size < sample.len() as f64)
+        {
+            return Err(SamplingError::PopulationSizeInvalid);
+        }
+
+        Ok(Self {
+            population_sizes: population_sizes.to_owned(),
+            samples: samples.to_owned(),
+        })
+    }
+
+    /// `N = Σ N_h`.
+    pub fn population_size(&self) -> f64 {
+        self.population_sizes.iter().sum()
+    }
+
+    /// `ȳ_st = Σ W_h ȳ_h`.
+    pub fn mean(&self, significance: f64) -> Result<SamplingEstimate, SamplingError> {
+        let population_size = self.population_size();
+        let (mut estimate, mut variance) = (0., 0.);
+        for (size, sample) in self.population_sizes.iter().zip(&self.samples) {
+            let weight = size / population_size;
+            estimate += weight * mean(sample);
+            variance += weight.powi(2)
+                * finite_population_correction(sample.len() as f64, *size)
+                * unbiased_variance(sample)
+                / sample.len() as f64;
+        }
+
+        interval(estimate, variance.sqrt(), significance)
+    }
+
+    /// `N ȳ_st`.
+    pub fn total(&self, significance: f64) -> Result<SamplingEstimate, SamplingError> {
+        let population_size = self.population_size();
+        let mean = self.mean(significance)?;
+        interval(
+            population_size * mean.estimate,
+            population_size * mean.standard_error,
+            significance,
+        )
+    }
+
+    /// Proportion of the units with the attribute, from samples of `1` for the units with it and `0` otherwise.
+    pub fn proportion(&self, significance: f64) -> Result<SamplingEstimate, SamplingError> {
+        if self.samples.iter().flatten().any(|x| *x != 0. && *x != 1.) {
+            return Err(SamplingError::ProportionInvalid);
+        }
+
+        self.mean(significance)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DesignEffect {
+    /// Ratio of the variance of the cluster sample mean to that of a simple random sample of as many units.
+    pub design_effect: f64,
+    /// `ρ = (deff - 1) / (m̄ - 1)`, `NaN` for clusters of single units.
+    pub intracluster_correlation: f64,
+    /// `Σ mᵢ / deff`, the size of the simple random sample as precise as the cluster sample.
+    pub effective_sample_size: f64,
+}
+
+/// Single-stage cluster sample: clusters drawn without replacement, all their units observed.
+pub struct ClusterSample {
+    clusters_count: f64,
+    clusters: Vec<Vec<f64>>,
+}
+
+impl ClusterSample {
+    /// `clusters` are the values of all the units of the clusters drawn out of `clusters_count`.
+    pub fn new(clusters_count: f64, clusters: &[Vec<f64>]) -> Result<Self, SamplingError> {
+        if clusters.len() < 2 {
+            return Err(SamplingError::SampleTooSmall);
+        }
+
+        if clusters.iter().any(Vec::is_empty) {
+            return Err(SamplingError::ClusterEmpty);
+        }
+
+        if !clusters_count.is_finite() || clusters_count < clusters.len() as f64 {
+            return Err(SamplingError::PopulationSizeInvalid);
+        }
+
+        Ok(Self {
+            clusters_count,
+            clusters: clusters.to_owned(),
+        })
+    }
+
+    /// Ratio estimator `Σ yᵢ / Σ mᵢ` of the mean per unit.
+    pub fn mean(&self, significance: f64) -> Result<SamplingEstimate, SamplingError> {
+        interval(self.ratio(), self.ratio_variance().sqrt(), significance)
+    }
+
+    /// `M / n Σ yᵢ`.
+    pub fn total(&self, significance: f64) -> Result<SamplingEstimate, SamplingError> {
+        let n = self.clusters.len() as f64;
+        let totals: Vec<_> = self
+            .clusters
+            .iter()
+            .map(|cluster| cluster.iter().sum())
+            .collect();
+        let variance = self.clusters_count.powi(2)
+            * finite_population_correction(n, self.clusters_count)
+            * unbiased_variance(&totals)
+            / n;
+
+        interval(
+            self.clusters_count * mean(&totals),
+            variance.sqrt(),
+            significance,
+        )
+    }
+
+    /// Proportion of the units with the attribute, from values of `1` for the units with it and `0` otherwise.
+    pub fn proportion(&self, significance: f64) -> Result<SamplingEstimate, SamplingError> {
+        if self.clusters.iter().flatten().any(|x| *x != 0. && *x != 1.) {
+            return Err(SamplingError::ProportionInvalid);
+        }
+
+        self.mean(significance)
+    }
+
+    /// Design effect against a simple random sample of the same units, drawn with the same sampling fraction.
+    pub fn design_effect(&self) -> DesignEffect {
+        let units: Vec<_> = self.clusters.iter().flatten().copied().collect();
+        let units_count = units.len() as f64;
+        let simple_variance =
+            finite_population_correction(self.clusters.len() as f64, self.clusters_count)
+                * unbiased_variance(&units)
+                / units_count;
+        let design_effect = self.ratio_variance() / simple_variance;
+        let mean_size = units_count / self.clusters.len() as f64;
+
+        DesignEffect {
+            design_effect,
+            intracluster_correlation: if mean_size > 1. {
+                (design_effect - 1.) / (mean_size - 1.)
+            } else {
+                f64::NAN
+            },
+            effective_sample_size: units_count / design_effect,
+        }
+    }
+
+    fn ratio(&self) -> f64 {
+        let units_count = self.clusters.iter().map(Vec::len).sum::<usize>() as f64;
+        self.clusters.iter().flatten().sum::<f64>() / units_count
+    }
+
+    /// `(1 - n / M) / (n m̄²) Σ (yᵢ - r mᵢ)² / (n - 1)`.
+    fn ratio_variance(&self) -> f64 {
+        let n = self.clusters.len() as f64;
+        let ratio = self.ratio();
+        let mean_size = self.clusters.iter().map(Vec::len).sum::<usize>() as f64 / n;
+        let residuals_sum = self
+            .clusters
+            .iter()
+            .map(|cluster| (cluster.iter().sum::<f64>() - ratio * cluster.len() as f64).powi(2))
+            .sum::<f64>();
+
+        finite_population_correction(n, self.clusters_count) / (n * mean_size.powi(2))
+            * residuals_sum
+            / (n - 1.)
+    }
+}
+
+/// Approximation of the variance of a systematic sample.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum SystematicVariance {
+    /// As for a simple random sample, fine for a list in random order.
+    SimpleRandom,
+    /// From the successive differences, for a list with a trend.
+    SuccessiveDifferences,
+}
+
+/// Systematic sample: every `k`-th unit of an ordered list, from a random start.
+pub struct SystematicSample {
+    population_size: f64,
+    sample: Vec<f64>,
+}
+
+impl SystematicSample {
+    /// `sample` holds the values in the order of the list.
+    pub fn new(population_size: f64, sample: &[f64]) -> Result<Self, SamplingError> {
+        if sample.len() < 2 {
+            return Err(SamplingError::SampleTooSmall);
+        }
+
+        if !population_size.is_finite() || population_size < sample.len() as f64 {
+            return Err(SamplingError::PopulationSizeInvalid);
+        }
+
+        Ok(Self {
+            population_size,
+            sample: sample.to_owned(),
+        })
+    }
+
+    /// `k = N / n`.
+    pub fn interval(&self) -> f64 {
+        self.population_size / self.sample.len() as f64
+    }
+
+    pub fn mean(
+        &self,
+        approximation: SystematicVariance,
+        significance: f64,
+    ) -> Result<SamplingEstimate, SamplingError> {
+        let n = self.sample.len() as f64;
+        let variance = match approximation {
+            SystematicVariance::SimpleRandom => unbiased_variance(&self.sample),
+            SystematicVariance::SuccessiveDifferences => {
+                self.sample
+                    .windows(2)
+                    .map(|pair| (pair[1] - pair[0]).powi(2))
+                    .sum::<f64>()
+                    / (2. * (n - 1.))
+            }
+        };
+
+        interval(
+            mean(&self.sample),
+            (finite_population_correction(n, self.population_size) * variance / n).sqrt(),
+            significance,
+        )
+    }
+
+    /// `N ȳ`.
+    pub fn total(
+        &self,
+        approximation: SystematicVariance,
+        significance: f64,
+    ) -> Result<SamplingEstimate, SamplingError> {
+        let mean = self.mean(approximation, significance)?;
+        interval(
+            self.population_size * mean.estimate,
+            self.population_size * mean.standard_error,
+            significance,
+        )
+    }
+}
+
+/// `1 - n / N`, the factor of the variance of the mean of a sample of `n` drawn without replacement
+/// from `N` units; 1 for an infinite population.
+pub fn finite_population_correction(sample_size: f64, population_size: f64) -> f64 {
+    if population_size.is_infinite() {
+        1.
+    } else {
+        1. - sample_size / population_size
+    }
+}
+
+/// Smallest sample size for which the confidence interval of the mean is within `± margin`,
+/// given the standard deviation `S` of the population of `population_size` units, if finite.
+pub fn mean_sample_size(
+    margin: f64,
+    std_dev: f64,
+    population_size: Option<f64>,
+    significance: f64,
+) -> Result<usize, SamplingError> {
+    if !(std_dev >= 0. && std_dev.is_finite()) {
+        return Err(SamplingError::DeviationInvalid);
+    }
+
+    let n_0 = initial_sample_size(margin, std_dev * std_dev, population_size, significance)?;
+    let n = match population_size {
+        Some(size) => n_0 / (1. + n_0 / size),
+        None => n_0,
+    };
+
+    Ok(round_up_sample_size(n))
+}
+
+/// Smallest sample size for which the confidence interval of the proportion is within `± margin`,
+/// given the anticipated proportion `p`, `0.5` being the conservative choice when nothing is known,
+/// and the `population_size`, if finite.
+pub fn proportion_sample_size(
+    margin: f64,
+    proportion: f64,
+    population_size: Option<f64>,
+    significance: f64,
+) -> Result<usize, SamplingError> {
+    if !(0. ..=1.).contains(&proportion) {
+        return Err(SamplingError::ProportionInvalid);
+    }
+
+    let n_0 = initial_sample_size(
+        margin,
+        proportion * (1. - proportion),
+        population_size,
+        significance,
+    )?;
+    let n = match population_size {
+        Some(size) => n_0 * size / (size + n_0 - 1.),
+        None => n_0,
+    };
+
+    Ok(round_up_sample_size(n))
+}
+
+/// `n₀ = z² S² / E²`, the sample size for an infinite population.
+fn initial_sample_size(
+    margin: f64,
+    variance: f64,
+    population_size: Option<f64>,
+    significance: f64,
+) -> Result<f64, SamplingError> {
+    if margin.is_nan() || margin <= 0. {
+        return Err(SamplingError::MarginInvalid);
+    }
+
+    if population_size.is_some_and(|size| !(size >= 1. && size.is_finite())) {
+        return Err(SamplingError::PopulationSizeInvalid);
+    }
+
+    alpha::validate(significance, SamplingError::SignificanceInvalid)?;
+
+    let z = Normal::standard().inverse_cdf(1. - significance / 2.);
+
+    Ok(z * z * variance / (margin * margin))
+}
+
+/// At least one value; the tolerance keeps round-off from adding one to an exact size.
+fn round_up_sample_size(n: f64) -> usize {
+    ((n * (1. - 1e-12)).ceil() as usize).max(1)
+}
+
+/// `n_h ∝ N_h`, rounded to integers summing to `n`.
+pub fn proportional_allocation(
+    population_sizes: &[f64],
+    sample_size: usize,
+) -> Result<Vec<usize>, SamplingError> {
+    optimal_allocation(
+        population_sizes,
+        &vec![1.; population_sizes.len()],
+        &vec![1.; population_sizes.len()],
+        sample_size,
+    )
+}
+
+/// Neyman's allocation `n_h ∝ N_h S_h`, minimizing the variance of the stratified mean for the sample size `n`.
+pub fn neyman_allocation(
+    population_sizes: &[f64],
+    std_devs: &[f64],
+    sample_size: usize,
+) -> Result<Vec<usize>, SamplingError> {
+    optimal_allocation(
+        population_sizes,
+        std_devs,
+        &vec![1.; population_sizes.len()],
+        sample_size,
+    )
+}
+
+/// Allocation `n_h ∝ N_h S_h / √c_h`, minimizing the variance of the stratified mean for the cost of `n` values,
+/// rounded to integers summing to `n` by the largest remainders.
+pub fn optimal_allocation(
+    population_sizes: &[f64],
+    std_devs: &[f64],
+    costs: &[f64],
+    sample_size: usize,
+) -> Result<Vec<usize>, SamplingError> {
+    if population_sizes.len() != std_devs.len() || population_sizes.len() != costs.len() {
+        return Err(SamplingError::LengthsMismatch);
+    }
+
+    if population_sizes.is_empty() {
+        return Err(SamplingError::StrataMissing);
+    }
+
+    if !population_sizes.iter().all(|size| *size > 0.) {
+        return Err(SamplingError::PopulationSizeInvalid);
+    }
+
+    if !std_devs.iter().all(|s| *s >= 0.) || std_devs.iter().all(|s| *s == 0.) {
+        return Err(SamplingError::DeviationInvalid);
+    }
+
+    if !costs.iter().all(|c| *c > 0.) {
+        return Err(SamplingError::CostInvalid);
+    }
+
+    let shares: Vec<_> = population_sizes
+        .iter()
+        .zip(std_devs)
+        .zip(costs)
+        .map(|((size, s), c)| size * s / c.sqrt())
+        .collect();
+    let shares_sum = shares.iter().sum::<f64>();
+    let quotas: Vec<_> = shares
+        .iter()
+        .map(|share| sample_size as f64 * share / shares_sum)
+        .collect();
+
+    let mut allocation: Vec<_> = quotas.iter().map(|quota| quota.floor() as usize).collect();
+    let mut order: Vec<_> = (0..quotas.len()).collect();
+    order.sort_by(|&i, &j| quotas[j].fract().total_cmp(&quotas[i].fract()));
+    let remainder = sample_size - allocation.iter().sum::<usize>();
+    for &h in order.iter().take(remainder) {
+        allocation[h] += 1;
+    }
+
+    Ok(allocation)
+}
+
+fn interval(
+    estimate: f64,
+    standard_error: f64,
+    significance: f64,
+) -> Result<SamplingEstimate, SamplingError> {
+    alpha::validate(significance, SamplingError::SignificanceInvalid)?;
+
+    let z = Normal::standard().inverse_cdf(1. - significance / 2.);
+
+    Ok(SamplingEstimate {
+        estimate,
+        standard_error,
+        confidence_interval: (estimate - z * standard_error, estimate + z * standard_error),
+    })
+}
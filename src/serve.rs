@@ -0,0 +1,145 @@
+//! HTTP service that accepts JSON problem definitions and returns JSON outcomes.
+//!
+//! | Endpoint             | Body                                                        |
+//! |----------------------|-------------------------------------------------------------|
+//! | `POST /ndh/complete` | `{"empirical": [..], "theoretical": [..], "alpha": 0.05}`   |
+//! | `POST /ndh/grouped`  | `{"ranges": [[22, 24], [24, 26], ..], "frequencies": [..], "alpha": 0.01}` |
+//! | `POST /ndh/raw`      | `{"sample": [..], "alpha": 0.05}`                           |
+//! | `POST /svh`          | `{"x": [..], "y": [..], "alpha": 0.05}`                     |
+//! | `POST /independence` | `{"table": [[..], [..]], "alpha": 0.05}`                    |
+//!
+//! A problem the solver rejects is answered with `422 Unprocessable Entity` and `{"error": "<message>"}`.
+
+use std::net::SocketAddr;
+
+use axum::Router;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, serve as serve_router};
+use serde::{Deserialize, Serialize};
+
+use crate::contingency::{IndependenceHypothesis, IndependenceOutcome};
+use crate::normal_distribution_hypothesis::{
+    CompleteNDHProblemSituation, IncompleteNDHProblemSituation, NDHOutcome, NDHProblemSituation,
+    NormalDistributionHypothesis,
+};
+use crate::same_variance_hypothesis::{SVHOutcome, SameVarianceHypothesis};
+
+#[derive(Deserialize)]
+struct CompleteNDHProblem {
+    empirical: Vec<f64>,
+    theoretical: Vec<f64>,
+    alpha: f64,
+}
+
+#[derive(Deserialize)]
+struct GroupedNDHProblem {
+    ranges: Vec<(f64, f64)>,
+    frequencies: Vec<f64>,
+    alpha: f64,
+}
+
+#[derive(Deserialize)]
+struct RawNDHProblem {
+    sample: Vec<f64>,
+    alpha: f64,
+}
+
+#[derive(Deserialize)]
+struct SVHProblem {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    alpha: f64,
+}
+
+#[derive(Deserialize)]
+struct IndependenceProblem {
+    table: Vec<Vec<f64>>,
+    alpha: f64,
+}
+
+/// Error of the solver, answered with `422 Unprocessable Entity`.
+struct Rejection(String);
+
+#[derive(Serialize)]
+struct RejectionBody {
+    error: String,
+}
+
+impl IntoResponse for Rejection {
+    fn into_response(self) -> Response {
+        let body = Json(RejectionBody { error: self.0 });
+        (StatusCode::UNPROCESSABLE_ENTITY, body).into_response()
+    }
+}
+
+fn reject(e: impl std::fmt::Display) -> Rejection {
+    Rejection(e.to_string())
+}
+
+fn solve_ndh(situation: Box<dyn NDHProblemSituation>) -> Result<Json<NDHOutcome>, Rejection> {
+    NormalDistributionHypothesis::new(situation)
+        .and_then(|ndh| ndh.outcome())
+        .map(Json)
+        .map_err(reject)
+}
+
+async fn ndh_complete(
+    Json(problem): Json<CompleteNDHProblem>,
+) -> Result<Json<NDHOutcome>, Rejection> {
+    let situation =
+        CompleteNDHProblemSituation::new(&problem.empirical, &problem.theoretical, problem.alpha)
+            .map_err(reject)?;
+
+    solve_ndh(Box::new(situation))
+}
+
+async fn ndh_grouped(
+    Json(problem): Json<GroupedNDHProblem>,
+) -> Result<Json<NDHOutcome>, Rejection> {
+    let situation =
+        IncompleteNDHProblemSituation::new(&problem.ranges, &problem.frequencies, problem.alpha)
+            .map_err(reject)?;
+
+    solve_ndh(Box::new(situation))
+}
+
+async fn ndh_raw(Json(problem): Json<RawNDHProblem>) -> Result<Json<NDHOutcome>, Rejection> {
+    let situation = IncompleteNDHProblemSituation::from_raw_sample(&problem.sample, problem.alpha)
+        .map_err(reject)?;
+
+    solve_ndh(Box::new(situation))
+}
+
+async fn svh(Json(problem): Json<SVHProblem>) -> Result<Json<SVHOutcome>, Rejection> {
+    SameVarianceHypothesis::new(&problem.x, &problem.y, problem.alpha)
+        .outcome()
+        .map(Json)
+        .map_err(reject)
+}
+
+async fn independence(
+    Json(problem): Json<IndependenceProblem>,
+) -> Result<Json<IndependenceOutcome>, Rejection> {
+    IndependenceHypothesis::new(&problem.table, problem.alpha)
+        .and_then(|independence| independence.outcome())
+        .map(Json)
+        .map_err(reject)
+}
+
+/// Routes of the service, to be served as is or nested into another application.
+pub fn router() -> Router {
+    Router::new()
+        .route("/ndh/complete", post(ndh_complete))
+        .route("/ndh/grouped", post(ndh_grouped))
+        .route("/ndh/raw", post(ndh_raw))
+        .route("/svh", post(svh))
+        .route("/independence", post(independence))
+}
+
+/// Serves [`router`] until the process is stopped.
+pub async fn serve(address: SocketAddr) -> std::io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(address).await?;
+    serve_router(listener, router()).await
+}
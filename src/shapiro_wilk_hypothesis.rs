@@ -0,0 +1,183 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *raw sample of a random variable **X***.
+//! **To figure out**: Is it appropriate to **assume** that the sample is a sample of a **Normal Distribution**?
+//!
+//! The `W` statistic and its p-value are computed with Royston's approximation (AS R94), valid for `3 <= n <= 5000`.
+
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::alpha;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+use crate::order_statistics::{NormalScores, normal_scores};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum SWError {
+    SignificanceInvalid,
+    SampleSizeInvalid,
+    SampleDegenerate,
+    ValueMissing,
+}
+
+impl std::fmt::Display for SWError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SWError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            SWError::SampleSizeInvalid => {
+                write!(f, "Sample size must be between 3 and 5000")
+            }
+            SWError::SampleDegenerate => {
+                write!(f, "Sample must contain at least two distinct values")
+            }
+            SWError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+pub struct ShapiroWilkHypothesis {
+    sample: Vec<f64>,
+    significance: f64,
+}
+
+impl ShapiroWilkHypothesis {
+    pub fn new(sample: &[f64], significance: f64) -> Result<Self, SWError> {
+        MissingPolicy::Error
+            .apply(sample)
+            .map_err(|_| SWError::ValueMissing)?;
+
+        if !(3..=5000).contains(&sample.len()) {
+            return Err(SWError::SampleSizeInvalid);
+        }
+
+        alpha::validate(significance, SWError::SignificanceInvalid)?;
+
+        let mut sample = sample.to_owned();
+        sample.sort_by(f64::total_cmp);
+        if sample[0] == sample[sample.len() - 1] {
+            return Err(SWError::SampleDegenerate);
+        }
+
+        Ok(Self {
+            sample,
+            significance,
+        })
+    }
+
+    /// Small values of `W` speak against normality.
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            "the sample follows a Normal distribution",
+            "the sample does not follow a Normal distribution",
+            Alternative::Less,
+        )
+    }
+
+    /// Returns the `W` statistic and its p-value.
+    pub fn statistic(&self) -> (f64, f64) {
+        let n = self.sample.len();
+        let coefficients = shapiro_wilk_coefficients(n);
+
+        let mean = self.sample.iter().sum::<f64>() / n as f64;
+        let numerator = coefficients
+            .iter()
+            .zip(self.sample.iter())
+            .map(|(a, x)| a * x)
+            .sum::<f64>()
+            .powi(2);
+        let denominator = self.sample.iter().map(|x| (x - mean).powi(2)).sum::<f64>();
+        let w = (numerator / denominator).min(1.);
+
+        (w, shapiro_wilk_p_value(w, n))
+    }
+
+    pub fn solve(&self) -> Result<bool, SWError> {
+        let (_, p_value) = self.statistic();
+
+        Ok(p_value > self.significance)
+    }
+}
+
+fn polynomial(coefficients: &[f64], x: f64) -> f64 {
+    coefficients.iter().rev().fold(0., |acc, c| acc * x + c)
+}
+
+/// Coefficients `a_i` for the ordered sample, antisymmetric around the middle.
+fn shapiro_wilk_coefficients(n: usize) -> Vec<f64> {
+    let mut a = vec![0.; n];
+    if n == 3 {
+        a[0] = -std::f64::consts::FRAC_1_SQRT_2;
+        a[2] = std::f64::consts::FRAC_1_SQRT_2;
+        return a;
+    }
+
+    let m = normal_scores(n, NormalScores::Blom);
+    let m_squared_sum = m.iter().map(|m_i| m_i.powi(2)).sum::<f64>();
+    let u = 1. / (n as f64).sqrt();
+
+    let a_n = m[n - 1] / m_squared_sum.sqrt()
+        + polynomial(
+            &[0., 0.221157, -0.147981, -2.071190, 4.434685, -2.706056],
+            u,
+        );
+    let a_n_1 = m[n - 2] / m_squared_sum.sqrt()
+        + polynomial(
+            &[0., 0.042981, -0.293762, -1.752461, 5.682633, -3.582633],
+            u,
+        );
+
+    let tails = if n > 5 { 2 } else { 1 };
+    let phi = if tails == 2 {
+        (m_squared_sum - 2. * m[n - 1].powi(2) - 2. * m[n - 2].powi(2))
+            / (1. - 2. * a_n.powi(2) - 2. * a_n_1.powi(2))
+    } else {
+        (m_squared_sum - 2. * m[n - 1].powi(2)) / (1. - 2. * a_n.powi(2))
+    };
+
+    for i in tails..n - tails {
+        a[i] = m[i] / phi.sqrt();
+    }
+    a[n - 1] = a_n;
+    a[0] = -a_n;
+    if tails == 2 {
+        a[n - 2] = a_n_1;
+        a[1] = -a_n_1;
+    }
+
+    a
+}
+
+fn shapiro_wilk_p_value(w: f64, n: usize) -> f64 {
+    if n == 3 {
+        let p = 6. / std::f64::consts::PI * (w.sqrt().asin() - 0.75f64.sqrt().asin());
+        return p.clamp(0., 1.);
+    }
+
+    let y = (1. - w).ln();
+    let (z, mean, std_dev) = if n <= 11 {
+        let n = n as f64;
+        let gamma = polynomial(&[-2.273, 0.459], n);
+        if y >= gamma {
+            return 0.;
+        }
+        (
+            -(gamma - y).ln(),
+            polynomial(&[0.544, -0.39978, 0.025054, -6.714e-4], n),
+            polynomial(&[1.3822, -0.77857, 0.062767, -0.0020322], n).exp(),
+        )
+    } else {
+        let ln_n = (n as f64).ln();
+        (
+            y,
+            polynomial(&[-1.5861, -0.31082, -0.083751, 0.0038915], ln_n),
+            polynomial(&[-0.4803, -0.082676, 0.0030302], ln_n).exp(),
+        )
+    };
+
+    1. - Normal::new(mean, std_dev).unwrap().cdf(z)
+}
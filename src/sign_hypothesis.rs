@@ -0,0 +1,290 @@
+//! Algorithms for solving problems of the following kinds.
+//!
+//! -  **Given**: *significance ratio*, *sample of a random variable **X***, *hypothetical median* `m₀`.
+//!    **To figure out**: Is it appropriate to **assume** that the median of **X** is `m₀`?
+//!
+//! -  **Given**: *significance ratio*, *sample of a random variable **X***, *order* `p`, *hypothetical quantile* `q₀`.
+//!    **To figure out**: Is it appropriate to **assume** that the `p`-quantile of **X** is `q₀`,
+//!    e.g. that 90% of the values lie below `q₀`?
+//!
+//! The sign test counts the values above `m₀` among those differing from it, which is `B(n, 1/2)`
+//! whatever the distribution of **X**; the p-value is exact. The quantile test generalizes it: the number
+//! of the values below `q₀` is `B(n, p)`. The distribution-free confidence interval of the quantile runs
+//! between the order statistics `x₍ₗ₎` and `x₍ᵤ₎`, with the largest `l` such that `P(B(n, p) < l) ≤ significance / 2`
+//! and the smallest `u` such that `P(B(n, p) ≥ u) ≤ significance / 2`; its exact coverage is at least `1 - significance`.
+
+use statrs::distribution::{Binomial, DiscreteCDF};
+
+use crate::alpha;
+use crate::descriptive::quantile;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+use crate::order_statistics::order_statistics;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum SignError {
+    SignificanceInvalid,
+    SampleEmpty,
+    SampleDegenerate,
+    OrderInvalid,
+    ValueMissing,
+}
+
+impl std::fmt::Display for SignError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SignError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            SignError::SampleEmpty => {
+                write!(f, "Sample must not be empty")
+            }
+            SignError::SampleDegenerate => {
+                write!(
+                    f,
+                    "Sample must contain values other than the hypothetical median or quantile"
+                )
+            }
+            SignError::OrderInvalid => {
+                write!(f, "Order of the quantile must be between 0.0 and 1.0")
+            }
+            SignError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+/// Sample quantile with its distribution-free confidence interval.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct QuantileInterval {
+    /// `p`.
+    pub order: f64,
+    pub estimate: f64,
+    /// `(x₍ₗ₎, x₍ᵤ₎)`, with an infinite bound where the sample is too small for the level.
+    pub confidence_interval: (f64, f64),
+    /// Exact coverage `1 - P(B(n, p) < l) - P(B(n, p) ≥ u)` of the interval.
+    pub confidence: f64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SignOutcome {
+    /// Number of the values above `m₀`.
+    pub positives: usize,
+    /// Number of the values below `m₀`.
+    pub negatives: usize,
+    /// Number of the values equal to `m₀`, left out of the test.
+    pub ties: usize,
+    pub p_value: f64,
+    pub median: QuantileInterval,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct QuantileOutcome {
+    /// Number of the values below `q₀`.
+    pub below: usize,
+    /// Number of the values above `q₀`.
+    pub above: usize,
+    /// Number of the values equal to `q₀`, left out of the test.
+    pub ties: usize,
+    /// Expected number of the values below `q₀`, `n p`.
+    pub below_expected: f64,
+    pub p_value: f64,
+    pub quantile: QuantileInterval,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+pub struct SignHypothesis {
+    sample: Vec<f64>,
+    median: f64,
+    significance: f64,
+}
+
+impl SignHypothesis {
+    pub fn new(sample: &[f64], median: f64, significance: f64) -> Result<Self, SignError> {
+        validate(sample, significance)?;
+
+        Ok(Self {
+            sample: sample.to_owned(),
+            median,
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            format!("Me(X) = {}", self.median),
+            format!("Me(X) ≠ {}", self.median),
+            Alternative::TwoSided,
+        )
+    }
+
+    pub fn solve(&self) -> Result<bool, SignError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<SignOutcome, SignError> {
+        let (negatives, positives) = signs(&self.sample, self.median)?;
+        let p_value = binomial_p_value(negatives, negatives + positives, 0.5);
+
+        Ok(SignOutcome {
+            positives,
+            negatives,
+            ties: self.sample.len() - negatives - positives,
+            p_value,
+            median: median_interval(&self.sample, self.significance),
+            hypotheses: self.hypotheses(),
+            accepted: p_value > self.significance,
+        })
+    }
+}
+
+pub struct QuantileHypothesis {
+    sample: Vec<f64>,
+    order: f64,
+    quantile: f64,
+    significance: f64,
+}
+
+impl QuantileHypothesis {
+    /// `H₀: P(X < quantile) = order`.
+    pub fn new(
+        sample: &[f64],
+        order: f64,
+        quantile: f64,
+        significance: f64,
+    ) -> Result<Self, SignError> {
+        validate(sample, significance)?;
+
+        if !(order > 0. && order < 1.) {
+            return Err(SignError::OrderInvalid);
+        }
+
+        Ok(Self {
+            sample: sample.to_owned(),
+            order,
+            quantile,
+            significance,
+        })
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        Hypotheses::new(
+            format!("x_{} = {}", self.order, self.quantile),
+            format!("x_{} ≠ {}", self.order, self.quantile),
+            Alternative::TwoSided,
+        )
+    }
+
+    pub fn solve(&self) -> Result<bool, SignError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<QuantileOutcome, SignError> {
+        let (below, above) = signs(&self.sample, self.quantile)?;
+        let n = below + above;
+        let p_value = binomial_p_value(below, n, self.order);
+
+        Ok(QuantileOutcome {
+            below,
+            above,
+            ties: self.sample.len() - n,
+            below_expected: n as f64 * self.order,
+            p_value,
+            quantile: quantile_interval(&self.sample, self.order, self.significance),
+            hypotheses: self.hypotheses(),
+            accepted: p_value > self.significance,
+        })
+    }
+}
+
+/// Distribution-free confidence interval of the median at the `1 - significance` level or above.
+pub fn median_interval(sample: &[f64], significance: f64) -> QuantileInterval {
+    quantile_interval(sample, 0.5, significance)
+}
+
+/// Distribution-free confidence interval of the `order`-quantile at the `1 - significance` level or above.
+///
+/// Returns `NaN`s for `order` outside `[0, 1]`.
+pub fn quantile_interval(sample: &[f64], order: f64, significance: f64) -> QuantileInterval {
+    let sorted = order_statistics(sample);
+    let n = sorted.len();
+    let Ok(binomial) = Binomial::new(order, n as u64) else {
+        return QuantileInterval {
+            order,
+            estimate: f64::NAN,
+            confidence_interval: (f64::NAN, f64::NAN),
+            confidence: f64::NAN,
+        };
+    };
+
+    // `P(B < l) = P(B ≤ l - 1)` grows with `l` and `P(B ≥ u) = P(B > u - 1)` falls with `u`.
+    let lower = (1..=n)
+        .take_while(|l| binomial.cdf(*l as u64 - 1) <= significance / 2.)
+        .last();
+    let upper = (1..=n)
+        .rev()
+        .take_while(|u| binomial.sf(*u as u64 - 1) <= significance / 2.)
+        .last();
+
+    let (lower_bound, lower_tail) = match lower {
+        Some(l) => (sorted[l - 1], binomial.cdf(l as u64 - 1)),
+        None => (f64::NEG_INFINITY, 0.),
+    };
+    let (upper_bound, upper_tail) = match upper {
+        Some(u) => (sorted[u - 1], binomial.sf(u as u64 - 1)),
+        None => (f64::INFINITY, 0.),
+    };
+
+    QuantileInterval {
+        order,
+        estimate: quantile(sample, order),
+        confidence_interval: (lower_bound, upper_bound),
+        confidence: 1. - lower_tail - upper_tail,
+    }
+}
+
+/// Numbers of the values below and above the hypothetical value.
+fn signs(sample: &[f64], value: f64) -> Result<(usize, usize), SignError> {
+    let below = sample.iter().filter(|x| **x < value).count();
+    let above = sample.iter().filter(|x| **x > value).count();
+    if below + above == 0 {
+        return Err(SignError::SampleDegenerate);
+    }
+
+    Ok((below, above))
+}
+
+/// Two-sided exact p-value of `count` successes in `B(n, p)`, twice the smaller tail.
+fn binomial_p_value(count: usize, n: usize, p: f64) -> f64 {
+    // `n >= 1` and `p` in `(0, 1)` are valid parameters.
+    let binomial = Binomial::new(p, n as u64).unwrap();
+    let lower_tail = binomial.cdf(count as u64);
+    let upper_tail = match count {
+        0 => 1.,
+        count => binomial.sf(count as u64 - 1),
+    };
+
+    (2. * lower_tail.min(upper_tail)).min(1.)
+}
+
+fn validate(sample: &[f64], significance: f64) -> Result<(), SignError> {
+    MissingPolicy::Error
+        .apply(sample)
+        .map_err(|_| SignError::ValueMissing)?;
+
+    if sample.is_empty() {
+        return Err(SignError::SampleEmpty);
+    }
+
+    alpha::validate(significance, SignError::SignificanceInvalid)?;
+
+    Ok(())
+}
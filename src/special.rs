@@ -0,0 +1,73 @@
+//! Laplace function and Standard Normal helpers in the form used by textbook tables.
+//!
+//! The Laplace function is `Φ₀(x) = 1/√(2π) ∫₀ˣ exp(-t²/2) dt = Φ(x) - 0.5`.
+//! Tables list `x` with 2 decimals and `Φ₀(x)` with 4 decimals, treating `Φ₀(x) = 0.5` for `x >= 5`;
+//! the `*_table` functions follow the same conventions.
+
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::rounding::round;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum SpecialError {
+    ProbabilityInvalid,
+}
+
+impl std::fmt::Display for SpecialError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SpecialError::ProbabilityInvalid => {
+                write!(
+                    f,
+                    "Probability is out of the domain of the inverse function"
+                )
+            }
+        }
+    }
+}
+
+/// Argument beyond which tables treat `Φ₀(x)` as exactly `0.5`.
+const LAPLACE_TABLE_LIMIT: f64 = 5.;
+
+pub fn laplace(x: f64) -> f64 {
+    Normal::standard().cdf(x) - 0.5
+}
+
+/// Inverse of the Laplace function, defined for `-0.5 < p < 0.5`.
+pub fn inverse_laplace(p: f64) -> Result<f64, SpecialError> {
+    if !(p > -0.5 && p < 0.5) {
+        return Err(SpecialError::ProbabilityInvalid);
+    }
+
+    Ok(Normal::standard().inverse_cdf(p + 0.5))
+}
+
+/// `Φ₀(x)` as read from a table: `x` rounded to 2 decimals, the value rounded to 4 decimals.
+pub fn laplace_table(x: f64) -> f64 {
+    let x = round(x, 2);
+    if x.abs() >= LAPLACE_TABLE_LIMIT {
+        return 0.5 * x.signum();
+    }
+
+    round(laplace(x), 4)
+}
+
+/// `x` such that `Φ₀(x) = p` as read from a table: rounded to 2 decimals.
+pub fn inverse_laplace_table(p: f64) -> Result<f64, SpecialError> {
+    inverse_laplace(round(p, 4)).map(|x| round(x, 2))
+}
+
+/// `z` such that `P(Z <= z) = p` for the Standard Normal `Z`.
+pub fn z_quantile(p: f64) -> Result<f64, SpecialError> {
+    if !(p > 0. && p < 1.) {
+        return Err(SpecialError::ProbabilityInvalid);
+    }
+
+    Ok(Normal::standard().inverse_cdf(p))
+}
+
+/// `z` such that `P(Z <= z) = p` as read from a table: rounded to 2 decimals.
+pub fn z_quantile_table(p: f64) -> Result<f64, SpecialError> {
+    z_quantile(p).map(|z| round(z, 2))
+}
@@ -0,0 +1,165 @@
+//! Random splitting of a dataset into training and holdout parts, so that a model fitted on one part
+//! can be evaluated on data it has not seen.
+//!
+//! The splits are made of observation indices, which select the rows of any number of parallel columns.
+//! The stratified split holds out the same proportion of every stratum, so that rare labels appear in both parts.
+//! The `k` folds hold out every observation exactly once, for cross-validation.
+
+use rand::seq::SliceRandom;
+
+use crate::rng::RngSource;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum SplitError {
+    ProportionInvalid,
+    SampleTooSmall,
+    FoldsCountInvalid,
+}
+
+impl std::fmt::Display for SplitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SplitError::ProportionInvalid => {
+                write!(f, "Holdout proportion must be between 0.0 and 1.0")
+            }
+            SplitError::SampleTooSmall => {
+                write!(f, "Sample is too small to leave observations in both parts")
+            }
+            SplitError::FoldsCountInvalid => {
+                write!(
+                    f,
+                    "Folds count must be at least 2 and at most the sample size"
+                )
+            }
+        }
+    }
+}
+
+/// Ascending indices of the observations of each part.
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Split {
+    pub train: Vec<usize>,
+    pub holdout: Vec<usize>,
+}
+
+impl Split {
+    /// The training and holdout parts of a column.
+    pub fn select<T: Clone>(&self, column: &[T]) -> (Vec<T>, Vec<T>) {
+        let pick = |indices: &[usize]| indices.iter().map(|i| column[*i].clone()).collect();
+        (pick(&self.train), pick(&self.holdout))
+    }
+}
+
+/// Holds out `round(p·n)` of the `n` observations chosen at random.
+pub fn random_split(
+    size: usize,
+    holdout_proportion: f64,
+    rng: RngSource,
+) -> Result<Split, SplitError> {
+    let holdout_size = holdout_size(size, holdout_proportion)?;
+
+    let mut indices: Vec<usize> = (0..size).collect();
+    rng.with_rng(|rng| indices.shuffle(rng));
+
+    Ok(split_at(indices, holdout_size))
+}
+
+/// Holds out `round(p·nₛ)` of the `nₛ` observations of every stratum, chosen at random.
+///
+/// Every stratum must leave observations in both parts.
+pub fn stratified_split<L: PartialEq>(
+    strata: &[L],
+    holdout_proportion: f64,
+    rng: RngSource,
+) -> Result<Split, SplitError> {
+    let mut labels: Vec<&L> = Vec::new();
+    let mut members: Vec<Vec<usize>> = Vec::new();
+    for (i, label) in strata.iter().enumerate() {
+        match labels.iter().position(|stratum| *stratum == label) {
+            Some(j) => members[j].push(i),
+            None => {
+                labels.push(label);
+                members.push(vec![i]);
+            }
+        }
+    }
+
+    let holdout_sizes = members
+        .iter()
+        .map(|indices| holdout_size(indices.len(), holdout_proportion))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut split = rng.with_rng(|rng| {
+        members
+            .into_iter()
+            .zip(holdout_sizes)
+            .map(|(mut indices, holdout_size)| {
+                indices.shuffle(rng);
+                split_at(indices, holdout_size)
+            })
+            .fold(
+                Split {
+                    train: Vec::new(),
+                    holdout: Vec::new(),
+                },
+                |mut split, part| {
+                    split.train.extend(part.train);
+                    split.holdout.extend(part.holdout);
+                    split
+                },
+            )
+    });
+    split.train.sort_unstable();
+    split.holdout.sort_unstable();
+
+    Ok(split)
+}
+
+/// Splits the `n` shuffled observations into `k` folds of sizes differing by at most one;
+/// the `j`-th split holds out the `j`-th fold and trains on the others.
+pub fn k_folds(size: usize, folds: usize, rng: RngSource) -> Result<Vec<Split>, SplitError> {
+    if folds < 2 || folds > size {
+        return Err(SplitError::FoldsCountInvalid);
+    }
+
+    let mut indices: Vec<usize> = (0..size).collect();
+    rng.with_rng(|rng| indices.shuffle(rng));
+
+    Ok((0..folds)
+        .map(|j| {
+            let (start, end) = (j * size / folds, (j + 1) * size / folds);
+            let mut holdout = indices[start..end].to_owned();
+            let mut train = [&indices[..start], &indices[end..]].concat();
+            holdout.sort_unstable();
+            train.sort_unstable();
+            Split { train, holdout }
+        })
+        .collect())
+}
+
+fn holdout_size(size: usize, holdout_proportion: f64) -> Result<usize, SplitError> {
+    if !(holdout_proportion > 0. && holdout_proportion < 1.) {
+        return Err(SplitError::ProportionInvalid);
+    }
+
+    let holdout_size = (holdout_proportion * size as f64).round() as usize;
+    if holdout_size == 0 || holdout_size == size {
+        return Err(SplitError::SampleTooSmall);
+    }
+
+    Ok(holdout_size)
+}
+
+/// The first `holdout_size` shuffled indices are held out.
+fn split_at(mut indices: Vec<usize>, holdout_size: usize) -> Split {
+    let mut train = indices.split_off(holdout_size);
+    indices.sort_unstable();
+    train.sort_unstable();
+
+    Split {
+        train,
+        holdout: indices,
+    }
+}
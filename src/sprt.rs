@@ -0,0 +1,165 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *error rates α and β*, *two simple hypotheses* `H₀: θ = θ₀` and `H₁: θ = θ₁`
+//! about the mean of a normal variable with known σ or about a proportion, *observations arriving one by one*.
+//! **To figure out**: After each observation, is it enough to **accept** one of the hypotheses?
+//!
+//! Wald's sequential probability ratio test accumulates the log-likelihood ratio
+//! and stops once it leaves `(ln(β / (1 - α)); ln((1 - β) / α))`.
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum SprtError {
+    ErrorRatesInvalid,
+    HypothesesInvalid,
+    StdDevInvalid,
+}
+
+impl std::fmt::Display for SprtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SprtError::ErrorRatesInvalid => {
+                write!(f, "Error rates must be positive with α + β < 1.0")
+            }
+            SprtError::HypothesesInvalid => {
+                write!(
+                    f,
+                    "Hypotheses must differ, and proportions must be between 0.0 and 1.0"
+                )
+            }
+            SprtError::StdDevInvalid => {
+                write!(f, "Standard deviation must be positive")
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Decision {
+    Continue,
+    AcceptH0,
+    AcceptH1,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum Model {
+    Mean { mu_0: f64, mu_1: f64, std_dev: f64 },
+    Proportion { p_0: f64, p_1: f64 },
+}
+
+pub struct Sprt {
+    model: Model,
+    lower_boundary: f64,
+    upper_boundary: f64,
+    log_likelihood_ratio: f64,
+    observations: usize,
+    decision: Decision,
+}
+
+impl Sprt {
+    /// `H₀: E(X) = mu_0` against `H₁: E(X) = mu_1` for a normal **X** with the known `std_dev`.
+    pub fn mean(
+        mu_0: f64,
+        mu_1: f64,
+        std_dev: f64,
+        alpha: f64,
+        beta: f64,
+    ) -> Result<Self, SprtError> {
+        if mu_0 == mu_1 || !mu_0.is_finite() || !mu_1.is_finite() {
+            return Err(SprtError::HypothesesInvalid);
+        }
+
+        if std_dev <= 0. || !std_dev.is_finite() {
+            return Err(SprtError::StdDevInvalid);
+        }
+
+        Self::with_model(
+            Model::Mean {
+                mu_0,
+                mu_1,
+                std_dev,
+            },
+            alpha,
+            beta,
+        )
+    }
+
+    /// `H₀: p = p_0` against `H₁: p = p_1` for the probability of success `p`.
+    pub fn proportion(p_0: f64, p_1: f64, alpha: f64, beta: f64) -> Result<Self, SprtError> {
+        let proportion_valid = |p: f64| p > 0. && p < 1.;
+        if p_0 == p_1 || !proportion_valid(p_0) || !proportion_valid(p_1) {
+            return Err(SprtError::HypothesesInvalid);
+        }
+
+        Self::with_model(Model::Proportion { p_0, p_1 }, alpha, beta)
+    }
+
+    fn with_model(model: Model, alpha: f64, beta: f64) -> Result<Self, SprtError> {
+        if !(alpha > 0. && beta > 0. && alpha + beta < 1.) {
+            return Err(SprtError::ErrorRatesInvalid);
+        }
+
+        Ok(Self {
+            model,
+            lower_boundary: (beta / (1. - alpha)).ln(),
+            upper_boundary: ((1. - beta) / alpha).ln(),
+            log_likelihood_ratio: 0.,
+            observations: 0,
+            decision: Decision::Continue,
+        })
+    }
+
+    /// Accounts one observation; for a proportion, any nonzero `x` is a success.
+    ///
+    /// Once a hypothesis is accepted, the decision is final and further observations are ignored.
+    /// Missing (NaN) and infinite observations are ignored as well, leaving the state unchanged.
+    pub fn observe(&mut self, x: f64) -> Decision {
+        if self.decision != Decision::Continue || !x.is_finite() {
+            return self.decision;
+        }
+
+        self.log_likelihood_ratio += match self.model {
+            Model::Mean {
+                mu_0,
+                mu_1,
+                std_dev,
+            } => (mu_1 - mu_0) / std_dev.powi(2) * (x - (mu_0 + mu_1) / 2.),
+            Model::Proportion { p_0, p_1 } => {
+                if x != 0. {
+                    (p_1 / p_0).ln()
+                } else {
+                    ((1. - p_1) / (1. - p_0)).ln()
+                }
+            }
+        };
+        self.observations += 1;
+
+        self.decision = if self.log_likelihood_ratio <= self.lower_boundary {
+            Decision::AcceptH0
+        } else if self.log_likelihood_ratio >= self.upper_boundary {
+            Decision::AcceptH1
+        } else {
+            Decision::Continue
+        };
+
+        self.decision
+    }
+
+    pub fn decision(&self) -> Decision {
+        self.decision
+    }
+
+    pub fn log_likelihood_ratio(&self) -> f64 {
+        self.log_likelihood_ratio
+    }
+
+    /// Number of observations accounted before the decision.
+    pub fn observations(&self) -> usize {
+        self.observations
+    }
+
+    /// `ln(β / (1 - α))` and `ln((1 - β) / α)`.
+    pub fn boundaries(&self) -> (f64, f64) {
+        (self.lower_boundary, self.upper_boundary)
+    }
+}
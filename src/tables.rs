@@ -0,0 +1,284 @@
+//! Generator of the classic statistical tables of critical values.
+//!
+//! **Given**: *significance ratios* and *freedom degrees ranges*.
+//! **To figure out**: Critical values of the **Chi-Squared**, **Student's t**, **Fisher–Snedecor**
+//! and **Standard Normal** distributions, formatted as text, CSV or LaTeX.
+
+use std::ops::RangeInclusive;
+
+use statrs::distribution::{ChiSquared, ContinuousCDF, FisherSnedecor, Normal, StudentsT};
+
+use crate::alpha::Alpha;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum TablesError {
+    SignificanceInvalid,
+    FreedomDegreesInvalid,
+}
+
+impl std::fmt::Display for TablesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TablesError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            TablesError::FreedomDegreesInvalid => {
+                write!(
+                    f,
+                    "Freedom Degrees ranges must be non-empty and start from 1"
+                )
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Tails {
+    /// `P(X > x) = significance`.
+    One,
+    /// `P(|X| > x) = significance`.
+    Two,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Format {
+    Text,
+    Csv,
+    Latex,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Table {
+    pub title: String,
+    /// Label of the column holding the row labels.
+    pub corner: String,
+    pub column_labels: Vec<String>,
+    pub row_labels: Vec<String>,
+    pub values: Vec<Vec<f64>>,
+}
+
+impl Table {
+    /// Renders the table with every value rounded to `digits` decimals.
+    pub fn render(&self, format: Format, digits: usize) -> String {
+        let rows: Vec<Vec<String>> = self
+            .row_labels
+            .iter()
+            .zip(self.values.iter())
+            .map(|(label, values)| {
+                std::iter::once(label.to_owned())
+                    .chain(values.iter().map(|value| format!("{value:.digits$}")))
+                    .collect()
+            })
+            .collect();
+        let header: Vec<String> = std::iter::once(self.corner.to_owned())
+            .chain(self.column_labels.iter().cloned())
+            .collect();
+
+        match format {
+            Format::Text => {
+                let widths: Vec<usize> = (0..header.len())
+                    .map(|j| {
+                        rows.iter()
+                            .map(|row| row[j].chars().count())
+                            .chain(std::iter::once(header[j].chars().count()))
+                            .max()
+                            .unwrap_or(0)
+                    })
+                    .collect();
+                let line = |cells: &[String]| {
+                    cells
+                        .iter()
+                        .zip(widths.iter())
+                        .map(|(cell, width)| format!("{cell:>width$}"))
+                        .collect::<Vec<_>>()
+                        .join("  ")
+                };
+
+                let mut text = format!("{}\n{}\n", self.title, line(&header));
+                for row in &rows {
+                    text += &line(row);
+                    text += "\n";
+                }
+                text
+            }
+            Format::Csv => std::iter::once(&header)
+                .chain(rows.iter())
+                .map(|row| row.join(",") + "\n")
+                .collect(),
+            Format::Latex => {
+                let mut latex = format!(
+                    "% {}\n\\begin{{tabular}}{{r|{}}}\n{} \\\\\n\\hline\n",
+                    self.title,
+                    "r".repeat(self.column_labels.len()),
+                    header.join(" & ")
+                );
+                for row in &rows {
+                    latex += &format!("{} \\\\\n", row.join(" & "));
+                }
+                latex += "\\end{tabular}\n";
+                latex
+            }
+        }
+    }
+}
+
+/// Upper critical values of the **Chi-Squared** distribution: rows are freedom degrees, columns are significances.
+pub fn chi_squared_table(
+    significances: &[f64],
+    freedom_degrees: RangeInclusive<usize>,
+) -> Result<Table, TablesError> {
+    validate(significances, &freedom_degrees)?;
+
+    let values = freedom_degrees
+        .clone()
+        .map(|k| {
+            let chi_squared_dist = ChiSquared::new(k as f64).unwrap();
+            significances
+                .iter()
+                .map(|significance| chi_squared_dist.inverse_cdf(1. - significance))
+                .collect()
+        })
+        .collect();
+
+    Ok(Table {
+        title: "Critical values of the Chi-Squared distribution".to_string(),
+        corner: "k".to_string(),
+        column_labels: significance_labels(significances),
+        row_labels: freedom_degrees.map(|k| k.to_string()).collect(),
+        values,
+    })
+}
+
+/// Critical values of the **Student's t** distribution: rows are freedom degrees, columns are significances.
+pub fn students_t_table(
+    significances: &[f64],
+    freedom_degrees: RangeInclusive<usize>,
+    tails: Tails,
+) -> Result<Table, TablesError> {
+    validate(significances, &freedom_degrees)?;
+
+    let values = freedom_degrees
+        .clone()
+        .map(|k| {
+            let students_t_dist = StudentsT::new(0., 1., k as f64).unwrap();
+            significances
+                .iter()
+                .map(|significance| {
+                    students_t_dist.inverse_cdf(1. - tail_probability(*significance, tails))
+                })
+                .collect()
+        })
+        .collect();
+
+    Ok(Table {
+        title: format!(
+            "Critical values of the Student's t distribution ({})",
+            tails_label(tails)
+        ),
+        corner: "k".to_string(),
+        column_labels: significance_labels(significances),
+        row_labels: freedom_degrees.map(|k| k.to_string()).collect(),
+        values,
+    })
+}
+
+/// Upper critical values of the **Fisher–Snedecor** distribution for one significance:
+/// rows are denominator freedom degrees, columns are numerator freedom degrees.
+pub fn fisher_snedecor_table(
+    significance: f64,
+    numerator_freedom_degrees: RangeInclusive<usize>,
+    denominator_freedom_degrees: RangeInclusive<usize>,
+) -> Result<Table, TablesError> {
+    validate(&[significance], &numerator_freedom_degrees)?;
+    validate(&[significance], &denominator_freedom_degrees)?;
+
+    let values = denominator_freedom_degrees
+        .clone()
+        .map(|k_2| {
+            numerator_freedom_degrees
+                .clone()
+                .map(|k_1| {
+                    FisherSnedecor::new(k_1 as f64, k_2 as f64)
+                        .unwrap()
+                        .inverse_cdf(1. - significance)
+                })
+                .collect()
+        })
+        .collect();
+
+    Ok(Table {
+        title: format!("Critical values of the Fisher–Snedecor distribution, α = {significance}"),
+        corner: "k2/k1".to_string(),
+        column_labels: numerator_freedom_degrees.map(|k| k.to_string()).collect(),
+        row_labels: denominator_freedom_degrees.map(|k| k.to_string()).collect(),
+        values,
+    })
+}
+
+/// Critical values of the **Standard Normal** distribution, one row per significance.
+pub fn standard_normal_table(significances: &[f64], tails: Tails) -> Result<Table, TablesError> {
+    validate(significances, &(1..=1))?;
+
+    let standard_normal = Normal::standard();
+    let values = significances
+        .iter()
+        .map(|significance| {
+            vec![standard_normal.inverse_cdf(1. - tail_probability(*significance, tails))]
+        })
+        .collect();
+
+    Ok(Table {
+        title: format!(
+            "Critical values of the Standard Normal distribution ({})",
+            tails_label(tails)
+        ),
+        corner: "α".to_string(),
+        column_labels: vec!["z".to_string()],
+        row_labels: significances
+            .iter()
+            .map(|significance| significance.to_string())
+            .collect(),
+        values,
+    })
+}
+
+fn validate(
+    significances: &[f64],
+    freedom_degrees: &RangeInclusive<usize>,
+) -> Result<(), TablesError> {
+    if significances.is_empty()
+        || significances
+            .iter()
+            .any(|significance| Alpha::new(*significance).is_err())
+    {
+        return Err(TablesError::SignificanceInvalid);
+    }
+
+    if freedom_degrees.is_empty() || *freedom_degrees.start() == 0 {
+        return Err(TablesError::FreedomDegreesInvalid);
+    }
+
+    Ok(())
+}
+
+fn tail_probability(significance: f64, tails: Tails) -> f64 {
+    match tails {
+        Tails::One => significance,
+        Tails::Two => significance / 2.,
+    }
+}
+
+fn tails_label(tails: Tails) -> &'static str {
+    match tails {
+        Tails::One => "one-sided",
+        Tails::Two => "two-sided",
+    }
+}
+
+fn significance_labels(significances: &[f64]) -> Vec<String> {
+    significances
+        .iter()
+        .map(|significance| significance.to_string())
+        .collect()
+}
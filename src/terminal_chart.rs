@@ -0,0 +1,125 @@
+//! Terminal rendering of charts with Unicode block characters, requiring no plotting dependency.
+//!
+//! - Histogram of a grouped sample.
+//! - Box plots of raw samples.
+//! - Observed vs expected frequencies bars.
+
+use std::fmt::Write;
+
+use crate::box_plot::BoxPlot;
+
+/// Blocks from one eighth to a full cell, for bars with sub-character precision.
+const BLOCKS: [char; 8] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+/// Horizontal bar of `value / max` of the `width` characters.
+fn bar(value: f64, max: f64, width: usize) -> String {
+    if max <= 0. || value <= 0. || value.is_nan() {
+        return String::new();
+    }
+
+    let eighths = (value / max * width as f64 * 8.).round() as usize;
+    let mut bar = BLOCKS[7].to_string().repeat(eighths / 8);
+    if let Some(partial) = (eighths % 8).checked_sub(1) {
+        bar.push(BLOCKS[partial]);
+    }
+    bar
+}
+
+/// Histogram of a grouped sample, one row per random value range, bars up to `width` characters.
+pub fn histogram(random_value_ranges: &[(f64, f64)], frequencies: &[f64], width: usize) -> String {
+    let max = frequencies.iter().copied().fold(0., f64::max);
+    let labels: Vec<_> = random_value_ranges
+        .iter()
+        .map(|(x_1, x_2)| format!("[{x_1:.2}; {x_2:.2})"))
+        .collect();
+    let label_width = labels
+        .iter()
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut chart = String::new();
+    for (label, m) in labels.iter().zip(frequencies.iter()) {
+        writeln!(chart, "{label:>label_width$} │{} {m}", bar(*m, max, width)).unwrap();
+    }
+    chart
+}
+
+/// Observed vs expected frequencies, two bars per class, up to `width` characters.
+pub fn observed_expected(observed: &[f64], expected: &[f64], width: usize) -> String {
+    let max = observed
+        .iter()
+        .chain(expected.iter())
+        .copied()
+        .fold(0., f64::max);
+
+    let mut chart = String::new();
+    for (i, (o, e)) in observed.iter().zip(expected.iter()).enumerate() {
+        writeln!(
+            chart,
+            "{:>3} observed │{} {o:.2}",
+            i + 1,
+            bar(*o, max, width)
+        )
+        .unwrap();
+        writeln!(chart, "    expected │{} {e:.2}", bar(*e, max, width)).unwrap();
+    }
+    chart
+}
+
+/// Box plot over `width` characters: whiskers, box from Q1 to Q3, median and outliers marked.
+///
+/// All the box plots share one scale so that they can be compared.
+pub fn box_plot(box_plots: &[BoxPlot], width: usize) -> String {
+    let min = box_plots
+        .iter()
+        .map(|box_plot| box_plot.min)
+        .fold(f64::INFINITY, f64::min);
+    let max = box_plots
+        .iter()
+        .map(|box_plot| box_plot.max)
+        .fold(f64::NEG_INFINITY, f64::max);
+    if box_plots.is_empty() || width < 2 {
+        return String::new();
+    }
+
+    let position = |value: f64| {
+        if max > min {
+            ((value - min) / (max - min) * (width - 1) as f64).round() as usize
+        } else {
+            0
+        }
+    };
+
+    let mut chart = String::new();
+    for box_plot in box_plots {
+        let mut line = vec![' '; width];
+        for cell in &mut line[position(box_plot.lower_whisker)..=position(box_plot.upper_whisker)] {
+            *cell = '─';
+        }
+        for cell in &mut line[position(box_plot.first_quartile)..=position(box_plot.third_quartile)]
+        {
+            *cell = '█';
+        }
+        line[position(box_plot.lower_whisker)] = '├';
+        line[position(box_plot.upper_whisker)] = '┤';
+        line[position(box_plot.median)] = '┃';
+        for outlier in &box_plot.outliers {
+            line[position(*outlier)] = '•';
+        }
+
+        writeln!(
+            chart,
+            "{}\nQ1 = {:.2}, median = {:.2}, Q3 = {:.2}, whiskers = [{:.2}; {:.2}], outliers = {:?}",
+            line.iter().collect::<String>(),
+            box_plot.first_quartile,
+            box_plot.median,
+            box_plot.third_quartile,
+            box_plot.lower_whisker,
+            box_plot.upper_whisker,
+            box_plot.outliers
+        )
+        .unwrap();
+    }
+    chart
+}
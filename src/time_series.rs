@@ -0,0 +1,481 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *time-ordered sample* `x₁, …, xₙ`, *lag* `h`.
+//! **To figure out**: Is it appropriate to **assume** that the series is not autocorrelated up to the lag `h`?
+//!
+//! The sample autocorrelations `r₁, …, rₕ` are combined into the Ljung–Box statistic
+//! `Q = n(n + 2) Σ rₖ² / (n - k)`, which is Chi-Squared with `h` freedom degrees for white noise.
+//!
+//! **Given**: *significance ratio*, *time-ordered sample*, *number of lagged differences* `p`.
+//! **To figure out**: Is it appropriate to **assume** that the series has a unit root, i.e. is not stationary?
+//!
+//! The augmented Dickey–Fuller regression
+//! `Δyₜ = α + βt + γyₜ₋₁ + δ₁Δyₜ₋₁ + … + δₚΔyₜ₋ₚ + εₜ` is fitted by least squares,
+//! and `τ = γ / se(γ)` is compared with MacKinnon's (2010) critical values.
+//!
+//! **Given**: *significance ratio*, *time-ordered sample*.
+//! **To figure out**: Is it appropriate to **assume** that the series has no monotonic trend?
+//!
+//! The Mann–Kendall statistic `S = Σᵢ<ⱼ sign(xⱼ - xᵢ)` is compared through its normal approximation
+//! with tie and continuity corrections; the trend is estimated by Sen's slope.
+
+use nalgebra::{DMatrix, DVector};
+use statrs::distribution::{ChiSquared, ContinuousCDF, Normal};
+
+use crate::alpha;
+use crate::descriptive::median;
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+use crate::numeric::{compensated_sum, mean};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum TSError {
+    SeriesDegenerate,
+    LagInvalid,
+    SignificanceInvalid,
+    FreedomDegreesInvalid,
+    SeriesTooShort,
+    SignificanceNotTabulated,
+    RegressionSingular,
+    ValueMissing,
+}
+
+impl std::fmt::Display for TSError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TSError::SeriesDegenerate => {
+                write!(f, "Series must contain at least two distinct values")
+            }
+            TSError::LagInvalid => {
+                write!(f, "Lag must be positive and less than the series length")
+            }
+            TSError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            TSError::FreedomDegreesInvalid => {
+                write!(
+                    f,
+                    "Freedom Degrees led to fail in initialization of Gamma underlying Chi"
+                )
+            }
+            TSError::SeriesTooShort => {
+                write!(f, "Series is too short for the number of lags")
+            }
+            TSError::SignificanceNotTabulated => {
+                write!(
+                    f,
+                    "Critical values are tabulated only for 0.01, 0.05 and 0.1"
+                )
+            }
+            TSError::RegressionSingular => {
+                write!(
+                    f,
+                    "Regressors of the test regression are linearly dependent"
+                )
+            }
+            TSError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+        }
+    }
+}
+
+/// Sample autocorrelations `r₁, …, r_max_lag`.
+pub fn acf(series: &[f64], max_lag: usize) -> Result<Vec<f64>, TSError> {
+    if max_lag == 0 || max_lag >= series.len() {
+        return Err(TSError::LagInvalid);
+    }
+
+    let series_mean = mean(series);
+    let deviations: Vec<f64> = series.iter().map(|x| x - series_mean).collect();
+    let variance_sum = compensated_sum(deviations.iter().map(|d| d.powi(2)));
+    if variance_sum == 0. {
+        return Err(TSError::SeriesDegenerate);
+    }
+
+    Ok((1..=max_lag)
+        .map(|k| {
+            compensated_sum(
+                deviations
+                    .iter()
+                    .zip(deviations[k..].iter())
+                    .map(|(d_t, d_t_k)| d_t * d_t_k),
+            ) / variance_sum
+        })
+        .collect())
+}
+
+/// Sample partial autocorrelations `φ₁₁, …, φ_max_lag,max_lag` by the Durbin–Levinson recursion.
+pub fn pacf(series: &[f64], max_lag: usize) -> Result<Vec<f64>, TSError> {
+    let r = acf(series, max_lag)?;
+
+    let mut partial = Vec::with_capacity(max_lag);
+    let mut phi: Vec<f64> = Vec::with_capacity(max_lag);
+    for k in 0..max_lag {
+        let numerator = r[k] - (0..k).map(|j| phi[j] * r[k - 1 - j]).sum::<f64>();
+        let denominator = 1. - (0..k).map(|j| phi[j] * r[j]).sum::<f64>();
+        let phi_kk = numerator / denominator;
+
+        let previous = phi.clone();
+        for j in 0..k {
+            phi[j] = previous[j] - phi_kk * previous[k - 1 - j];
+        }
+        phi.push(phi_kk);
+        partial.push(phi_kk);
+    }
+
+    Ok(partial)
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct LjungBoxOutcome {
+    pub autocorrelations: Vec<f64>,
+    pub q_observed: f64,
+    pub freedom_degrees: f64,
+    pub chi_squared_critical_value: f64,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    /// Whether the hypothesis of no autocorrelation is accepted.
+    pub accepted: bool,
+}
+
+pub struct LjungBoxHypothesis {
+    series: Vec<f64>,
+    lag: usize,
+    fitted_parameters: usize,
+    significance: f64,
+}
+
+impl LjungBoxHypothesis {
+    pub fn new(series: &[f64], lag: usize, significance: f64) -> Result<Self, TSError> {
+        reject_missing(series)?;
+
+        if lag == 0 || lag >= series.len() {
+            return Err(TSError::LagInvalid);
+        }
+
+        alpha::validate(significance, TSError::SignificanceInvalid)?;
+
+        Ok(Self {
+            series: series.to_owned(),
+            lag,
+            fitted_parameters: 0,
+            significance,
+        })
+    }
+
+    /// For residuals of a fitted ARMA(p, q) model, the freedom degrees are reduced by `p + q`.
+    pub fn with_fitted_parameters(mut self, fitted_parameters: usize) -> Self {
+        self.fitted_parameters = fitted_parameters;
+        self
+    }
+
+    pub fn solve(&self) -> Result<bool, TSError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<LjungBoxOutcome, TSError> {
+        let n = self.series.len() as f64;
+        let autocorrelations = acf(&self.series, self.lag)?;
+
+        let q_observed = n
+            * (n + 2.)
+            * compensated_sum(
+                autocorrelations
+                    .iter()
+                    .enumerate()
+                    .map(|(i, r)| r.powi(2) / (n - (i + 1) as f64)),
+            );
+
+        let freedom_degrees = self.lag as f64 - self.fitted_parameters as f64;
+        let chi_squared_dist =
+            ChiSquared::new(freedom_degrees).map_err(|_| TSError::FreedomDegreesInvalid)?;
+        let chi_squared_critical_value = chi_squared_dist.inverse_cdf(1. - self.significance);
+
+        Ok(LjungBoxOutcome {
+            autocorrelations,
+            q_observed,
+            freedom_degrees,
+            chi_squared_critical_value,
+            p_value: chi_squared_dist.sf(q_observed),
+            hypotheses: Hypotheses::new(
+                format!("ρ(1) = … = ρ({}) = 0", self.lag),
+                format!("ρ(k) ≠ 0 for some k <= {}", self.lag),
+                Alternative::Greater,
+            ),
+            accepted: q_observed < chi_squared_critical_value,
+        })
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Deterministic {
+    /// No deterministic terms: stationarity around zero.
+    None,
+    /// Constant `α`: stationarity around a level.
+    Constant,
+    /// Constant `α` and trend `βt`: stationarity around a linear trend.
+    ConstantTrend,
+}
+
+/// MacKinnon (2010) response surface coefficients `β∞, β₁, β₂, β₃` of
+/// `τ_crit(T) = β∞ + β₁ / T + β₂ / T² + β₃ / T³` for the significances `0.01, 0.05, 0.1`.
+const MACKINNON_SIGNIFICANCES: [f64; 3] = [0.01, 0.05, 0.1];
+const MACKINNON_NONE: [[f64; 4]; 3] = [
+    [-2.56574, -2.2358, -3.627, 0.],
+    [-1.94100, -0.2686, -3.365, 31.223],
+    [-1.61682, 0.2656, -2.714, 25.364],
+];
+const MACKINNON_CONSTANT: [[f64; 4]; 3] = [
+    [-3.43035, -6.5393, -16.786, -79.433],
+    [-2.86154, -2.8903, -4.234, -40.040],
+    [-2.56677, -1.5384, -2.809, 0.],
+];
+const MACKINNON_CONSTANT_TREND: [[f64; 4]; 3] = [
+    [-3.95877, -9.0531, -28.428, -134.155],
+    [-3.41049, -4.3904, -9.036, -45.374],
+    [-3.12705, -2.5856, -3.925, -22.380],
+];
+
+/// Schwert's rule `⌊12 · (n / 100)^(1/4)⌋` for the number of lagged differences.
+pub fn schwert_lags(series_length: usize) -> usize {
+    (12. * (series_length as f64 / 100.).powf(0.25)).floor() as usize
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ADFOutcome {
+    pub tau_observed: f64,
+    pub tau_critical_value: f64,
+    /// Observations used in the regression.
+    pub observations: usize,
+    pub hypotheses: Hypotheses,
+    /// Whether the hypothesis of a unit root is accepted.
+    pub accepted: bool,
+}
+
+pub struct AugmentedDickeyFuller {
+    series: Vec<f64>,
+    lags: usize,
+    deterministic: Deterministic,
+    significance: f64,
+}
+
+impl AugmentedDickeyFuller {
+    pub fn new(
+        series: &[f64],
+        lags: usize,
+        deterministic: Deterministic,
+        significance: f64,
+    ) -> Result<Self, TSError> {
+        reject_missing(series)?;
+
+        if !MACKINNON_SIGNIFICANCES.contains(&significance) {
+            return Err(TSError::SignificanceNotTabulated);
+        }
+
+        // The regression needs more observations than regressors.
+        let regressors = 1 + lags + deterministic_terms(deterministic);
+        if series.len() < lags + 1 + regressors + 1 {
+            return Err(TSError::SeriesTooShort);
+        }
+
+        Ok(Self {
+            series: series.to_owned(),
+            lags,
+            deterministic,
+            significance,
+        })
+    }
+
+    pub fn solve(&self) -> Result<bool, TSError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<ADFOutcome, TSError> {
+        let differences: Vec<f64> = self.series.windows(2).map(|w| w[1] - w[0]).collect();
+        let observations = differences.len() - self.lags;
+        let regressors = 1 + self.lags + deterministic_terms(self.deterministic);
+
+        // Columns: `yₜ₋₁`, `Δyₜ₋₁, …, Δyₜ₋ₚ`, then the deterministic terms.
+        let design = DMatrix::from_fn(observations, regressors, |row, column| {
+            let t = row + self.lags;
+            match column {
+                0 => self.series[t],
+                column if column <= self.lags => differences[t - column],
+                column if column == self.lags + 1 => 1.,
+                _ => (t + 1) as f64,
+            }
+        });
+        let response =
+            DVector::from_iterator(observations, differences[self.lags..].iter().copied());
+
+        let gram_inverse = (design.transpose() * &design)
+            .try_inverse()
+            .ok_or(TSError::RegressionSingular)?;
+        let coefficients = &gram_inverse * design.transpose() * &response;
+        let residuals = response - &design * &coefficients;
+        let residual_variance = residuals.norm_squared() / (observations - regressors) as f64;
+        if residual_variance == 0. {
+            return Err(TSError::SeriesDegenerate);
+        }
+
+        let tau_observed = coefficients[0] / (residual_variance * gram_inverse[(0, 0)]).sqrt();
+        let tau_critical_value = self.critical_value(observations as f64);
+
+        Ok(ADFOutcome {
+            tau_observed,
+            tau_critical_value,
+            observations,
+            hypotheses: Hypotheses::new(
+                "the series has a unit root",
+                match self.deterministic {
+                    Deterministic::None => "the series is stationary",
+                    Deterministic::Constant => "the series is stationary around a level",
+                    Deterministic::ConstantTrend => "the series is stationary around a trend",
+                },
+                Alternative::Less,
+            ),
+            accepted: tau_observed >= tau_critical_value,
+        })
+    }
+
+    fn critical_value(&self, observations: f64) -> f64 {
+        let table = match self.deterministic {
+            Deterministic::None => &MACKINNON_NONE,
+            Deterministic::Constant => &MACKINNON_CONSTANT,
+            Deterministic::ConstantTrend => &MACKINNON_CONSTANT_TREND,
+        };
+        let i = MACKINNON_SIGNIFICANCES
+            .iter()
+            .position(|alpha| *alpha == self.significance)
+            .unwrap();
+
+        table[i]
+            .iter()
+            .enumerate()
+            .map(|(power, beta)| beta / observations.powi(power as i32))
+            .sum()
+    }
+}
+
+fn reject_missing(series: &[f64]) -> Result<(), TSError> {
+    MissingPolicy::Error
+        .apply(series)
+        .map(|_| ())
+        .map_err(|_| TSError::ValueMissing)
+}
+
+fn deterministic_terms(deterministic: Deterministic) -> usize {
+    match deterministic {
+        Deterministic::None => 0,
+        Deterministic::Constant => 1,
+        Deterministic::ConstantTrend => 2,
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct MannKendallOutcome {
+    pub s: f64,
+    /// Variance of `S` corrected for ties.
+    pub variance: f64,
+    pub z_observed: f64,
+    pub z_critical_value: f64,
+    pub p_value: f64,
+    /// Median of the pairwise slopes `(xⱼ - xᵢ) / (j - i)`.
+    pub sens_slope: f64,
+    /// Confidence interval for the slope at the level `1 - significance`.
+    pub sens_slope_interval: (f64, f64),
+    pub hypotheses: Hypotheses,
+    /// Whether the hypothesis of no trend is accepted.
+    pub accepted: bool,
+}
+
+pub struct MannKendallHypothesis {
+    series: Vec<f64>,
+    significance: f64,
+}
+
+impl MannKendallHypothesis {
+    pub fn new(series: &[f64], significance: f64) -> Result<Self, TSError> {
+        reject_missing(series)?;
+
+        if series.len() < 3 {
+            return Err(TSError::SeriesTooShort);
+        }
+
+        alpha::validate(significance, TSError::SignificanceInvalid)?;
+
+        Ok(Self {
+            series: series.to_owned(),
+            significance,
+        })
+    }
+
+    pub fn solve(&self) -> Result<bool, TSError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<MannKendallOutcome, TSError> {
+        let n = self.series.len();
+
+        let mut s = 0.;
+        let mut slopes = Vec::with_capacity(n * (n - 1) / 2);
+        for i in 0..n {
+            for j in i + 1..n {
+                let difference = self.series[j] - self.series[i];
+                if difference != 0. {
+                    s += difference.signum();
+                }
+                slopes.push(difference / (j - i) as f64);
+            }
+        }
+
+        let mut sorted = self.series.clone();
+        sorted.sort_by(f64::total_cmp);
+        let ties_term = sorted
+            .chunk_by(|a, b| a == b)
+            .map(|tie| {
+                let t = tie.len() as f64;
+                t * (t - 1.) * (2. * t + 5.)
+            })
+            .sum::<f64>();
+        let n = n as f64;
+        let variance = (n * (n - 1.) * (2. * n + 5.) - ties_term) / 18.;
+        if variance <= 0. {
+            return Err(TSError::SeriesDegenerate);
+        }
+
+        let z_observed = if s == 0. {
+            0.
+        } else {
+            (s - s.signum()) / variance.sqrt()
+        };
+        let standard_normal = Normal::standard();
+        // The critical region is two-sided, so each tail holds `significance / 2`.
+        let z_critical_value = standard_normal.inverse_cdf(1. - self.significance / 2.);
+
+        // Ranks of the interval bounds among the ordered slopes, after Gilbert (1987).
+        slopes.sort_by(f64::total_cmp);
+        let slopes_count = slopes.len() as f64;
+        let spread = z_critical_value * variance.sqrt();
+        let lower_rank = ((slopes_count - spread) / 2.).round().max(1.) as usize;
+        let upper_rank = (((slopes_count + spread) / 2.).round() + 1.).min(slopes_count) as usize;
+
+        Ok(MannKendallOutcome {
+            s,
+            variance,
+            z_observed,
+            z_critical_value,
+            p_value: 2. * standard_normal.sf(z_observed.abs()),
+            sens_slope: median(&slopes),
+            sens_slope_interval: (slopes[lower_rank - 1], slopes[upper_rank - 1]),
+            hypotheses: Hypotheses::new(
+                "the series has no monotonic trend",
+                "the series has a monotonic trend",
+                Alternative::TwoSided,
+            ),
+            accepted: z_observed.abs() < z_critical_value,
+        })
+    }
+}
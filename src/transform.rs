@@ -0,0 +1,215 @@
+//! Transformations of raw samples towards normality.
+//!
+//! The Box–Cox transform `(x^λ - 1) / λ`, or `ln x` for `λ = 0`, includes the logarithm and, up to
+//! a linear change, the square root; its `λ` is estimated by maximizing the profile log-likelihood
+//! `ℓ(λ) = -n/2 ln σ̂²(λ) + (λ - 1) Σ ln xᵢ` of the normal model of the transformed sample.
+//! The rank transforms discard the values but their order, so they always remove skewness.
+
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::numeric::biased_variance;
+use crate::order_statistics::average_ranks;
+use crate::pipeline::{NormalityTest, PipelineError};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum TransformError {
+    SupportInvalid,
+    SampleDegenerate,
+    Normality(PipelineError),
+}
+
+impl std::fmt::Display for TransformError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TransformError::SupportInvalid => {
+                write!(
+                    f,
+                    "Sample contains values outside the domain of the transform"
+                )
+            }
+            TransformError::SampleDegenerate => {
+                write!(f, "Sample must contain at least two distinct values")
+            }
+            TransformError::Normality(e) => {
+                write!(f, "Normality check of the transformed sample failed: {e}")
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Transform {
+    /// `ln x`, for positive samples.
+    Log,
+    /// `√x`, for non-negative samples.
+    SquareRoot,
+    /// Box–Cox transform for positive samples, with `λ` estimated by profile likelihood if `None`.
+    BoxCox { lambda: Option<f64> },
+    /// Average ranks.
+    Rank,
+    /// Blom's normal scores of the average ranks, `Φ⁻¹((r - 3/8) / (n + 1/4))`.
+    RankNormal,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TransformOutcome {
+    /// The transform applied, with the estimated `λ` filled in for Box–Cox.
+    pub transform: Transform,
+    pub transformed: Vec<f64>,
+    /// Whether the normality test accepts the transformed sample.
+    pub normal: bool,
+}
+
+/// Bounds of the search for the Box–Cox `λ`.
+const LAMBDA_RANGE: (f64, f64) = (-5., 5.);
+/// Number of points of the grid locating the maximum of the profile log-likelihood.
+const LAMBDA_GRID_POINTS: usize = 100;
+
+impl Transform {
+    pub fn apply(&self, sample: &[f64]) -> Result<Vec<f64>, TransformError> {
+        match self {
+            Transform::Log => log(sample),
+            Transform::SquareRoot => square_root(sample),
+            Transform::BoxCox {
+                lambda: Some(lambda),
+            } => box_cox(sample, *lambda),
+            Transform::BoxCox { lambda: None } => box_cox(sample, box_cox_lambda(sample)?),
+            Transform::Rank => Ok(ranks(sample)),
+            Transform::RankNormal => Ok(rank_normal_scores(sample)),
+        }
+    }
+
+    /// Applies the transform and checks the normality of the result.
+    pub fn check_normality(
+        &self,
+        sample: &[f64],
+        normality_test: NormalityTest,
+        significance: f64,
+    ) -> Result<TransformOutcome, TransformError> {
+        let transform = match self {
+            Transform::BoxCox { lambda: None } => Transform::BoxCox {
+                lambda: Some(box_cox_lambda(sample)?),
+            },
+            transform => *transform,
+        };
+        let transformed = transform.apply(sample)?;
+        let normal = normality_test
+            .solve(&transformed, significance)
+            .map_err(TransformError::Normality)?;
+
+        Ok(TransformOutcome {
+            transform,
+            transformed,
+            normal,
+        })
+    }
+}
+
+pub fn log(sample: &[f64]) -> Result<Vec<f64>, TransformError> {
+    if !sample.iter().all(|x| *x > 0.) {
+        return Err(TransformError::SupportInvalid);
+    }
+
+    Ok(sample.iter().map(|x| x.ln()).collect())
+}
+
+pub fn square_root(sample: &[f64]) -> Result<Vec<f64>, TransformError> {
+    if !sample.iter().all(|x| *x >= 0.) {
+        return Err(TransformError::SupportInvalid);
+    }
+
+    Ok(sample.iter().map(|x| x.sqrt()).collect())
+}
+
+/// `(x^λ - 1) / λ`, or `ln x` for `λ = 0`.
+pub fn box_cox(sample: &[f64], lambda: f64) -> Result<Vec<f64>, TransformError> {
+    let logarithms = log(sample)?;
+
+    Ok(if lambda == 0. {
+        logarithms
+    } else {
+        logarithms
+            .iter()
+            .map(|ln_x| (lambda * ln_x).exp_m1() / lambda)
+            .collect()
+    })
+}
+
+/// Profile log-likelihood of `λ`, up to an additive constant.
+pub fn box_cox_log_likelihood(sample: &[f64], lambda: f64) -> Result<f64, TransformError> {
+    let transformed = box_cox(sample, lambda)?;
+    let n = sample.len() as f64;
+    let ln_sum = sample.iter().map(|x| x.ln()).sum::<f64>();
+
+    Ok(-n / 2. * biased_variance(&transformed).ln() + (lambda - 1.) * ln_sum)
+}
+
+/// `λ` maximizing the profile log-likelihood over `[-5, 5]`.
+pub fn box_cox_lambda(sample: &[f64]) -> Result<f64, TransformError> {
+    let logarithms = log(sample)?;
+    if !logarithms.iter().any(|ln_x| *ln_x != logarithms[0]) {
+        return Err(TransformError::SampleDegenerate);
+    }
+
+    let likelihood = |lambda: f64| box_cox_log_likelihood(sample, lambda).unwrap_or(f64::NAN);
+    let (low, high) = LAMBDA_RANGE;
+    let step = (high - low) / LAMBDA_GRID_POINTS as f64;
+    let best = (0..=LAMBDA_GRID_POINTS)
+        .map(|i| low + i as f64 * step)
+        .max_by(|a, b| likelihood(*a).total_cmp(&likelihood(*b)))
+        .unwrap_or(1.);
+
+    // Golden-section search within the neighbouring grid cells.
+    let ratio = (5f64.sqrt() - 1.) / 2.;
+    let (mut a, mut b) = ((best - step).max(low), (best + step).min(high));
+    while b - a > 1e-10 {
+        let (c, d) = (b - ratio * (b - a), a + ratio * (b - a));
+        if likelihood(c) > likelihood(d) {
+            b = d;
+        } else {
+            a = c;
+        }
+    }
+
+    Ok((a + b) / 2.)
+}
+
+/// Average ranks starting from 1.
+pub fn ranks(sample: &[f64]) -> Vec<f64> {
+    average_ranks(sample).0
+}
+
+/// Blom's normal scores of the average ranks, which keep the ties tied.
+pub fn rank_normal_scores(sample: &[f64]) -> Vec<f64> {
+    let n = sample.len() as f64;
+    let standard_normal = Normal::standard();
+
+    ranks(sample)
+        .iter()
+        .map(|rank| standard_normal.inverse_cdf((rank - 0.375) / (n + 0.25)))
+        .collect()
+}
+
+/// Checks the normality of the sample after every candidate transform that applies to it;
+/// the outcomes whose `normal` is `true` are the transforms that achieve normality.
+///
+/// Transforms whose domain excludes the sample, or whose normality check fails, are skipped;
+/// the outcomes keep the order of the candidates.
+pub fn normalizing_transforms(
+    sample: &[f64],
+    candidates: &[Transform],
+    normality_test: NormalityTest,
+    significance: f64,
+) -> Vec<TransformOutcome> {
+    candidates
+        .iter()
+        .filter_map(|transform| {
+            transform
+                .check_normality(sample, normality_test, significance)
+                .ok()
+        })
+        .collect()
+}
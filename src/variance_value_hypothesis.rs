@@ -0,0 +1,189 @@
+//! Algorithm for solving problems of the following kind.
+//!
+//! **Given**: *significance ratio*, *sample of a normal random variable **X***, *hypothetical variance* `σ₀²`.
+//! **To figure out**: Is it appropriate to **assume** `Var(X) = σ₀²`?
+//!
+//! The statistic `χ² = (n - 1) s² / σ₀²` with the unbiased sample variance `s²` is **Chi-Squared** with
+//! `n - 1` freedom degrees under `H₀`. Against `σ² ≠ σ₀²` the critical region is two-tailed, with
+//! the critical values `χ²(1 - α/2)` and `χ²(α/2)`; against `σ² > σ₀²` or `σ² < σ₀²` it is the upper
+//! or the lower tail.
+
+use statrs::distribution::{ChiSquared, ContinuousCDF};
+
+use crate::alpha;
+use crate::critical_region::CriticalRegion;
+use crate::critical_values::{CriticalValueError, CriticalValueSource};
+use crate::hypotheses::{Alternative, Hypotheses};
+use crate::missing::MissingPolicy;
+use crate::numeric::unbiased_variance;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum VarianceValueError {
+    SignificanceInvalid,
+    SampleTooSmall,
+    FreedomDegreesInvalid,
+    VarianceInvalid,
+    CriticalValueNotTabulated,
+    ValueMissing,
+    StatisticNotFinite,
+}
+
+impl std::fmt::Display for VarianceValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            VarianceValueError::SignificanceInvalid => {
+                write!(f, "Significance must be between 0.0 and 1.0")
+            }
+            VarianceValueError::SampleTooSmall => {
+                write!(f, "Sample must contain at least two values")
+            }
+            VarianceValueError::FreedomDegreesInvalid => {
+                write!(
+                    f,
+                    "Freedom Degrees led to fail in initialization of Gamma underlying Chi"
+                )
+            }
+            VarianceValueError::VarianceInvalid => {
+                write!(f, "Hypothetical variance must be positive and finite")
+            }
+            VarianceValueError::CriticalValueNotTabulated => {
+                write!(
+                    f,
+                    "Standard tables hold no critical value for these freedom degrees and significance"
+                )
+            }
+            VarianceValueError::ValueMissing => {
+                write!(f, "Sample contains missing (NaN) values")
+            }
+            VarianceValueError::StatisticNotFinite => {
+                write!(f, "Chi-Squared statistic is not finite for this sample")
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VarianceValueOutcome {
+    /// Unbiased sample variance `s²`.
+    pub variance: f64,
+    pub freedom_degrees: f64,
+    pub chi_squared_observed: f64,
+    /// Both critical values if the region is two-tailed.
+    pub critical_region: CriticalRegion,
+    pub p_value: f64,
+    pub hypotheses: Hypotheses,
+    pub accepted: bool,
+}
+
+pub struct VarianceValueHypothesis {
+    sample: Vec<f64>,
+    variance: f64,
+    significance: f64,
+    alternative: Alternative,
+    critical_value_source: CriticalValueSource,
+}
+
+impl VarianceValueHypothesis {
+    /// `H₀: Var(X) = variance` against `Var(X) ≠ variance`.
+    pub fn new(
+        sample: &[f64],
+        variance: f64,
+        significance: f64,
+    ) -> Result<Self, VarianceValueError> {
+        MissingPolicy::Error
+            .apply(sample)
+            .map_err(|_| VarianceValueError::ValueMissing)?;
+
+        if sample.len() < 2 {
+            return Err(VarianceValueError::SampleTooSmall);
+        }
+
+        if variance.is_nan() || variance <= 0. || variance.is_infinite() {
+            return Err(VarianceValueError::VarianceInvalid);
+        }
+
+        alpha::validate(significance, VarianceValueError::SignificanceInvalid)?;
+
+        Ok(Self {
+            sample: sample.to_owned(),
+            variance,
+            significance,
+            alternative: Alternative::TwoSided,
+            critical_value_source: CriticalValueSource::Quantile,
+        })
+    }
+
+    pub fn with_alternative(mut self, alternative: Alternative) -> Self {
+        self.alternative = alternative;
+        self
+    }
+
+    /// Chooses whether the critical values are computed or looked up in the standard tables.
+    pub fn with_critical_value_source(mut self, source: CriticalValueSource) -> Self {
+        self.critical_value_source = source;
+        self
+    }
+
+    pub fn hypotheses(&self) -> Hypotheses {
+        let sign = match self.alternative {
+            Alternative::TwoSided => "≠",
+            Alternative::Greater => ">",
+            Alternative::Less => "<",
+        };
+
+        Hypotheses::new(
+            format!("σ²(X) = {}", self.variance),
+            format!("σ²(X) {sign} {}", self.variance),
+            self.alternative,
+        )
+    }
+
+    pub fn solve(&self) -> Result<bool, VarianceValueError> {
+        Ok(self.outcome()?.accepted)
+    }
+
+    pub fn outcome(&self) -> Result<VarianceValueOutcome, VarianceValueError> {
+        let freedom_degrees = self.sample.len() as f64 - 1.;
+        let variance = unbiased_variance(&self.sample);
+        let chi_squared_observed = freedom_degrees * variance / self.variance;
+        if !chi_squared_observed.is_finite() {
+            return Err(VarianceValueError::StatisticNotFinite);
+        }
+
+        let critical_region = CriticalRegion::chi_squared(
+            freedom_degrees,
+            self.significance,
+            self.alternative,
+            self.critical_value_source,
+        )
+        .map_err(|e| match e {
+            CriticalValueError::SignificanceInvalid => VarianceValueError::SignificanceInvalid,
+            CriticalValueError::FreedomDegreesInvalid => VarianceValueError::FreedomDegreesInvalid,
+            CriticalValueError::NotTabulated => VarianceValueError::CriticalValueNotTabulated,
+        })?;
+
+        // At least one freedom degree is valid.
+        let chi_squared = ChiSquared::new(freedom_degrees).unwrap();
+        let (lower_tail, upper_tail) = (
+            chi_squared.cdf(chi_squared_observed),
+            chi_squared.sf(chi_squared_observed),
+        );
+        let p_value = match self.alternative {
+            Alternative::TwoSided => (2. * lower_tail.min(upper_tail)).min(1.),
+            Alternative::Greater => upper_tail,
+            Alternative::Less => lower_tail,
+        };
+
+        Ok(VarianceValueOutcome {
+            variance,
+            freedom_degrees,
+            chi_squared_observed,
+            critical_region,
+            p_value,
+            hypotheses: self.hypotheses(),
+            accepted: !critical_region.contains(chi_squared_observed),
+        })
+    }
+}
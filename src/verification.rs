@@ -0,0 +1,270 @@
+//! Self-checks of the tests against known distributions.
+//!
+//! Each function simulates samples under `H₀` and counts how often the test rejects it,
+//! which estimates the type-I error rate; a correct test rejects in about `α` of the replications.
+//!
+//! Known departures:
+//! - the Same Variance Hypothesis puts the larger variance into the numerator and compares it with
+//!   the upper `α` point, so it rejects equal variances in about `2α` of the replications;
+//! - the Normal Distribution Hypothesis over a raw sample grouped by Sturges' rule is liberal,
+//!   since the tail classes have small expected frequencies: it rejects in about `2α` of the replications
+//!   for a hundred or more observations, and stays below `3α`.
+//!
+//! ```ignore
+//! let estimate = verification::same_mean(10, 15, Variances::Equal, 0.05, 10_000, RngSource::Seed(1))?;
+//! assert!(estimate.deviation(0.05).abs() < 4.);
+//! ```
+
+use rand::{Rng, RngCore};
+use statrs::distribution::Normal;
+
+use crate::contingency::{ContingencyError, IndependenceHypothesis};
+use crate::cramer_von_mises_hypothesis::{CramerVonMisesHypothesis, CvMError};
+use crate::mann_whitney_hypothesis::{MWHError, MannWhitneyHypothesis};
+use crate::normal_distribution_hypothesis::{
+    IncompleteNDHProblemSituation, NDHError, NormalDistributionHypothesis,
+};
+use crate::rng::RngSource;
+use crate::same_mean_hypothesis::{SMHError, SameMeanHypothesis, Variances};
+use crate::same_variance_hypothesis::{SVHError, SameVarianceHypothesis};
+use crate::shapiro_wilk_hypothesis::{SWError, ShapiroWilkHypothesis};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum VerificationError {
+    ReplicationsInvalid,
+    NormalDistribution(NDHError),
+    ShapiroWilk(SWError),
+    SameVariance(SVHError),
+    SameMean(SMHError),
+    MannWhitney(MWHError),
+    CramerVonMises(CvMError),
+    Contingency(ContingencyError),
+}
+
+impl std::fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            VerificationError::ReplicationsInvalid => {
+                write!(f, "At least one replication is required")
+            }
+            VerificationError::NormalDistribution(e) => {
+                write!(f, "Normal Distribution Hypothesis failed: {e}")
+            }
+            VerificationError::ShapiroWilk(e) => {
+                write!(f, "Shapiro–Wilk test failed: {e}")
+            }
+            VerificationError::SameVariance(e) => {
+                write!(f, "Same Variance Hypothesis failed: {e}")
+            }
+            VerificationError::SameMean(e) => {
+                write!(f, "Same Mean Hypothesis failed: {e}")
+            }
+            VerificationError::MannWhitney(e) => {
+                write!(f, "Mann–Whitney test failed: {e}")
+            }
+            VerificationError::CramerVonMises(e) => {
+                write!(f, "Cramér–von Mises test failed: {e}")
+            }
+            VerificationError::Contingency(e) => {
+                write!(f, "Independence test failed: {e}")
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct TypeOneErrorEstimate {
+    pub rejections: usize,
+    pub replications: usize,
+    /// Share of the replications in which `H₀` was rejected.
+    pub rate: f64,
+}
+
+impl TypeOneErrorEstimate {
+    /// Deviation of the rate from `significance` in standard errors of the binomial proportion,
+    /// approximately Standard Normal for a test of exact size `significance`.
+    pub fn deviation(&self, significance: f64) -> f64 {
+        let standard_error = (significance * (1. - significance) / self.replications as f64).sqrt();
+        (self.rate - significance) / standard_error
+    }
+}
+
+/// Runs `replications` times the `rejects` simulation, which draws samples under `H₀` from the generator
+/// and tells whether the test rejected `H₀`.
+pub fn type_one_error_rate(
+    replications: usize,
+    rng: &RngSource,
+    mut rejects: impl FnMut(&mut dyn RngCore) -> Result<bool, VerificationError>,
+) -> Result<TypeOneErrorEstimate, VerificationError> {
+    if replications == 0 {
+        return Err(VerificationError::ReplicationsInvalid);
+    }
+
+    let rejections = rng.with_rng(|rng| {
+        (0..replications).try_fold(0, |rejections, _| {
+            Ok::<_, VerificationError>(rejections + usize::from(rejects(rng)?))
+        })
+    })?;
+
+    Ok(TypeOneErrorEstimate {
+        rejections,
+        replications,
+        rate: rejections as f64 / replications as f64,
+    })
+}
+
+fn standard_normal_sample(rng: &mut dyn RngCore, size: usize) -> Vec<f64> {
+    let standard_normal = Normal::standard();
+    (0..size).map(|_| rng.sample(standard_normal)).collect()
+}
+
+/// Normal Distribution Hypothesis over Standard Normal samples grouped by Sturges' rule.
+pub fn normal_distribution(
+    sample_size: usize,
+    significance: f64,
+    replications: usize,
+    rng: RngSource,
+) -> Result<TypeOneErrorEstimate, VerificationError> {
+    type_one_error_rate(replications, &rng, |rng| {
+        let sample = standard_normal_sample(rng, sample_size);
+        IncompleteNDHProblemSituation::from_raw_sample(&sample, significance)
+            .and_then(|situation| NormalDistributionHypothesis::new(Box::new(situation)))
+            .and_then(|ndh| ndh.solve())
+            .map(|accepted| !accepted)
+            .map_err(VerificationError::NormalDistribution)
+    })
+}
+
+/// Shapiro–Wilk test over Standard Normal samples.
+pub fn shapiro_wilk(
+    sample_size: usize,
+    significance: f64,
+    replications: usize,
+    rng: RngSource,
+) -> Result<TypeOneErrorEstimate, VerificationError> {
+    type_one_error_rate(replications, &rng, |rng| {
+        let sample = standard_normal_sample(rng, sample_size);
+        ShapiroWilkHypothesis::new(&sample, significance)
+            .and_then(|swh| swh.solve())
+            .map(|accepted| !accepted)
+            .map_err(VerificationError::ShapiroWilk)
+    })
+}
+
+/// Same Variance Hypothesis over two Standard Normal samples.
+pub fn same_variance(
+    x_size: usize,
+    y_size: usize,
+    significance: f64,
+    replications: usize,
+    rng: RngSource,
+) -> Result<TypeOneErrorEstimate, VerificationError> {
+    type_one_error_rate(replications, &rng, |rng| {
+        let (x_sample, y_sample) = (
+            standard_normal_sample(rng, x_size),
+            standard_normal_sample(rng, y_size),
+        );
+        SameVarianceHypothesis::new(&x_sample, &y_sample, significance)
+            .solve()
+            .map(|accepted| !accepted)
+            .map_err(VerificationError::SameVariance)
+    })
+}
+
+/// Same Mean Hypothesis over two Standard Normal samples.
+pub fn same_mean(
+    x_size: usize,
+    y_size: usize,
+    variances: Variances,
+    significance: f64,
+    replications: usize,
+    rng: RngSource,
+) -> Result<TypeOneErrorEstimate, VerificationError> {
+    type_one_error_rate(replications, &rng, |rng| {
+        let (x_sample, y_sample) = (
+            standard_normal_sample(rng, x_size),
+            standard_normal_sample(rng, y_size),
+        );
+        SameMeanHypothesis::new(&x_sample, &y_sample, variances, significance)
+            .and_then(|smh| smh.solve())
+            .map(|accepted| !accepted)
+            .map_err(VerificationError::SameMean)
+    })
+}
+
+/// Mann–Whitney U test over two Standard Normal samples.
+pub fn mann_whitney(
+    x_size: usize,
+    y_size: usize,
+    significance: f64,
+    replications: usize,
+    rng: RngSource,
+) -> Result<TypeOneErrorEstimate, VerificationError> {
+    type_one_error_rate(replications, &rng, |rng| {
+        let (x_sample, y_sample) = (
+            standard_normal_sample(rng, x_size),
+            standard_normal_sample(rng, y_size),
+        );
+        MannWhitneyHypothesis::new(&x_sample, &y_sample, significance)
+            .and_then(|mwh| mwh.solve())
+            .map(|accepted| !accepted)
+            .map_err(VerificationError::MannWhitney)
+    })
+}
+
+/// Two-sample Cramér–von Mises test over two Standard Normal samples.
+pub fn cramer_von_mises(
+    x_size: usize,
+    y_size: usize,
+    significance: f64,
+    replications: usize,
+    rng: RngSource,
+) -> Result<TypeOneErrorEstimate, VerificationError> {
+    type_one_error_rate(replications, &rng, |rng| {
+        let (x_sample, y_sample) = (
+            standard_normal_sample(rng, x_size),
+            standard_normal_sample(rng, y_size),
+        );
+        CramerVonMisesHypothesis::new(&x_sample, &y_sample, significance)
+            .and_then(|cvm| cvm.solve())
+            .map(|accepted| !accepted)
+            .map_err(VerificationError::CramerVonMises)
+    })
+}
+
+/// Chi-Squared test of independence over `rows × columns` tables of `total` observations
+/// falling into the cells with equal probabilities; tables with an empty row or column are redrawn,
+/// which needs `total >= max(rows, columns)`.
+pub fn independence(
+    rows: usize,
+    columns: usize,
+    total: usize,
+    significance: f64,
+    replications: usize,
+    rng: RngSource,
+) -> Result<TypeOneErrorEstimate, VerificationError> {
+    type_one_error_rate(replications, &rng, |rng| {
+        loop {
+            let mut table = vec![vec![0.; columns]; rows];
+            for _ in 0..total {
+                table[rng.gen_range(0..rows)][rng.gen_range(0..columns)] += 1.;
+            }
+
+            match IndependenceHypothesis::new(&table, significance) {
+                Ok(independence) => {
+                    return independence
+                        .solve()
+                        .map(|accepted| !accepted)
+                        .map_err(VerificationError::Contingency);
+                }
+                Err(ContingencyError::TableInvalid)
+                    if rows >= 2 && columns >= 2 && total >= rows.max(columns) =>
+                {
+                    continue;
+                }
+                Err(e) => return Err(VerificationError::Contingency(e)),
+            }
+        }
+    })
+}
@@ -0,0 +1,198 @@
+//! WebAssembly API for a browser-based homework checker: arrays in, JSON-like objects out.
+//!
+//! Every function returns the outcome of the test as a plain JavaScript object, or throws an `Error`
+//! with the message of the Rust error. Random value ranges are passed as the `k + 1` edges of the `k` ranges,
+//! and contingency tables as a row-major flat array with the number of rows.
+//!
+//! ```js
+//! import init, { ndhGrouped } from "statistics_problems";
+//!
+//! await init();
+//! const outcome = ndhGrouped([22, 24, 26, 28, 30, 32, 34], [2, 12, 34, 40, 10, 2], 0.01);
+//! console.log(outcome.hypotheses.null, outcome.accepted);
+//! ```
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::contingency::IndependenceHypothesis;
+use crate::hypotheses::Hypotheses;
+use crate::mann_whitney_hypothesis::MannWhitneyHypothesis;
+use crate::normal_distribution_hypothesis::{
+    CompleteNDHProblemSituation, IncompleteNDHProblemSituation, NDHError, NDHProblemSituation,
+    NormalDistributionHypothesis,
+};
+use crate::same_mean_hypothesis::{SameMeanHypothesis, Variances};
+use crate::same_variance_hypothesis::SameVarianceHypothesis;
+use crate::shapiro_wilk_hypothesis::ShapiroWilkHypothesis;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SameMeanOutcome {
+    t: f64,
+    freedom_degrees: f64,
+    hypotheses: Hypotheses,
+    accepted: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MannWhitneyOutcome {
+    u: f64,
+    z: f64,
+    hypotheses: Hypotheses,
+    accepted: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ShapiroWilkOutcome {
+    w: f64,
+    p_value: f64,
+    hypotheses: Hypotheses,
+    accepted: bool,
+}
+
+fn js_error(e: impl std::fmt::Display) -> JsError {
+    JsError::new(&e.to_string())
+}
+
+fn to_js(value: &impl Serialize) -> Result<JsValue, JsError> {
+    serde_wasm_bindgen::to_value(value).map_err(js_error)
+}
+
+fn ndh_outcome(situation: Box<dyn NDHProblemSituation>) -> Result<JsValue, JsError> {
+    let outcome = NormalDistributionHypothesis::new(situation)
+        .and_then(|ndh| ndh.outcome())
+        .map_err(js_error)?;
+
+    to_js(&outcome)
+}
+
+#[wasm_bindgen(js_name = ndhComplete)]
+pub fn ndh_complete(
+    empirical_sample: &[f64],
+    theoretical_sample: &[f64],
+    significance: f64,
+) -> Result<JsValue, JsError> {
+    let situation =
+        CompleteNDHProblemSituation::new(empirical_sample, theoretical_sample, significance)
+            .map_err(js_error)?;
+
+    ndh_outcome(Box::new(situation))
+}
+
+#[wasm_bindgen(js_name = ndhGrouped)]
+pub fn ndh_grouped(
+    edges: &[f64],
+    frequencies: &[f64],
+    significance: f64,
+) -> Result<JsValue, JsError> {
+    if edges.len() != frequencies.len() + 1 {
+        return Err(js_error(NDHError::NonEqualSamplesLengths));
+    }
+
+    let random_value_ranges: Vec<_> = edges.windows(2).map(|edge| (edge[0], edge[1])).collect();
+    let situation =
+        IncompleteNDHProblemSituation::new(&random_value_ranges, frequencies, significance)
+            .map_err(js_error)?;
+
+    ndh_outcome(Box::new(situation))
+}
+
+/// Groups the raw sample by Sturges' rule.
+#[wasm_bindgen(js_name = ndhRaw)]
+pub fn ndh_raw(sample: &[f64], significance: f64) -> Result<JsValue, JsError> {
+    let situation =
+        IncompleteNDHProblemSituation::from_raw_sample(sample, significance).map_err(js_error)?;
+
+    ndh_outcome(Box::new(situation))
+}
+
+#[wasm_bindgen(js_name = sameVariance)]
+pub fn same_variance(
+    x_sample: &[f64],
+    y_sample: &[f64],
+    significance: f64,
+) -> Result<JsValue, JsError> {
+    let outcome = SameVarianceHypothesis::new(x_sample, y_sample, significance)
+        .outcome()
+        .map_err(js_error)?;
+
+    to_js(&outcome)
+}
+
+/// Student's t-test if `equalVariances`, Welch's t-test otherwise.
+#[wasm_bindgen(js_name = sameMean)]
+pub fn same_mean(
+    x_sample: &[f64],
+    y_sample: &[f64],
+    significance: f64,
+    equal_variances: bool,
+) -> Result<JsValue, JsError> {
+    let variances = if equal_variances {
+        Variances::Equal
+    } else {
+        Variances::Unequal
+    };
+    let smh =
+        SameMeanHypothesis::new(x_sample, y_sample, variances, significance).map_err(js_error)?;
+    let (t, freedom_degrees) = smh.statistic();
+
+    to_js(&SameMeanOutcome {
+        t,
+        freedom_degrees,
+        hypotheses: smh.hypotheses(),
+        accepted: smh.solve().map_err(js_error)?,
+    })
+}
+
+#[wasm_bindgen(js_name = mannWhitney)]
+pub fn mann_whitney(
+    x_sample: &[f64],
+    y_sample: &[f64],
+    significance: f64,
+) -> Result<JsValue, JsError> {
+    let mwh = MannWhitneyHypothesis::new(x_sample, y_sample, significance).map_err(js_error)?;
+    let (u, z) = mwh.statistic().map_err(js_error)?;
+
+    to_js(&MannWhitneyOutcome {
+        u,
+        z,
+        hypotheses: mwh.hypotheses(),
+        accepted: mwh.solve().map_err(js_error)?,
+    })
+}
+
+#[wasm_bindgen(js_name = shapiroWilk)]
+pub fn shapiro_wilk(sample: &[f64], significance: f64) -> Result<JsValue, JsError> {
+    let swh = ShapiroWilkHypothesis::new(sample, significance).map_err(js_error)?;
+    let (w, p_value) = swh.statistic();
+
+    to_js(&ShapiroWilkOutcome {
+        w,
+        p_value,
+        hypotheses: swh.hypotheses(),
+        accepted: swh.solve().map_err(js_error)?,
+    })
+}
+
+/// `table` holds the frequencies row by row.
+#[wasm_bindgen]
+pub fn independence(table: &[f64], rows: usize, significance: f64) -> Result<JsValue, JsError> {
+    if rows == 0 || table.is_empty() || !table.len().is_multiple_of(rows) {
+        return Err(JsError::new(
+            "Table size must be a multiple of the number of rows",
+        ));
+    }
+
+    let table: Vec<_> = table
+        .chunks(table.len() / rows)
+        .map(<[f64]>::to_vec)
+        .collect();
+    let outcome = IndependenceHypothesis::new(&table, significance)
+        .and_then(|independence| independence.outcome())
+        .map_err(js_error)?;
+
+    to_js(&outcome)
+}
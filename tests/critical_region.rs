@@ -0,0 +1,93 @@
+//! The critical regions of every alternative match the printed tables.
+
+use statistics_problems::critical_region::CriticalRegion;
+use statistics_problems::critical_values::CriticalValueSource;
+use statistics_problems::hypotheses::Alternative;
+use statistics_problems::same_mean_hypothesis::{SameMeanHypothesis, Variances};
+
+const TOLERANCE: f64 = 1e-3;
+
+fn assert_region(actual: CriticalRegion, expected: CriticalRegion) {
+    let close = |a: Option<f64>, b: Option<f64>| match (a, b) {
+        (Some(a), Some(b)) => (a - b).abs() < TOLERANCE,
+        (a, b) => a == b,
+    };
+    assert!(
+        close(actual.lower(), expected.lower()) && close(actual.upper(), expected.upper()),
+        "{actual:?} differs from {expected:?}"
+    );
+}
+
+#[test]
+fn chi_squared_regions_match_the_table() {
+    let region = |alternative| {
+        CriticalRegion::chi_squared(10., 0.05, alternative, CriticalValueSource::Quantile).unwrap()
+    };
+
+    assert_region(region(Alternative::Greater), CriticalRegion::Upper(18.307));
+    assert_region(region(Alternative::Less), CriticalRegion::Lower(3.940));
+    assert_region(
+        region(Alternative::TwoSided),
+        CriticalRegion::TwoTailed(3.247, 20.483),
+    );
+}
+
+#[test]
+fn students_t_regions_match_the_table() {
+    for source in [CriticalValueSource::Quantile, CriticalValueSource::Table] {
+        let region =
+            |alternative| CriticalRegion::students_t(10., 0.05, alternative, source).unwrap();
+
+        assert_region(region(Alternative::Greater), CriticalRegion::Upper(1.812));
+        assert_region(region(Alternative::Less), CriticalRegion::Lower(-1.812));
+        assert_region(
+            region(Alternative::TwoSided),
+            CriticalRegion::TwoTailed(-2.228, 2.228),
+        );
+    }
+}
+
+#[test]
+fn fisher_snedecor_regions_match_the_table() {
+    let region = |alternative| {
+        CriticalRegion::fisher_snedecor(5., 10., 0.05, alternative, CriticalValueSource::Quantile)
+            .unwrap()
+    };
+
+    // `F(0.95; 5, 10) = 1 / F(0.05; 10, 5) = 1 / 4.735`.
+    assert_region(region(Alternative::Greater), CriticalRegion::Upper(3.326));
+    assert_region(region(Alternative::Less), CriticalRegion::Lower(0.2112));
+    // `F(0.975; 5, 10) = 1 / F(0.025; 10, 5) = 1 / 6.619`.
+    assert_region(
+        region(Alternative::TwoSided),
+        CriticalRegion::TwoTailed(0.1511, 4.236),
+    );
+}
+
+#[test]
+fn one_sided_t_test_rejects_in_its_tail_only() {
+    let x_sample = [5.9, 6.4, 6.1, 6.8, 6.3, 5.7, 6.6, 6.2];
+    let y_sample = [5.6, 6.0, 5.8, 6.3, 5.5, 6.1, 5.9, 5.7];
+    let outcome = |alternative| {
+        SameMeanHypothesis::new(&x_sample, &y_sample, Variances::Equal, 0.05)
+            .unwrap()
+            .with_alternative(alternative)
+            .outcome()
+            .unwrap()
+    };
+
+    let greater = outcome(Alternative::Greater);
+    assert_region(greater.critical_region, CriticalRegion::Upper(1.761));
+    assert!(!greater.accepted);
+
+    let less = outcome(Alternative::Less);
+    assert_region(less.critical_region, CriticalRegion::Lower(-1.761));
+    assert!(less.accepted);
+
+    let two_sided = outcome(Alternative::TwoSided);
+    assert_region(
+        two_sided.critical_region,
+        CriticalRegion::TwoTailed(-2.145, 2.145),
+    );
+    assert_eq!(two_sided.accepted, two_sided.t_observed.abs() < 2.145);
+}
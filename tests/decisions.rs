@@ -0,0 +1,157 @@
+//! The `no_std` decisions of the core crate must agree with the hypotheses of this crate.
+
+use statistics_problems::contingency::IndependenceHypothesis;
+use statistics_problems::critical_values::StatrsQuantiles;
+use statistics_problems::normal_distribution_hypothesis::{
+    IncompleteNDHProblemSituation, NormalDistributionHypothesis,
+};
+use statistics_problems::same_mean_hypothesis::{SameMeanHypothesis, Variances};
+use statistics_problems::same_variance_hypothesis::SameVarianceHypothesis;
+use statistics_problems_core::decisions::{self, Decision};
+
+const TOLERANCE: f64 = 1e-9;
+const SIGNIFICANCES: [f64; 3] = [0.01, 0.05, 0.1];
+
+const X_SAMPLES: [&[f64]; 3] = [
+    &[4.2, 5.1, 3.8, 4.9, 5.5, 4.4, 4.7],
+    &[12., 15., 11., 14., 18., 13.],
+    &[0.31, 0.29, 0.35, 0.28, 0.33, 0.30, 0.34, 0.27],
+];
+const Y_SAMPLES: [&[f64]; 3] = [
+    &[5.0, 6.3, 5.8, 6.9, 5.4, 6.1],
+    &[9., 22., 4., 17., 25., 8., 13.],
+    &[0.30, 0.32, 0.29, 0.31, 0.30],
+];
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < TOLERANCE * expected.abs().max(1.),
+        "{actual} differs from {expected}"
+    );
+}
+
+fn assert_agree(decision: Decision, statistic: f64, critical_value: f64, accepted: bool) {
+    assert_close(decision.statistic, statistic);
+    assert_close(decision.critical_value, critical_value);
+    assert_eq!(decision.accepted, accepted);
+}
+
+#[test]
+fn normality_agrees() {
+    let ranges = [(0., 2.), (2., 4.), (4., 6.), (6., 8.), (8., 10.)];
+    for frequencies in [
+        [6., 18., 35., 27., 14.],
+        [10., 30., 40., 15., 5.],
+        [30., 10., 5., 10., 30.],
+    ] {
+        for significance in SIGNIFICANCES {
+            let situation =
+                IncompleteNDHProblemSituation::new(&ranges, &frequencies, significance).unwrap();
+            let outcome = NormalDistributionHypothesis::new(Box::new(situation))
+                .unwrap()
+                .outcome()
+                .unwrap();
+            let decision =
+                decisions::normality(&ranges, &frequencies, significance, &StatrsQuantiles)
+                    .unwrap();
+
+            assert_agree(
+                decision,
+                outcome.chi_squared_observed,
+                outcome.chi_squared_critical_value,
+                outcome.accepted,
+            );
+        }
+    }
+}
+
+#[test]
+fn same_variance_agrees() {
+    for (x_sample, y_sample) in X_SAMPLES.into_iter().zip(Y_SAMPLES) {
+        for significance in SIGNIFICANCES {
+            let outcome = SameVarianceHypothesis::new(x_sample, y_sample, significance)
+                .outcome()
+                .unwrap();
+            let decision =
+                decisions::same_variance(x_sample, y_sample, significance, &StatrsQuantiles)
+                    .unwrap();
+
+            assert_agree(
+                decision,
+                outcome.fisher_snedecor_observed,
+                outcome.fisher_snedecor_critical_value,
+                outcome.accepted,
+            );
+        }
+    }
+}
+
+#[test]
+fn variance_ratio_agrees() {
+    for (x_sample, y_sample) in X_SAMPLES.into_iter().zip(Y_SAMPLES) {
+        for ratio in [0.5, 2., 10.] {
+            let outcome = SameVarianceHypothesis::new(x_sample, y_sample, 0.05)
+                .with_ratio(ratio)
+                .unwrap()
+                .outcome()
+                .unwrap();
+            let decision =
+                decisions::variance_ratio(x_sample, y_sample, ratio, 0.05, &StatrsQuantiles)
+                    .unwrap();
+
+            assert_agree(
+                decision,
+                outcome.fisher_snedecor_observed,
+                outcome.fisher_snedecor_critical_value,
+                outcome.accepted,
+            );
+        }
+    }
+}
+
+#[test]
+fn same_mean_agrees() {
+    for (x_sample, y_sample) in X_SAMPLES.into_iter().zip(Y_SAMPLES) {
+        for variances in [Variances::Equal, Variances::Unequal] {
+            for significance in SIGNIFICANCES {
+                let hypothesis =
+                    SameMeanHypothesis::new(x_sample, y_sample, variances, significance).unwrap();
+                let decision = decisions::same_mean(
+                    x_sample,
+                    y_sample,
+                    variances == Variances::Equal,
+                    significance,
+                    &StatrsQuantiles,
+                )
+                .unwrap();
+
+                assert_close(decision.statistic, hypothesis.statistic().0);
+                assert_eq!(decision.accepted, hypothesis.solve().unwrap());
+            }
+        }
+    }
+}
+
+#[test]
+fn independence_agrees() {
+    for table in [
+        vec![vec![20., 15.], vec![30., 35.]],
+        vec![vec![12., 30., 8.], vec![25., 10., 15.], vec![5., 9., 26.]],
+        vec![vec![40., 10., 20., 30.], vec![38., 12., 21., 29.]],
+    ] {
+        for significance in SIGNIFICANCES {
+            let outcome = IndependenceHypothesis::new(&table, significance)
+                .unwrap()
+                .outcome()
+                .unwrap();
+            let decision = decisions::independence(&table, significance, &StatrsQuantiles).unwrap();
+
+            assert_agree(
+                decision,
+                outcome.chi_squared_observed,
+                outcome.chi_squared_critical_value,
+                outcome.accepted,
+            );
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! The theoretical frequency table must agree with the fitted distribution and with the Normal Distribution Hypothesis.
+
+use statistics_problems::fit::{Estimate, Family, Fit, Method, frequency_table};
+use statistics_problems::normal_distribution_hypothesis::{
+    IncompleteNDHProblemSituation, NDHProblemSituation,
+};
+
+const TOLERANCE: f64 = 1e-9;
+
+fn fitted(family: Family, sample_size: f64, parameters: &[(&'static str, f64)]) -> Fit {
+    Fit {
+        family,
+        method: Method::MaximumLikelihood,
+        sample_size,
+        parameters: parameters
+            .iter()
+            .map(|&(name, value)| Estimate {
+                name,
+                value,
+                standard_error: 0.,
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn standard_normal_table_matches_the_laplace_function() {
+    let fit = fitted(Family::Normal, 200., &[("μ", 0.), ("σ", 1.)]);
+    let table = frequency_table(&fit, &[(-1., 0.), (0., 1.), (1., 2.)]);
+
+    let probabilities = [
+        0.341_344_746_068_542_9,
+        0.341_344_746_068_542_9,
+        0.135_905_121_983_278_3,
+    ];
+    let cumulative_probabilities = [0.5, 0.841_344_746_068_542_9, 0.977_249_868_051_820_8];
+    let mut cumulative_expected_frequency = 0.;
+    for ((row, probability), cumulative_probability) in table
+        .iter()
+        .zip(probabilities)
+        .zip(cumulative_probabilities)
+    {
+        cumulative_expected_frequency += 200. * probability;
+        assert!((row.probability - probability).abs() < TOLERANCE);
+        assert!((row.cumulative_probability - cumulative_probability).abs() < TOLERANCE);
+        assert!((row.expected_frequency - 200. * probability).abs() < 1e3 * TOLERANCE);
+        assert!(
+            (row.cumulative_expected_frequency - cumulative_expected_frequency).abs()
+                < 1e3 * TOLERANCE
+        );
+    }
+}
+
+#[test]
+fn table_gives_the_theoretical_sample_of_the_normal_distribution_hypothesis() {
+    let ranges = [(0., 2.), (2., 4.), (4., 6.), (6., 8.), (8., 10.)];
+    let frequencies = [6., 18., 35., 27., 14.];
+    let situation = IncompleteNDHProblemSituation::new(&ranges, &frequencies, 0.05).unwrap();
+
+    let table = frequency_table(&situation.fit().unwrap(), &ranges);
+    for (row, expected) in table.iter().zip(situation.theoretical_sample()) {
+        assert_eq!(row.expected_frequency, expected);
+    }
+}
+
+#[test]
+fn moments_agree_across_families() {
+    let exponential = fitted(Family::Exponential, 10., &[("λ", 0.5)]);
+    let weibull = fitted(Family::Weibull, 10., &[("k", 1.), ("λ", 2.)]);
+    assert!((exponential.mean() - 2.).abs() < TOLERANCE);
+    assert!((weibull.mean() - exponential.mean()).abs() < TOLERANCE);
+    assert!((weibull.variance() - exponential.variance()).abs() < 1e-6);
+
+    let binomial = fitted(Family::Binomial { trials: 20 }, 10., &[("p", 0.3)]);
+    assert!((binomial.mean() - 6.).abs() < TOLERANCE);
+    assert!((binomial.variance() - 4.2).abs() < TOLERANCE);
+}
@@ -0,0 +1,15 @@
+//! The textbook collection must keep being solved as printed.
+
+use statistics_problems::golden;
+
+#[test]
+fn textbook_problems_are_solved_as_printed() {
+    let problems = golden::textbook_problems().unwrap();
+    assert!(!problems.is_empty());
+
+    let failed: Vec<_> = golden::run(&problems)
+        .into_iter()
+        .filter(|outcome| !outcome.passed)
+        .collect();
+    assert!(failed.is_empty(), "{failed:#?}");
+}
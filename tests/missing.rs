@@ -0,0 +1,65 @@
+//! The constructors of the tests reject samples with missing (NaN) values.
+
+use statistics_problems::anova::{AnovaError, OneWayAnova, RepeatedMeasuresAnova};
+use statistics_problems::energy_hypothesis::{EnergyError, EnergyHypothesis};
+use statistics_problems::equivalence_hypothesis::{TostError, TostVariances};
+use statistics_problems::moment_hypothesis::{MomentError, SkewnessHypothesis};
+use statistics_problems::multivariate::{MultiSample, MultivariateError};
+use statistics_problems::ppcc_hypothesis::{PpccError, PpccHypothesis};
+use statistics_problems::reliability::{CronbachAlpha, ReliabilityError};
+use statistics_problems::time_series::{MannKendallHypothesis, TSError};
+
+const SAMPLE: [f64; 6] = [1.2, f64::NAN, 3.4, 2.8, 1.9, 4.1];
+const COMPLETE: [f64; 6] = [2.2, 1.7, 3.1, 2.5, 3.9, 2.0];
+
+#[test]
+fn univariate_tests_reject_missing_values() {
+    assert_eq!(
+        SkewnessHypothesis::new(&SAMPLE, 0.05).err(),
+        Some(MomentError::ValueMissing)
+    );
+    assert_eq!(
+        PpccHypothesis::new(&SAMPLE, 0.05).err(),
+        Some(PpccError::ValueMissing)
+    );
+    assert_eq!(
+        MannKendallHypothesis::new(&SAMPLE, 0.05).err(),
+        Some(TSError::ValueMissing)
+    );
+    assert_eq!(
+        TostVariances::new(&COMPLETE, &SAMPLE, 1.5, 0.05).err(),
+        Some(TostError::ValueMissing)
+    );
+}
+
+#[test]
+fn grouped_tests_reject_missing_values() {
+    let groups = vec![SAMPLE.to_vec(), COMPLETE.to_vec()];
+
+    assert_eq!(
+        OneWayAnova::new(&groups, 0.05).err(),
+        Some(AnovaError::ValueMissing)
+    );
+    assert_eq!(
+        RepeatedMeasuresAnova::new(&groups, 0.05).err(),
+        Some(AnovaError::ValueMissing)
+    );
+    assert_eq!(
+        CronbachAlpha::new(&groups, 0.05).err(),
+        Some(ReliabilityError::ValueMissing)
+    );
+}
+
+#[test]
+fn multivariate_samples_reject_missing_values() {
+    let observations = vec![vec![1., 2.], vec![f64::NAN, 3.], vec![2., 5.]];
+
+    assert_eq!(
+        MultiSample::new(&observations).err(),
+        Some(MultivariateError::ValueMissing)
+    );
+    assert_eq!(
+        EnergyHypothesis::new(&observations, &observations, 0.05).err(),
+        Some(EnergyError::ValueMissing)
+    );
+}
@@ -0,0 +1,52 @@
+//! The tests refuse to decide on a statistic that is not finite.
+
+use statistics_problems::normal_distribution_hypothesis::{
+    CompleteNDHProblemSituation, IncompleteNDHProblemSituation, NDHError, NDHProblemSituation,
+    NormalDistributionHypothesis,
+};
+use statistics_problems::variance_value_hypothesis::{VarianceValueError, VarianceValueHypothesis};
+
+const RANGES: [(f64, f64); 5] = [(0., 1.), (1., 2.), (2., 3.), (3., 4.), (4., 5.)];
+
+fn normality(situation: impl NDHProblemSituation + 'static) -> Result<bool, NDHError> {
+    NormalDistributionHypothesis::new(Box::new(situation))?.solve()
+}
+
+#[test]
+fn degenerate_grouped_sample_is_not_decided() {
+    let situation =
+        IncompleteNDHProblemSituation::new(&RANGES, &[0., 0., 10., 0., 0.], 0.05).unwrap();
+
+    assert_eq!(normality(situation), Err(NDHError::StatisticNotFinite));
+}
+
+#[test]
+fn zero_expected_count_is_not_decided() {
+    let situation =
+        CompleteNDHProblemSituation::new(&[6., 12., 18., 9., 5.], &[0., 14., 18., 10., 8.], 0.05)
+            .unwrap();
+
+    assert_eq!(normality(situation), Err(NDHError::StatisticNotFinite));
+}
+
+#[test]
+fn missing_observed_count_is_not_decided() {
+    let situation = CompleteNDHProblemSituation::new(
+        &[6., 12., f64::NAN, 9., 5.],
+        &[5., 13., 18., 9., 5.],
+        0.05,
+    )
+    .unwrap();
+
+    assert_eq!(normality(situation), Err(NDHError::StatisticNotFinite));
+}
+
+#[test]
+fn infinite_value_is_not_decided() {
+    let hypothesis = VarianceValueHypothesis::new(&[1.5, f64::INFINITY, 2.5], 1., 0.05).unwrap();
+
+    assert_eq!(
+        hypothesis.solve(),
+        Err(VarianceValueError::StatisticNotFinite)
+    );
+}
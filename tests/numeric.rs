@@ -0,0 +1,81 @@
+//! The compensated helpers must reproduce exact references where naive accumulation drifts.
+
+use statistics_problems::numeric::{Welford, compensated_sum, mean, unbiased_variance};
+
+/// Measurements around `1e9` whose deviations from the mean `1e9 + 10` are `-6, -3, 3, 6`,
+/// all representable exactly, so the sum of squared deviations is exactly `90` per block.
+fn offset_sample(blocks: usize) -> Vec<f64> {
+    [4., 7., 13., 16.]
+        .iter()
+        .cycle()
+        .take(4 * blocks)
+        .map(|x| 1e9 + x)
+        .collect()
+}
+
+#[test]
+fn sum_of_tenths_is_correctly_rounded() {
+    // Exactly `10⁶ · fl(0.1) = 100000.0000000000055511151231257827`, which rounds to `10⁵`;
+    // the naive sum drifts to `100000.00000133288`.
+    assert_eq!(compensated_sum(std::iter::repeat_n(0.1, 1_000_000)), 1e5);
+}
+
+#[test]
+fn alternating_sum_keeps_the_small_terms() {
+    // Every `1` is absorbed by `±1e20` in a naive sum, which ends at `0`.
+    let values = [1e20, 1., -1e20].into_iter().cycle().take(3 * 100_000);
+
+    assert_eq!(compensated_sum(values), 100_000.);
+}
+
+#[test]
+fn alternating_signs_cancel_exactly() {
+    // `Σ (-1)^k (1e9 + k / 8)` over `k < 2·10⁵` pairs into `-1 / 8` per pair.
+    let values = (0..200_000).map(|k| {
+        let value = 1e9 + k as f64 / 8.;
+        if k % 2 == 0 { value } else { -value }
+    });
+
+    assert_eq!(compensated_sum(values), -12_500.);
+}
+
+#[test]
+fn infinite_terms_keep_the_sum_infinite() {
+    // The compensation of an infinite sum is `∞ - ∞`, which must not turn the sum into NaN.
+    assert_eq!(compensated_sum([1., f64::INFINITY, 2.]), f64::INFINITY);
+    assert_eq!(
+        compensated_sum([f64::NEG_INFINITY, 1e300, 1.]),
+        f64::NEG_INFINITY
+    );
+    assert_eq!(
+        compensated_sum([f64::INFINITY, f64::INFINITY]),
+        f64::INFINITY
+    );
+    assert!(compensated_sum([f64::INFINITY, f64::NEG_INFINITY]).is_nan());
+}
+
+#[test]
+fn offset_variance_is_exact() {
+    let sample = offset_sample(25_000);
+    let n = sample.len() as f64;
+
+    assert_eq!(mean(&sample), 1e9 + 10.);
+    let variance = 90. * 25_000. / (n - 1.);
+    assert!((unbiased_variance(&sample) - variance).abs() < 1e-12 * variance);
+}
+
+#[test]
+fn streaming_offset_variance_is_exact() {
+    let sample = offset_sample(25_000);
+    let n = sample.len() as f64;
+    let mut welford = Welford::new();
+    welford.extend(sample.iter().copied());
+
+    assert_eq!(welford.count(), sample.len());
+    // The running mean carries a rounding error of a few ulps of `1e9`; the naive
+    // `Σx² / n - x̄²` comes out negative on this sample.
+    assert!((welford.mean() - (1e9 + 10.)).abs() < 1e-14 * 1e9);
+    assert!((welford.biased_variance() - 22.5).abs() < 1e-9 * 22.5);
+    let variance = 22.5 * n / (n - 1.);
+    assert!((welford.unbiased_variance() - variance).abs() < 1e-9 * variance);
+}
@@ -0,0 +1,145 @@
+//! Every hypothesis statement of the crate must be translated, leaving no English words behind,
+//! and the derivations refuse the samples they cannot be written for.
+
+use std::path::Path;
+
+use statistics_problems::hypotheses::Hypotheses;
+use statistics_problems::normal_distribution_hypothesis::{
+    CompleteNDHProblemSituation, IncompleteNDHProblemSituation, NDHError, NDHProblemSituation,
+    NormalDistributionHypothesis, SpecifiedProportionsGoodnessOfFit,
+};
+use statistics_problems::report::{self, ExpectedFrequencyStyle, Locale};
+
+/// Latin symbols of the statements, which are kept as they are.
+const SYMBOLS: [&str; 3] = ["AUC", "Me", "Var"];
+
+/// String literals within the arguments of the `Hypotheses::new` calls of a source file.
+fn statements(source: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    for (start, _) in source.match_indices("Hypotheses::new(") {
+        let mut depth = 0;
+        let mut literal: Option<String> = None;
+        for c in source[start + "Hypotheses::new".len()..].chars() {
+            match (&mut literal, c) {
+                (Some(text), '"') => {
+                    statements.push(std::mem::take(text));
+                    literal = None;
+                }
+                (Some(text), c) => text.push(c),
+                (None, '"') => literal = Some(String::new()),
+                (None, '(') => depth += 1,
+                (None, ')') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                (None, _) => {}
+            }
+        }
+    }
+
+    statements
+}
+
+/// Format arguments replaced by a number, as they are filled in by the crate.
+fn formatted(statement: &str) -> String {
+    let mut formatted = String::new();
+    let mut argument = false;
+    for c in statement.chars() {
+        match c {
+            '{' => argument = true,
+            '}' if argument => {
+                argument = false;
+                formatted.push('2');
+            }
+            _ if !argument => formatted.push(c),
+            _ => {}
+        }
+    }
+
+    formatted
+}
+
+fn sources(directory: &Path, statements_found: &mut Vec<String>) {
+    for entry in std::fs::read_dir(directory).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            sources(&path, statements_found);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            statements_found.extend(statements(&std::fs::read_to_string(&path).unwrap()));
+        }
+    }
+}
+
+fn assert_translated(statement: &str) {
+    let translated = report::statement(statement, Locale::Russian);
+    let english: Vec<_> = translated
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .filter(|word| word.len() > 1 && !SYMBOLS.contains(word))
+        .collect();
+    assert!(
+        english.is_empty(),
+        "{statement:?} is translated into {translated:?}, keeping {english:?}"
+    );
+}
+
+#[test]
+fn every_statement_is_translated() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut statements_found = Vec::new();
+    sources(&root.join("src"), &mut statements_found);
+    sources(&root.join("core").join("src"), &mut statements_found);
+    assert!(statements_found.len() > 50);
+
+    for statement in statements_found {
+        assert_translated(&formatted(&statement));
+    }
+}
+
+#[test]
+fn goodness_of_fit_statements_are_translated() {
+    let situations: [Box<dyn NDHProblemSituation>; 3] = [
+        Box::new(
+            CompleteNDHProblemSituation::new(&[6., 18., 20., 8.], &[8., 16., 18., 10.], 0.05)
+                .unwrap(),
+        ),
+        Box::new(
+            SpecifiedProportionsGoodnessOfFit::new(&[6., 18., 20., 8.], &[0.25; 4], 0.05).unwrap(),
+        ),
+        Box::new(
+            IncompleteNDHProblemSituation::new(
+                &[(0., 2.), (2., 4.), (4., 6.), (6., 8.)],
+                &[6., 18., 20., 8.],
+                0.05,
+            )
+            .unwrap(),
+        ),
+    ];
+
+    for situation in situations {
+        let Hypotheses {
+            null, alternative, ..
+        } = NormalDistributionHypothesis::new(situation)
+            .unwrap()
+            .outcome()
+            .unwrap()
+            .hypotheses;
+        assert_translated(&null);
+        assert_translated(&alternative);
+    }
+}
+
+#[test]
+fn expected_frequencies_of_a_degenerate_sample_are_refused() {
+    let situation =
+        IncompleteNDHProblemSituation::new(&[(0., 1.), (1., 2.), (2., 3.)], &[0., 10., 0.], 0.05)
+            .unwrap();
+
+    for style in [ExpectedFrequencyStyle::Cdf, ExpectedFrequencyStyle::Laplace] {
+        assert_eq!(
+            report::expected_frequencies(&situation, style),
+            Err(NDHError::SampleDegenerate)
+        );
+    }
+}
@@ -0,0 +1,47 @@
+//! Degenerate ROC problems whose standard errors vanish.
+
+use statistics_problems::roc::{AucHypothesis, DeLongHypothesis};
+
+const LABELS: [bool; 6] = [true, true, true, false, false, false];
+
+#[test]
+fn tied_scores_accept_no_discrimination() {
+    let outcome = AucHypothesis::new(&[0.5; 6], &LABELS, 0.05)
+        .unwrap()
+        .outcome()
+        .unwrap();
+
+    assert_eq!(outcome.auc, 0.5);
+    assert_eq!(outcome.standard_error, 0.);
+    assert_eq!(outcome.z_observed, 0.);
+    assert_eq!(outcome.p_value, 1.);
+    assert!(outcome.accepted);
+}
+
+#[test]
+fn separating_scores_reject_no_discrimination() {
+    let outcome = AucHypothesis::new(&[6., 5., 4., 3., 2., 1.], &LABELS, 0.05)
+        .unwrap()
+        .outcome()
+        .unwrap();
+
+    assert_eq!(outcome.auc, 1.);
+    assert_eq!(outcome.standard_error, 0.);
+    assert_eq!(outcome.p_value, 0.);
+    assert!(!outcome.accepted);
+}
+
+#[test]
+fn identical_scores_accept_equal_areas() {
+    let scores = [0.9, 0.4, 0.7, 0.3, 0.8, 0.1];
+    let outcome = DeLongHypothesis::new(&scores, &scores, &LABELS, 0.05)
+        .unwrap()
+        .outcome()
+        .unwrap();
+
+    assert_eq!(outcome.difference, 0.);
+    assert_eq!(outcome.standard_error, 0.);
+    assert_eq!(outcome.z_observed, 0.);
+    assert_eq!(outcome.p_value, 1.);
+    assert!(outcome.accepted);
+}
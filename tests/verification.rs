@@ -0,0 +1,99 @@
+//! Statistical regression tests: the simulated type-I error rates must stay consistent with the significance.
+
+use statistics_problems::rng::RngSource;
+use statistics_problems::same_mean_hypothesis::Variances;
+use statistics_problems::verification::{self, TypeOneErrorEstimate};
+
+const SIGNIFICANCE: f64 = 0.05;
+const REPLICATIONS: usize = 4000;
+
+/// Four standard errors keep the false alarm rate of each test below `1e-4`.
+fn assert_size(estimate: TypeOneErrorEstimate, significance: f64) {
+    let deviation = estimate.deviation(significance);
+    assert!(
+        deviation.abs() < 4.,
+        "rate {} deviates from {significance} by {deviation:.2} standard errors",
+        estimate.rate
+    );
+}
+
+/// The grouped test is liberal: its rate must not fall below `α` and must stay clearly below `3α`.
+#[test]
+fn normal_distribution_stays_below_thrice_the_significance() {
+    let estimate =
+        verification::normal_distribution(200, SIGNIFICANCE, REPLICATIONS, RngSource::Seed(8))
+            .unwrap();
+    assert!(estimate.deviation(SIGNIFICANCE) > -4.);
+    assert!(
+        estimate.deviation(3. * SIGNIFICANCE) < -4.,
+        "rate {} is not below {}",
+        estimate.rate,
+        3. * SIGNIFICANCE
+    );
+}
+
+#[test]
+fn shapiro_wilk_holds_its_size() {
+    let estimate =
+        verification::shapiro_wilk(30, SIGNIFICANCE, REPLICATIONS, RngSource::Seed(1)).unwrap();
+    assert_size(estimate, SIGNIFICANCE);
+}
+
+#[test]
+fn students_t_holds_its_size() {
+    let estimate = verification::same_mean(
+        10,
+        15,
+        Variances::Equal,
+        SIGNIFICANCE,
+        REPLICATIONS,
+        RngSource::Seed(2),
+    )
+    .unwrap();
+    assert_size(estimate, SIGNIFICANCE);
+}
+
+#[test]
+fn welchs_t_holds_its_size() {
+    let estimate = verification::same_mean(
+        10,
+        15,
+        Variances::Unequal,
+        SIGNIFICANCE,
+        REPLICATIONS,
+        RngSource::Seed(3),
+    )
+    .unwrap();
+    assert_size(estimate, SIGNIFICANCE);
+}
+
+#[test]
+fn mann_whitney_holds_its_size() {
+    let estimate =
+        verification::mann_whitney(20, 25, SIGNIFICANCE, REPLICATIONS, RngSource::Seed(4)).unwrap();
+    assert_size(estimate, SIGNIFICANCE);
+}
+
+#[test]
+fn cramer_von_mises_holds_its_size() {
+    let estimate =
+        verification::cramer_von_mises(20, 25, SIGNIFICANCE, REPLICATIONS, RngSource::Seed(5))
+            .unwrap();
+    assert_size(estimate, SIGNIFICANCE);
+}
+
+#[test]
+fn independence_holds_its_size() {
+    let estimate =
+        verification::independence(3, 4, 200, SIGNIFICANCE, REPLICATIONS, RngSource::Seed(6))
+            .unwrap();
+    assert_size(estimate, SIGNIFICANCE);
+}
+
+#[test]
+fn same_variance_rejects_at_twice_the_significance() {
+    let estimate =
+        verification::same_variance(10, 15, SIGNIFICANCE, REPLICATIONS, RngSource::Seed(7))
+            .unwrap();
+    assert_size(estimate, 2. * SIGNIFICANCE);
+}